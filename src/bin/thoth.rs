@@ -1,8 +1,18 @@
 extern crate clap;
-use clap::{crate_authors, crate_version, App, AppSettings, Arg};
+use std::fs;
+use std::io::Write;
+
+use clap::{crate_authors, crate_version, App, AppSettings, Arg, ArgGroup};
 use dialoguer::{console::Term, theme::ColorfulTheme, Input, MultiSelect, Password, Select};
 use dotenv::dotenv;
+use futures::StreamExt;
+use uuid::Uuid;
 
+use thoth::formats::all_formats;
+use thoth::formats::generate_for_format;
+use thoth::formats::parse_work_statuses;
+use thoth::formats::DEFAULT_EXPORT_STATUSES;
+use thoth::onix::OnixHeader;
 use thoth::server::api::start_server as api_server;
 use thoth::server::app::start_server as app_server;
 use thoth_api::account::model::AccountData;
@@ -14,8 +24,15 @@ use thoth_api::account::service::update_password;
 use thoth_api::db::establish_connection;
 use thoth_api::db::run_migrations;
 use thoth_api::errors::Result;
+use thoth_api::errors::ThothError;
+use thoth_client::work::get_work;
+use thoth_client::work::stream_works;
+use thoth_client::work::work_ids_query::WorkStatus;
+use thoth_client::work::work_query::WorkQueryWork;
+use thoth_client::work::StreamParameters;
 
 fn main() -> Result<()> {
+    let format_ids: Vec<&str> = all_formats().iter().map(|format| format.id).collect();
     let matches = App::new(env!("CARGO_PKG_NAME"))
         .version(crate_version!())
         .author(crate_authors!("\n"))
@@ -69,6 +86,83 @@ fn main() -> Result<()> {
                 .subcommand(App::new("register").about("Create a new user account"))
                 .subcommand(App::new("password").about("Reset a password")),
         )
+        .subcommand(
+            App::new("export")
+                .about("Export works from a Thoth API without going through the export server")
+                .arg(
+                    Arg::with_name("format")
+                        .short("f")
+                        .long("format")
+                        .value_name("FORMAT")
+                        .help("Format to export to")
+                        .possible_values(&format_ids)
+                        .required(true)
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("work-id")
+                        .short("w")
+                        .long("work-id")
+                        .value_name("WORK_ID")
+                        .help("ID of a work to export. May be given more than once")
+                        .multiple(true)
+                        .number_of_values(1)
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("work-ids-file")
+                        .long("work-ids-file")
+                        .value_name("FILE")
+                        .help("File listing the IDs of works to export, one per line")
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("publisher-id")
+                        .short("p")
+                        .long("publisher-id")
+                        .value_name("PUBLISHER_ID")
+                        .help(
+                            "ID of a publisher whose works to export. May be given more than once",
+                        )
+                        .multiple(true)
+                        .number_of_values(1)
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("status")
+                        .short("s")
+                        .long("status")
+                        .value_name("STATUSES")
+                        .default_value(DEFAULT_EXPORT_STATUSES)
+                        .help(
+                            "Comma separated statuses of the publishers' works to export, e.g. active,forthcoming",
+                        )
+                        .takes_value(true),
+                )
+                .group(
+                    ArgGroup::with_name("works")
+                        .args(&["work-id", "work-ids-file", "publisher-id"])
+                        .multiple(true)
+                        .required(true),
+                )
+                .arg(
+                    Arg::with_name("endpoint")
+                        .short("e")
+                        .long("endpoint")
+                        .value_name("URL")
+                        .default_value("http://localhost:8000/graphql")
+                        .help("GraphQL endpoint of the Thoth API to export from")
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("output")
+                        .short("o")
+                        .long("output")
+                        .value_name("FILE")
+                        .help("File to write the export to, instead of standard output")
+                        .takes_value(true),
+                ),
+        )
         .get_matches();
 
     match matches.subcommand() {
@@ -185,6 +279,88 @@ fn main() -> Result<()> {
             }
             _ => unreachable!(),
         },
+        ("export", Some(export_matches)) => {
+            let format_id = export_matches.value_of("format").unwrap();
+            let endpoint = export_matches.value_of("endpoint").unwrap();
+            let mut work_ids = parse_uuids(export_matches.values_of("work-id"))?;
+            if let Some(path) = export_matches.value_of("work-ids-file") {
+                let contents = fs::read_to_string(path)?;
+                work_ids.extend(parse_uuids(Some(
+                    contents
+                        .lines()
+                        .map(str::trim)
+                        .filter(|line| !line.is_empty()),
+                ))?);
+            }
+            let publisher_ids = parse_uuids(export_matches.values_of("publisher-id"))?;
+            let status = export_matches.value_of("status").unwrap();
+            let work_statuses = parse_work_statuses(status).map_err(|status| {
+                ThothError::InvalidInput(format!("{} is not a work status", status))
+            })?;
+            let mut runtime = tokio::runtime::Runtime::new()?;
+            let works = runtime.block_on(fetch_export_works(
+                work_ids,
+                publisher_ids,
+                work_statuses,
+                endpoint.to_string(),
+            ))?;
+            dotenv().ok();
+            let body = generate_for_format(format_id, &works, &OnixHeader::from_env())?;
+            match export_matches.value_of("output") {
+                Some(path) => fs::write(path, body)?,
+                None => std::io::stdout().write_all(&body)?,
+            }
+            Ok(())
+        }
         _ => unreachable!(),
     }
 }
+
+fn parse_uuids<'a, I: Iterator<Item = &'a str>>(values: Option<I>) -> Result<Vec<Uuid>> {
+    values
+        .into_iter()
+        .flatten()
+        .map(|value| {
+            Uuid::parse_str(value).map_err(|_| {
+                ThothError::InvalidInput(format!("{} is not a valid ID", value)).into()
+            })
+        })
+        .collect()
+}
+
+/// Fetch the listed works, then every work of the listed publishers with one of the given
+/// statuses, reporting progress on standard error. Fails on the first work that cannot be fetched.
+async fn fetch_export_works(
+    work_ids: Vec<Uuid>,
+    publisher_ids: Vec<Uuid>,
+    work_statuses: Vec<WorkStatus>,
+    thoth_url: String,
+) -> Result<Vec<WorkQueryWork>> {
+    let mut works = Vec::new();
+    for work_id in work_ids {
+        let work = get_work(work_id, thoth_url.clone()).await.map_err(|e| {
+            ThothError::InternalError(format!("Could not fetch work {}: {}", work_id, e))
+        })?;
+        works.push(work);
+    }
+    if !publisher_ids.is_empty() {
+        let mut pages = Box::pin(stream_works(
+            Some(publisher_ids),
+            StreamParameters {
+                work_statuses: Some(work_statuses),
+                ..StreamParameters::default()
+            },
+            thoth_url,
+        ));
+        while let Some(page) = pages.next().await {
+            let page = page?;
+            eprintln!(
+                "Fetched {} of {} works",
+                page.offset + page.works.len() as i64,
+                page.total
+            );
+            works.extend(page.works);
+        }
+    }
+    Ok(works)
+}
@@ -0,0 +1,82 @@
+use std::collections::HashMap;
+
+use thoth_api::errors;
+use thoth_client::contributors::contributors_query::ContributorsQueryWorks;
+use thoth_client::contributors::contributors_query::ContributorsQueryWorksContributionsContributor;
+use uuid::Uuid;
+
+use crate::doab::quote_value;
+
+/// Column headings of a publisher's contributor list, in order
+const CONTRIBUTOR_COLUMNS: [&str; 5] = [
+    "contributor_id",
+    "full_name",
+    "orcid",
+    "website",
+    "contributions",
+];
+
+/// A row of a publisher's contributor list
+#[derive(Debug, PartialEq)]
+pub struct ContributorRow {
+    pub contributor_id: Uuid,
+    pub full_name: String,
+    pub orcid: String,
+    pub website: String,
+    /// Number of contributions the contributor made to the publisher's works
+    pub contributions: usize,
+}
+
+impl ContributorRow {
+    fn new(contributor: &ContributorsQueryWorksContributionsContributor) -> Self {
+        ContributorRow {
+            contributor_id: contributor.contributor_id,
+            full_name: contributor.full_name.clone(),
+            orcid: contributor.orcid.clone().unwrap_or_default(),
+            website: contributor.website.clone().unwrap_or_default(),
+            contributions: 0,
+        }
+    }
+
+    fn values(&self) -> [String; 5] {
+        [
+            self.contributor_id.to_string(),
+            self.full_name.clone(),
+            self.orcid.clone(),
+            self.website.clone(),
+            self.contributions.to_string(),
+        ]
+    }
+}
+
+/// One row per contributor credited on any of the given works, however many contributions they
+/// made, ordered by name
+pub fn contributor_rows(works: &[ContributorsQueryWorks]) -> Vec<ContributorRow> {
+    let mut rows: HashMap<Uuid, ContributorRow> = HashMap::new();
+    for contribution in works.iter().flat_map(|w| w.contributions.iter()) {
+        let contributor = &contribution.contributor;
+        rows.entry(contributor.contributor_id)
+            .or_insert_with(|| ContributorRow::new(contributor))
+            .contributions += 1;
+    }
+    let mut rows: Vec<ContributorRow> = rows.into_iter().map(|(_, row)| row).collect();
+    // contributors sharing a name are kept in a stable order by their ID
+    rows.sort_by(|a, b| {
+        a.full_name
+            .cmp(&b.full_name)
+            .then(a.contributor_id.cmp(&b.contributor_id))
+    });
+    rows
+}
+
+/// Write the contributors of the given works as CSV, headed by the column names
+pub fn generate_contributors_csv(works: &[ContributorsQueryWorks]) -> errors::Result<Vec<u8>> {
+    let mut csv = CONTRIBUTOR_COLUMNS.join(",");
+    csv.push_str("\r\n");
+    for row in contributor_rows(works) {
+        let values: Vec<String> = row.values().iter().map(|v| quote_value(v)).collect();
+        csv.push_str(&values.join(","));
+        csv.push_str("\r\n");
+    }
+    Ok(csv.into_bytes())
+}
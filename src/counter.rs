@@ -0,0 +1,163 @@
+use std::collections::HashMap;
+
+use chrono::Datelike;
+use serde_derive::Deserialize;
+use thoth_client::counter::counter_query::CounterQueryWorks;
+use thoth_client::counter::counter_query::PublicationType;
+use thoth_client::counter::counter_query::WorkType;
+use uuid::Uuid;
+
+/// Namespace used for Thoth identifiers in `Publisher_ID` and `Proprietary_ID` columns
+const PROPRIETARY_NAMESPACE: &str = "thoth";
+/// Platform reported when the caller does not name the one serving the content
+pub const DEFAULT_PLATFORM: &str = "Thoth";
+const DOI_RESOLVER: &str = "https://doi.org/";
+/// Column headings of a COUNTER Release 5 Title Report (TR), in order
+const TITLE_REPORT_COLUMNS: [&str; 17] = [
+    "Title",
+    "Publisher",
+    "Publisher_ID",
+    "Platform",
+    "DOI",
+    "Proprietary_ID",
+    "ISBN",
+    "Print_ISSN",
+    "Online_ISSN",
+    "URI",
+    "Data_Type",
+    "Section_Type",
+    "YOP",
+    "Access_Type",
+    "Access_Method",
+    "Metric_Type",
+    "Reporting_Period_Total",
+];
+
+/// Usage figures to merge into the report, keyed on work ID
+#[derive(Deserialize, Default)]
+pub struct CounterUsage {
+    pub metric_type: Option<String>,
+    #[serde(default)]
+    pub counts: HashMap<Uuid, i64>,
+}
+
+/// One title-level row of a COUNTER Title Report
+pub struct TitleReportRow {
+    pub title: String,
+    pub publisher: String,
+    pub publisher_id: String,
+    pub platform: String,
+    pub doi: String,
+    pub proprietary_id: String,
+    pub isbn: String,
+    pub uri: String,
+    pub data_type: String,
+    pub section_type: String,
+    pub yop: String,
+    pub access_type: String,
+    pub access_method: String,
+    pub metric_type: String,
+    pub reporting_period_total: String,
+}
+
+impl TitleReportRow {
+    pub fn new(work: &CounterQueryWorks, platform: &str, usage: &CounterUsage) -> Self {
+        // COUNTER expects the bare DOI, without the resolver
+        let doi = work
+            .doi
+            .as_ref()
+            .map(|d| d.trim_start_matches(DOI_RESOLVER).to_string())
+            .unwrap_or_default();
+        let section_type = match work.work_type {
+            WorkType::BOOK_CHAPTER => "Chapter",
+            _ => "Book",
+        };
+        let access_type = match work.license {
+            Some(_) => "OA_Gold",
+            None => "Controlled",
+        };
+        let (metric_type, reporting_period_total) = match usage.counts.get(&work.work_id) {
+            Some(count) => (
+                usage.metric_type.clone().unwrap_or_default(),
+                count.to_string(),
+            ),
+            None => (String::new(), String::new()),
+        };
+        TitleReportRow {
+            title: work.full_title.clone(),
+            publisher: work.imprint.publisher.publisher_name.clone(),
+            publisher_id: format!(
+                "{}:{}",
+                PROPRIETARY_NAMESPACE, work.imprint.publisher.publisher_id
+            ),
+            platform: platform.to_string(),
+            doi,
+            proprietary_id: format!("{}:{}", PROPRIETARY_NAMESPACE, work.work_id),
+            isbn: main_isbn(work).unwrap_or_default(),
+            uri: work.landing_page.clone().unwrap_or_default(),
+            data_type: "Book".to_string(),
+            section_type: section_type.to_string(),
+            yop: work
+                .publication_date
+                .map(|d| d.year().to_string())
+                .unwrap_or_default(),
+            access_type: access_type.to_string(),
+            access_method: "Regular".to_string(),
+            metric_type,
+            reporting_period_total,
+        }
+    }
+
+    fn values(&self) -> [&str; 17] {
+        [
+            &self.title,
+            &self.publisher,
+            &self.publisher_id,
+            &self.platform,
+            &self.doi,
+            &self.proprietary_id,
+            &self.isbn,
+            "",
+            "",
+            &self.uri,
+            &self.data_type,
+            &self.section_type,
+            &self.yop,
+            &self.access_type,
+            &self.access_method,
+            &self.metric_type,
+            &self.reporting_period_total,
+        ]
+    }
+}
+
+/// The ISBN COUNTER should identify a title by: the PDF one, or the first available otherwise
+fn main_isbn(work: &CounterQueryWorks) -> Option<String> {
+    work.publications
+        .iter()
+        .find(|p| p.publication_type.eq(&PublicationType::PDF) && p.isbn.is_some())
+        .or_else(|| work.publications.iter().find(|p| p.isbn.is_some()))
+        .and_then(|p| p.isbn.clone())
+}
+
+/// Write title report rows as COUNTER tabular (tab-separated) data, headed by the column names
+pub fn generate_title_report(
+    works: &[CounterQueryWorks],
+    platform: &str,
+    usage: &CounterUsage,
+) -> String {
+    let mut report = TITLE_REPORT_COLUMNS.join("\t");
+    report.push('\n');
+    for work in works {
+        let row = TitleReportRow::new(work, platform, usage);
+        let values: Vec<String> = row.values().iter().map(|v| clean_value(v)).collect();
+        report.push_str(&values.join("\t"));
+        report.push('\n');
+    }
+    report
+}
+
+// Tabs and line breaks would shift columns or split rows in tabular output
+fn clean_value(value: &str) -> String {
+    value.replace(|c| c == '\t' || c == '\n' || c == '\r', " ")
+}
@@ -0,0 +1,135 @@
+use thoth_api::errors;
+use thoth_client::work::work_query::ContributionType;
+use thoth_client::work::work_query::SubjectType;
+use thoth_client::work::work_query::WorkQueryWork;
+
+/// Column headings of a DOAB metadata upload, in order, spelled as DOAB requires them
+const DOAB_COLUMNS: [&str; 7] = [
+    "handle",
+    "title",
+    "authors",
+    "abstract",
+    "license",
+    "subjects",
+    "fulltext_url",
+];
+/// Separator between the values of a column that holds several, such as authors or subjects
+const VALUE_SEPARATOR: &str = "; ";
+
+/// Creative Commons licence URL fragments and the DOAB vocabulary term each one maps to. More
+/// specific fragments come first so that e.g. "by-nc-sa" is not taken for "by-nc".
+const LICENSE_TERMS: [(&str, &str); 7] = [
+    ("/by-nc-nd/", "CC BY-NC-ND"),
+    ("/by-nc-sa/", "CC BY-NC-SA"),
+    ("/by-nc/", "CC BY-NC"),
+    ("/by-nd/", "CC BY-ND"),
+    ("/by-sa/", "CC BY-SA"),
+    ("/by/", "CC BY"),
+    ("/zero/", "CC0"),
+];
+
+/// A row of a DOAB metadata upload
+#[derive(Debug, PartialEq)]
+pub struct DoabRow {
+    /// Left empty: DOAB assigns a handle to each title when it is ingested
+    pub handle: String,
+    pub title: String,
+    pub authors: String,
+    pub r#abstract: String,
+    pub license: String,
+    pub subjects: String,
+    pub fulltext_url: String,
+}
+
+impl DoabRow {
+    /// Describe a work for DOAB, which only accepts open access titles: works without a
+    /// recognised open licence or without a canonical location for their full text are left out
+    pub fn new(work: &WorkQueryWork) -> Option<Self> {
+        let license = work.license.as_deref().and_then(doab_license)?;
+        let fulltext_url = work.canonical_location()?;
+        Some(DoabRow {
+            handle: String::new(),
+            title: work.full_title.clone(),
+            authors: authors(work).join(VALUE_SEPARATOR),
+            r#abstract: work.long_abstract.clone().unwrap_or_default(),
+            license: license.to_string(),
+            subjects: keywords(work).join(VALUE_SEPARATOR),
+            fulltext_url: fulltext_url.to_string(),
+        })
+    }
+
+    fn values(&self) -> [&str; 7] {
+        [
+            &self.handle,
+            &self.title,
+            &self.authors,
+            &self.r#abstract,
+            &self.license,
+            &self.subjects,
+            &self.fulltext_url,
+        ]
+    }
+}
+
+/// The DOAB term for a Creative Commons licence URL, if it is one DOAB accepts
+fn doab_license(license: &str) -> Option<&'static str> {
+    let license = license.to_lowercase();
+    if !license.contains("creativecommons.org/") {
+        return None;
+    }
+    LICENSE_TERMS
+        .iter()
+        .find(|(fragment, _)| license.contains(fragment))
+        .map(|(_, term)| *term)
+}
+
+/// Names of the work's authors, or of its editors if it has no authors, inverted as DOAB lists them
+fn authors(work: &WorkQueryWork) -> Vec<String> {
+    let author_type = if work
+        .contributions
+        .iter()
+        .any(|c| c.contribution_type.eq(&ContributionType::AUTHOR))
+    {
+        ContributionType::AUTHOR
+    } else {
+        ContributionType::EDITOR
+    };
+    work.contributions
+        .iter()
+        .filter(|c| c.contribution_type.eq(&author_type))
+        .map(|c| match &c.first_name {
+            Some(first_name) => format!("{}, {}", c.last_name, first_name),
+            None => c.last_name.clone(),
+        })
+        .collect()
+}
+
+fn keywords(work: &WorkQueryWork) -> Vec<&str> {
+    work.subjects
+        .iter()
+        .filter(|s| s.subject_type.eq(&SubjectType::KEYWORD))
+        .map(|s| s.subject_code.as_str())
+        .collect()
+}
+
+/// Write the open access works among those given as a DOAB metadata upload (CSV, headed by the
+/// column names). Works DOAB would not accept are skipped.
+pub fn generate_doab(works: &[WorkQueryWork]) -> errors::Result<Vec<u8>> {
+    let mut csv = DOAB_COLUMNS.join(",");
+    csv.push_str("\r\n");
+    for row in works.iter().filter_map(DoabRow::new) {
+        let values: Vec<String> = row.values().iter().map(|v| quote_value(v)).collect();
+        csv.push_str(&values.join(","));
+        csv.push_str("\r\n");
+    }
+    Ok(csv.into_bytes())
+}
+
+// Values holding a separator, quote or line break are quoted, with quotes doubled (RFC 4180)
+pub(crate) fn quote_value(value: &str) -> String {
+    if value.contains(|c| c == ',' || c == '"' || c == '\n' || c == '\r') {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
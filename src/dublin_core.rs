@@ -0,0 +1,91 @@
+use std::io::Write;
+
+use thoth_client::work::work_query::ContributionType;
+use thoth_client::work::work_query::LanguageRelation;
+use thoth_client::work::work_query::WorkQueryWork;
+use xml::writer::{EventWriter, Result, XmlEvent};
+
+const OAI_DC_NS: &str = "http://www.openarchives.org/OAI/2.0/oai_dc/";
+const DC_NS: &str = "http://purl.org/dc/elements/1.1/";
+const XSI_NS: &str = "http://www.w3.org/2001/XMLSchema-instance";
+const OAI_DC_SCHEMA_LOCATION: &str =
+    "http://www.openarchives.org/OAI/2.0/oai_dc/ http://www.openarchives.org/OAI/2.0/oai_dc.xsd";
+
+/// Write a work as an unqualified Dublin Core `oai_dc:dc` record
+pub fn write_dublin_core<W: Write>(w: &mut EventWriter<W>, work: &WorkQueryWork) -> Result<()> {
+    w.write(
+        XmlEvent::start_element("oai_dc:dc")
+            .ns("oai_dc", OAI_DC_NS)
+            .ns("dc", DC_NS)
+            .ns("xsi", XSI_NS)
+            .attr("xsi:schemaLocation", OAI_DC_SCHEMA_LOCATION),
+    )?;
+    write_dc_element(w, "dc:title", &work.full_title)?;
+    for title in work.titles.iter().filter(|title| !title.is_default()) {
+        let language = title.language_code.to_string().to_lowercase();
+        w.write(XmlEvent::start_element("dc:title").attr("xml:lang", &language))?;
+        w.write(XmlEvent::Characters(&title.full_title()))?;
+        w.write(XmlEvent::end_element())?;
+    }
+    for contribution in &work.contributions {
+        let element = match contribution.contribution_type {
+            ContributionType::AUTHOR => "dc:creator",
+            _ => "dc:contributor",
+        };
+        write_dc_element(w, element, &contribution.full_name)?;
+    }
+    for subject in &work.subjects {
+        write_dc_element(w, "dc:subject", &subject.subject_code)?;
+    }
+    // Works without abstracts by language fall back to their own long abstract
+    if work.abstracts.is_empty() {
+        if let Some(long_abstract) = &work.long_abstract {
+            write_dc_element(w, "dc:description", long_abstract)?;
+        }
+    }
+    for work_abstract in &work.abstracts {
+        let language = work_abstract.language_code.to_string().to_lowercase();
+        w.write(XmlEvent::start_element("dc:description").attr("xml:lang", &language))?;
+        w.write(XmlEvent::Characters(&work_abstract.text))?;
+        w.write(XmlEvent::end_element())?;
+    }
+    write_dc_element(w, "dc:publisher", &work.imprint.publisher.publisher_name)?;
+    if let Some(date) = &work.publication_date {
+        write_dc_element(w, "dc:date", &date.format("%Y-%m-%d").to_string())?;
+    }
+    write_dc_element(w, "dc:type", "Text")?;
+    if let Some(doi) = &work.doi {
+        write_dc_element(w, "dc:identifier", doi)?;
+    }
+    if let Some(short_doi) = &work.short_doi {
+        write_dc_element(w, "dc:identifier", short_doi)?;
+    }
+    for publication in &work.publications {
+        if let Some(isbn) = &publication.isbn {
+            let urn = format!("urn:isbn:{}", isbn.replace("-", ""));
+            write_dc_element(w, "dc:identifier", &urn)?;
+        }
+    }
+    if let Some(landing_page) = &work.landing_page {
+        write_dc_element(w, "dc:identifier", landing_page)?;
+    }
+    // The language a work was translated from is not a language of the work itself
+    for language in work
+        .languages
+        .iter()
+        .filter(|l| !l.language_relation.eq(&LanguageRelation::TRANSLATED_FROM))
+    {
+        let code = language.language_code.to_string().to_lowercase();
+        write_dc_element(w, "dc:language", &code)?;
+    }
+    if let Some(license) = &work.license {
+        write_dc_element(w, "dc:rights", license)?;
+    }
+    w.write(XmlEvent::end_element())
+}
+
+fn write_dc_element<W: Write>(w: &mut EventWriter<W>, element: &str, value: &str) -> Result<()> {
+    w.write(XmlEvent::start_element(element))?;
+    w.write(XmlEvent::Characters(value))?;
+    w.write(XmlEvent::end_element())
+}
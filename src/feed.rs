@@ -0,0 +1,174 @@
+use chrono::prelude::*;
+use thoth_api::errors::ThothError;
+use thoth_client::feed::feed_query::ContributionType;
+use thoth_client::feed::feed_query::FeedQueryWorks;
+use xml::writer::XmlEvent;
+
+use crate::sitemap::write_element;
+use crate::sitemap::write_xml;
+
+const ATOM_NS: &str = "http://www.w3.org/2005/Atom";
+const DC_NS: &str = "http://purl.org/dc/elements/1.1/";
+const FEED_TITLE: &str = "Thoth: recently published works";
+/// Author of a feed listing the works of several publishers
+const FEED_AUTHOR: &str = "Thoth";
+/// Number of works listed in a feed unless the request asks for a different number
+pub const DEFAULT_FEED_SIZE: usize = 50;
+/// Largest number of works a feed request may ask for
+pub const MAX_FEED_SIZE: usize = 200;
+/// Longest abstract summary given for an entry, in characters
+const SUMMARY_LENGTH: usize = 500;
+
+/// Generate an Atom feed of works, most recently published first. `feed_url` is the feed's
+/// own public URL, which also serves as its identifier.
+pub fn generate_atom(works: &[FeedQueryWorks], feed_url: &str) -> Result<Vec<u8>, ThothError> {
+    write_xml(|w| {
+        w.write(XmlEvent::start_element("feed").default_ns(ATOM_NS))?;
+        write_element(w, "title", FEED_TITLE)?;
+        write_element(w, "id", feed_url)?;
+        w.write(
+            XmlEvent::start_element("link")
+                .attr("rel", "self")
+                .attr("href", feed_url),
+        )?;
+        w.write(XmlEvent::end_element())?;
+        write_element(w, "updated", &feed_updated(works).to_rfc3339())?;
+        // Atom requires an author for every entry, which those without contributors inherit
+        w.write(XmlEvent::start_element("author"))?;
+        write_element(w, "name", feed_author(works))?;
+        w.write(XmlEvent::end_element())?;
+        for work in works {
+            w.write(XmlEvent::start_element("entry"))?;
+            write_element(w, "id", &entry_id(work))?;
+            write_element(w, "title", &work.full_title)?;
+            if let Some(location) = work_location(work) {
+                w.write(XmlEvent::start_element("link").attr("href", location))?;
+                w.write(XmlEvent::end_element())?;
+            }
+            if let Some(date) = work.publication_date {
+                write_element(w, "published", &date_time(date).to_rfc3339())?;
+            }
+            write_element(w, "updated", &timestamp(work.updated_at).to_rfc3339())?;
+            for author in authors(work) {
+                w.write(XmlEvent::start_element("author"))?;
+                write_element(w, "name", author)?;
+                w.write(XmlEvent::end_element())?;
+            }
+            if let Some(summary) = summary(work) {
+                write_element(w, "summary", &summary)?;
+            }
+            w.write(XmlEvent::end_element())?;
+        }
+        w.write(XmlEvent::end_element())
+    })
+}
+
+/// Generate an RSS 2.0 feed of works, most recently published first. `site_url` is the public
+/// URL of this server, which RSS requires as the channel's link.
+pub fn generate_rss(works: &[FeedQueryWorks], site_url: &str) -> Result<Vec<u8>, ThothError> {
+    write_xml(|w| {
+        w.write(
+            XmlEvent::start_element("rss")
+                .attr("version", "2.0")
+                .ns("dc", DC_NS),
+        )?;
+        w.write(XmlEvent::start_element("channel"))?;
+        write_element(w, "title", FEED_TITLE)?;
+        write_element(w, "link", site_url)?;
+        write_element(w, "description", FEED_TITLE)?;
+        write_element(w, "lastBuildDate", &feed_updated(works).to_rfc2822())?;
+        for work in works {
+            w.write(XmlEvent::start_element("item"))?;
+            write_element(w, "title", &work.full_title)?;
+            if let Some(location) = work_location(work) {
+                write_element(w, "link", location)?;
+            }
+            w.write(XmlEvent::start_element("guid").attr("isPermaLink", "false"))?;
+            w.write(XmlEvent::Characters(&entry_id(work)))?;
+            w.write(XmlEvent::end_element())?;
+            if let Some(date) = work.publication_date {
+                write_element(w, "pubDate", &date_time(date).to_rfc2822())?;
+            }
+            for author in authors(work) {
+                write_element(w, "dc:creator", author)?;
+            }
+            if let Some(summary) = summary(work) {
+                write_element(w, "description", &summary)?;
+            }
+            w.write(XmlEvent::end_element())?;
+        }
+        w.write(XmlEvent::end_element())?;
+        w.write(XmlEvent::end_element())
+    })
+}
+
+/// Permanent identifier of an entry, unaffected by changes to the work's title or URLs
+fn entry_id(work: &FeedQueryWorks) -> String {
+    format!("urn:uuid:{}", work.work_id)
+}
+
+/// Public URL of a work: its landing page if it has one, or its DOI otherwise
+fn work_location(work: &FeedQueryWorks) -> Option<&str> {
+    work.landing_page.as_deref().or_else(|| work.doi.as_deref())
+}
+
+fn timestamp(seconds: f64) -> DateTime<Utc> {
+    Utc.timestamp(seconds as i64, 0)
+}
+
+fn date_time(date: NaiveDate) -> DateTime<Utc> {
+    Utc.from_utc_datetime(&date.and_hms(0, 0, 0))
+}
+
+/// Last time any work in the feed changed. An empty feed reports the time it was generated.
+fn feed_updated(works: &[FeedQueryWorks]) -> DateTime<Utc> {
+    if works.is_empty() {
+        return Utc::now();
+    }
+    timestamp(works.iter().map(|work| work.updated_at).fold(0.0, f64::max))
+}
+
+/// The publisher of the works when they all share one, or Thoth otherwise
+fn feed_author(works: &[FeedQueryWorks]) -> &str {
+    match works.first() {
+        Some(first)
+            if works.iter().all(|work| {
+                work.imprint.publisher.publisher_name == first.imprint.publisher.publisher_name
+            }) =>
+        {
+            &first.imprint.publisher.publisher_name
+        }
+        _ => FEED_AUTHOR,
+    }
+}
+
+/// Names of the work's authors, or of its editors if it has no authors
+fn authors(work: &FeedQueryWorks) -> Vec<&str> {
+    let author_type = if work
+        .contributions
+        .iter()
+        .any(|c| c.contribution_type.eq(&ContributionType::AUTHOR))
+    {
+        ContributionType::AUTHOR
+    } else {
+        ContributionType::EDITOR
+    };
+    work.contributions
+        .iter()
+        .filter(|c| c.contribution_type.eq(&author_type))
+        .map(|c| c.full_name.as_str())
+        .collect()
+}
+
+/// The work's short abstract, or the start of its long abstract if it has no short one
+fn summary(work: &FeedQueryWorks) -> Option<String> {
+    let text = work
+        .short_abstract
+        .as_ref()
+        .or_else(|| work.long_abstract.as_ref())?;
+    if text.chars().count() <= SUMMARY_LENGTH {
+        return Some(text.to_string());
+    }
+    let truncated: String = text.chars().take(SUMMARY_LENGTH).collect();
+    Some(format!("{}…", truncated.trim_end()))
+}
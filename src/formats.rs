@@ -0,0 +1,159 @@
+use serde::de::DeserializeOwned;
+use serde::Serializer;
+use serde_derive::Serialize;
+use thoth_api::errors;
+use thoth_api::errors::ThothError;
+use thoth_client::work::work_query::WorkQueryWork;
+
+use crate::doab::generate_doab;
+use crate::jsonld::generate_jsonld;
+use crate::onix::generate_onix_3_batch;
+use crate::onix::validate_onix_3;
+use crate::onix::OnixHeader;
+use crate::scholar::generate_scholar_meta;
+use crate::validation::ValidationIssue;
+
+pub const ONIX_FORMAT_ID: &str = "onix_3.0";
+pub const JSONLD_FORMAT_ID: &str = "jsonld";
+pub const SCHOLAR_FORMAT_ID: &str = "scholar";
+pub const DOAB_FORMAT_ID: &str = "doab";
+
+/// Work statuses a bulk export includes unless the caller asks for others
+pub const DEFAULT_EXPORT_STATUSES: &str = "active";
+
+/// Checks a work against the requirements of an export format, returning the problems found
+pub type Validator = fn(&WorkQueryWork) -> Vec<ValidationIssue>;
+
+/// An export, served at `/{path}/{work_id}`, or at `/{path}/{publisher_id}` for formats that
+/// describe a publisher's whole catalogue
+#[derive(Debug, Clone, Serialize)]
+pub struct ExportFormat {
+    pub id: &'static str,
+    pub name: &'static str,
+    pub path: &'static str,
+    pub content_type: &'static str,
+    /// The format's checks, for formats where `?validate=true` returns a dry-run
+    /// `ValidationReport` instead of the document. Serialised as whether there are any.
+    #[serde(rename = "validates", serialize_with = "serialize_is_some")]
+    pub validator: Option<Validator>,
+    /// Whether the format is exported for all of a publisher's works rather than for one work
+    pub per_publisher: bool,
+}
+
+/// Parse a comma separated list of work statuses, named as in the API but in any case, e.g.
+/// `active,out_of_print`, into the status enum of a client query. Fails with the first name
+/// that is not a work status.
+pub fn parse_work_statuses<T: DeserializeOwned>(
+    statuses: &str,
+) -> std::result::Result<Vec<T>, String> {
+    statuses
+        .split(',')
+        .map(|status| {
+            let name = serde_json::Value::String(status.trim().to_uppercase());
+            // the client's enum would take an unknown name as `Other`, so it is checked against
+            // the API's own
+            serde_json::from_value::<thoth_api::work::model::WorkStatus>(name.clone())
+                .and_then(|_| serde_json::from_value(name))
+                .map_err(|_| status.trim().to_string())
+        })
+        .collect()
+}
+
+/// Every format works can be exported in
+pub fn all_formats() -> Vec<ExportFormat> {
+    vec![
+        ExportFormat {
+            id: ONIX_FORMAT_ID,
+            name: "ONIX 3.0",
+            path: "onix",
+            content_type: "text/xml; charset=utf-8",
+            validator: Some(validate_onix_3),
+            per_publisher: false,
+        },
+        ExportFormat {
+            id: JSONLD_FORMAT_ID,
+            name: "JSON-LD (schema.org)",
+            path: "jsonld",
+            content_type: "application/ld+json",
+            validator: None,
+            per_publisher: false,
+        },
+        ExportFormat {
+            id: SCHOLAR_FORMAT_ID,
+            name: "Google Scholar meta tags",
+            path: "scholar",
+            content_type: "text/html; charset=utf-8",
+            validator: None,
+            per_publisher: false,
+        },
+        ExportFormat {
+            id: DOAB_FORMAT_ID,
+            name: "DOAB metadata (CSV)",
+            path: "doab",
+            content_type: "text/csv; charset=utf-8",
+            validator: None,
+            per_publisher: true,
+        },
+    ]
+}
+
+fn serialize_is_some<T, S: Serializer>(
+    value: &Option<T>,
+    serializer: S,
+) -> std::result::Result<S::Ok, S::Error> {
+    serializer.serialize_bool(value.is_some())
+}
+
+/// The checks of an export format, if it is one that can be validated
+pub fn format_validator(format_id: &str) -> Option<Validator> {
+    all_formats()
+        .into_iter()
+        .find(|format| format.id == format_id)
+        .and_then(|format| format.validator)
+}
+
+/// Generate a single document describing the works in an export format. ONIX describes several
+/// works in one message, JSON-LD as an array of documents and DOAB as one row per work; other
+/// formats take one work.
+pub fn generate_for_format(
+    format_id: &str,
+    works: &[WorkQueryWork],
+    onix_header: &OnixHeader,
+) -> errors::Result<Vec<u8>> {
+    match (format_id, works) {
+        (_, []) => Err(ThothError::InvalidInput("No works to export".to_string()).into()),
+        (ONIX_FORMAT_ID, works) => generate_onix_3_batch(works, onix_header),
+        (JSONLD_FORMAT_ID, [work]) => Ok(generate_jsonld(work).into_bytes()),
+        (JSONLD_FORMAT_ID, works) => {
+            let documents: Vec<String> = works.iter().map(generate_jsonld).collect();
+            Ok(format!("[{}]", documents.join(",")).into_bytes())
+        }
+        (SCHOLAR_FORMAT_ID, [work]) => Ok(generate_scholar_meta(work).into_bytes()),
+        (SCHOLAR_FORMAT_ID, _) => Err(ThothError::InvalidInput(
+            "Google Scholar meta tags describe a single work".to_string(),
+        )
+        .into()),
+        (DOAB_FORMAT_ID, works) => generate_doab(works),
+        (format_id, _) => {
+            Err(ThothError::InvalidInput(format!("Unknown export format: {}", format_id)).into())
+        }
+    }
+}
+
+#[test]
+fn test_parse_work_statuses() {
+    use thoth_client::work::work_ids_query::WorkStatus;
+
+    assert_eq!(
+        parse_work_statuses::<WorkStatus>(DEFAULT_EXPORT_STATUSES),
+        Ok(vec![WorkStatus::ACTIVE])
+    );
+    assert_eq!(
+        parse_work_statuses::<WorkStatus>("active, Out_Of_Print"),
+        Ok(vec![WorkStatus::ACTIVE, WorkStatus::OUT_OF_PRINT])
+    );
+    assert_eq!(
+        parse_work_statuses::<WorkStatus>("active,published"),
+        Err("published".to_string())
+    );
+}
@@ -0,0 +1,122 @@
+use serde_json::{json, Map, Value};
+use thoth_client::work::work_query::ContributionType;
+use thoth_client::work::work_query::LanguageRelation;
+use thoth_client::work::work_query::WorkQueryWork;
+use thoth_client::work::work_query::WorkQueryWorkContributions;
+use thoth_client::work::work_query::WorkType;
+
+const SCHEMA_ORG_CONTEXT: &str = "https://schema.org";
+const ORCID_URL: &str = "https://orcid.org/";
+
+/// Describe a work as a schema.org `Book` (or `Chapter`) JSON-LD document
+pub fn generate_jsonld(work: &WorkQueryWork) -> String {
+    let mut doc = Map::new();
+    doc.insert("@context".to_string(), json!(SCHEMA_ORG_CONTEXT));
+    let schema_type = match work.work_type {
+        WorkType::BOOK_CHAPTER => "Chapter",
+        _ => "Book",
+    };
+    doc.insert("@type".to_string(), json!(schema_type));
+    if let Some(id) = work.doi.as_ref().or_else(|| work.landing_page.as_ref()) {
+        doc.insert("@id".to_string(), json!(id));
+    }
+    doc.insert("name".to_string(), json!(work.full_title));
+    insert_people(&mut doc, "author", work, ContributionType::AUTHOR);
+    insert_people(&mut doc, "editor", work, ContributionType::EDITOR);
+    insert_people(&mut doc, "translator", work, ContributionType::TRANSLATOR);
+    doc.insert(
+        "publisher".to_string(),
+        json!({
+            "@type": "Organization",
+            "name": work.imprint.publisher.publisher_name,
+        }),
+    );
+    let isbns: Vec<&String> = work
+        .publications
+        .iter()
+        .filter_map(|p| p.isbn.as_ref())
+        .collect();
+    // Rich result validators reject `"isbn": null`, so leave the property out instead
+    if !isbns.is_empty() {
+        doc.insert("isbn".to_string(), json!(isbns));
+    }
+    if let Some(date) = &work.publication_date {
+        doc.insert(
+            "datePublished".to_string(),
+            json!(date.format("%Y-%m-%d").to_string()),
+        );
+    }
+    let languages: Vec<String> = work
+        .languages
+        .iter()
+        .filter(|l| !l.language_relation.eq(&LanguageRelation::TRANSLATED_FROM))
+        .map(|l| l.language_code.to_string().to_lowercase())
+        .collect();
+    if !languages.is_empty() {
+        doc.insert("inLanguage".to_string(), json!(languages));
+    }
+    let same_as: Vec<&String> = work.doi.iter().chain(work.short_doi.iter()).collect();
+    match same_as.as_slice() {
+        [] => {}
+        [doi] => {
+            doc.insert("sameAs".to_string(), json!(doi));
+        }
+        _ => {
+            doc.insert("sameAs".to_string(), json!(same_as));
+        }
+    }
+    // The landing page describes the work better than its full text, so it is preferred here
+    if let Some(url) = work
+        .landing_page
+        .as_deref()
+        .or_else(|| work.canonical_location())
+    {
+        doc.insert("url".to_string(), json!(url));
+    }
+    if let Some(long_abstract) = &work.long_abstract {
+        doc.insert("description".to_string(), json!(long_abstract));
+    }
+    if let Some(license) = &work.license {
+        doc.insert("license".to_string(), json!(license));
+    }
+    if let Some(page_count) = work.page_count {
+        doc.insert("numberOfPages".to_string(), json!(page_count));
+    }
+    Value::Object(doc).to_string()
+}
+
+fn insert_people(
+    doc: &mut Map<String, Value>,
+    property: &str,
+    work: &WorkQueryWork,
+    contribution_type: ContributionType,
+) {
+    let people: Vec<Value> = work
+        .contributions
+        .iter()
+        .filter(|c| c.contribution_type.eq(&contribution_type))
+        .map(person)
+        .collect();
+    if !people.is_empty() {
+        doc.insert(property.to_string(), Value::Array(people));
+    }
+}
+
+fn person(contribution: &WorkQueryWorkContributions) -> Value {
+    let mut person = Map::new();
+    person.insert("@type".to_string(), json!("Person"));
+    person.insert("name".to_string(), json!(contribution.full_name));
+    if let Some(first_name) = &contribution.first_name {
+        person.insert("givenName".to_string(), json!(first_name));
+    }
+    person.insert("familyName".to_string(), json!(contribution.last_name));
+    if let Some(orcid) = &contribution.contributor.orcid {
+        let orcid_url = if orcid.starts_with("http") {
+            orcid.to_string()
+        } else {
+            format!("{}{}", ORCID_URL, orcid)
+        };
+        person.insert("sameAs".to_string(), json!(orcid_url));
+    }
+    Value::Object(person)
+}
@@ -4,5 +4,16 @@ extern crate actix_web;
 extern crate dotenv;
 extern crate juniper;
 
+pub mod contributors;
+pub mod counter;
+pub mod doab;
+pub mod dublin_core;
+pub mod feed;
+pub mod formats;
+pub mod jsonld;
+pub mod oai;
 pub mod onix;
+pub mod scholar;
 pub mod server;
+pub mod sitemap;
+pub mod validation;
@@ -0,0 +1,837 @@
+use std::io::Write;
+
+use chrono::prelude::*;
+use chrono::Duration;
+use futures::future::try_join_all;
+use serde_derive::Deserialize;
+use thoth_api::errors::ThothError;
+use thoth_client::oai::get_sets;
+use thoth_client::oai::get_work_headers;
+use thoth_client::oai::oai_sets_query::OaiSetsQueryPublishers;
+use thoth_client::oai::oai_works_query;
+use thoth_client::work::get_work;
+use thoth_client::work::work_query::WorkQueryWork;
+use thoth_client::work::work_query::WorkStatus;
+use uuid::Uuid;
+use xml::writer::{EventWriter, Result, XmlEvent};
+
+use crate::dublin_core::write_dublin_core;
+use crate::onix::env_value;
+use crate::sitemap::write_xml;
+
+const OAI_NS: &str = "http://www.openarchives.org/OAI/2.0/";
+const XSI_NS: &str = "http://www.w3.org/2001/XMLSchema-instance";
+const OAI_SCHEMA_LOCATION: &str =
+    "http://www.openarchives.org/OAI/2.0/ http://www.openarchives.org/OAI/2.0/OAI-PMH.xsd";
+const OAI_DC_PREFIX: &str = "oai_dc";
+const OAI_DC_SCHEMA: &str = "http://www.openarchives.org/OAI/2.0/oai_dc.xsd";
+const OAI_DC_NS: &str = "http://www.openarchives.org/OAI/2.0/oai_dc/";
+const REPOSITORY_NAME: &str = "Thoth";
+const REPOSITORY_IDENTIFIER: &str = "thoth.pub";
+const GRANULARITY: &str = "YYYY-MM-DDThh:mm:ssZ";
+const DATESTAMP_FORMAT: &str = "%Y-%m-%dT%H:%M:%SZ";
+const DATE_FORMAT: &str = "%Y-%m-%d";
+const SET_PREFIX: &str = "publisher-";
+/// Number of identifiers or records returned before issuing a resumption token
+const PAGE_SIZE: i64 = 50;
+
+/// Arguments of an OAI-PMH request, taken from the query string or a form body
+#[derive(Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct OaiParams {
+    pub verb: Option<String>,
+    pub identifier: Option<String>,
+    pub metadata_prefix: Option<String>,
+    pub from: Option<String>,
+    pub until: Option<String>,
+    pub set: Option<String>,
+    pub resumption_token: Option<String>,
+}
+
+/// Details of this repository that are given in answer to `Identify`
+pub struct OaiRepository {
+    admin_email: Option<String>,
+}
+
+impl OaiRepository {
+    /// Read the repository details from the environment. The administrator's address is
+    /// taken from `OAI_ADMIN_EMAIL`, falling back to the ONIX sender's `ONIX_SENDER_EMAIL`.
+    pub fn from_env() -> Self {
+        OaiRepository {
+            admin_email: env_value("OAI_ADMIN_EMAIL").or_else(|| env_value("ONIX_SENDER_EMAIL")),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct OaiError {
+    code: &'static str,
+    message: String,
+}
+
+impl OaiError {
+    fn new(code: &'static str, message: &str) -> OaiError {
+        OaiError {
+            code,
+            message: message.to_string(),
+        }
+    }
+
+    fn bad_argument(message: &str) -> OaiError {
+        OaiError::new("badArgument", message)
+    }
+}
+
+struct RecordHeader {
+    identifier: String,
+    datestamp: String,
+    set_spec: String,
+}
+
+struct ResumptionToken {
+    token: String,
+    complete_list_size: i64,
+    cursor: i64,
+}
+
+enum OaiResponse {
+    Identify {
+        admin_email: String,
+        earliest_datestamp: String,
+    },
+    ListMetadataFormats,
+    ListSets(Vec<OaiSetsQueryPublishers>),
+    ListIdentifiers(Vec<RecordHeader>, Option<ResumptionToken>),
+    ListRecords(Vec<(RecordHeader, WorkQueryWork)>, Option<ResumptionToken>),
+    GetRecord(RecordHeader, WorkQueryWork),
+}
+
+/// Selective harvesting arguments, given directly or restored from a resumption token
+struct ListArgs {
+    offset: i64,
+    from: Option<String>,
+    until: Option<String>,
+    set: Option<String>,
+    resumed: bool,
+}
+
+impl ListArgs {
+    fn from_params(params: &OaiParams) -> std::result::Result<ListArgs, OaiError> {
+        if let Some(token) = &params.resumption_token {
+            // resumptionToken is an exclusive argument
+            if params.metadata_prefix.is_some()
+                || params.from.is_some()
+                || params.until.is_some()
+                || params.set.is_some()
+            {
+                return Err(OaiError::bad_argument(
+                    "resumptionToken cannot be combined with other arguments",
+                ));
+            }
+            return ListArgs::from_token(token);
+        }
+        check_metadata_prefix(params)?;
+        Ok(ListArgs {
+            offset: 0,
+            from: params.from.clone(),
+            until: params.until.clone(),
+            set: params.set.clone(),
+            resumed: false,
+        })
+    }
+
+    fn from_token(token: &str) -> std::result::Result<ListArgs, OaiError> {
+        let bad_token = || OaiError::new("badResumptionToken", "Invalid resumption token");
+        let parts: Vec<&str> = token.split('|').collect();
+        if parts.len() != 4 {
+            return Err(bad_token());
+        }
+        let optional = |part: &str| match part {
+            "" => None,
+            part => Some(part.to_string()),
+        };
+        Ok(ListArgs {
+            offset: parts[0]
+                .parse()
+                .ok()
+                .filter(|offset| *offset >= 0)
+                .ok_or_else(bad_token)?,
+            from: optional(parts[1]),
+            until: optional(parts[2]),
+            set: optional(parts[3]),
+            resumed: true,
+        })
+    }
+
+    fn token(&self, offset: i64) -> String {
+        format!(
+            "{}|{}|{}|{}",
+            offset,
+            self.from.as_deref().unwrap_or(""),
+            self.until.as_deref().unwrap_or(""),
+            self.set.as_deref().unwrap_or("")
+        )
+    }
+
+    fn variables(&self) -> std::result::Result<oai_works_query::Variables, OaiError> {
+        if let (Some(from), Some(until)) = (&self.from, &self.until) {
+            if from.len() != until.len() {
+                return Err(OaiError::bad_argument(
+                    "from and until must have the same granularity",
+                ));
+            }
+        }
+        let from = match &self.from {
+            Some(from) => Some(parse_datestamp(from, false)?),
+            None => None,
+        };
+        let until = match &self.until {
+            Some(until) => Some(parse_datestamp(until, true)?),
+            None => None,
+        };
+        if let (Some(from), Some(until)) = (from, until) {
+            if from >= until {
+                return Err(OaiError::bad_argument("from must not be later than until"));
+            }
+        }
+        let publishers = match &self.set {
+            Some(set) => Some(vec![parse_set_spec(set)?]),
+            None => None,
+        };
+        Ok(oai_works_query::Variables {
+            limit: PAGE_SIZE,
+            offset: self.offset,
+            publishers,
+            updated_at_after: from.map(|d| d.timestamp() as f64),
+            updated_at_before: until.map(|d| d.timestamp() as f64),
+        })
+    }
+
+    /// The token to include in a list response, if any. The last page of a resumed list
+    /// carries an empty token to signal that the list is complete.
+    fn resumption_token(
+        &self,
+        page_size: usize,
+        complete_list_size: i64,
+    ) -> Option<ResumptionToken> {
+        let next_offset = self.offset + page_size as i64;
+        if next_offset < complete_list_size {
+            Some(ResumptionToken {
+                token: self.token(next_offset),
+                complete_list_size,
+                cursor: self.offset,
+            })
+        } else if self.resumed {
+            Some(ResumptionToken {
+                token: "".to_string(),
+                complete_list_size,
+                cursor: self.offset,
+            })
+        } else {
+            None
+        }
+    }
+}
+
+/// Parse a `from` or `until` argument. The API's `updatedAtBefore` filter is exclusive,
+/// so an `until` bound is moved to the end of the day or second it designates.
+fn parse_datestamp(value: &str, until: bool) -> std::result::Result<NaiveDateTime, OaiError> {
+    let invalid = || OaiError::bad_argument(&format!("Invalid date: {}", value));
+    if value.len() == 10 {
+        let date = NaiveDate::parse_from_str(value, DATE_FORMAT).map_err(|_| invalid())?;
+        let start = date.and_hms(0, 0, 0);
+        Ok(if until {
+            start + Duration::days(1)
+        } else {
+            start
+        })
+    } else {
+        let datetime =
+            NaiveDateTime::parse_from_str(value, DATESTAMP_FORMAT).map_err(|_| invalid())?;
+        Ok(if until {
+            datetime + Duration::seconds(1)
+        } else {
+            datetime
+        })
+    }
+}
+
+fn format_datestamp(timestamp: f64) -> String {
+    NaiveDateTime::from_timestamp(timestamp as i64, 0)
+        .format(DATESTAMP_FORMAT)
+        .to_string()
+}
+
+fn parse_set_spec(set: &str) -> std::result::Result<Uuid, OaiError> {
+    if !set.starts_with(SET_PREFIX) {
+        return Err(OaiError::bad_argument("Unknown set"));
+    }
+    Uuid::parse_str(&set[SET_PREFIX.len()..]).map_err(|_| OaiError::bad_argument("Unknown set"))
+}
+
+fn set_spec(publisher_id: &Uuid) -> String {
+    format!("{}{}", SET_PREFIX, publisher_id)
+}
+
+fn oai_identifier(work_id: &Uuid) -> String {
+    format!("oai:{}:{}", REPOSITORY_IDENTIFIER, work_id)
+}
+
+fn parse_oai_identifier(identifier: &str) -> std::result::Result<Uuid, OaiError> {
+    let prefix = format!("oai:{}:", REPOSITORY_IDENTIFIER);
+    let not_found = || OaiError::new("idDoesNotExist", "Unknown identifier");
+    if !identifier.starts_with(&prefix) {
+        return Err(not_found());
+    }
+    Uuid::parse_str(&identifier[prefix.len()..]).map_err(|_| not_found())
+}
+
+fn check_metadata_prefix(params: &OaiParams) -> std::result::Result<(), OaiError> {
+    match params.metadata_prefix.as_deref() {
+        Some(OAI_DC_PREFIX) => Ok(()),
+        Some(_) => Err(OaiError::new(
+            "cannotDisseminateFormat",
+            "Only oai_dc is supported",
+        )),
+        None => Err(OaiError::bad_argument("metadataPrefix is required")),
+    }
+}
+
+/// Reject arguments that are not allowed for the requested verb
+fn check_arguments(params: &OaiParams, allowed: &[&str]) -> std::result::Result<(), OaiError> {
+    let given = [
+        ("identifier", params.identifier.is_some()),
+        ("metadataPrefix", params.metadata_prefix.is_some()),
+        ("from", params.from.is_some()),
+        ("until", params.until.is_some()),
+        ("set", params.set.is_some()),
+        ("resumptionToken", params.resumption_token.is_some()),
+    ];
+    match given
+        .iter()
+        .find(|(name, present)| *present && !allowed.contains(name))
+    {
+        Some((name, _)) => Err(OaiError::bad_argument(&format!(
+            "Illegal argument: {}",
+            name
+        ))),
+        None => Ok(()),
+    }
+}
+
+fn work_header(work: &WorkQueryWork) -> RecordHeader {
+    RecordHeader {
+        identifier: oai_identifier(&work.work_id),
+        datestamp: format_datestamp(work.updated_at),
+        set_spec: set_spec(&work.imprint.publisher.publisher_id),
+    }
+}
+
+type OaiResult = std::result::Result<OaiResponse, OaiError>;
+
+/// Fetch a work that can be harvested. Only active works are listed, and as the repository
+/// keeps no deleted records (`deletedRecord` is `no`), any other work is as unknown as one
+/// that never existed.
+async fn get_active_work(
+    work_id: Uuid,
+    thoth_url: String,
+) -> std::result::Result<WorkQueryWork, OaiError> {
+    match get_work(work_id, thoth_url).await {
+        Ok(work) if work.work_status == WorkStatus::ACTIVE => Ok(work),
+        _ => Err(OaiError::new("idDoesNotExist", "Unknown identifier")),
+    }
+}
+
+/// `Identify` cannot be answered without an administrator address, which the protocol
+/// requires, so a missing one is a server error rather than an OAI-PMH error.
+async fn identify(
+    repository: &OaiRepository,
+    thoth_url: String,
+) -> std::result::Result<OaiResult, ThothError> {
+    let admin_email = repository.admin_email.clone().ok_or_else(|| {
+        ThothError::InternalError("OAI_ADMIN_EMAIL must be set to answer Identify".to_string())
+    })?;
+    let variables = oai_works_query::Variables {
+        limit: 1,
+        offset: 0,
+        publishers: None,
+        updated_at_after: None,
+        updated_at_before: None,
+    };
+    let earliest = match get_work_headers(variables, thoth_url).await {
+        Ok(data) => data.works.first().map(|w| w.updated_at).unwrap_or(0.0),
+        Err(_) => 0.0,
+    };
+    Ok(Ok(OaiResponse::Identify {
+        admin_email,
+        earliest_datestamp: format_datestamp(earliest),
+    }))
+}
+
+async fn list_metadata_formats(
+    params: &OaiParams,
+    thoth_url: String,
+) -> std::result::Result<OaiResponse, OaiError> {
+    if let Some(identifier) = &params.identifier {
+        let work_id = parse_oai_identifier(identifier)?;
+        get_active_work(work_id, thoth_url).await?;
+    }
+    Ok(OaiResponse::ListMetadataFormats)
+}
+
+async fn list_sets(thoth_url: String) -> std::result::Result<OaiResponse, OaiError> {
+    get_sets(thoth_url)
+        .await
+        .map(OaiResponse::ListSets)
+        .map_err(|_| OaiError::new("noSetHierarchy", "Sets could not be retrieved"))
+}
+
+async fn list_headers(
+    args: &ListArgs,
+    thoth_url: String,
+) -> std::result::Result<(Vec<RecordHeader>, Option<ResumptionToken>), OaiError> {
+    let data = get_work_headers(args.variables()?, thoth_url)
+        .await
+        .map_err(|_| OaiError::new("noRecordsMatch", "Records could not be retrieved"))?;
+    if data.works.is_empty() {
+        return Err(OaiError::new(
+            "noRecordsMatch",
+            "No records match the request",
+        ));
+    }
+    let token = args.resumption_token(data.works.len(), data.work_total.0);
+    let headers = data
+        .works
+        .iter()
+        .map(|work| RecordHeader {
+            identifier: oai_identifier(&work.work_id),
+            datestamp: format_datestamp(work.updated_at),
+            set_spec: set_spec(&work.imprint.publisher.publisher_id),
+        })
+        .collect();
+    Ok((headers, token))
+}
+
+/// A work that is listed but cannot be fetched fails the whole page, rather than being left
+/// out: a harvester would otherwise take the page as complete and never come back for it.
+async fn list_records(
+    args: &ListArgs,
+    thoth_url: String,
+) -> std::result::Result<OaiResult, ThothError> {
+    let (headers, token) = match list_headers(args, thoth_url.clone()).await {
+        Ok(page) => page,
+        Err(e) => return Ok(Err(e)),
+    };
+    let work_ids = match headers
+        .iter()
+        .map(|header| parse_oai_identifier(&header.identifier))
+        .collect::<std::result::Result<Vec<Uuid>, OaiError>>()
+    {
+        Ok(work_ids) => work_ids,
+        Err(e) => return Ok(Err(e)),
+    };
+    let works = try_join_all(
+        work_ids
+            .into_iter()
+            .map(|work_id| get_work(work_id, thoth_url.clone())),
+    )
+    .await?;
+    let records = headers.into_iter().zip(works).collect();
+    Ok(Ok(OaiResponse::ListRecords(records, token)))
+}
+
+async fn get_record(
+    params: &OaiParams,
+    thoth_url: String,
+) -> std::result::Result<OaiResponse, OaiError> {
+    let identifier = params
+        .identifier
+        .as_ref()
+        .ok_or_else(|| OaiError::bad_argument("identifier is required"))?;
+    check_metadata_prefix(params)?;
+    let work_id = parse_oai_identifier(identifier)?;
+    let work = get_active_work(work_id, thoth_url).await?;
+    Ok(OaiResponse::GetRecord(work_header(&work), work))
+}
+
+/// Answer an OAI-PMH request, returning the XML response body. Protocol errors are part of
+/// the response; an error is only returned when the request could not be answered at all.
+pub async fn handle_oai_request(
+    params: OaiParams,
+    repository: &OaiRepository,
+    base_url: &str,
+    thoth_url: String,
+) -> std::result::Result<Vec<u8>, ThothError> {
+    let response = match params.verb.as_deref() {
+        Some("Identify") => match check_arguments(&params, &[]) {
+            Ok(_) => identify(repository, thoth_url).await?,
+            Err(e) => Err(e),
+        },
+        Some("ListMetadataFormats") => match check_arguments(&params, &["identifier"]) {
+            Ok(_) => list_metadata_formats(&params, thoth_url).await,
+            Err(e) => Err(e),
+        },
+        Some("ListSets") => match check_arguments(&params, &["resumptionToken"]) {
+            Ok(_) if params.resumption_token.is_some() => Err(OaiError::new(
+                "badResumptionToken",
+                "ListSets does not issue resumption tokens",
+            )),
+            Ok(_) => list_sets(thoth_url).await,
+            Err(e) => Err(e),
+        },
+        Some(verb @ "ListIdentifiers") | Some(verb @ "ListRecords") => {
+            let allowed = ["metadataPrefix", "from", "until", "set", "resumptionToken"];
+            match check_arguments(&params, &allowed).and_then(|_| ListArgs::from_params(&params)) {
+                Ok(args) if verb == "ListIdentifiers" => list_headers(&args, thoth_url)
+                    .await
+                    .map(|(headers, token)| OaiResponse::ListIdentifiers(headers, token)),
+                Ok(args) => list_records(&args, thoth_url).await?,
+                Err(e) => Err(e),
+            }
+        }
+        Some("GetRecord") => match check_arguments(&params, &["identifier", "metadataPrefix"]) {
+            Ok(_) => get_record(&params, thoth_url).await,
+            Err(e) => Err(e),
+        },
+        _ => Err(OaiError::new("badVerb", "Illegal or missing verb")),
+    };
+
+    write_xml(|w| write_response(w, &params, base_url, &response))
+}
+
+fn write_text<W: Write>(w: &mut EventWriter<W>, element: &str, value: &str) -> Result<()> {
+    w.write(XmlEvent::start_element(element))?;
+    w.write(XmlEvent::Characters(value))?;
+    w.write(XmlEvent::end_element())
+}
+
+fn write_response<W: Write>(
+    w: &mut EventWriter<W>,
+    params: &OaiParams,
+    base_url: &str,
+    response: &OaiResult,
+) -> Result<()> {
+    w.write(
+        XmlEvent::start_element("OAI-PMH")
+            .default_ns(OAI_NS)
+            .ns("xsi", XSI_NS)
+            .attr("xsi:schemaLocation", OAI_SCHEMA_LOCATION),
+    )?;
+    let response_date = Utc::now().format(DATESTAMP_FORMAT).to_string();
+    write_text(w, "responseDate", &response_date)?;
+
+    // Request arguments are only echoed back when they were valid
+    let mut request = XmlEvent::start_element("request");
+    if !matches!(
+        response,
+        Err(OaiError {
+            code: "badVerb",
+            ..
+        }) | Err(OaiError {
+            code: "badArgument",
+            ..
+        })
+    ) {
+        let args = [
+            ("verb", &params.verb),
+            ("identifier", &params.identifier),
+            ("metadataPrefix", &params.metadata_prefix),
+            ("from", &params.from),
+            ("until", &params.until),
+            ("set", &params.set),
+            ("resumptionToken", &params.resumption_token),
+        ];
+        for (name, value) in args.iter() {
+            if let Some(value) = value {
+                request = request.attr(*name, value);
+            }
+        }
+    }
+    w.write(request)?;
+    w.write(XmlEvent::Characters(base_url))?;
+    w.write(XmlEvent::end_element())?;
+
+    match response {
+        Err(error) => {
+            w.write(XmlEvent::start_element("error").attr("code", error.code))?;
+            w.write(XmlEvent::Characters(&error.message))?;
+            w.write(XmlEvent::end_element())?;
+        }
+        Ok(OaiResponse::Identify {
+            admin_email,
+            earliest_datestamp,
+        }) => {
+            w.write(XmlEvent::start_element("Identify"))?;
+            write_text(w, "repositoryName", REPOSITORY_NAME)?;
+            write_text(w, "baseURL", base_url)?;
+            write_text(w, "protocolVersion", "2.0")?;
+            write_text(w, "adminEmail", admin_email)?;
+            write_text(w, "earliestDatestamp", earliest_datestamp)?;
+            write_text(w, "deletedRecord", "no")?;
+            write_text(w, "granularity", GRANULARITY)?;
+            w.write(XmlEvent::end_element())?;
+        }
+        Ok(OaiResponse::ListMetadataFormats) => {
+            w.write(XmlEvent::start_element("ListMetadataFormats"))?;
+            w.write(XmlEvent::start_element("metadataFormat"))?;
+            write_text(w, "metadataPrefix", OAI_DC_PREFIX)?;
+            write_text(w, "schema", OAI_DC_SCHEMA)?;
+            write_text(w, "metadataNamespace", OAI_DC_NS)?;
+            w.write(XmlEvent::end_element())?;
+            w.write(XmlEvent::end_element())?;
+        }
+        Ok(OaiResponse::ListSets(publishers)) => {
+            w.write(XmlEvent::start_element("ListSets"))?;
+            for publisher in publishers {
+                w.write(XmlEvent::start_element("set"))?;
+                write_text(w, "setSpec", &set_spec(&publisher.publisher_id))?;
+                write_text(w, "setName", &publisher.publisher_name)?;
+                w.write(XmlEvent::end_element())?;
+            }
+            w.write(XmlEvent::end_element())?;
+        }
+        Ok(OaiResponse::ListIdentifiers(headers, token)) => {
+            w.write(XmlEvent::start_element("ListIdentifiers"))?;
+            for header in headers {
+                write_header(w, header)?;
+            }
+            write_resumption_token(w, token)?;
+            w.write(XmlEvent::end_element())?;
+        }
+        Ok(OaiResponse::ListRecords(records, token)) => {
+            w.write(XmlEvent::start_element("ListRecords"))?;
+            for (header, work) in records {
+                write_record(w, header, work)?;
+            }
+            write_resumption_token(w, token)?;
+            w.write(XmlEvent::end_element())?;
+        }
+        Ok(OaiResponse::GetRecord(header, work)) => {
+            w.write(XmlEvent::start_element("GetRecord"))?;
+            write_record(w, header, work)?;
+            w.write(XmlEvent::end_element())?;
+        }
+    }
+    w.write(XmlEvent::end_element())
+}
+
+fn write_header<W: Write>(w: &mut EventWriter<W>, header: &RecordHeader) -> Result<()> {
+    w.write(XmlEvent::start_element("header"))?;
+    write_text(w, "identifier", &header.identifier)?;
+    write_text(w, "datestamp", &header.datestamp)?;
+    write_text(w, "setSpec", &header.set_spec)?;
+    w.write(XmlEvent::end_element())
+}
+
+fn write_record<W: Write>(
+    w: &mut EventWriter<W>,
+    header: &RecordHeader,
+    work: &WorkQueryWork,
+) -> Result<()> {
+    w.write(XmlEvent::start_element("record"))?;
+    write_header(w, header)?;
+    w.write(XmlEvent::start_element("metadata"))?;
+    write_dublin_core(w, work)?;
+    w.write(XmlEvent::end_element())?;
+    w.write(XmlEvent::end_element())
+}
+
+fn write_resumption_token<W: Write>(
+    w: &mut EventWriter<W>,
+    token: &Option<ResumptionToken>,
+) -> Result<()> {
+    if let Some(token) = token {
+        let complete_list_size = token.complete_list_size.to_string();
+        let cursor = token.cursor.to_string();
+        w.write(
+            XmlEvent::start_element("resumptionToken")
+                .attr("completeListSize", &complete_list_size)
+                .attr("cursor", &cursor),
+        )?;
+        if !token.token.is_empty() {
+            w.write(XmlEvent::Characters(&token.token))?;
+        }
+        w.write(XmlEvent::end_element())?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+fn error_code<T>(result: std::result::Result<T, OaiError>) -> Option<&'static str> {
+    result.err().map(|e| e.code)
+}
+
+#[test]
+fn test_list_args_from_token() {
+    let args = ListArgs::from_token("50|2020-01-01||publisher-abc").unwrap();
+    assert_eq!(args.offset, 50);
+    assert_eq!(args.from.as_deref(), Some("2020-01-01"));
+    assert_eq!(args.until, None);
+    assert_eq!(args.set.as_deref(), Some("publisher-abc"));
+    assert!(args.resumed);
+    assert_eq!(args.token(100), "100|2020-01-01||publisher-abc");
+
+    assert!(ListArgs::from_token("50|||").is_ok());
+    for token in &["", "50", "50||||", "fifty|||", "-50|||", "|||"] {
+        assert_eq!(
+            error_code(ListArgs::from_token(token)),
+            Some("badResumptionToken"),
+            "token {:?}",
+            token
+        );
+    }
+}
+
+#[test]
+fn test_list_args_from_params() {
+    let params = OaiParams {
+        resumption_token: Some("50|||".to_string()),
+        metadata_prefix: Some(OAI_DC_PREFIX.to_string()),
+        ..Default::default()
+    };
+    assert_eq!(
+        error_code(ListArgs::from_params(&params)),
+        Some("badArgument")
+    );
+
+    let params = OaiParams {
+        resumption_token: Some("50|||".to_string()),
+        ..Default::default()
+    };
+    assert_eq!(ListArgs::from_params(&params).unwrap().offset, 50);
+
+    let params = OaiParams {
+        metadata_prefix: Some("marc21".to_string()),
+        ..Default::default()
+    };
+    assert_eq!(
+        error_code(ListArgs::from_params(&params)),
+        Some("cannotDisseminateFormat")
+    );
+
+    let params = OaiParams {
+        from: Some("2020-01-01".to_string()),
+        ..Default::default()
+    };
+    assert_eq!(
+        error_code(ListArgs::from_params(&params)),
+        Some("badArgument")
+    );
+}
+
+#[test]
+fn test_parse_datestamp() {
+    assert_eq!(
+        parse_datestamp("2020-01-31", false).unwrap(),
+        NaiveDate::from_ymd(2020, 1, 31).and_hms(0, 0, 0)
+    );
+    // until is inclusive, but the API's upper bound is not
+    assert_eq!(
+        parse_datestamp("2020-01-31", true).unwrap(),
+        NaiveDate::from_ymd(2020, 2, 1).and_hms(0, 0, 0)
+    );
+    assert_eq!(
+        parse_datestamp("2020-01-31T23:59:59Z", false).unwrap(),
+        NaiveDate::from_ymd(2020, 1, 31).and_hms(23, 59, 59)
+    );
+    assert_eq!(
+        parse_datestamp("2020-01-31T23:59:59Z", true).unwrap(),
+        NaiveDate::from_ymd(2020, 2, 1).and_hms(0, 0, 0)
+    );
+    for value in &[
+        "",
+        "2020-1-31",
+        "2020-02-30",
+        "31/01/2020",
+        "2020-01-31T23:59:59",
+        "2020-01-31T23:59Z",
+        "2020-01-31 23:59:59Z",
+    ] {
+        assert!(parse_datestamp(value, false).is_err(), "value {:?}", value);
+    }
+}
+
+#[test]
+fn test_list_args_variables() {
+    let args = |from: Option<&str>, until: Option<&str>| ListArgs {
+        offset: 0,
+        from: from.map(str::to_string),
+        until: until.map(str::to_string),
+        set: None,
+        resumed: false,
+    };
+    let variables = args(Some("2020-01-01"), Some("2020-01-01"))
+        .variables()
+        .unwrap();
+    assert_eq!(variables.updated_at_after, Some(1_577_836_800.0));
+    assert_eq!(variables.updated_at_before, Some(1_577_923_200.0));
+
+    assert_eq!(
+        error_code(args(Some("2020-01-01"), Some("2020-01-01T00:00:00Z")).variables()),
+        Some("badArgument")
+    );
+    assert_eq!(
+        error_code(args(Some("2020-01-02"), Some("2020-01-01")).variables()),
+        Some("badArgument")
+    );
+
+    let mut set_args = args(None, None);
+    set_args.set = Some("publisher-00000000-0000-0000-aaaa-000000000001".to_string());
+    assert!(set_args.variables().unwrap().publishers.is_some());
+    set_args.set = Some("imprint-00000000-0000-0000-aaaa-000000000001".to_string());
+    assert_eq!(error_code(set_args.variables()), Some("badArgument"));
+}
+
+#[test]
+fn test_check_arguments() {
+    let params = OaiParams {
+        identifier: Some(oai_identifier(&Uuid::nil())),
+        metadata_prefix: Some(OAI_DC_PREFIX.to_string()),
+        ..Default::default()
+    };
+    assert!(check_arguments(&params, &["identifier", "metadataPrefix"]).is_ok());
+    assert_eq!(
+        check_arguments(&params, &["identifier"])
+            .err()
+            .map(|e| (e.code, e.message)),
+        Some((
+            "badArgument",
+            "Illegal argument: metadataPrefix".to_string()
+        ))
+    );
+    assert_eq!(
+        error_code(check_arguments(&params, &[])),
+        Some("badArgument")
+    );
+    assert!(check_arguments(&OaiParams::default(), &[]).is_ok());
+}
+
+#[test]
+fn test_resumption_token() {
+    let args = ListArgs {
+        offset: 0,
+        from: None,
+        until: None,
+        set: None,
+        resumed: false,
+    };
+    // A list that fits on one page is given without a token
+    assert!(args.resumption_token(10, 10).is_none());
+
+    let token = args.resumption_token(50, 120).unwrap();
+    assert_eq!(token.token, "50|||");
+    assert_eq!(token.cursor, 0);
+    assert_eq!(token.complete_list_size, 120);
+
+    let args = ListArgs::from_token(&token.token).unwrap();
+    let token = args.resumption_token(50, 120).unwrap();
+    assert_eq!(token.token, "100|||");
+    assert_eq!(token.cursor, 50);
+
+    // The last page of a resumed list closes it with an empty token
+    let args = ListArgs::from_token(&token.token).unwrap();
+    let token = args.resumption_token(20, 120).unwrap();
+    assert_eq!(token.token, "");
+    assert_eq!(token.cursor, 100);
+    assert_eq!(token.complete_list_size, 120);
+}
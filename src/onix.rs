@@ -1,29 +1,232 @@
 use std::collections::HashMap;
+use std::env;
+use std::fmt;
 use std::io::Write;
 
 use chrono::prelude::*;
 use thoth_api::errors;
+use thoth_client::work::work_query::AbstractType;
 use thoth_client::work::work_query::ContributionType;
 use thoth_client::work::work_query::LanguageRelation;
+use thoth_client::work::work_query::PriceType;
 use thoth_client::work::work_query::PublicationType;
 use thoth_client::work::work_query::SubjectType;
+use thoth_client::work::work_query::TitleType;
+use thoth_client::work::work_query::UnpricedItemType;
 use thoth_client::work::work_query::WorkQueryWork;
+use thoth_client::work::work_query::WorkQueryWorkContributions;
+use thoth_client::work::work_query::WorkQueryWorkFundings;
+use thoth_client::work::work_query::WorkQueryWorkIssues;
 use thoth_client::work::work_query::WorkQueryWorkPublications;
+use thoth_client::work::work_query::WorkQueryWorkPublicationsPrices;
 use thoth_client::work::work_query::WorkStatus;
+use uuid::Uuid;
 use xml::writer::events::StartElementBuilder;
 use xml::writer::{EmitterConfig, EventWriter, Result, XmlEvent};
 
-pub fn generate_onix_3(mut work: WorkQueryWork) -> errors::Result<Vec<u8>> {
+use crate::validation::ValidationIssue;
+
+/// ONIX for Books release the generator writes, as given in the `release` attribute
+pub const ONIX_RELEASE: &str = "3.0";
+/// Issue of the ONIX codelists every code in the generated message is taken from
+pub const ONIX_CODELIST_ISSUE: u32 = 51;
+
+/// ONIX code list 44 name identifier type assumed for sender and addressee identifiers
+const GLN_ID_TYPE: &str = "06";
+/// Name a message is sent under when it spans several publishers and none is configured
+const DEFAULT_SENDER_NAME: &str = "Thoth";
+
+/// An identifier of the sender or addressee of an ONIX message
+#[derive(Debug, Clone, PartialEq)]
+pub struct OnixPartyId {
+    /// ONIX code list 44 name identifier type, e.g. "06" for a GLN
+    pub id_type: String,
+    pub value: String,
+}
+
+/// Who ONIX messages are sent by and to, which differs between the organisations running Thoth.
+/// Distribution partners match incoming feeds on the sender identifier.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct OnixHeader {
+    /// Defaults to the name of the works' publisher, or Thoth for a batch spanning publishers
+    pub sender_name: Option<String>,
+    pub sender_id: Option<OnixPartyId>,
+    pub sender_email: Option<String>,
+    pub addressee_name: Option<String>,
+    pub addressee_id: Option<OnixPartyId>,
+}
+
+impl OnixHeader {
+    /// Read the header from `ONIX_SENDER_*` and `ONIX_ADDRESSEE_*` environment variables.
+    /// Identifiers are taken to be GLNs unless their `*_ID_TYPE` variable says otherwise.
+    pub fn from_env() -> Self {
+        OnixHeader {
+            sender_name: env_value("ONIX_SENDER_NAME"),
+            sender_id: party_id_from_env("ONIX_SENDER_ID", "ONIX_SENDER_ID_TYPE"),
+            sender_email: env_value("ONIX_SENDER_EMAIL"),
+            addressee_name: env_value("ONIX_ADDRESSEE_NAME"),
+            addressee_id: party_id_from_env("ONIX_ADDRESSEE_ID", "ONIX_ADDRESSEE_ID_TYPE"),
+        }
+    }
+
+    fn sender_name(&self, works: &[WorkQueryWork]) -> String {
+        if let Some(sender_name) = &self.sender_name {
+            return sender_name.clone();
+        }
+        // A batch spanning several publishers is sent on their behalf by Thoth
+        match works.first() {
+            Some(first)
+                if works.iter().all(|work| {
+                    work.imprint.publisher.publisher_id == first.imprint.publisher.publisher_id
+                }) =>
+            {
+                first.imprint.publisher.publisher_name.clone()
+            }
+            _ => DEFAULT_SENDER_NAME.to_string(),
+        }
+    }
+}
+
+pub(crate) fn env_value(name: &str) -> Option<String> {
+    env::var(name)
+        .ok()
+        .map(|value| value.trim().to_string())
+        .filter(|value| !value.is_empty())
+}
+
+fn party_id_from_env(value_name: &str, type_name: &str) -> Option<OnixPartyId> {
+    env_value(value_name).map(|value| OnixPartyId {
+        id_type: env_value(type_name).unwrap_or_else(|| GLN_ID_TYPE.to_string()),
+        value,
+    })
+}
+
+pub fn generate_onix_3(work: WorkQueryWork, header: &OnixHeader) -> errors::Result<Vec<u8>> {
+    generate_onix_3_batch(&[work], header)
+}
+
+/// Write a single ONIX message with one `Product` for each of the works
+pub fn generate_onix_3_batch(
+    works: &[WorkQueryWork],
+    header: &OnixHeader,
+) -> errors::Result<Vec<u8>> {
     let mut buffer = Vec::new();
     let mut writer = EmitterConfig::new()
         .perform_indent(true)
         .create_writer(&mut buffer);
-    match handle_event(&mut writer, &mut work) {
+    match handle_event(&mut writer, works, header) {
         Ok(_) => Ok(buffer),
         Err(e) => Err(errors::ThothError::from(e).into()),
     }
 }
 
+/// Whether the work's product would carry neither a price, a reason for having none, nor an
+/// open licence, which some distribution partners reject
+pub fn is_unpriced_closed(work: &WorkQueryWork) -> bool {
+    let (_, _, prices, unpriced_item_type) = get_publications_data(&work.publications);
+    prices.is_empty() && unpriced_item_type.is_none() && work.license.is_none()
+}
+
+/// A product left out of an export, with why
+#[derive(Debug, PartialEq)]
+pub struct SkippedProduct {
+    /// The publication the product describes, or the work when it has no publication to export
+    pub id: Uuid,
+    pub reason: &'static str,
+}
+
+impl fmt::Display for SkippedProduct {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} ({})", self.id, self.reason)
+    }
+}
+
+/// Set aside the works whose product would have neither a price nor an open licence. Returns
+/// the works left to export and the products set aside.
+pub fn skip_unpriced(works: Vec<WorkQueryWork>) -> (Vec<WorkQueryWork>, Vec<SkippedProduct>) {
+    let (skipped, works): (Vec<WorkQueryWork>, Vec<WorkQueryWork>) =
+        works.into_iter().partition(is_unpriced_closed);
+    let skipped = skipped
+        .iter()
+        .map(|work| match main_publication(&work.publications) {
+            Some(publication) => SkippedProduct {
+                id: publication.publication_id,
+                reason: "no price or open licence",
+            },
+            None => SkippedProduct {
+                id: work.work_id,
+                reason: "no publication with an ISBN",
+            },
+        })
+        .collect();
+    (works, skipped)
+}
+
+/// List the problems `generate_onix_3` would run into with this work, without generating it
+pub fn validate_onix_3(work: &WorkQueryWork) -> Vec<ValidationIssue> {
+    // Derived parts of the product are checked through the helpers `write_product` fills them
+    // in with, so that the report cannot drift from what is generated
+    let mut issues = Vec::new();
+    if main_publication(&work.publications).is_none() {
+        issues.push(ValidationIssue::error(
+            "isbn",
+            "No publication with an ISBN: the product identifier would be empty",
+        ));
+    }
+    if work.canonical_location().is_none() {
+        issues.push(ValidationIssue::error(
+            "publication_url",
+            "No PDF publication with a URL: no download link can be supplied",
+        ));
+    }
+    if abstract_text_contents(work).is_empty() {
+        issues.push(ValidationIssue::warning(
+            "long_abstract",
+            "No long abstract or abstracts by language: no description will be supplied",
+        ));
+    }
+    if work.license.is_none() {
+        issues.push(ValidationIssue::warning(
+            "license",
+            "No license: EpubLicense will be omitted",
+        ));
+    }
+    if work.doi.is_none() {
+        issues.push(ValidationIssue::warning(
+            "doi",
+            "No DOI: only proprietary and ISBN identifiers will be supplied",
+        ));
+    }
+    if work.publication_date.is_none() {
+        issues.push(ValidationIssue::warning(
+            "publication_date",
+            "No publication date",
+        ));
+    }
+    if work.contributions.is_empty() {
+        issues.push(ValidationIssue::warning("contributions", "No contributors"));
+    }
+    if work.languages.is_empty() {
+        issues.push(ValidationIssue::warning("languages", "No languages"));
+    }
+    if work.subjects.is_empty() {
+        issues.push(ValidationIssue::warning("subjects", "No subjects"));
+    }
+    if work.landing_page.is_none() {
+        issues.push(ValidationIssue::warning(
+            "landing_page",
+            "No landing page: web shop link will be omitted",
+        ));
+    }
+    if work.page_count.is_none() {
+        issues.push(ValidationIssue::warning(
+            "page_count",
+            "No page count: extent will be omitted",
+        ));
+    }
+    issues
+}
+
 fn string_to_static_str(s: String) -> &'static str {
     Box::leak(s.into_boxed_str())
 }
@@ -49,7 +252,10 @@ fn langrel_to_role(language_relation: &LanguageRelation) -> &str {
     }
 }
 
-fn contribution_type_to_role(contribution_type: &ContributionType) -> &str {
+/// ONIX code list 17 contributor role for a contribution type. Every variant is matched
+/// explicitly, so that a contribution type added to the schema fails to compile here until
+/// it is given a role, rather than being exported with a wrong one.
+fn contribution_type_to_onix_role(contribution_type: &ContributionType) -> &str {
     match contribution_type {
         ContributionType::AUTHOR => "A01",          // By (author)
         ContributionType::EDITOR => "B01",          // Edited by
@@ -61,7 +267,18 @@ fn contribution_type_to_role(contribution_type: &ContributionType) -> &str {
         ContributionType::INTRODUCTION_BY => "A24", // Introduction by
         ContributionType::AFTERWORD_BY => "A19",    // Afterword by
         ContributionType::PREFACE_BY => "A15",      // Preface by
-        _ => unreachable!(),
+        // only returned for values missing from the client schema
+        ContributionType::Other(_) => unreachable!(),
+    }
+}
+
+fn title_type_to_code(title_type: &TitleType) -> &str {
+    match title_type {
+        TitleType::DISTINCTIVE => "01",
+        TitleType::ORIGINAL => "03",
+        TitleType::TRANSLATED => "06",
+        TitleType::ALTERNATIVE => "14",
+        TitleType::Other(_) => unreachable!(),
     }
 }
 
@@ -84,33 +301,216 @@ fn wstatus_to_status(work_status: &WorkStatus) -> &str {
     }
 }
 
+fn price_type_to_code(price_type: &PriceType) -> &str {
+    match price_type {
+        PriceType::RRP_EXCLUDING_TAX => "01", // RRP excluding tax
+        PriceType::RRP_INCLUDING_TAX => "02", // RRP including tax
+        PriceType::FIXED_RETAIL_EXCLUDING_TAX => "03", // Fixed retail price excluding tax
+        PriceType::FIXED_RETAIL_INCLUDING_TAX => "04", // Fixed retail price including tax
+        PriceType::SUPPLIER_NET_EXCLUDING_TAX => "05", // Supplier's net price excluding tax
+        PriceType::SUPPLIER_NET_INCLUDING_TAX => "07", // Supplier's net price including tax
+        _ => unreachable!(),
+    }
+}
+
+fn unpriced_item_type_to_code(unpriced_item_type: &UnpricedItemType) -> &str {
+    match unpriced_item_type {
+        UnpricedItemType::FREE_OF_CHARGE => "01", // Free of charge
+        UnpricedItemType::PRICE_TO_BE_ANNOUNCED => "02", // Price to be announced
+        UnpricedItemType::NOT_SOLD_SEPARATELY => "03", // Not sold separately
+        UnpricedItemType::CONTACT_SUPPLIER => "04", // Contact supplier
+        _ => unreachable!(),
+    }
+}
+
+/// ONIX `ProductForm` and, where one applies, `ProductFormDetail` codes for a publication type
+pub fn publication_type_to_onix_form(
+    publication_type: &PublicationType,
+) -> (&'static str, Option<&'static str>) {
+    match publication_type {
+        PublicationType::PAPERBACK => ("BC", None), // Paperback / softback
+        PublicationType::HARDBACK => ("BB", None),  // Hardback
+        PublicationType::PDF => ("EB", Some("E107")), // Digital download and online, PDF
+        PublicationType::HTML => ("EC", Some("E105")), // Digital online, HTML
+        // Codelist 175 has no plain XML detail, so only the form is given
+        PublicationType::XML => ("EB", None), // Digital download and online
+        PublicationType::EPUB => ("EB", Some("E101")), // Digital download and online, EPUB
+        PublicationType::MOBI => ("EB", Some("E127")), // Digital download and online, Mobipocket
+        PublicationType::Other(_) => unreachable!(),
+    }
+}
+
+/// Type of the publication whose ISBN identifies the product, as chosen by
+/// `main_publication`, defaulting to PDF when no publication has an ISBN
+fn main_publication_type(publications: &[WorkQueryWorkPublications]) -> &PublicationType {
+    main_publication(publications)
+        .map(|publication| &publication.publication_type)
+        .unwrap_or(&MAIN_PUBLICATION_TYPES[0])
+}
+
+/// ONIX text type, language and text of each abstract of a work. Works without any
+/// abstracts by language fall back to their own long abstract, taken to be in English.
+fn abstract_text_contents(work: &WorkQueryWork) -> Vec<(&'static str, String, &str)> {
+    if work.abstracts.is_empty() {
+        return work
+            .long_abstract
+            .iter()
+            // 03 Description ("30 Abstract" not implemented in OAPEN)
+            .map(|text| ("03", "eng".to_string(), text.as_str()))
+            .collect();
+    }
+    work.abstracts
+        .iter()
+        .map(|a| {
+            let text_type = match a.abstract_type {
+                AbstractType::SHORT => "02", // Short description/annotation
+                AbstractType::LONG => "03",  // Description
+                _ => unreachable!(),
+            };
+            let language = a.language_code.to_string().to_lowercase();
+            (text_type, language, a.text.as_str())
+        })
+        .collect()
+}
+
 fn get_publications_data(
     publications: &[WorkQueryWorkPublications],
-) -> (String, String, Vec<String>) {
-    let mut main_isbn = "".to_string();
-    let mut pdf_url = "".to_string();
-    let mut isbns: Vec<String> = Vec::new();
-
-    for publication in publications {
-        if publication.publication_type.eq(&PublicationType::PDF) {
-            pdf_url = publication.publication_url.as_ref().unwrap().to_string();
-        }
-
-        if let Some(isbn) = &publication.isbn {
-            isbns.push(isbn.replace("-", ""));
-            // The default product ISBN is the PDF's
-            if publication.publication_type.eq(&PublicationType::PDF) {
-                main_isbn = isbn.replace("-", "");
-            }
-            // Books that don't have a PDF ISBN will use the paperback's
-            if publication.publication_type.eq(&PublicationType::PAPERBACK) && main_isbn.is_empty()
-            {
-                main_isbn = isbn.replace("-", "");
-            }
-        }
+) -> (
+    String,
+    Vec<String>,
+    &[WorkQueryWorkPublicationsPrices],
+    Option<&UnpricedItemType>,
+) {
+    let isbns: Vec<String> = publications
+        .iter()
+        .filter_map(|publication| publication.isbn.as_ref())
+        .map(|isbn| isbn.replace("-", ""))
+        .collect();
+    match main_publication(publications) {
+        Some(publication) => (
+            publication.isbn.as_deref().unwrap_or("").replace("-", ""),
+            isbns,
+            &publication.prices,
+            publication.unpriced_item_type.as_ref(),
+        ),
+        None => ("".to_string(), isbns, &[], None),
     }
+}
+
+/// Publication types in the order a work's product prefers them to describe it
+static MAIN_PUBLICATION_TYPES: [PublicationType; 7] = [
+    PublicationType::PDF,
+    PublicationType::PAPERBACK,
+    PublicationType::HARDBACK,
+    PublicationType::EPUB,
+    PublicationType::HTML,
+    PublicationType::MOBI,
+    PublicationType::XML,
+];
+
+/// The publication a work's product describes: the PDF, or for books that don't have a PDF
+/// ISBN, the first publication with an ISBN in the order of `MAIN_PUBLICATION_TYPES`
+fn main_publication(
+    publications: &[WorkQueryWorkPublications],
+) -> Option<&WorkQueryWorkPublications> {
+    let with_isbn = |publication_type: &'static PublicationType| {
+        publications.iter().filter(move |publication| {
+            publication.isbn.is_some() && publication.publication_type == *publication_type
+        })
+    };
+    with_isbn(&MAIN_PUBLICATION_TYPES[0]).last().or_else(|| {
+        MAIN_PUBLICATION_TYPES[1..]
+            .iter()
+            .find_map(|publication_type| with_isbn(publication_type).next())
+    })
+}
+
+/// Words that mark a name without a first name as an organisation rather than a person
+const CORPORATE_NAME_WORDS: [&str; 20] = [
+    "academy",
+    "association",
+    "center",
+    "centre",
+    "collective",
+    "committee",
+    "company",
+    "council",
+    "foundation",
+    "group",
+    "inc",
+    "institute",
+    "ltd",
+    "network",
+    "office",
+    "press",
+    "project",
+    "society",
+    "trust",
+    "university",
+];
+
+/// Thoth has no contributor kind, so tell organisations apart from mononymous people by
+/// their name: they have no first name or ORCID, and use a word typical of organisations
+fn is_corporate_name(contribution: &WorkQueryWorkContributions) -> bool {
+    contribution.first_name.is_none()
+        && contribution.contributor.orcid.is_none()
+        && contribution
+            .full_name
+            .split(|c: char| !c.is_alphanumeric())
+            .any(|word| CORPORATE_NAME_WORDS.contains(&word.to_lowercase().as_str()))
+}
+
+/// Details of a grant as ONIX funding identifiers, named after the FundRef assertions they match
+fn funding_identifiers(funding: &WorkQueryWorkFundings) -> Vec<(&'static str, &str)> {
+    vec![
+        ("programname", &funding.program),
+        ("projectname", &funding.project_name),
+        ("grantnumber", &funding.grant_number),
+        ("jurisdiction", &funding.jurisdiction),
+    ]
+    .into_iter()
+    .filter_map(|(name, value)| value.as_deref().map(|value| (name, value)))
+    .collect()
+}
+
+/// ISSNs of an issue's series as ONIX identifier values: digits only, each given once
+fn series_issns(issue: &WorkQueryWorkIssues) -> Vec<String> {
+    let mut issns: Vec<String> = vec![&issue.series.issn_print, &issue.series.issn_digital]
+        .into_iter()
+        .map(|issn| issn.replace('-', "").trim().to_uppercase())
+        .filter(|issn| !issn.is_empty())
+        .collect();
+    issns.dedup();
+    issns
+}
 
-    (main_isbn, pdf_url, isbns)
+/// Write the name composite of a contributor: `CorporateName` for organisations, otherwise
+/// `PersonName` followed by the structured name parts when both first and last names are known
+fn write_contributor_name<W: Write>(
+    w: &mut EventWriter<W>,
+    contribution: &WorkQueryWorkContributions,
+) -> Result<()> {
+    if is_corporate_name(contribution) {
+        return write_element_block("CorporateName", None, None, w, |w| {
+            let event: XmlEvent = XmlEvent::Characters(&contribution.full_name);
+            w.write(event).ok();
+        });
+    }
+    write_element_block("PersonName", None, None, w, |w| {
+        let event: XmlEvent = XmlEvent::Characters(&contribution.full_name);
+        w.write(event).ok();
+    })?;
+    if let Some(first_name) = &contribution.first_name {
+        write_element_block("NamesBeforeKey", None, None, w, |w| {
+            let event: XmlEvent = XmlEvent::Characters(&first_name);
+            w.write(event).ok();
+        })?;
+        write_element_block("KeyNames", None, None, w, |w| {
+            let event: XmlEvent = XmlEvent::Characters(&contribution.last_name);
+            w.write(event).ok();
+        })?;
+    }
+    Ok(())
 }
 
 fn write_element_block<W: Write, F: Fn(&mut EventWriter<W>)>(
@@ -147,147 +547,267 @@ fn write_element_block<W: Write, F: Fn(&mut EventWriter<W>)>(
     w.write(event)
 }
 
-fn handle_event<W: Write>(w: &mut EventWriter<W>, work: &mut WorkQueryWork) -> Result<()> {
+fn handle_event<W: Write>(
+    w: &mut EventWriter<W>,
+    works: &[WorkQueryWork],
+    header: &OnixHeader,
+) -> Result<()> {
     let ns_map: HashMap<String, String> = HashMap::new();
     let mut attr_map: HashMap<String, String> = HashMap::new();
 
     attr_map.insert(
         "xmlns".to_string(),
-        "http://ns.editeur.org/onix/3.0/reference".to_string(),
+        format!("http://ns.editeur.org/onix/{}/reference", ONIX_RELEASE),
     );
-    attr_map.insert("release".to_string(), "3.0".to_string());
+    attr_map.insert("release".to_string(), ONIX_RELEASE.to_string());
 
-    let work_id = format!("urn:uuid:{}", &work.work_id.to_string());
-    let (main_isbn, pdf_url, isbns) = get_publications_data(&work.publications);
+    let sender_name = header.sender_name(works);
 
     write_element_block("ONIXMessage", Some(ns_map), Some(attr_map), w, |w| {
         write_element_block("Header", None, None, w, |w| {
             write_element_block("Sender", None, None, w, |w| {
+                if let Some(sender_id) = &header.sender_id {
+                    write_party_id("Sender", sender_id, w).ok();
+                }
                 write_element_block("SenderName", None, None, w, |w| {
-                    let event: XmlEvent =
-                        XmlEvent::Characters(&work.imprint.publisher.publisher_name);
-                    w.write(event).ok();
-                })
-                .ok();
-                write_element_block("EmailAddress", None, None, w, |w| {
-                    let event: XmlEvent = XmlEvent::Characters("javi@openbookpublishers.com");
+                    let event: XmlEvent = XmlEvent::Characters(&sender_name);
                     w.write(event).ok();
                 })
                 .ok();
+                if let Some(sender_email) = &header.sender_email {
+                    write_element_block("EmailAddress", None, None, w, |w| {
+                        let event: XmlEvent = XmlEvent::Characters(sender_email);
+                        w.write(event).ok();
+                    })
+                    .ok();
+                }
             })
             .ok();
+            if header.addressee_id.is_some() || header.addressee_name.is_some() {
+                write_element_block("Addressee", None, None, w, |w| {
+                    if let Some(addressee_id) = &header.addressee_id {
+                        write_party_id("Addressee", addressee_id, w).ok();
+                    }
+                    if let Some(addressee_name) = &header.addressee_name {
+                        write_element_block("AddresseeName", None, None, w, |w| {
+                            let event: XmlEvent = XmlEvent::Characters(addressee_name);
+                            w.write(event).ok();
+                        })
+                        .ok();
+                    }
+                })
+                .ok();
+            }
             write_element_block("SentDateTime", None, None, w, |w| {
                 let utc = Utc::now().format("%Y%m%dT%H%M%S").to_string();
                 let event: XmlEvent = XmlEvent::Characters(&utc);
                 w.write(event).ok();
             })
             .ok();
+            write_element_block("MessageNote", None, None, w, |w| {
+                let note = format!("ONIX codelists Issue {}", ONIX_CODELIST_ISSUE);
+                let event: XmlEvent = XmlEvent::Characters(&note);
+                w.write(event).ok();
+            })
+            .ok();
         })
         .ok();
 
-        write_element_block("Product", None, None, w, |w| {
-            write_element_block("RecordReference", None, None, w, |w| {
+        for work in works {
+            write_product(w, work).ok();
+        }
+    })
+}
+
+/// Write a `SenderIdentifier` or `AddresseeIdentifier`, as given by `party`
+fn write_party_id<W: Write>(party: &str, id: &OnixPartyId, w: &mut EventWriter<W>) -> Result<()> {
+    write_element_block(&format!("{}Identifier", party), None, None, w, |w| {
+        write_element_block(&format!("{}IDType", party), None, None, w, |w| {
+            let event: XmlEvent = XmlEvent::Characters(&id.id_type);
+            w.write(event).ok();
+        })
+        .ok();
+        write_element_block("IDValue", None, None, w, |w| {
+            let event: XmlEvent = XmlEvent::Characters(&id.value);
+            w.write(event).ok();
+        })
+        .ok();
+    })
+}
+
+fn write_product<W: Write>(w: &mut EventWriter<W>, work: &WorkQueryWork) -> Result<()> {
+    let work_id = format!("urn:uuid:{}", &work.work_id.to_string());
+    let (main_isbn, isbns, prices, unpriced_item_type) = get_publications_data(&work.publications);
+    let main_type = main_publication_type(&work.publications);
+
+    write_element_block("Product", None, None, w, |w| {
+        write_element_block("RecordReference", None, None, w, |w| {
+            let event: XmlEvent = XmlEvent::Characters(&work_id);
+            w.write(event).ok();
+        })
+        .ok();
+        // 03 Notification confirmed on publication
+        write_element_block("NotificationType", None, None, w, |w| {
+            let event: XmlEvent = XmlEvent::Characters("03");
+            w.write(event).ok();
+        })
+        .ok();
+        // 01 Publisher
+        write_element_block("RecordSourceType", None, None, w, |w| {
+            let event: XmlEvent = XmlEvent::Characters("01");
+            w.write(event).ok();
+        })
+        .ok();
+        write_element_block("ProductIdentifier", None, None, w, |w| {
+            // 01 Proprietary
+            write_element_block("ProductIDType", None, None, w, |w| {
+                let event: XmlEvent = XmlEvent::Characters("01");
+                w.write(event).ok();
+            })
+            .ok();
+            write_element_block("IDValue", None, None, w, |w| {
                 let event: XmlEvent = XmlEvent::Characters(&work_id);
                 w.write(event).ok();
             })
             .ok();
-            // 03 Notification confirmed on publication
-            write_element_block("NotificationType", None, None, w, |w| {
-                let event: XmlEvent = XmlEvent::Characters("03");
+        })
+        .ok();
+        write_element_block("ProductIdentifier", None, None, w, |w| {
+            // 15 ISBN-13
+            write_element_block("ProductIDType", None, None, w, |w| {
+                let event: XmlEvent = XmlEvent::Characters("15");
                 w.write(event).ok();
             })
             .ok();
-            // 01 Publisher
-            write_element_block("RecordSourceType", None, None, w, |w| {
-                let event: XmlEvent = XmlEvent::Characters("01");
+            write_element_block("IDValue", None, None, w, |w| {
+                let event: XmlEvent = XmlEvent::Characters(&main_isbn);
                 w.write(event).ok();
             })
             .ok();
+        })
+        .ok();
+        if let Some(doi) = &work.doi {
             write_element_block("ProductIdentifier", None, None, w, |w| {
-                // 01 Proprietary
                 write_element_block("ProductIDType", None, None, w, |w| {
-                    let event: XmlEvent = XmlEvent::Characters("01");
+                    let event: XmlEvent = XmlEvent::Characters("06");
                     w.write(event).ok();
                 })
                 .ok();
                 write_element_block("IDValue", None, None, w, |w| {
-                    let event: XmlEvent = XmlEvent::Characters(&work_id);
+                    let sanitised_doi = doi.replace("https://doi.org/", "");
+                    let event: XmlEvent = XmlEvent::Characters(&sanitised_doi);
                     w.write(event).ok();
                 })
                 .ok();
             })
             .ok();
-            write_element_block("ProductIdentifier", None, None, w, |w| {
-                // 15 ISBN-13
-                write_element_block("ProductIDType", None, None, w, |w| {
-                    let event: XmlEvent = XmlEvent::Characters("15");
-                    w.write(event).ok();
-                })
-                .ok();
-                write_element_block("IDValue", None, None, w, |w| {
-                    let event: XmlEvent = XmlEvent::Characters(&main_isbn);
+        }
+        write_element_block("DescriptiveDetail", None, None, w, |w| {
+            // 00 Single-component retail product
+            write_element_block("ProductComposition", None, None, w, |w| {
+                let event: XmlEvent = XmlEvent::Characters("00");
+                w.write(event).ok();
+            })
+            .ok();
+            let (form, form_detail) = publication_type_to_onix_form(main_type);
+            write_element_block("ProductForm", None, None, w, |w| {
+                let event: XmlEvent = XmlEvent::Characters(form);
+                w.write(event).ok();
+            })
+            .ok();
+            if let Some(form_detail) = form_detail {
+                write_element_block("ProductFormDetail", None, None, w, |w| {
+                    let event: XmlEvent = XmlEvent::Characters(form_detail);
                     w.write(event).ok();
                 })
                 .ok();
+            }
+            // 10 Text (eye-readable)
+            write_element_block("PrimaryContentType", None, None, w, |w| {
+                let event: XmlEvent = XmlEvent::Characters("10");
+                w.write(event).ok();
             })
             .ok();
-            if let Some(doi) = &work.doi {
-                write_element_block("ProductIdentifier", None, None, w, |w| {
-                    write_element_block("ProductIDType", None, None, w, |w| {
-                        let event: XmlEvent = XmlEvent::Characters("06");
+            if let Some(license) = &work.license {
+                write_element_block("EpubLicense", None, None, w, |w| {
+                    write_element_block("EpubLicenseName", None, None, w, |w| {
+                        let event: XmlEvent = XmlEvent::Characters("Creative Commons License");
                         w.write(event).ok();
                     })
                     .ok();
-                    write_element_block("IDValue", None, None, w, |w| {
-                        let sanitised_doi = doi.replace("https://doi.org/", "");
-                        let event: XmlEvent = XmlEvent::Characters(&sanitised_doi);
-                        w.write(event).ok();
+                    write_element_block("EpubLicenseExpression", None, None, w, |w| {
+                        write_element_block("EpubLicenseExpressionType", None, None, w, |w| {
+                            let event: XmlEvent = XmlEvent::Characters("02");
+                            w.write(event).ok();
+                        })
+                        .ok();
+                        write_element_block("EpubLicenseExpressionLink", None, None, w, |w| {
+                            let license_url = license.to_string();
+                            let event: XmlEvent = XmlEvent::Characters(&license_url);
+                            w.write(event).ok();
+                        })
+                        .ok();
                     })
                     .ok();
                 })
                 .ok();
             }
-            write_element_block("DescriptiveDetail", None, None, w, |w| {
-                // 00 Single-component retail product
-                write_element_block("ProductComposition", None, None, w, |w| {
-                    let event: XmlEvent = XmlEvent::Characters("00");
-                    w.write(event).ok();
-                })
-                .ok();
-                // EB Digital download and online
-                write_element_block("ProductForm", None, None, w, |w| {
-                    let event: XmlEvent = XmlEvent::Characters("EB");
-                    w.write(event).ok();
-                })
-                .ok();
-                // E107 PDF
-                write_element_block("ProductFormDetail", None, None, w, |w| {
-                    let event: XmlEvent = XmlEvent::Characters("E107");
-                    w.write(event).ok();
-                })
-                .ok();
-                // 10 Text (eye-readable)
-                write_element_block("PrimaryContentType", None, None, w, |w| {
-                    let event: XmlEvent = XmlEvent::Characters("10");
-                    w.write(event).ok();
-                })
-                .ok();
-                if let Some(license) = &work.license {
-                    write_element_block("EpubLicense", None, None, w, |w| {
-                        write_element_block("EpubLicenseName", None, None, w, |w| {
-                            let event: XmlEvent = XmlEvent::Characters("Creative Commons License");
+            for issue in &work.issues {
+                write_element_block("Collection", None, None, w, |w| {
+                    // 10 Publisher collection
+                    write_element_block("CollectionType", None, None, w, |w| {
+                        let event: XmlEvent = XmlEvent::Characters("10");
+                        w.write(event).ok();
+                    })
+                    .ok();
+                    for issn in series_issns(issue) {
+                        write_element_block("CollectionIdentifier", None, None, w, |w| {
+                            // 02 ISSN
+                            write_element_block("CollectionIDType", None, None, w, |w| {
+                                let event: XmlEvent = XmlEvent::Characters("02");
+                                w.write(event).ok();
+                            })
+                            .ok();
+                            write_element_block("IDValue", None, None, w, |w| {
+                                let event: XmlEvent = XmlEvent::Characters(&issn);
+                                w.write(event).ok();
+                            })
+                            .ok();
+                        })
+                        .ok();
+                    }
+                    write_element_block("CollectionSequence", None, None, w, |w| {
+                        // 03 Publication order
+                        write_element_block("CollectionSequenceType", None, None, w, |w| {
+                            let event: XmlEvent = XmlEvent::Characters("03");
+                            w.write(event).ok();
+                        })
+                        .ok();
+                        write_element_block("CollectionSequenceNumber", None, None, w, |w| {
+                            let ordinal = issue.issue_ordinal.to_string();
+                            let event: XmlEvent = XmlEvent::Characters(&ordinal);
+                            w.write(event).ok();
+                        })
+                        .ok();
+                    })
+                    .ok();
+                    write_element_block("TitleDetail", None, None, w, |w| {
+                        // 01 Distinctive title
+                        write_element_block("TitleType", None, None, w, |w| {
+                            let event: XmlEvent = XmlEvent::Characters("01");
                             w.write(event).ok();
                         })
                         .ok();
-                        write_element_block("EpubLicenseExpression", None, None, w, |w| {
-                            write_element_block("EpubLicenseExpressionType", None, None, w, |w| {
+                        write_element_block("TitleElement", None, None, w, |w| {
+                            // 02 Collection level
+                            write_element_block("TitleElementLevel", None, None, w, |w| {
                                 let event: XmlEvent = XmlEvent::Characters("02");
                                 w.write(event).ok();
                             })
                             .ok();
-                            write_element_block("EpubLicenseExpressionLink", None, None, w, |w| {
-                                let license_url = license.to_string();
-                                let event: XmlEvent = XmlEvent::Characters(&license_url);
+                            write_element_block("TitleText", None, None, w, |w| {
+                                let event: XmlEvent =
+                                    XmlEvent::Characters(&issue.series.series_name);
                                 w.write(event).ok();
                             })
                             .ok();
@@ -295,11 +815,55 @@ fn handle_event<W: Write>(w: &mut EventWriter<W>, work: &mut WorkQueryWork) -> R
                         .ok();
                     })
                     .ok();
-                }
+                })
+                .ok();
+            }
+            write_element_block("TitleDetail", None, None, w, |w| {
+                // 01 Distinctive title (book)
+                write_element_block("TitleType", None, None, w, |w| {
+                    let event: XmlEvent = XmlEvent::Characters("01");
+                    w.write(event).ok();
+                })
+                .ok();
+                write_element_block("TitleElement", None, None, w, |w| {
+                    // 01 Product
+                    write_element_block("TitleElementLevel", None, None, w, |w| {
+                        let event: XmlEvent = XmlEvent::Characters("01");
+                        w.write(event).ok();
+                    })
+                    .ok();
+                    if let Some(subtitle) = &work.subtitle {
+                        write_element_block("TitleText", None, None, w, |w| {
+                            let event: XmlEvent = XmlEvent::Characters(&work.title);
+                            w.write(event).ok();
+                        })
+                        .ok();
+                        write_element_block("Subtitle", None, None, w, |w| {
+                            let event: XmlEvent = XmlEvent::Characters(&subtitle);
+                            w.write(event).ok();
+                        })
+                        .ok();
+                    } else {
+                        write_element_block("TitleText", None, None, w, |w| {
+                            let event: XmlEvent = XmlEvent::Characters(&work.full_title);
+                            w.write(event).ok();
+                        })
+                        .ok();
+                    }
+                })
+                .ok();
+            })
+            .ok();
+            for title in work.titles.iter().filter(|title| !title.is_default()) {
+                let mut lang_fmt: HashMap<String, String> = HashMap::new();
+                lang_fmt.insert(
+                    "language".to_string(),
+                    title.language_code.to_string().to_lowercase(),
+                );
                 write_element_block("TitleDetail", None, None, w, |w| {
-                    // 01 Distinctive title (book)
                     write_element_block("TitleType", None, None, w, |w| {
-                        let event: XmlEvent = XmlEvent::Characters("01");
+                        let code = title_type_to_code(&title.title_type);
+                        let event: XmlEvent = XmlEvent::Characters(code);
                         w.write(event).ok();
                     })
                     .ok();
@@ -310,129 +874,164 @@ fn handle_event<W: Write>(w: &mut EventWriter<W>, work: &mut WorkQueryWork) -> R
                             w.write(event).ok();
                         })
                         .ok();
-                        if let Some(subtitle) = &work.subtitle {
-                            write_element_block("TitleText", None, None, w, |w| {
-                                let event: XmlEvent = XmlEvent::Characters(&work.title);
-                                w.write(event).ok();
-                            })
-                            .ok();
+                        write_element_block("TitleText", None, Some(lang_fmt.to_owned()), w, |w| {
+                            let event: XmlEvent = XmlEvent::Characters(&title.title);
+                            w.write(event).ok();
+                        })
+                        .ok();
+                        if let Some(subtitle) = &title.subtitle {
                             write_element_block("Subtitle", None, None, w, |w| {
                                 let event: XmlEvent = XmlEvent::Characters(&subtitle);
                                 w.write(event).ok();
                             })
                             .ok();
-                        } else {
-                            write_element_block("TitleText", None, None, w, |w| {
-                                let event: XmlEvent = XmlEvent::Characters(&work.full_title);
-                                w.write(event).ok();
-                            })
-                            .ok();
                         }
                     })
                     .ok();
                 })
                 .ok();
-                for (mut sequence_number, contribution) in work.contributions.iter().enumerate() {
-                    sequence_number += 1;
-                    write_element_block("Contributor", None, None, w, |w| {
-                        write_element_block("SequenceNumber", None, None, w, |w| {
-                            let seq = &sequence_number.to_string();
-                            let event: XmlEvent = XmlEvent::Characters(seq);
-                            w.write(event).ok();
-                        })
-                        .ok();
-                        write_element_block("ContributorRole", None, None, w, |w| {
-                            let role = contribution_type_to_role(&contribution.contribution_type);
-                            let event: XmlEvent = XmlEvent::Characters(role);
-                            w.write(event).ok();
-                        })
-                        .ok();
-                        if let Some(orcid) = &contribution.contributor.orcid {
-                            write_element_block("NameIdentifier", None, None, w, |w| {
-                                write_element_block("NameIDType", None, None, w, |w| {
-                                    let event: XmlEvent = XmlEvent::Characters("21");
-                                    w.write(event).ok();
-                                })
-                                .ok();
-                                write_element_block("IDValue", None, None, w, |w| {
-                                    let event: XmlEvent = XmlEvent::Characters(&orcid);
-                                    w.write(event).ok();
-                                })
-                                .ok();
-                            })
-                            .ok();
-                        }
-                        if let Some(first_name) = &contribution.first_name {
-                            write_element_block("NamesBeforeKey", None, None, w, |w| {
-                                let event: XmlEvent = XmlEvent::Characters(&first_name);
-                                w.write(event).ok();
-                            })
-                            .ok();
-                            write_element_block("KeyNames", None, None, w, |w| {
-                                let event: XmlEvent = XmlEvent::Characters(&contribution.last_name);
+            }
+            for (mut sequence_number, contribution) in work.contributions.iter().enumerate() {
+                sequence_number += 1;
+                write_element_block("Contributor", None, None, w, |w| {
+                    write_element_block("SequenceNumber", None, None, w, |w| {
+                        let seq = &sequence_number.to_string();
+                        let event: XmlEvent = XmlEvent::Characters(seq);
+                        w.write(event).ok();
+                    })
+                    .ok();
+                    write_element_block("ContributorRole", None, None, w, |w| {
+                        let role = contribution_type_to_onix_role(&contribution.contribution_type);
+                        let event: XmlEvent = XmlEvent::Characters(role);
+                        w.write(event).ok();
+                    })
+                    .ok();
+                    if let Some(orcid) = &contribution.contributor.orcid {
+                        write_element_block("NameIdentifier", None, None, w, |w| {
+                            write_element_block("NameIDType", None, None, w, |w| {
+                                let event: XmlEvent = XmlEvent::Characters("21");
                                 w.write(event).ok();
                             })
                             .ok();
-                        } else {
-                            write_element_block("PersonName", None, None, w, |w| {
-                                let event: XmlEvent = XmlEvent::Characters(&contribution.full_name);
+                            write_element_block("IDValue", None, None, w, |w| {
+                                let event: XmlEvent = XmlEvent::Characters(&orcid);
                                 w.write(event).ok();
                             })
                             .ok();
-                        }
-                    })
-                    .ok();
-                }
-                for language in &work.languages {
-                    write_element_block("Language", None, None, w, |w| {
-                        write_element_block("LanguageRole", None, None, w, |w| {
-                            let role = langrel_to_role(&language.language_relation);
-                            let event: XmlEvent = XmlEvent::Characters(role);
-                            w.write(event).ok();
-                        })
-                        .ok();
-                        write_element_block("LanguageCode", None, None, w, |w| {
-                            let code = &language.language_code.to_string().to_lowercase();
-                            let event: XmlEvent = XmlEvent::Characters(&code);
-                            w.write(event).ok();
                         })
                         .ok();
+                    }
+                    write_contributor_name(w, contribution).ok();
+                })
+                .ok();
+            }
+            for language in &work.languages {
+                write_element_block("Language", None, None, w, |w| {
+                    write_element_block("LanguageRole", None, None, w, |w| {
+                        let role = langrel_to_role(&language.language_relation);
+                        let event: XmlEvent = XmlEvent::Characters(role);
+                        w.write(event).ok();
                     })
                     .ok();
-                }
-                if let Some(page_count) = &work.page_count {
-                    write_element_block("Extent", None, None, w, |w| {
-                        // 00 Main content
-                        write_element_block("ExtentType", None, None, w, |w| {
-                            let event: XmlEvent = XmlEvent::Characters("00");
+                    write_element_block("LanguageCode", None, None, w, |w| {
+                        let code = &language.language_code.to_string().to_lowercase();
+                        let event: XmlEvent = XmlEvent::Characters(&code);
+                        w.write(event).ok();
+                    })
+                    .ok();
+                })
+                .ok();
+            }
+            if let Some(page_count) = &work.page_count {
+                write_element_block("Extent", None, None, w, |w| {
+                    // 00 Main content
+                    write_element_block("ExtentType", None, None, w, |w| {
+                        let event: XmlEvent = XmlEvent::Characters("00");
+                        w.write(event).ok();
+                    })
+                    .ok();
+                    write_element_block("ExtentValue", None, None, w, |w| {
+                        let pcount = page_count.to_string();
+                        let event: XmlEvent = XmlEvent::Characters(&pcount);
+                        w.write(event).ok();
+                    })
+                    .ok();
+                    // 03 Pages
+                    write_element_block("ExtentUnit", None, None, w, |w| {
+                        let event: XmlEvent = XmlEvent::Characters("03");
+                        w.write(event).ok();
+                    })
+                    .ok();
+                })
+                .ok();
+            }
+            for subject in &work.subjects {
+                write_element_block("Subject", None, None, w, |w| {
+                    if work
+                        .main_subjects
+                        .iter()
+                        .any(|m| m.subject_id == subject.subject_id)
+                    {
+                        write_element_block("MainSubject", None, None, w, |_| {}).ok();
+                    }
+                    write_element_block("SubjectSchemeIdentifier", None, None, w, |w| {
+                        let scheme = stype_to_scheme(&subject.subject_type);
+                        let event: XmlEvent = XmlEvent::Characters(scheme);
+                        w.write(event).ok();
+                    })
+                    .ok();
+                    write_element_block("SubjectCode", None, None, w, |w| {
+                        let event: XmlEvent = XmlEvent::Characters(&subject.subject_code);
+                        w.write(event).ok();
+                    })
+                    .ok();
+                })
+                .ok();
+            }
+        })
+        .ok();
+        let abstracts = abstract_text_contents(&work);
+        if !abstracts.is_empty() || work.toc.is_some() {
+            write_element_block("CollateralDetail", None, None, w, |w| {
+                for (text_type, language, text) in &abstracts {
+                    let mut lang_fmt: HashMap<String, String> = HashMap::new();
+                    lang_fmt.insert("language".to_string(), language.to_string());
+                    write_element_block("TextContent", None, None, w, |w| {
+                        write_element_block("TextType", None, None, w, |w| {
+                            let event: XmlEvent = XmlEvent::Characters(text_type);
                             w.write(event).ok();
                         })
                         .ok();
-                        write_element_block("ExtentValue", None, None, w, |w| {
-                            let pcount = page_count.to_string();
-                            let event: XmlEvent = XmlEvent::Characters(&pcount);
+                        // 00 Unrestricted
+                        write_element_block("ContentAudience", None, None, w, |w| {
+                            let event: XmlEvent = XmlEvent::Characters("00");
                             w.write(event).ok();
                         })
                         .ok();
-                        // 03 Pages
-                        write_element_block("ExtentUnit", None, None, w, |w| {
-                            let event: XmlEvent = XmlEvent::Characters("03");
+                        write_element_block("Text", None, Some(lang_fmt.to_owned()), w, |w| {
+                            let event: XmlEvent = XmlEvent::Characters(text);
                             w.write(event).ok();
                         })
                         .ok();
                     })
                     .ok();
                 }
-                for subject in &work.subjects {
-                    write_element_block("Subject", None, None, w, |w| {
-                        write_element_block("SubjectSchemeIdentifier", None, None, w, |w| {
-                            let scheme = stype_to_scheme(&subject.subject_type);
-                            let event: XmlEvent = XmlEvent::Characters(scheme);
+                if let Some(toc) = &work.toc {
+                    write_element_block("TextContent", None, None, w, |w| {
+                        // 04 Table of contents
+                        write_element_block("TextType", None, None, w, |w| {
+                            let event: XmlEvent = XmlEvent::Characters("04");
+                            w.write(event).ok();
+                        })
+                        .ok();
+                        // 00 Unrestricted
+                        write_element_block("ContentAudience", None, None, w, |w| {
+                            let event: XmlEvent = XmlEvent::Characters("00");
                             w.write(event).ok();
                         })
                         .ok();
-                        write_element_block("SubjectCode", None, None, w, |w| {
-                            let event: XmlEvent = XmlEvent::Characters(&subject.subject_code);
+                        write_element_block("Text", None, None, w, |w| {
+                            let event: XmlEvent = XmlEvent::Characters(&toc);
                             w.write(event).ok();
                         })
                         .ok();
@@ -441,161 +1040,178 @@ fn handle_event<W: Write>(w: &mut EventWriter<W>, work: &mut WorkQueryWork) -> R
                 }
             })
             .ok();
-            if work.long_abstract.is_some() || work.toc.is_some() {
-                write_element_block("CollateralDetail", None, None, w, |w| {
-                    if let Some(labstract) = &work.long_abstract {
-                        let mut lang_fmt: HashMap<String, String> = HashMap::new();
-                        lang_fmt.insert("language".to_string(), "eng".to_string());
-                        write_element_block("TextContent", None, None, w, |w| {
-                            // 03 Description ("30 Abstract" not implemented in OAPEN)
-                            write_element_block("TextType", None, None, w, |w| {
-                                let event: XmlEvent = XmlEvent::Characters("03");
-                                w.write(event).ok();
-                            })
-                            .ok();
-                            // 00 Unrestricted
-                            write_element_block("ContentAudience", None, None, w, |w| {
-                                let event: XmlEvent = XmlEvent::Characters("00");
+        }
+        write_element_block("PublishingDetail", None, None, w, |w| {
+            write_element_block("Imprint", None, None, w, |w| {
+                write_element_block("ImprintName", None, None, w, |w| {
+                    let event: XmlEvent = XmlEvent::Characters(&work.imprint.imprint_name);
+                    w.write(event).ok();
+                })
+                .ok();
+            })
+            .ok();
+            write_element_block("Publisher", None, None, w, |w| {
+                // 01 Publisher
+                write_element_block("PublishingRole", None, None, w, |w| {
+                    let event: XmlEvent = XmlEvent::Characters("01");
+                    w.write(event).ok();
+                })
+                .ok();
+                write_element_block("PublisherName", None, None, w, |w| {
+                    let event: XmlEvent =
+                        XmlEvent::Characters(&work.imprint.publisher.publisher_name);
+                    w.write(event).ok();
+                })
+                .ok();
+            })
+            .ok();
+            for funding in &work.fundings {
+                write_element_block("Publisher", None, None, w, |w| {
+                    // 16 Funding body
+                    write_element_block("PublishingRole", None, None, w, |w| {
+                        let event: XmlEvent = XmlEvent::Characters("16");
+                        w.write(event).ok();
+                    })
+                    .ok();
+                    if let Some(funder_doi) = &funding.funder.funder_doi {
+                        write_element_block("PublisherIdentifier", None, None, w, |w| {
+                            // 32 FundRef DOI
+                            write_element_block("PublisherIDType", None, None, w, |w| {
+                                let event: XmlEvent = XmlEvent::Characters("32");
                                 w.write(event).ok();
                             })
                             .ok();
-                            write_element_block("Text", None, Some(lang_fmt.to_owned()), w, |w| {
-                                let event: XmlEvent = XmlEvent::Characters(&labstract);
+                            write_element_block("IDValue", None, None, w, |w| {
+                                let sanitised_doi = funder_doi.replace("https://doi.org/", "");
+                                let event: XmlEvent = XmlEvent::Characters(&sanitised_doi);
                                 w.write(event).ok();
                             })
                             .ok();
                         })
                         .ok();
                     }
-                    if let Some(toc) = &work.toc {
-                        write_element_block("TextContent", None, None, w, |w| {
-                            // 04 Table of contents
-                            write_element_block("TextType", None, None, w, |w| {
-                                let event: XmlEvent = XmlEvent::Characters("04");
-                                w.write(event).ok();
-                            })
-                            .ok();
-                            // 00 Unrestricted
-                            write_element_block("ContentAudience", None, None, w, |w| {
-                                let event: XmlEvent = XmlEvent::Characters("00");
-                                w.write(event).ok();
-                            })
-                            .ok();
-                            write_element_block("Text", None, None, w, |w| {
-                                let event: XmlEvent = XmlEvent::Characters(&toc);
-                                w.write(event).ok();
-                            })
-                            .ok();
+                    write_element_block("PublisherName", None, None, w, |w| {
+                        let event: XmlEvent = XmlEvent::Characters(&funding.funder.funder_name);
+                        w.write(event).ok();
+                    })
+                    .ok();
+                    let identifiers = funding_identifiers(funding);
+                    if !identifiers.is_empty() {
+                        write_element_block("Funding", None, None, w, |w| {
+                            for (name, value) in &identifiers {
+                                write_element_block("FundingIdentifier", None, None, w, |w| {
+                                    // 01 Proprietary
+                                    write_element_block("FundingIDType", None, None, w, |w| {
+                                        let event: XmlEvent = XmlEvent::Characters("01");
+                                        w.write(event).ok();
+                                    })
+                                    .ok();
+                                    write_element_block("IDTypeName", None, None, w, |w| {
+                                        let event: XmlEvent = XmlEvent::Characters(name);
+                                        w.write(event).ok();
+                                    })
+                                    .ok();
+                                    write_element_block("IDValue", None, None, w, |w| {
+                                        let event: XmlEvent = XmlEvent::Characters(value);
+                                        w.write(event).ok();
+                                    })
+                                    .ok();
+                                })
+                                .ok();
+                            }
                         })
                         .ok();
                     }
                 })
                 .ok();
             }
-            write_element_block("PublishingDetail", None, None, w, |w| {
-                write_element_block("Imprint", None, None, w, |w| {
-                    write_element_block("ImprintName", None, None, w, |w| {
-                        let event: XmlEvent = XmlEvent::Characters(&work.imprint.imprint_name);
-                        w.write(event).ok();
-                    })
-                    .ok();
+            if let Some(place) = &work.place {
+                write_element_block("CityOfPublication", None, None, w, |w| {
+                    let event: XmlEvent = XmlEvent::Characters(&place);
+                    w.write(event).ok();
                 })
                 .ok();
-                write_element_block("Publisher", None, None, w, |w| {
-                    // 01 Publisher
-                    write_element_block("PublishingRole", None, None, w, |w| {
-                        let event: XmlEvent = XmlEvent::Characters("01");
-                        w.write(event).ok();
-                    })
-                    .ok();
-                    write_element_block("PublisherName", None, None, w, |w| {
-                        let event: XmlEvent =
-                            XmlEvent::Characters(&work.imprint.publisher.publisher_name);
+            }
+            write_element_block("PublishingStatus", None, None, w, |w| {
+                let status = wstatus_to_status(&work.work_status);
+                let event: XmlEvent = XmlEvent::Characters(status);
+                w.write(event).ok();
+            })
+            .ok();
+            if let Some(date) = &work.publication_date {
+                let mut date_fmt: HashMap<String, String> = HashMap::new();
+                date_fmt.insert(
+                    "dateformat".to_string(),
+                    "01".to_string(), // 01 YYYYMM
+                );
+                write_element_block("PublishingDate", None, None, w, |w| {
+                    // 19 Publication date of print counterpart
+                    write_element_block("PublishingDateRole", None, None, w, |w| {
+                        let event: XmlEvent = XmlEvent::Characters("19");
                         w.write(event).ok();
                     })
                     .ok();
-                })
-                .ok();
-                if let Some(place) = &work.place {
-                    write_element_block("CityOfPublication", None, None, w, |w| {
-                        let event: XmlEvent = XmlEvent::Characters(&place);
+                    // dateformat="01" YYYYMM
+                    write_element_block("Date", None, Some(date_fmt.to_owned()), w, |w| {
+                        let pub_date = date.format("%Y%m").to_string();
+                        let event: XmlEvent = XmlEvent::Characters(&pub_date);
                         w.write(event).ok();
                     })
                     .ok();
-                }
-                write_element_block("PublishingStatus", None, None, w, |w| {
-                    let status = wstatus_to_status(&work.work_status);
-                    let event: XmlEvent = XmlEvent::Characters(status);
-                    w.write(event).ok();
                 })
                 .ok();
-                if let Some(date) = &work.publication_date {
-                    let mut date_fmt: HashMap<String, String> = HashMap::new();
-                    date_fmt.insert(
-                        "dateformat".to_string(),
-                        "01".to_string(), // 01 YYYYMM
-                    );
-                    write_element_block("PublishingDate", None, None, w, |w| {
-                        // 19 Publication date of print counterpart
-                        write_element_block("PublishingDateRole", None, None, w, |w| {
-                            let event: XmlEvent = XmlEvent::Characters("19");
-                            w.write(event).ok();
-                        })
-                        .ok();
-                        // dateformat="01" YYYYMM
-                        write_element_block("Date", None, Some(date_fmt.to_owned()), w, |w| {
-                            let pub_date = date.format("%Y%m").to_string();
-                            let event: XmlEvent = XmlEvent::Characters(&pub_date);
+            }
+        })
+        .ok();
+        // The work's other formats; publications without an ISBN cannot be referred to
+        let related_isbns: Vec<&String> = isbns.iter().filter(|isbn| **isbn != main_isbn).collect();
+        if !related_isbns.is_empty() {
+            write_element_block("RelatedMaterial", None, None, w, |w| {
+                for isbn in &related_isbns {
+                    write_element_block("RelatedProduct", None, None, w, |w| {
+                        // 06 Alternative format
+                        write_element_block("ProductRelationCode", None, None, w, |w| {
+                            let event: XmlEvent = XmlEvent::Characters("06");
                             w.write(event).ok();
                         })
                         .ok();
-                    })
-                    .ok();
-                }
-            })
-            .ok();
-            if !isbns.is_empty() {
-                write_element_block("RelatedMaterial", None, None, w, |w| {
-                    for isbn in &isbns {
-                        write_element_block("RelatedProduct", None, None, w, |w| {
-                            // 06 Alternative format
-                            write_element_block("ProductRelationCode", None, None, w, |w| {
-                                let event: XmlEvent = XmlEvent::Characters("06");
+                        write_element_block("ProductIdentifier", None, None, w, |w| {
+                            // 15 ISBN-13
+                            write_element_block("ProductIDType", None, None, w, |w| {
+                                let event: XmlEvent = XmlEvent::Characters("15");
                                 w.write(event).ok();
                             })
                             .ok();
-                            write_element_block("ProductIdentifier", None, None, w, |w| {
-                                // 06 ISBN
-                                write_element_block("ProductIDType", None, None, w, |w| {
-                                    let event: XmlEvent = XmlEvent::Characters("06");
-                                    w.write(event).ok();
-                                })
-                                .ok();
-                                write_element_block("IDValue", None, None, w, |w| {
-                                    let event: XmlEvent = XmlEvent::Characters(&isbn);
-                                    w.write(event).ok();
-                                })
-                                .ok();
+                            write_element_block("IDValue", None, None, w, |w| {
+                                let event: XmlEvent = XmlEvent::Characters(&isbn);
+                                w.write(event).ok();
                             })
                             .ok();
                         })
                         .ok();
-                    }
-                })
-                .ok();
-            }
-            write_element_block("ProductSupply", None, None, w, |w| {
-                let mut supplies: HashMap<String, String> = HashMap::new();
-                supplies.insert(
-                    pdf_url.to_string(),
-                    "Publisher's website: download the title".to_string(),
-                );
-                if let Some(landing_page) = &work.landing_page {
-                    supplies.insert(
-                        landing_page.to_string(),
-                        "Publisher's website: web shop".to_string(),
-                    );
+                    })
+                    .ok();
                 }
+            })
+            .ok();
+        }
+        let mut supplies: HashMap<String, String> = HashMap::new();
+        // Works without a canonical location are still offered through their landing page
+        if let Some(location) = work.canonical_location() {
+            supplies.insert(
+                location.to_string(),
+                "Publisher's website: download the title".to_string(),
+            );
+        }
+        if let Some(landing_page) = &work.landing_page {
+            supplies.insert(
+                landing_page.to_string(),
+                "Publisher's website: web shop".to_string(),
+            );
+        }
+        // ProductSupply must hold at least one SupplyDetail, so with no URL it is left out
+        if !supplies.is_empty() {
+            write_element_block("ProductSupply", None, None, w, |w| {
                 for (url, description) in supplies.iter() {
                     write_element_block("SupplyDetail", None, None, w, |w| {
                         write_element_block("Supplier", None, None, w, |w| {
@@ -638,18 +1254,480 @@ fn handle_event<W: Write>(w: &mut EventWriter<W>, work: &mut WorkQueryWork) -> R
                             w.write(event).ok();
                         })
                         .ok();
-                        // 04 Contact supplier
-                        write_element_block("UnpricedItemType", None, None, w, |w| {
-                            let event: XmlEvent = XmlEvent::Characters("04");
-                            w.write(event).ok();
-                        })
-                        .ok();
+                        if prices.is_empty() {
+                            // Contact supplier unless the publication says why it has no price
+                            let code = unpriced_item_type
+                                .map(unpriced_item_type_to_code)
+                                .unwrap_or("04");
+                            write_element_block("UnpricedItemType", None, None, w, |w| {
+                                let event: XmlEvent = XmlEvent::Characters(code);
+                                w.write(event).ok();
+                            })
+                            .ok();
+                        }
+                        for price in prices {
+                            write_element_block("Price", None, None, w, |w| {
+                                write_element_block("PriceType", None, None, w, |w| {
+                                    let code = price_type_to_code(&price.price_type);
+                                    let event: XmlEvent = XmlEvent::Characters(code);
+                                    w.write(event).ok();
+                                })
+                                .ok();
+                                write_element_block("PriceAmount", None, None, w, |w| {
+                                    let amount = format!("{:.2}", price.unit_price);
+                                    let event: XmlEvent = XmlEvent::Characters(&amount);
+                                    w.write(event).ok();
+                                })
+                                .ok();
+                                write_element_block("CurrencyCode", None, None, w, |w| {
+                                    let code = price.currency_code.to_string();
+                                    let event: XmlEvent = XmlEvent::Characters(&code);
+                                    w.write(event).ok();
+                                })
+                                .ok();
+                                write_element_block("Territory", None, None, w, |w| {
+                                    // WORLD is a region; anything else is a list of countries
+                                    let element = if price.territory == "WORLD" {
+                                        "RegionsIncluded"
+                                    } else {
+                                        "CountriesIncluded"
+                                    };
+                                    write_element_block(element, None, None, w, |w| {
+                                        let event: XmlEvent =
+                                            XmlEvent::Characters(&price.territory);
+                                        w.write(event).ok();
+                                    })
+                                    .ok();
+                                })
+                                .ok();
+                            })
+                            .ok();
+                        }
                     })
                     .ok();
                 }
             })
             .ok();
-        })
-        .ok();
+        }
     })
 }
+
+#[test]
+fn test_publication_type_to_onix_form() {
+    // Listing every variant here and in the mapping means a new publication type fails to
+    // compile until it is given a form code
+    for publication_type in &[
+        PublicationType::PAPERBACK,
+        PublicationType::HARDBACK,
+        PublicationType::PDF,
+        PublicationType::HTML,
+        PublicationType::XML,
+        PublicationType::EPUB,
+        PublicationType::MOBI,
+    ] {
+        let (form, form_detail) = publication_type_to_onix_form(publication_type);
+        assert_eq!(form.len(), 2);
+        if let Some(form_detail) = form_detail {
+            assert!(form_detail.starts_with('E'));
+        }
+    }
+    assert_eq!(
+        publication_type_to_onix_form(&PublicationType::PDF),
+        ("EB", Some("E107"))
+    );
+    assert_eq!(
+        publication_type_to_onix_form(&PublicationType::PAPERBACK),
+        ("BC", None)
+    );
+    assert_eq!(
+        publication_type_to_onix_form(&PublicationType::HARDBACK),
+        ("BB", None)
+    );
+}
+
+#[cfg(test)]
+fn test_work(landing_page: Option<&str>, pdf_url: Option<&str>) -> WorkQueryWork {
+    serde_json::from_value(serde_json::json!({
+        "workId": "00000000-0000-0000-aaaa-000000000001",
+        "workStatus": "ACTIVE",
+        "fullTitle": "Book Title: Book Subtitle",
+        "title": "Book Title",
+        "subtitle": "Book Subtitle",
+        "workType": "MONOGRAPH",
+        "landingPage": landing_page,
+        "updatedAt": 1_600_000_000.0,
+        "imprint": {
+            "imprintName": "Imprint",
+            "publisher": {
+                "publisherId": "00000000-0000-0000-aaaa-000000000002",
+                "publisherName": "Publisher"
+            }
+        },
+        "contributions": [],
+        "languages": [],
+        "titles": [],
+        "abstracts": [],
+        "publications": [{
+            "publicationId": "00000000-0000-0000-aaaa-000000000003",
+            "publicationType": "PDF",
+            "publicationUrl": pdf_url,
+            "isbn": "978-3-16-148410-0",
+            "prices": []
+        }],
+        "subjects": [],
+        "mainSubjects": [],
+        "fundings": [],
+        "issues": []
+    }))
+    .unwrap()
+}
+
+#[test]
+fn test_onix_supply_without_canonical_location() {
+    let work = test_work(Some("https://example.org/book"), None);
+    assert_eq!(work.canonical_location(), None);
+    let onix = String::from_utf8(generate_onix_3(work, &OnixHeader::default()).unwrap()).unwrap();
+    assert!(onix.contains("<WebsiteLink>https://example.org/book</WebsiteLink>"));
+    assert!(!onix.contains("download the title"));
+    assert!(!onix.contains("<WebsiteLink />"));
+
+    let work = test_work(None, None);
+    let onix = String::from_utf8(generate_onix_3(work, &OnixHeader::default()).unwrap()).unwrap();
+    assert!(!onix.contains("<ProductSupply>"));
+}
+
+#[test]
+fn test_onix_supply_with_canonical_location() {
+    let work = test_work(None, Some("https://example.org/book.pdf"));
+    assert_eq!(
+        work.canonical_location(),
+        Some("https://example.org/book.pdf")
+    );
+    let onix = String::from_utf8(generate_onix_3(work, &OnixHeader::default()).unwrap()).unwrap();
+    assert!(onix.contains("<WebsiteLink>https://example.org/book.pdf</WebsiteLink>"));
+    assert!(onix.contains("download the title"));
+}
+
+#[test]
+fn test_validate_onix_3_agrees_with_generator() {
+    let errors = |work: &WorkQueryWork| -> Vec<String> {
+        validate_onix_3(work)
+            .into_iter()
+            .filter(|issue| issue.severity == crate::validation::Severity::Error)
+            .map(|issue| issue.field)
+            .collect()
+    };
+    // the generator writes no download link without a PDF URL, which validation reports
+    let work = test_work(None, None);
+    assert_eq!(errors(&work), vec!["publication_url"]);
+    let work = test_work(None, Some("https://example.org/book.pdf"));
+    assert!(errors(&work).is_empty());
+
+    let mut work = test_work(None, Some("https://example.org/book.pdf"));
+    work.publications[0].isbn = None;
+    assert_eq!(errors(&work), vec!["isbn"]);
+}
+
+#[test]
+fn test_onix_main_subject() {
+    let mut work = test_work(None, None);
+    work.subjects = serde_json::from_value(serde_json::json!([
+        {
+            "subjectId": "00000000-0000-0000-bbbb-000000000001",
+            "subjectCode": "SOC026000",
+            "subjectType": "BISAC"
+        },
+        {
+            "subjectId": "00000000-0000-0000-bbbb-000000000002",
+            "subjectCode": "SOC000000",
+            "subjectType": "BISAC"
+        },
+        {
+            "subjectId": "00000000-0000-0000-bbbb-000000000003",
+            "subjectCode": "HIS000000",
+            "subjectType": "BISAC"
+        }
+    ]))
+    .unwrap();
+    work.main_subjects = serde_json::from_value(serde_json::json!([
+        { "subjectId": "00000000-0000-0000-bbbb-000000000002" }
+    ]))
+    .unwrap();
+    let onix = String::from_utf8(generate_onix_3(work, &OnixHeader::default()).unwrap()).unwrap();
+    assert_eq!(onix.matches("<MainSubject />").count(), 1);
+    // the flag belongs to the subject block it opens
+    let flagged = &onix[onix.find("<MainSubject />").unwrap()..];
+    let flagged = &flagged[..flagged.find("</Subject>").unwrap()];
+    assert!(flagged.contains("<SubjectCode>SOC000000</SubjectCode>"));
+}
+
+#[cfg(test)]
+fn test_contributor_name(
+    first_name: Option<&str>,
+    last_name: &str,
+    full_name: &str,
+    orcid: Option<&str>,
+) -> (bool, String) {
+    let contribution: WorkQueryWorkContributions = serde_json::from_value(serde_json::json!({
+        "contributionType": "AUTHOR",
+        "mainContribution": true,
+        "createdAt": 0.0,
+        "firstName": first_name,
+        "lastName": last_name,
+        "fullName": full_name,
+        "contributor": { "orcid": orcid }
+    }))
+    .unwrap();
+    let mut buffer = Vec::new();
+    let mut writer = EmitterConfig::new()
+        .write_document_declaration(false)
+        .create_writer(&mut buffer);
+    write_contributor_name(&mut writer, &contribution).unwrap();
+    (
+        is_corporate_name(&contribution),
+        String::from_utf8(buffer).unwrap(),
+    )
+}
+
+#[test]
+fn test_onix_contributor_name() {
+    let (corporate, name) = test_contributor_name(Some("Jane"), "Doe", "Jane Doe", None);
+    assert!(!corporate);
+    assert_eq!(
+        name,
+        "<PersonName>Jane Doe</PersonName>\
+         <NamesBeforeKey>Jane</NamesBeforeKey>\
+         <KeyNames>Doe</KeyNames>"
+    );
+
+    // a mononymous person has no first name to split the name on
+    let (corporate, name) = test_contributor_name(None, "Plato", "Plato", None);
+    assert!(!corporate);
+    assert_eq!(name, "<PersonName>Plato</PersonName>");
+
+    let (corporate, name) =
+        test_contributor_name(None, "Open Book Collective", "Open Book Collective", None);
+    assert!(corporate);
+    assert_eq!(name, "<CorporateName>Open Book Collective</CorporateName>");
+
+    // only people have ORCIDs, whatever their name looks like
+    let (corporate, name) = test_contributor_name(
+        None,
+        "Open Book Collective",
+        "Open Book Collective",
+        Some("https://orcid.org/0000-0002-1825-0097"),
+    );
+    assert!(!corporate);
+    assert_eq!(name, "<PersonName>Open Book Collective</PersonName>");
+}
+
+#[test]
+fn test_skip_unpriced() {
+    let unpriced = test_work(None, None);
+    assert!(is_unpriced_closed(&unpriced));
+
+    let mut licensed = test_work(None, None);
+    licensed.work_id = Uuid::parse_str("00000000-0000-0000-aaaa-000000000011").unwrap();
+    licensed.license = Some("https://creativecommons.org/licenses/by/4.0/".to_string());
+    assert!(!is_unpriced_closed(&licensed));
+
+    let mut priced = test_work(None, None);
+    priced.work_id = Uuid::parse_str("00000000-0000-0000-aaaa-000000000012").unwrap();
+    priced.publications[0].prices = serde_json::from_value(serde_json::json!([{
+        "currencyCode": "GBP",
+        "unitPrice": 10.0,
+        "priceType": "RRP_INCLUDING_TAX",
+        "territory": "WORLD"
+    }]))
+    .unwrap();
+    assert!(!is_unpriced_closed(&priced));
+
+    // a publication saying why it has no price is not closed, even without a licence
+    let mut free = test_work(None, None);
+    free.work_id = Uuid::parse_str("00000000-0000-0000-aaaa-000000000014").unwrap();
+    free.publications[0].unpriced_item_type = Some(UnpricedItemType::FREE_OF_CHARGE);
+    assert!(!is_unpriced_closed(&free));
+
+    let mut no_isbn = test_work(None, None);
+    no_isbn.work_id = Uuid::parse_str("00000000-0000-0000-aaaa-000000000013").unwrap();
+    for publication in no_isbn.publications.iter_mut() {
+        publication.isbn = None;
+    }
+
+    let unpriced_publication_id = unpriced.publications[0].publication_id;
+    let no_isbn_id = no_isbn.work_id;
+    let (works, skipped) = skip_unpriced(vec![unpriced, licensed, priced, free, no_isbn]);
+    assert_eq!(
+        skipped,
+        vec![
+            SkippedProduct {
+                id: unpriced_publication_id,
+                reason: "no price or open licence",
+            },
+            SkippedProduct {
+                id: no_isbn_id,
+                reason: "no publication with an ISBN",
+            },
+        ]
+    );
+    assert!(skipped.iter().all(|product| !product.reason.is_empty()));
+    let work_ids: Vec<Uuid> = works.iter().map(|work| work.work_id).collect();
+    assert_eq!(
+        work_ids,
+        vec![
+            Uuid::parse_str("00000000-0000-0000-aaaa-000000000011").unwrap(),
+            Uuid::parse_str("00000000-0000-0000-aaaa-000000000012").unwrap(),
+            Uuid::parse_str("00000000-0000-0000-aaaa-000000000014").unwrap(),
+        ]
+    );
+}
+
+#[test]
+fn test_onix_related_products() {
+    let mut work = test_work(None, None);
+    work.publications = serde_json::from_value(serde_json::json!([
+        {
+            "publicationId": "00000000-0000-0000-aaaa-000000000003",
+            "publicationType": "PDF",
+            "isbn": "978-3-16-148410-0",
+            "prices": []
+        },
+        {
+            "publicationId": "00000000-0000-0000-aaaa-000000000004",
+            "publicationType": "PAPERBACK",
+            "isbn": "978-1-78374-238-7",
+            "prices": []
+        },
+        {
+            "publicationId": "00000000-0000-0000-aaaa-000000000005",
+            "publicationType": "HARDBACK",
+            "isbn": "978-1-78374-239-4",
+            "prices": []
+        },
+        {
+            "publicationId": "00000000-0000-0000-aaaa-000000000006",
+            "publicationType": "HTML",
+            "isbn": null,
+            "prices": []
+        }
+    ]))
+    .unwrap();
+    let onix = String::from_utf8(generate_onix_3(work, &OnixHeader::default()).unwrap()).unwrap();
+    let related = &onix[onix.find("<RelatedMaterial>").unwrap()..];
+    let related = &related[..related.find("</RelatedMaterial>").unwrap()];
+    // the product itself is the PDF, so only the print formats are alternatives to it
+    assert_eq!(related.matches("<RelatedProduct>").count(), 2);
+    assert_eq!(
+        related.matches("<ProductIDType>15</ProductIDType>").count(),
+        2
+    );
+    assert!(related.contains("<IDValue>9781783742387</IDValue>"));
+    assert!(related.contains("<IDValue>9781783742394</IDValue>"));
+    assert!(!related.contains("9783161484100"));
+}
+
+#[test]
+fn test_onix_hardback_only() {
+    let mut work = test_work(None, None);
+    work.publications = serde_json::from_value(serde_json::json!([
+        {
+            "publicationId": "00000000-0000-0000-aaaa-000000000003",
+            "publicationType": "PDF",
+            "prices": []
+        },
+        {
+            "publicationId": "00000000-0000-0000-aaaa-000000000005",
+            "publicationType": "HARDBACK",
+            "isbn": "978-1-78374-239-4",
+            "prices": []
+        }
+    ]))
+    .unwrap();
+    assert_eq!(
+        main_publication_type(&work.publications),
+        &PublicationType::HARDBACK
+    );
+    assert!(validate_onix_3(&work)
+        .iter()
+        .all(|issue| issue.field != "isbn"));
+    let onix = String::from_utf8(generate_onix_3(work, &OnixHeader::default()).unwrap()).unwrap();
+    assert!(onix.contains("<IDValue>9781783742394</IDValue>"));
+    assert!(onix.contains("<ProductForm>BB</ProductForm>"));
+    assert!(!onix.contains("<ProductFormDetail>"));
+}
+
+#[test]
+fn test_contribution_type_to_onix_role() {
+    use thoth_api::contribution::model::ContributionType as ApiContributionType;
+
+    let contribution_types = [
+        ApiContributionType::Author,
+        ApiContributionType::Editor,
+        ApiContributionType::Translator,
+        ApiContributionType::Photographer,
+        ApiContributionType::Ilustrator,
+        ApiContributionType::MusicEditor,
+        ApiContributionType::ForewordBy,
+        ApiContributionType::IntroductionBy,
+        ApiContributionType::AfterwordBy,
+        ApiContributionType::PrefaceBy,
+    ];
+    for contribution_type in contribution_types.iter() {
+        // Fails to compile when a contribution type is added, as a reminder to list it above
+        match contribution_type {
+            ApiContributionType::Author
+            | ApiContributionType::Editor
+            | ApiContributionType::Translator
+            | ApiContributionType::Photographer
+            | ApiContributionType::Ilustrator
+            | ApiContributionType::MusicEditor
+            | ApiContributionType::ForewordBy
+            | ApiContributionType::IntroductionBy
+            | ApiContributionType::AfterwordBy
+            | ApiContributionType::PrefaceBy => {}
+        }
+        let client_type: ContributionType =
+            serde_json::from_value(serde_json::to_value(contribution_type).unwrap()).unwrap();
+        assert!(
+            !matches!(client_type, ContributionType::Other(_)),
+            "{:?} is missing from the client schema",
+            contribution_type
+        );
+        let role = contribution_type_to_onix_role(&client_type);
+        assert!(
+            matches!(role.as_bytes(), [b'A'..=b'F', b'0'..=b'9', b'0'..=b'9']),
+            "{:?} maps to {}, which is not a code list 17 contributor role",
+            contribution_type,
+            role
+        );
+    }
+}
+
+#[test]
+fn test_onix_header() {
+    let onix =
+        String::from_utf8(generate_onix_3(test_work(None, None), &OnixHeader::default()).unwrap())
+            .unwrap();
+    assert!(onix.contains("<SenderName>Publisher</SenderName>"));
+    assert!(!onix.contains("<SenderIdentifier>"));
+    assert!(!onix.contains("<EmailAddress>"));
+    assert!(!onix.contains("<Addressee>"));
+
+    let header = OnixHeader {
+        sender_name: Some("Thoth Open Metadata".to_string()),
+        sender_id: Some(OnixPartyId {
+            id_type: GLN_ID_TYPE.to_string(),
+            value: "5012345678900".to_string(),
+        }),
+        sender_email: Some("metadata@example.org".to_string()),
+        addressee_name: Some("Distributor".to_string()),
+        addressee_id: None,
+    };
+    let onix = String::from_utf8(generate_onix_3(test_work(None, None), &header).unwrap()).unwrap();
+    assert!(onix.contains("<SenderIDType>06</SenderIDType>"));
+    assert!(onix.contains("<IDValue>5012345678900</IDValue>"));
+    assert!(onix.contains("<SenderName>Thoth Open Metadata</SenderName>"));
+    assert!(onix.contains("<EmailAddress>metadata@example.org</EmailAddress>"));
+    assert!(onix.contains("<AddresseeName>Distributor</AddresseeName>"));
+    assert!(!onix.contains("<AddresseeIdentifier>"));
+}
@@ -0,0 +1,60 @@
+use thoth_client::work::work_query::ContributionType;
+use thoth_client::work::work_query::WorkQueryWork;
+
+/// Describe a work with the `<meta>` tags Google Scholar reads when indexing a landing page
+pub fn generate_scholar_meta(work: &WorkQueryWork) -> String {
+    let mut tags: Vec<(&str, String)> = vec![("citation_title", work.full_title.clone())];
+    // Edited books are credited to their editors when they have no authors
+    let author_type = if work
+        .contributions
+        .iter()
+        .any(|c| c.contribution_type.eq(&ContributionType::AUTHOR))
+    {
+        ContributionType::AUTHOR
+    } else {
+        ContributionType::EDITOR
+    };
+    for contribution in work
+        .contributions
+        .iter()
+        .filter(|c| c.contribution_type.eq(&author_type))
+    {
+        tags.push(("citation_author", contribution.full_name.clone()));
+    }
+    if let Some(date) = &work.publication_date {
+        tags.push((
+            "citation_publication_date",
+            date.format("%Y/%m/%d").to_string(),
+        ));
+    }
+    tags.push((
+        "citation_publisher",
+        work.imprint.publisher.publisher_name.clone(),
+    ));
+    for publication in &work.publications {
+        if let Some(isbn) = &publication.isbn {
+            tags.push(("citation_isbn", isbn.clone()));
+        }
+    }
+    // Only a PDF can be given here, so there is no falling back to the landing page
+    if let Some(pdf_url) = work.canonical_location() {
+        tags.push(("citation_pdf_url", pdf_url.to_string()));
+    }
+    tags.iter()
+        .map(|(name, content)| {
+            format!(
+                "<meta name=\"{}\" content=\"{}\">\n",
+                name,
+                escape_attribute(content)
+            )
+        })
+        .collect()
+}
+
+fn escape_attribute(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('"', "&quot;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
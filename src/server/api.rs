@@ -1,6 +1,7 @@
 use std::env;
 use std::io;
 use std::sync::Arc;
+use std::time::Instant;
 
 use actix_cors::Cors;
 use actix_identity::CookieIdentityPolicy;
@@ -10,22 +11,75 @@ use actix_web::middleware::Logger;
 use actix_web::{error, web, App, Error, HttpRequest, HttpResponse, HttpServer, Result};
 use dotenv::dotenv;
 use juniper::http::graphiql::graphiql_source;
-use juniper::http::GraphQLRequest;
+use serde::de::DeserializeOwned;
+use serde_derive::{Deserialize, Serialize};
 use thoth_api::account::model::AccountDetails;
+use thoth_api::account::model::DecodedApiKey;
 use thoth_api::account::model::DecodedToken;
 use thoth_api::account::model::LoginCredentials;
 use thoth_api::account::service::get_account;
 use thoth_api::account::service::get_account_details;
 use thoth_api::account::service::login;
+use thoth_api::contributor::model::Contributor;
+use thoth_api::contributor::model::NewContributor;
+use thoth_api::contributor::model::OrcidContributor;
+use thoth_api::contributor::orcid::fetch_orcid_profile;
+use thoth_api::contributor::orcid::parse_orcid;
 use thoth_api::db::establish_connection;
 use thoth_api::db::PgPool;
 use thoth_api::errors::ThothError;
+use thoth_api::funder::crossref::search_funder_registry;
+use thoth_api::graphql::model::page_bounds;
 use thoth_api::graphql::model::Context;
+use thoth_api::graphql::model::MAX_LIMIT;
 use thoth_api::graphql::model::{create_schema, Schema};
+use thoth_api::work::onix::parse_onix_products;
+use thoth_api::work::onix::OnixProduct;
+use thoth_api::work::onix::OnixValidationReport;
+use thoth_client::contributors::get_publisher_contributions;
+use thoth_client::counter::get_counter_works;
+use thoth_client::feed::feed_query::FeedQueryWorks;
+use thoth_client::feed::get_feed_works;
 use thoth_client::work::get_work;
+use thoth_client::work::get_work_ids;
+use thoth_client::work::get_work_ids_page;
+use thoth_client::work::work_query::WorkQueryWork;
 use uuid::Uuid;
 
-use crate::onix::generate_onix_3;
+use crate::contributors::generate_contributors_csv;
+use crate::counter::generate_title_report;
+use crate::counter::CounterUsage;
+use crate::counter::DEFAULT_PLATFORM;
+use crate::doab::generate_doab;
+use crate::feed::generate_atom;
+use crate::feed::generate_rss;
+use crate::feed::DEFAULT_FEED_SIZE;
+use crate::feed::MAX_FEED_SIZE;
+use crate::formats::all_formats;
+use crate::formats::format_validator;
+use crate::formats::parse_work_statuses;
+use crate::formats::DEFAULT_EXPORT_STATUSES;
+use crate::formats::JSONLD_FORMAT_ID;
+use crate::formats::ONIX_FORMAT_ID;
+use crate::formats::SCHOLAR_FORMAT_ID;
+use crate::jsonld::generate_jsonld;
+use crate::oai::handle_oai_request;
+use crate::oai::OaiParams;
+use crate::oai::OaiRepository;
+use crate::onix::generate_onix_3_batch;
+use crate::onix::skip_unpriced;
+use crate::onix::validate_onix_3;
+use crate::onix::OnixHeader;
+use crate::onix::SkippedProduct;
+use crate::onix::ONIX_CODELIST_ISSUE;
+use crate::onix::ONIX_RELEASE;
+use crate::scholar::generate_scholar_meta;
+use crate::server::persisted_queries::PersistedQueryCache;
+use crate::server::persisted_queries::PersistedQueryRequest;
+use crate::sitemap::generate_sitemap;
+use crate::sitemap::generate_sitemap_page;
+use crate::sitemap::SitemapCache;
+use crate::validation::ValidationReport;
 
 #[get("/graphiql")]
 async fn graphiql() -> HttpResponse {
@@ -35,14 +89,33 @@ async fn graphiql() -> HttpResponse {
         .body(html)
 }
 
+/// The GraphQL schema in schema definition language, printed from the schema the API serves, so
+/// that clients can generate typed bindings offline or keep the schema under version control
+#[get("/schema.graphql")]
+async fn schema_sdl(st: web::Data<Arc<Schema>>) -> HttpResponse {
+    HttpResponse::Ok()
+        .content_type("text/plain; charset=utf-8")
+        .body(st.as_schema_language())
+}
+
+/// Execute a GraphQL request. Queries may be sent by their SHA-256 hash using the automatic
+/// persisted queries protocol, in which case a hash the server does not know is answered with
+/// `PERSISTED_QUERY_NOT_FOUND` so that the client resends the full query.
 #[post("/graphql")]
 async fn graphql(
     st: web::Data<Arc<Schema>>,
     pool: web::Data<PgPool>,
+    persisted_queries: web::Data<PersistedQueryCache>,
     token: DecodedToken,
-    data: web::Json<GraphQLRequest>,
+    api_key: DecodedApiKey,
+    request: web::Json<PersistedQueryRequest>,
 ) -> Result<HttpResponse, Error> {
-    let ctx = Context::new(pool.into_inner(), token);
+    let data = match persisted_queries.resolve(request.into_inner()) {
+        Ok(data) => data,
+        Err(e) if e.is_protocol_response() => return Ok(HttpResponse::Ok().json(e.to_json())),
+        Err(e) => return Ok(HttpResponse::BadRequest().json(e.to_json())),
+    };
+    let ctx = Context::new(pool.into_inner(), token, api_key)?;
     let result = web::block(move || {
         let res = data.execute(&st, &ctx);
         Ok::<_, serde_json::error::Error>(serde_json::to_string(&res)?)
@@ -53,30 +126,791 @@ async fn graphql(
         .body(result))
 }
 
+#[derive(Deserialize)]
+struct ExportOptions {
+    #[serde(default)]
+    validate: bool,
+    /// Leave out ONIX products with neither a price nor an open licence
+    #[serde(default)]
+    skip_unpriced: bool,
+}
+
+#[derive(Serialize)]
+struct OnixCodelists {
+    release: &'static str,
+    codelist_issue: u32,
+}
+
+#[get("/formats")]
+async fn formats() -> HttpResponse {
+    HttpResponse::Ok().json(all_formats())
+}
+
+/// Works checked by one request for works missing metadata unless the caller asks for others
+const DEFAULT_MISSING_PAGE_SIZE: i32 = 100;
+/// Response header giving the number of works in the whole list a page was taken from
+const TOTAL_WORKS_HEADER: &str = "Thoth-Total-Works";
+
+#[derive(Deserialize)]
+struct MissingMetadataOptions {
+    /// Comma separated IDs of the publishers whose works to check
+    publishers: String,
+    /// Number of works to check, at most `MAX_QUERY_LIMIT`
+    limit: Option<i32>,
+    /// Number of works, in order of ID, to skip before the first one checked
+    offset: Option<i32>,
+}
+
+/// List the works, a page at a time, that would fail a format's validation, with the problems
+/// found in each
+#[get("/formats/{format_id}/missing")]
+async fn works_missing_for_format(
+    req: HttpRequest,
+    path: web::Path<(String,)>,
+    options: web::Query<MissingMetadataOptions>,
+) -> HttpResponse {
+    let format_id = (path.0).0;
+    let validate = match format_validator(&format_id) {
+        Some(validate) => validate,
+        None => {
+            return HttpResponse::NotFound().body(format!("Not a validated format: {}", format_id))
+        }
+    };
+    let publishers: Vec<Uuid> = match options
+        .publishers
+        .split(',')
+        .map(|id| id.trim().parse())
+        .collect()
+    {
+        Ok(ids) => ids,
+        Err(_) => return HttpResponse::BadRequest().body("Invalid publisher ID"),
+    };
+    let (limit, offset) = match page_bounds(
+        options.limit.unwrap_or(DEFAULT_MISSING_PAGE_SIZE),
+        options.offset.unwrap_or(0),
+        *MAX_LIMIT,
+    ) {
+        Ok(bounds) => bounds,
+        Err(e) => return HttpResponse::BadRequest().body(e.to_string()),
+    };
+    let thoth_url = local_graphql_url(&req);
+    let page = match get_work_ids_page(Some(publishers), None, limit, offset, thoth_url.clone())
+        .await
+    {
+        Ok(page) => page,
+        Err(e) => {
+            return HttpResponse::InternalServerError().body(format!("Could not list works: {}", e))
+        }
+    };
+    let mut reports = Vec::new();
+    let mut missing = Vec::new();
+    for work_id in page.work_ids {
+        let issues = match fetch_work(work_id, thoth_url.clone()).await {
+            Some(work) => validate(&work),
+            None => {
+                missing.push(work_id);
+                continue;
+            }
+        };
+        let report = ValidationReport::new(work_id, &format_id, issues);
+        if !report.valid {
+            reports.push(report);
+        }
+    }
+    let mut response = HttpResponse::Ok();
+    response.header(TOTAL_WORKS_HEADER, page.total.to_string());
+    if !missing.is_empty() {
+        response.header(MISSING_WORKS_HEADER, join_ids(&missing));
+    }
+    response.json(reports)
+}
+
+#[get("/onix/codelists")]
+async fn onix_codelists() -> HttpResponse {
+    HttpResponse::Ok().json(OnixCodelists {
+        release: ONIX_RELEASE,
+        codelist_issue: ONIX_CODELIST_ISSUE,
+    })
+}
+
 #[get("/onix/{uuid}")]
-async fn onix(req: HttpRequest, path: web::Path<(Uuid,)>) -> HttpResponse {
+async fn onix(
+    req: HttpRequest,
+    path: web::Path<(Uuid,)>,
+    options: web::Query<ExportOptions>,
+    header: web::Data<OnixHeader>,
+) -> HttpResponse {
     let work_id = (path.0).0;
-    let scheme = if req.app_config().secure() {
-        "https".to_string()
-    } else {
-        "http".to_string()
+    let started = Instant::now();
+    let thoth_url = local_graphql_url(&req);
+    match get_work(work_id, thoth_url).await {
+        Ok(work) if options.validate => {
+            let report = ValidationReport::new(work_id, ONIX_FORMAT_ID, validate_onix_3(&work));
+            HttpResponse::Ok().json(report)
+        }
+        Ok(work) => {
+            let (works, skipped) = match options.skip_unpriced {
+                true => skip_unpriced(vec![work]),
+                false => (vec![work], vec![]),
+            };
+            match generate_onix_3_batch(&works, &header) {
+                Ok(body) => {
+                    log_export(ONIX_FORMAT_ID, work_id, 200, body.len(), started, None);
+                    let mut response = HttpResponse::Ok();
+                    // a skipped work still gets a message, without a product
+                    if !skipped.is_empty() {
+                        response.header(SKIPPED_PRODUCTS_HEADER, join_skipped(&skipped));
+                    }
+                    response
+                        .header("Content-Disposition", "attachment")
+                        .content_type("text/xml; charset=utf-8")
+                        .body(String::from_utf8(body).unwrap())
+                }
+                Err(e) => {
+                    log_export(
+                        ONIX_FORMAT_ID,
+                        work_id,
+                        500,
+                        0,
+                        started,
+                        Some(&e.to_string()),
+                    );
+                    HttpResponse::InternalServerError()
+                        .body(format!("Could not generate ONIX for: {}", work_id))
+                }
+            }
+        }
+        Err(e) => {
+            log_export(
+                ONIX_FORMAT_ID,
+                work_id,
+                404,
+                0,
+                started,
+                Some(&e.to_string()),
+            );
+            HttpResponse::NotFound().body(format!("Not found: {}", work_id))
+        }
+    }
+}
+
+/// Most works a batch export will fetch in a single request
+const MAX_BATCH_SIZE: usize = 100;
+/// Response header listing, comma separated, the works that could not be fetched, e.g. because
+/// they do not exist
+const MISSING_WORKS_HEADER: &str = "Thoth-Missing-Works";
+/// Response header listing, comma separated, the products left out of an ONIX export because
+/// they would have had neither a price nor an open licence. Each is given as the ID of its
+/// publication, or of its work if it has none to export, followed by the reason in brackets.
+const SKIPPED_PRODUCTS_HEADER: &str = "Thoth-Skipped-Products";
+
+#[derive(Deserialize)]
+struct BatchExportRequest {
+    work_ids: Vec<Uuid>,
+}
+
+/// Fetch each requested work once, setting aside the IDs that could not be found
+async fn get_batch_works(
+    request: &BatchExportRequest,
+    thoth_url: String,
+) -> std::result::Result<(Vec<WorkQueryWork>, Vec<Uuid>), HttpResponse> {
+    if request.work_ids.len() > MAX_BATCH_SIZE {
+        return Err(HttpResponse::BadRequest().body(format!(
+            "At most {} works can be exported at once",
+            MAX_BATCH_SIZE
+        )));
+    }
+    let mut work_ids = request.work_ids.clone();
+    let mut seen = Vec::new();
+    work_ids.retain(|work_id| {
+        let first = !seen.contains(work_id);
+        seen.push(*work_id);
+        first
+    });
+    let (works, missing) = fetch_works(work_ids, thoth_url).await;
+    if works.is_empty() {
+        return Err(HttpResponse::NotFound().body("None of the requested works were found"));
+    }
+    Ok((works, missing))
+}
+
+/// Fetch a work, logging why it could not be fetched otherwise
+async fn fetch_work(work_id: Uuid, thoth_url: String) -> Option<WorkQueryWork> {
+    match get_work(work_id, thoth_url).await {
+        Ok(work) => Some(work),
+        Err(e) => {
+            log::warn!("Could not fetch work {}: {}", work_id, e);
+            None
+        }
+    }
+}
+
+/// Fetch each of the works, setting aside the IDs of those that could not be fetched
+async fn fetch_works(work_ids: Vec<Uuid>, thoth_url: String) -> (Vec<WorkQueryWork>, Vec<Uuid>) {
+    let mut works = Vec::new();
+    let mut missing = Vec::new();
+    for work_id in work_ids {
+        match fetch_work(work_id, thoth_url.clone()).await {
+            Some(work) => works.push(work),
+            None => missing.push(work_id),
+        }
+    }
+    (works, missing)
+}
+
+fn join_ids(ids: &[Uuid]) -> String {
+    let ids: Vec<String> = ids.iter().map(|id| id.to_string()).collect();
+    ids.join(",")
+}
+
+fn join_skipped(skipped: &[SkippedProduct]) -> String {
+    let skipped: Vec<String> = skipped.iter().map(|product| product.to_string()).collect();
+    skipped.join(",")
+}
+
+fn batch_response(
+    missing: &[Uuid],
+    skipped: &[SkippedProduct],
+    content_type: &str,
+    body: String,
+) -> HttpResponse {
+    let mut response = HttpResponse::Ok();
+    if !missing.is_empty() {
+        response.header(MISSING_WORKS_HEADER, join_ids(missing));
+    }
+    if !skipped.is_empty() {
+        response.header(SKIPPED_PRODUCTS_HEADER, join_skipped(skipped));
+    }
+    response.content_type(content_type).body(body)
+}
+
+/// Export several works as a single ONIX message, one `Product` per work
+#[post("/onix")]
+async fn onix_batch(
+    req: HttpRequest,
+    request: web::Json<BatchExportRequest>,
+    options: web::Query<ExportOptions>,
+    header: web::Data<OnixHeader>,
+) -> HttpResponse {
+    let (works, missing) = match get_batch_works(&request, local_graphql_url(&req)).await {
+        Ok(batch) => batch,
+        Err(response) => return response,
+    };
+    let (works, skipped) = match options.skip_unpriced {
+        true => skip_unpriced(works),
+        false => (works, vec![]),
+    };
+    match generate_onix_3_batch(&works, &header) {
+        Ok(body) => batch_response(
+            &missing,
+            &skipped,
+            "text/xml; charset=utf-8",
+            String::from_utf8(body).unwrap(),
+        ),
+        Err(e) => HttpResponse::InternalServerError()
+            .body(format!("Could not generate ONIX for the batch: {}", e)),
+    }
+}
+
+/// Check how the products of an uploaded ONIX 3.0 file would map into Thoth, without saving
+/// anything. Responds with a field by field report for each product.
+#[post("/onix/validate")]
+async fn onix_validate(body: web::Bytes) -> HttpResponse {
+    match parse_onix_products(&body) {
+        Ok(products) => HttpResponse::Ok().json(
+            products
+                .iter()
+                .map(OnixProduct::validation_report)
+                .collect::<Vec<OnixValidationReport>>(),
+        ),
+        Err(e) => HttpResponse::BadRequest().body(e.to_string()),
+    }
+}
+
+/// Export several works as a JSON array of schema.org documents
+#[post("/jsonld")]
+async fn jsonld_batch(req: HttpRequest, request: web::Json<BatchExportRequest>) -> HttpResponse {
+    let (works, missing) = match get_batch_works(&request, local_graphql_url(&req)).await {
+        Ok(batch) => batch,
+        Err(response) => return response,
     };
-    let thoth_url = format!("{}://{}/graphql", scheme, req.app_config().local_addr());
-    if let Ok(work) = get_work(work_id, thoth_url).await {
-        if let Ok(body) = generate_onix_3(work) {
+    let documents: Vec<String> = works.iter().map(generate_jsonld).collect();
+    batch_response(
+        &missing,
+        &[],
+        "application/ld+json",
+        format!("[{}]", documents.join(",")),
+    )
+}
+
+#[get("/jsonld/{uuid}")]
+async fn jsonld(req: HttpRequest, path: web::Path<(Uuid,)>) -> HttpResponse {
+    let work_id = (path.0).0;
+    let started = Instant::now();
+    match get_work(work_id, local_graphql_url(&req)).await {
+        Ok(work) => {
+            let body = generate_jsonld(&work);
+            log_export(JSONLD_FORMAT_ID, work_id, 200, body.len(), started, None);
             HttpResponse::Ok()
+                .content_type("application/ld+json")
+                .body(body)
+        }
+        Err(e) => {
+            log_export(
+                JSONLD_FORMAT_ID,
+                work_id,
+                404,
+                0,
+                started,
+                Some(&e.to_string()),
+            );
+            HttpResponse::NotFound().body(format!("Not found: {}", work_id))
+        }
+    }
+}
+
+#[get("/scholar/{uuid}")]
+async fn scholar(req: HttpRequest, path: web::Path<(Uuid,)>) -> HttpResponse {
+    let work_id = (path.0).0;
+    let started = Instant::now();
+    match get_work(work_id, local_graphql_url(&req)).await {
+        Ok(work) => {
+            let body = generate_scholar_meta(&work);
+            log_export(SCHOLAR_FORMAT_ID, work_id, 200, body.len(), started, None);
+            HttpResponse::Ok()
+                .content_type("text/html; charset=utf-8")
+                .body(body)
+        }
+        Err(e) => {
+            log_export(
+                SCHOLAR_FORMAT_ID,
+                work_id,
+                404,
+                0,
+                started,
+                Some(&e.to_string()),
+            );
+            HttpResponse::NotFound().body(format!("Not found: {}", work_id))
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct BulkExportOptions {
+    /// Comma separated statuses of the works to include, e.g. `active,forthcoming`
+    status: Option<String>,
+}
+
+impl BulkExportOptions {
+    /// The statuses asked for, or the default ones. Fails with a response naming the first
+    /// that is not a work status.
+    fn work_statuses<T: DeserializeOwned>(&self) -> std::result::Result<Vec<T>, HttpResponse> {
+        parse_work_statuses(self.status.as_deref().unwrap_or(DEFAULT_EXPORT_STATUSES)).map_err(
+            |status| HttpResponse::BadRequest().body(format!("Not a work status: {}", status)),
+        )
+    }
+}
+
+/// Export a publisher's open access works as a DOAB metadata upload
+#[get("/doab/{uuid}")]
+async fn doab(
+    req: HttpRequest,
+    path: web::Path<(Uuid,)>,
+    options: web::Query<BulkExportOptions>,
+) -> HttpResponse {
+    let publisher_id = (path.0).0;
+    let work_statuses = match options.work_statuses() {
+        Ok(work_statuses) => work_statuses,
+        Err(response) => return response,
+    };
+    let thoth_url = local_graphql_url(&req);
+    let work_ids = match get_work_ids(
+        Some(vec![publisher_id]),
+        Some(work_statuses),
+        thoth_url.clone(),
+    )
+    .await
+    {
+        Ok(work_ids) => work_ids,
+        Err(e) => {
+            return HttpResponse::InternalServerError().body(format!("Could not list works: {}", e))
+        }
+    };
+    let (works, missing) = fetch_works(work_ids, thoth_url).await;
+    match generate_doab(&works) {
+        Ok(body) => {
+            let mut response = HttpResponse::Ok();
+            if !missing.is_empty() {
+                response.header(MISSING_WORKS_HEADER, join_ids(&missing));
+            }
+            response
                 .header("Content-Disposition", "attachment")
-                .content_type("text/xml; charset=utf-8")
+                .content_type("text/csv; charset=utf-8")
                 .body(String::from_utf8(body).unwrap())
-        } else {
-            HttpResponse::InternalServerError()
-                .body(format!("Could not generate ONIX for: {}", work_id))
         }
+        Err(e) => HttpResponse::InternalServerError()
+            .body(format!("Could not generate DOAB metadata: {}", e)),
+    }
+}
+
+/// Export the contributors credited on a publisher's works, with their number of contributions
+#[get("/contributors/csv/{uuid}")]
+async fn contributors_csv(
+    req: HttpRequest,
+    path: web::Path<(Uuid,)>,
+    options: web::Query<BulkExportOptions>,
+) -> HttpResponse {
+    let publisher_id = (path.0).0;
+    let work_statuses = match options.work_statuses() {
+        Ok(work_statuses) => work_statuses,
+        Err(response) => return response,
+    };
+    let thoth_url = local_graphql_url(&req);
+    let works = match get_publisher_contributions(publisher_id, Some(work_statuses), thoth_url)
+        .await
+    {
+        Ok(works) => works,
+        Err(e) => {
+            return HttpResponse::InternalServerError().body(format!("Could not list works: {}", e))
+        }
+    };
+    match generate_contributors_csv(&works) {
+        Ok(body) => HttpResponse::Ok()
+            .header("Content-Disposition", "attachment")
+            .content_type("text/csv; charset=utf-8")
+            .body(String::from_utf8(body).unwrap()),
+        Err(e) => HttpResponse::InternalServerError()
+            .body(format!("Could not generate contributor list: {}", e)),
+    }
+}
+
+#[derive(Deserialize)]
+struct CounterOptions {
+    platform: Option<String>,
+    #[serde(flatten)]
+    bulk: BulkExportOptions,
+}
+
+async fn counter_response(
+    req: HttpRequest,
+    publisher_id: Uuid,
+    options: CounterOptions,
+    usage: CounterUsage,
+) -> HttpResponse {
+    let work_statuses = match options.bulk.work_statuses() {
+        Ok(work_statuses) => work_statuses,
+        Err(response) => return response,
+    };
+    let platform = options
+        .platform
+        .unwrap_or_else(|| DEFAULT_PLATFORM.to_string());
+    match get_counter_works(publisher_id, Some(work_statuses), local_graphql_url(&req)).await {
+        Ok(works) => HttpResponse::Ok()
+            .header("Content-Disposition", "attachment")
+            .content_type("text/tab-separated-values; charset=utf-8")
+            .body(generate_title_report(&works, &platform, &usage)),
+        Err(e) => HttpResponse::InternalServerError()
+            .body(format!("Could not generate title report: {}", e)),
+    }
+}
+
+#[get("/counter/{uuid}")]
+async fn counter(
+    req: HttpRequest,
+    path: web::Path<(Uuid,)>,
+    options: web::Query<CounterOptions>,
+) -> HttpResponse {
+    let publisher_id = (path.0).0;
+    counter_response(
+        req,
+        publisher_id,
+        options.into_inner(),
+        CounterUsage::default(),
+    )
+    .await
+}
+
+// Usage counts are posted so publishers can merge their own figures into the report
+#[post("/counter/{uuid}")]
+async fn counter_with_usage(
+    req: HttpRequest,
+    path: web::Path<(Uuid,)>,
+    options: web::Query<CounterOptions>,
+    usage: web::Json<CounterUsage>,
+) -> HttpResponse {
+    let publisher_id = (path.0).0;
+    counter_response(req, publisher_id, options.into_inner(), usage.into_inner()).await
+}
+
+/// URL of this server's own GraphQL endpoint, used by export routes to query data
+fn local_graphql_url(req: &HttpRequest) -> String {
+    let scheme = if req.app_config().secure() {
+        "https".to_string()
     } else {
-        HttpResponse::NotFound().body(format!("Not found: {}", work_id))
+        "http".to_string()
+    };
+    format!("{}://{}/graphql", scheme, req.app_config().local_addr())
+}
+
+async fn oai_response(
+    req: HttpRequest,
+    params: OaiParams,
+    repository: &OaiRepository,
+) -> HttpResponse {
+    let base_url = format!("{}/oai", public_base_url(&req));
+    match handle_oai_request(params, repository, &base_url, local_graphql_url(&req)).await {
+        Ok(body) => HttpResponse::Ok()
+            .content_type("text/xml; charset=utf-8")
+            .body(body),
+        // OAI-PMH harvesters retry a 503 after the time given in Retry-After
+        Err(e) => {
+            log::error!("Could not answer OAI-PMH request: {}", e);
+            HttpResponse::ServiceUnavailable()
+                .header("Retry-After", "60")
+                .body(format!("Could not answer OAI-PMH request: {}", e))
+        }
     }
 }
 
+#[get("/oai")]
+async fn oai(
+    req: HttpRequest,
+    params: web::Query<OaiParams>,
+    repository: web::Data<OaiRepository>,
+) -> HttpResponse {
+    oai_response(req, params.into_inner(), &repository).await
+}
+
+// OAI-PMH requires repositories to accept requests as form-encoded POST bodies too
+#[post("/oai")]
+async fn oai_form(
+    req: HttpRequest,
+    params: web::Form<OaiParams>,
+    repository: web::Data<OaiRepository>,
+) -> HttpResponse {
+    oai_response(req, params.into_inner(), &repository).await
+}
+
+/// Public URL this server is being reached at, used to build absolute links in responses
+fn public_base_url(req: &HttpRequest) -> String {
+    let connection_info = req.connection_info();
+    format!("{}://{}", connection_info.scheme(), connection_info.host())
+}
+
+fn sitemap_response(body: Vec<u8>) -> HttpResponse {
+    HttpResponse::Ok()
+        .content_type("application/xml; charset=utf-8")
+        .body(body)
+}
+
+#[get("/sitemap.xml")]
+async fn sitemap(req: HttpRequest, cache: web::Data<SitemapCache>) -> HttpResponse {
+    let base_url = public_base_url(&req);
+    let key = format!("{}/sitemap.xml", base_url);
+    if let Some(body) = cache.get(&key) {
+        return sitemap_response(body);
+    }
+    match generate_sitemap(&base_url, local_graphql_url(&req)).await {
+        Ok(body) => {
+            cache.insert(key, body.clone());
+            sitemap_response(body)
+        }
+        Err(e) => {
+            HttpResponse::InternalServerError().body(format!("Could not generate sitemap: {}", e))
+        }
+    }
+}
+
+#[get("/sitemap/{page}.xml")]
+async fn sitemap_page(
+    req: HttpRequest,
+    path: web::Path<(i64,)>,
+    cache: web::Data<SitemapCache>,
+) -> HttpResponse {
+    let page = (path.0).0;
+    let key = format!("{}/sitemap/{}.xml", public_base_url(&req), page);
+    if let Some(body) = cache.get(&key) {
+        return sitemap_response(body);
+    }
+    match generate_sitemap_page(page, local_graphql_url(&req)).await {
+        Ok(Some(body)) => {
+            cache.insert(key, body.clone());
+            sitemap_response(body)
+        }
+        Ok(None) => HttpResponse::NotFound().body(format!("Not found: sitemap page {}", page)),
+        Err(e) => {
+            HttpResponse::InternalServerError().body(format!("Could not generate sitemap: {}", e))
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct FeedOptions {
+    publisher: Option<Uuid>,
+    limit: Option<usize>,
+}
+
+/// Fetch the works listed in a feed, or the response to give if the request cannot be served
+async fn get_feed(
+    req: &HttpRequest,
+    options: &FeedOptions,
+) -> std::result::Result<Vec<FeedQueryWorks>, HttpResponse> {
+    let count = options.limit.unwrap_or(DEFAULT_FEED_SIZE);
+    if count == 0 || count > MAX_FEED_SIZE {
+        return Err(HttpResponse::BadRequest().body(format!(
+            "A feed lists between 1 and {} works",
+            MAX_FEED_SIZE
+        )));
+    }
+    get_feed_works(count, options.publisher, local_graphql_url(req))
+        .await
+        .map_err(|e| {
+            HttpResponse::InternalServerError().body(format!("Could not generate feed: {}", e))
+        })
+}
+
+fn feed_response(
+    content_type: &str,
+    body: std::result::Result<Vec<u8>, ThothError>,
+) -> HttpResponse {
+    match body {
+        Ok(body) => HttpResponse::Ok().content_type(content_type).body(body),
+        Err(e) => {
+            HttpResponse::InternalServerError().body(format!("Could not generate feed: {}", e))
+        }
+    }
+}
+
+#[get("/feed.atom")]
+async fn feed_atom(req: HttpRequest, options: web::Query<FeedOptions>) -> HttpResponse {
+    let works = match get_feed(&req, &options).await {
+        Ok(works) => works,
+        Err(response) => return response,
+    };
+    // The feed's URL is its identifier, so it must not vary with the number of entries
+    let mut feed_url = format!("{}/feed.atom", public_base_url(&req));
+    if let Some(publisher_id) = options.publisher {
+        feed_url = format!("{}?publisher={}", feed_url, publisher_id);
+    }
+    feed_response(
+        "application/atom+xml; charset=utf-8",
+        generate_atom(&works, &feed_url),
+    )
+}
+
+#[get("/feed.rss")]
+async fn feed_rss(req: HttpRequest, options: web::Query<FeedOptions>) -> HttpResponse {
+    let works = match get_feed(&req, &options).await {
+        Ok(works) => works,
+        Err(response) => return response,
+    };
+    feed_response(
+        "application/rss+xml; charset=utf-8",
+        generate_rss(&works, &public_base_url(&req)),
+    )
+}
+
+/// Record the outcome of an export request as a single line of `key=value` pairs
+fn log_export(
+    format_id: &str,
+    work_id: Uuid,
+    status: u16,
+    size: usize,
+    started: Instant,
+    error: Option<&str>,
+) {
+    let duration_ms = started.elapsed().as_millis();
+    match error {
+        None => log::info!(
+            target: "thoth::export",
+            "format_id={} work_id={} status={} size={} duration_ms={}",
+            format_id,
+            work_id,
+            status,
+            size,
+            duration_ms
+        ),
+        Some(error) => log::error!(
+            target: "thoth::export",
+            "format_id={} work_id={} status={} size={} duration_ms={} error={:?}",
+            format_id,
+            work_id,
+            status,
+            size,
+            duration_ms,
+            error
+        ),
+    }
+}
+
+#[derive(Deserialize)]
+struct OrcidRequest {
+    orcid: String,
+}
+
+#[post("/contributor/orcid")]
+async fn create_contributor_from_orcid(
+    token: DecodedToken,
+    pool: web::Data<PgPool>,
+    payload: web::Json<OrcidRequest>,
+) -> Result<HttpResponse, Error> {
+    token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
+    let orcid = parse_orcid(&payload.orcid).map_err(error::ErrorBadRequest)?;
+
+    // A contributor may only be recorded once per ORCID
+    let lookup_pool = pool.clone();
+    let lookup_orcid = orcid.clone();
+    let existing = web::block(move || {
+        let connection = lookup_pool.get().unwrap();
+        Contributor::find_by_orcid(&lookup_orcid, &connection)
+    })
+    .await?;
+    if let Some(contributor) = existing {
+        return Ok(HttpResponse::Ok().json(OrcidContributor {
+            contributor,
+            created: false,
+            autofilled_fields: vec![],
+            orcid_error: None,
+        }));
+    }
+
+    // ORCID being unavailable should not prevent recording the contributor
+    let (profile, orcid_error) = match fetch_orcid_profile(&orcid).await {
+        Ok(profile) => (Some(profile), None),
+        Err(e) => (None, Some(e.to_string())),
+    };
+    let (data, autofilled_fields) = NewContributor::from_orcid(&orcid, profile.as_ref());
+    data.validate().map_err(error::ErrorBadRequest)?;
+    let contributor = web::block(move || {
+        let connection = pool.get().unwrap();
+        data.insert(&connection)
+    })
+    .await?;
+    Ok(HttpResponse::Ok().json(OrcidContributor {
+        contributor,
+        created: true,
+        autofilled_fields,
+        orcid_error,
+    }))
+}
+
+#[derive(Deserialize)]
+struct FunderRegistryQuery {
+    name: String,
+}
+
+#[get("/funder/registry")]
+async fn funder_registry(
+    token: DecodedToken,
+    query: web::Query<FunderRegistryQuery>,
+) -> Result<HttpResponse, Error> {
+    token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
+    let candidates = search_funder_registry(&query.name)
+        .await
+        .map_err(error::ErrorBadGateway)?;
+    Ok(HttpResponse::Ok().json(candidates))
+}
+
 #[post("/account/login")]
 async fn login_credentials(
     payload: web::Json<LoginCredentials>,
@@ -161,7 +995,29 @@ fn config(cfg: &mut web::ServiceConfig) {
     cfg.data(pool);
     cfg.service(graphql);
     cfg.service(graphiql);
+    cfg.service(schema_sdl);
+    cfg.service(formats);
+    cfg.service(works_missing_for_format);
+    // Registered before `onix` so that "codelists" is not taken for a work ID
+    cfg.service(onix_codelists);
     cfg.service(onix);
+    cfg.service(onix_batch);
+    cfg.service(onix_validate);
+    cfg.service(jsonld);
+    cfg.service(jsonld_batch);
+    cfg.service(scholar);
+    cfg.service(doab);
+    cfg.service(contributors_csv);
+    cfg.service(counter);
+    cfg.service(counter_with_usage);
+    cfg.service(oai);
+    cfg.service(oai_form);
+    cfg.service(sitemap);
+    cfg.service(sitemap_page);
+    cfg.service(feed_atom);
+    cfg.service(feed_rss);
+    cfg.service(create_contributor_from_orcid);
+    cfg.service(funder_registry);
     cfg.service(login_credentials);
     cfg.service(login_session);
     cfg.service(account_details);
@@ -175,9 +1031,19 @@ pub async fn start_server(port: String) -> io::Result<()> {
     let domain = env::var("THOTH_DOMAIN").expect("THOTH_DOMAIN must be set");
     let session_duration =
         env::var("SESSION_DURATION_SECONDS").expect("SESSION_DURATION_SECONDS must be set");
+    // Shared across workers so each sitemap is only built once per cache period
+    let sitemap_cache = web::Data::new(SitemapCache::default());
+    // Likewise shared, so a query registered through one worker is known to all
+    let persisted_queries = web::Data::new(PersistedQueryCache::default());
+    let onix_header = web::Data::new(OnixHeader::from_env());
+    let oai_repository = web::Data::new(OaiRepository::from_env());
 
     HttpServer::new(move || {
         App::new()
+            .app_data(sitemap_cache.clone())
+            .app_data(persisted_queries.clone())
+            .app_data(onix_header.clone())
+            .app_data(oai_repository.clone())
             .wrap(Logger::default())
             .wrap(IdentityService::new(
                 CookieIdentityPolicy::new(secret_str.as_bytes())
@@ -1,3 +1,4 @@
+use std::env;
 use std::io;
 
 use actix_cors::Cors;
@@ -7,6 +8,7 @@ use actix_web::App;
 use actix_web::HttpResponse;
 use actix_web::HttpServer;
 use dotenv::dotenv;
+use serde_json::json;
 
 const NO_CACHE: &str = "no-cache";
 
@@ -78,6 +80,18 @@ async fn index() -> HttpResponse {
         .body(INDEX_FILE)
 }
 
+/// Settings the app reads at startup, letting one build of it be deployed against different
+/// APIs. Without `THOTH_APP_API` the app keeps the `THOTH_API` it was compiled with.
+#[get("/config.json")]
+async fn runtime_config() -> HttpResponse {
+    match env::var("THOTH_APP_API") {
+        Ok(thoth_api) => HttpResponse::Ok()
+            .header("Cache-Control", NO_CACHE)
+            .json(json!({ "thoth_api": thoth_api })),
+        Err(_) => HttpResponse::NotFound().finish(),
+    }
+}
+
 #[actix_rt::main]
 pub async fn start_server(port: String) -> io::Result<()> {
     env_logger::init();
@@ -91,6 +105,7 @@ pub async fn start_server(port: String) -> io::Result<()> {
                     .finish(),
             )
             .configure(config)
+            .service(runtime_config)
             .default_service(web::route().to(index))
     })
     .bind(format!("0.0.0.0:{}", port))?
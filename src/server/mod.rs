@@ -1,2 +1,3 @@
 pub mod api;
 pub mod app;
+pub mod persisted_queries;
@@ -0,0 +1,280 @@
+use juniper::http::GraphQLRequest;
+use juniper::InputValue;
+use serde_derive::Deserialize;
+use serde_json::json;
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+/// Version of the automatic persisted queries protocol this server implements
+const PERSISTED_QUERY_VERSION: i32 = 1;
+/// Most queries kept at once. Once full, registering a query evicts the least recently used
+/// one, so that clients can neither grow the cache without bound nor lock others out of it.
+const MAX_PERSISTED_QUERIES: usize = 1000;
+
+/// Queries registered through automatic persisted queries, by the hex SHA-256 hash of their text
+pub struct PersistedQueryCache {
+    capacity: usize,
+    queries: Mutex<LruQueries>,
+}
+
+/// Stored queries, each with the tick at which it was last registered or looked up
+#[derive(Default)]
+struct LruQueries {
+    tick: u64,
+    entries: HashMap<String, (String, u64)>,
+}
+
+impl LruQueries {
+    fn next_tick(&mut self) -> u64 {
+        self.tick += 1;
+        self.tick
+    }
+
+    fn get(&mut self, hash: &str) -> Option<String> {
+        let tick = self.next_tick();
+        self.entries.get_mut(hash).map(|(query, last_used)| {
+            *last_used = tick;
+            query.clone()
+        })
+    }
+
+    fn insert(&mut self, hash: String, query: String, capacity: usize) {
+        let tick = self.next_tick();
+        if !self.entries.contains_key(&hash) && self.entries.len() >= capacity {
+            let oldest = self
+                .entries
+                .iter()
+                .min_by_key(|(_, (_, last_used))| *last_used)
+                .map(|(hash, _)| hash.clone());
+            if let Some(oldest) = oldest {
+                self.entries.remove(&oldest);
+            }
+        }
+        self.entries.insert(hash, (query, tick));
+    }
+}
+
+impl Default for PersistedQueryCache {
+    fn default() -> Self {
+        PersistedQueryCache::with_capacity(MAX_PERSISTED_QUERIES)
+    }
+}
+
+/// A GraphQL request that may identify its query by hash rather than give it in full, as
+/// sent by Apollo-style clients
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PersistedQueryRequest {
+    query: Option<String>,
+    operation_name: Option<String>,
+    variables: Option<InputValue>,
+    extensions: Option<RequestExtensions>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RequestExtensions {
+    persisted_query: Option<PersistedQuery>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct PersistedQuery {
+    version: i32,
+    sha256_hash: String,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum PersistedQueryError {
+    /// The hash is not known: the client should resend the request with the full query
+    NotFound,
+    /// The client asked for a protocol version this server does not implement
+    NotSupported,
+    /// The query given does not match the hash given with it
+    HashMismatch,
+    /// The request has neither a query nor a persisted query hash
+    MissingQuery,
+}
+
+impl PersistedQueryError {
+    /// Code reported in the error's extensions, which clients use to decide how to retry
+    fn code(&self) -> &'static str {
+        match self {
+            PersistedQueryError::NotFound => "PERSISTED_QUERY_NOT_FOUND",
+            PersistedQueryError::NotSupported => "PERSISTED_QUERY_NOT_SUPPORTED",
+            PersistedQueryError::HashMismatch | PersistedQueryError::MissingQuery => "BAD_REQUEST",
+        }
+    }
+
+    fn message(&self) -> &'static str {
+        match self {
+            PersistedQueryError::NotFound => "PersistedQueryNotFound",
+            PersistedQueryError::NotSupported => "PersistedQueryNotSupported",
+            PersistedQueryError::HashMismatch => "provided sha does not match query",
+            PersistedQueryError::MissingQuery => "must provide a query or a persisted query hash",
+        }
+    }
+
+    /// Whether the error is part of the normal exchange with a client, rather than a
+    /// malformed request
+    pub fn is_protocol_response(&self) -> bool {
+        matches!(
+            self,
+            PersistedQueryError::NotFound | PersistedQueryError::NotSupported
+        )
+    }
+
+    /// GraphQL response body describing the error
+    pub fn to_json(&self) -> serde_json::Value {
+        json!({
+            "errors": [{
+                "message": self.message(),
+                "extensions": { "code": self.code() }
+            }]
+        })
+    }
+}
+
+fn query_hash(query: &str) -> String {
+    format!("{:x}", Sha256::digest(query.as_bytes()))
+}
+
+impl PersistedQueryCache {
+    pub fn with_capacity(capacity: usize) -> Self {
+        PersistedQueryCache {
+            capacity,
+            queries: Mutex::new(LruQueries::default()),
+        }
+    }
+
+    /// Turn a request into one juniper can execute: queries given by hash are looked up, and
+    /// queries given in full alongside their hash are registered for later requests
+    pub fn resolve(
+        &self,
+        request: PersistedQueryRequest,
+    ) -> Result<GraphQLRequest, PersistedQueryError> {
+        let persisted = request.extensions.and_then(|e| e.persisted_query);
+        let query = match (request.query, persisted) {
+            (Some(query), None) => query,
+            (None, None) => return Err(PersistedQueryError::MissingQuery),
+            (_, Some(persisted)) if persisted.version != PERSISTED_QUERY_VERSION => {
+                return Err(PersistedQueryError::NotSupported)
+            }
+            (Some(query), Some(persisted)) => {
+                if query_hash(&query) != persisted.sha256_hash.to_lowercase() {
+                    return Err(PersistedQueryError::HashMismatch);
+                }
+                self.queries.lock().unwrap().insert(
+                    persisted.sha256_hash.to_lowercase(),
+                    query.clone(),
+                    self.capacity,
+                );
+                query
+            }
+            (None, Some(persisted)) => self
+                .queries
+                .lock()
+                .unwrap()
+                .get(&persisted.sha256_hash.to_lowercase())
+                .ok_or(PersistedQueryError::NotFound)?,
+        };
+        Ok(GraphQLRequest::new(
+            query,
+            request.operation_name,
+            request.variables,
+        ))
+    }
+}
+
+#[cfg(test)]
+const QUERY: &str = "{ works { workId } }";
+
+#[cfg(test)]
+fn request(query: Option<&str>, hash: &str, version: i32) -> PersistedQueryRequest {
+    serde_json::from_value(json!({
+        "query": query,
+        "extensions": {
+            "persistedQuery": { "version": version, "sha256Hash": hash }
+        }
+    }))
+    .unwrap()
+}
+
+#[test]
+fn test_register_then_resolve_by_hash() {
+    let cache = PersistedQueryCache::default();
+    let hash = query_hash(QUERY);
+    assert!(cache.resolve(request(Some(QUERY), &hash, 1)).is_ok());
+    assert!(cache.resolve(request(None, &hash, 1)).is_ok());
+    assert!(cache
+        .resolve(request(None, &hash.to_uppercase(), 1))
+        .is_ok());
+}
+
+#[test]
+fn test_hash_mismatch_is_rejected() {
+    let cache = PersistedQueryCache::default();
+    let hash = query_hash("{ publishers { publisherId } }");
+    assert_eq!(
+        cache.resolve(request(Some(QUERY), &hash, 1)).err(),
+        Some(PersistedQueryError::HashMismatch)
+    );
+    assert_eq!(
+        cache.resolve(request(None, &hash, 1)).err(),
+        Some(PersistedQueryError::NotFound)
+    );
+}
+
+#[test]
+fn test_unknown_hash_is_not_found() {
+    let cache = PersistedQueryCache::default();
+    let error = cache
+        .resolve(request(None, &query_hash(QUERY), 1))
+        .err()
+        .unwrap();
+    assert_eq!(error, PersistedQueryError::NotFound);
+    assert!(error.is_protocol_response());
+    assert_eq!(
+        error.to_json()["errors"][0]["message"],
+        "PersistedQueryNotFound"
+    );
+}
+
+#[test]
+fn test_unsupported_version() {
+    let cache = PersistedQueryCache::default();
+    let hash = query_hash(QUERY);
+    assert_eq!(
+        cache.resolve(request(Some(QUERY), &hash, 2)).err(),
+        Some(PersistedQueryError::NotSupported)
+    );
+    assert_eq!(
+        cache.resolve(request(None, &hash, 2)).err(),
+        Some(PersistedQueryError::NotSupported)
+    );
+}
+
+#[test]
+fn test_full_cache_evicts_least_recently_used() {
+    let cache = PersistedQueryCache::with_capacity(2);
+    let queries = ["{ a }", "{ b }", "{ c }"];
+    let hashes: Vec<String> = queries.iter().copied().map(query_hash).collect();
+    cache
+        .resolve(request(Some(queries[0]), &hashes[0], 1))
+        .unwrap();
+    cache
+        .resolve(request(Some(queries[1]), &hashes[1], 1))
+        .unwrap();
+    // Using the first query makes the second the least recently used
+    cache.resolve(request(None, &hashes[0], 1)).unwrap();
+    cache
+        .resolve(request(Some(queries[2]), &hashes[2], 1))
+        .unwrap();
+    assert!(cache.resolve(request(None, &hashes[0], 1)).is_ok());
+    assert_eq!(
+        cache.resolve(request(None, &hashes[1], 1)).err(),
+        Some(PersistedQueryError::NotFound)
+    );
+    assert!(cache.resolve(request(None, &hashes[2], 1)).is_ok());
+}
@@ -0,0 +1,153 @@
+use std::collections::HashMap;
+use std::io::Write;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use chrono::prelude::*;
+use thoth_api::errors::ThothError;
+use thoth_client::sitemap::get_sitemap_page;
+use thoth_client::sitemap::sitemap_query::SitemapQueryWorks;
+use xml::writer::{EmitterConfig, EventWriter, XmlEvent};
+
+const SITEMAP_NS: &str = "http://www.sitemaps.org/schemas/sitemap/0.9";
+const LASTMOD_FORMAT: &str = "%Y-%m-%dT%H:%M:%SZ";
+/// Maximum number of URLs allowed in a single sitemap by the sitemaps.org protocol
+pub const MAX_SITEMAP_URLS: i64 = 50_000;
+/// Number of works requested from the GraphQL API at a time while building a sitemap
+const FETCH_SIZE: i64 = 1_000;
+/// How long a generated sitemap is served from the cache before it is rebuilt
+const CACHE_TTL: Duration = Duration::from_secs(600);
+
+/// In-memory store of recently generated sitemaps, keyed on their public URL
+#[derive(Default)]
+pub struct SitemapCache {
+    entries: Mutex<HashMap<String, (Instant, Vec<u8>)>>,
+}
+
+impl SitemapCache {
+    pub fn get(&self, key: &str) -> Option<Vec<u8>> {
+        let entries = self.entries.lock().unwrap();
+        entries
+            .get(key)
+            .filter(|(generated, _)| generated.elapsed() < CACHE_TTL)
+            .map(|(_, body)| body.clone())
+    }
+
+    pub fn insert(&self, key: String, body: Vec<u8>) {
+        let mut entries = self.entries.lock().unwrap();
+        entries.retain(|_, (generated, _)| generated.elapsed() < CACHE_TTL);
+        entries.insert(key, (Instant::now(), body));
+    }
+}
+
+/// Generate the root sitemap: a plain URL set when every active work fits in one sitemap,
+/// or a sitemap index pointing at `{base_url}/sitemap/{page}.xml` otherwise
+pub async fn generate_sitemap(base_url: &str, thoth_url: String) -> Result<Vec<u8>, ThothError> {
+    let first = get_sitemap_page(FETCH_SIZE, 0, thoth_url.clone()).await?;
+    let total = first.work_total.0;
+    if total > MAX_SITEMAP_URLS {
+        let pages = (total + MAX_SITEMAP_URLS - 1) / MAX_SITEMAP_URLS;
+        return write_xml(|w| write_sitemap_index(w, base_url, pages));
+    }
+    let mut works = first.works;
+    works.extend(fetch_works(works.len() as i64, total, thoth_url).await?);
+    write_xml(|w| write_urlset(w, &works))
+}
+
+/// Generate one page of a sitemap index, numbered from 1. Returns `None` if the page is out of range
+pub async fn generate_sitemap_page(
+    page: i64,
+    thoth_url: String,
+) -> Result<Option<Vec<u8>>, ThothError> {
+    if page < 1 {
+        return Ok(None);
+    }
+    let offset = (page - 1) * MAX_SITEMAP_URLS;
+    let first = get_sitemap_page(FETCH_SIZE, offset, thoth_url.clone()).await?;
+    let total = first.work_total.0;
+    if offset >= total {
+        return Ok(None);
+    }
+    let end = total.min(offset + MAX_SITEMAP_URLS);
+    let mut works = first.works;
+    works.extend(fetch_works(offset + works.len() as i64, end, thoth_url).await?);
+    write_xml(|w| write_urlset(w, &works)).map(Some)
+}
+
+async fn fetch_works(
+    mut offset: i64,
+    end: i64,
+    thoth_url: String,
+) -> Result<Vec<SitemapQueryWorks>, ThothError> {
+    let mut works = Vec::new();
+    while offset < end {
+        let limit = FETCH_SIZE.min(end - offset);
+        let data = get_sitemap_page(limit, offset, thoth_url.clone()).await?;
+        if data.works.is_empty() {
+            break;
+        }
+        offset += data.works.len() as i64;
+        works.extend(data.works);
+    }
+    Ok(works)
+}
+
+pub(crate) fn write_xml<F>(write: F) -> Result<Vec<u8>, ThothError>
+where
+    F: FnOnce(&mut EventWriter<&mut Vec<u8>>) -> xml::writer::Result<()>,
+{
+    let mut buffer = Vec::new();
+    let mut writer = EmitterConfig::new()
+        .perform_indent(true)
+        .create_writer(&mut buffer);
+    write(&mut writer)?;
+    Ok(buffer)
+}
+
+/// Public URL of a work: its landing page if it has one, or its DOI otherwise
+fn work_location(work: &SitemapQueryWorks) -> Option<&str> {
+    work.landing_page.as_deref().or_else(|| work.doi.as_deref())
+}
+
+fn write_urlset<W: Write>(
+    w: &mut EventWriter<W>,
+    works: &[SitemapQueryWorks],
+) -> xml::writer::Result<()> {
+    w.write(XmlEvent::start_element("urlset").default_ns(SITEMAP_NS))?;
+    for work in works {
+        if let Some(location) = work_location(work) {
+            let lastmod = NaiveDateTime::from_timestamp(work.updated_at as i64, 0)
+                .format(LASTMOD_FORMAT)
+                .to_string();
+            w.write(XmlEvent::start_element("url"))?;
+            write_element(w, "loc", location)?;
+            write_element(w, "lastmod", &lastmod)?;
+            w.write(XmlEvent::end_element())?;
+        }
+    }
+    w.write(XmlEvent::end_element())
+}
+
+fn write_sitemap_index<W: Write>(
+    w: &mut EventWriter<W>,
+    base_url: &str,
+    pages: i64,
+) -> xml::writer::Result<()> {
+    w.write(XmlEvent::start_element("sitemapindex").default_ns(SITEMAP_NS))?;
+    for page in 1..=pages {
+        w.write(XmlEvent::start_element("sitemap"))?;
+        write_element(w, "loc", &format!("{}/sitemap/{}.xml", base_url, page))?;
+        w.write(XmlEvent::end_element())?;
+    }
+    w.write(XmlEvent::end_element())
+}
+
+pub(crate) fn write_element<W: Write>(
+    w: &mut EventWriter<W>,
+    element: &str,
+    value: &str,
+) -> xml::writer::Result<()> {
+    w.write(XmlEvent::start_element(element))?;
+    w.write(XmlEvent::Characters(value))?;
+    w.write(XmlEvent::end_element())
+}
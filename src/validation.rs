@@ -0,0 +1,55 @@
+use serde_derive::Serialize;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    /// The output would be invalid or rejected by recipients
+    Error,
+    /// The output is valid but incomplete, or a default value was used
+    Warning,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ValidationIssue {
+    pub field: String,
+    pub severity: Severity,
+    pub message: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ValidationReport {
+    pub work_id: Uuid,
+    pub format_id: String,
+    pub valid: bool,
+    pub issues: Vec<ValidationIssue>,
+}
+
+impl ValidationIssue {
+    pub fn error(field: &str, message: &str) -> Self {
+        ValidationIssue {
+            field: field.to_string(),
+            severity: Severity::Error,
+            message: message.to_string(),
+        }
+    }
+
+    pub fn warning(field: &str, message: &str) -> Self {
+        ValidationIssue {
+            field: field.to_string(),
+            severity: Severity::Warning,
+            message: message.to_string(),
+        }
+    }
+}
+
+impl ValidationReport {
+    pub fn new(work_id: Uuid, format_id: &str, issues: Vec<ValidationIssue>) -> Self {
+        ValidationReport {
+            work_id,
+            format_id: format_id.to_string(),
+            valid: !issues.iter().any(|i| i.severity == Severity::Error),
+            issues,
+        }
+    }
+}
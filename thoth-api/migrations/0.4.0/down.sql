@@ -0,0 +1,55 @@
+ALTER TABLE publisher
+    DROP COLUMN default_imprint_id;
+
+ALTER TABLE work
+    DROP COLUMN short_doi;
+
+DROP TABLE work_title_history;
+DROP TRIGGER set_updated_at ON work_title;
+DROP TABLE work_title;
+DROP TYPE title_type;
+
+ALTER TABLE publication
+    DROP COLUMN unpriced_item_type;
+DROP TYPE unpriced_item_type;
+
+DROP TABLE work_abstract_history;
+DROP TRIGGER set_updated_at ON work_abstract;
+DROP TABLE work_abstract;
+DROP TYPE abstract_type;
+
+ALTER TABLE work
+    DROP COLUMN cover_format,
+    DROP COLUMN cover_width,
+    DROP COLUMN cover_height;
+
+DROP TABLE webhook_delivery;
+DROP TRIGGER set_updated_at ON webhook;
+DROP TABLE webhook;
+DROP TYPE webhook_event;
+
+ALTER TABLE publication
+    DROP CONSTRAINT publication_non_physical_no_dimensions,
+    DROP CONSTRAINT publication_weight_not_missing,
+    DROP CONSTRAINT publication_depth_not_missing,
+    DROP CONSTRAINT publication_height_not_missing,
+    DROP CONSTRAINT publication_width_not_missing,
+    DROP COLUMN width_mm,
+    DROP COLUMN width_in,
+    DROP COLUMN height_mm,
+    DROP COLUMN height_in,
+    DROP COLUMN depth_mm,
+    DROP COLUMN depth_in,
+    DROP COLUMN weight_g,
+    DROP COLUMN weight_oz;
+
+ALTER TABLE price
+    DROP CONSTRAINT price_unit_price_check;
+
+ALTER TABLE price
+    DROP COLUMN price_type,
+    DROP COLUMN territory;
+DROP TYPE price_type;
+
+DROP TRIGGER set_updated_at ON api_key;
+DROP TABLE api_key;
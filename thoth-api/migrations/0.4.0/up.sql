@@ -0,0 +1,177 @@
+CREATE TABLE api_key (
+    api_key_id          UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    publisher_id        UUID NOT NULL REFERENCES publisher(publisher_id) ON DELETE CASCADE,
+    account_id          UUID NOT NULL REFERENCES account(account_id) ON DELETE CASCADE,
+    label               TEXT NOT NULL CHECK (octet_length(label) >= 1),
+    hash                BYTEA NOT NULL,
+    salt                TEXT NOT NULL,
+    created_at          TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at          TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+SELECT diesel_manage_updated_at('api_key');
+
+CREATE TYPE price_type AS ENUM (
+    'rrp-excluding-tax',
+    'rrp-including-tax',
+    'fixed-retail-excluding-tax',
+    'fixed-retail-including-tax',
+    'supplier-net-excluding-tax',
+    'supplier-net-including-tax'
+);
+
+-- Existing prices default to RRP excluding tax, valid worldwide
+ALTER TABLE price
+    ADD COLUMN price_type price_type NOT NULL DEFAULT 'rrp-excluding-tax',
+    ADD COLUMN territory TEXT NOT NULL DEFAULT 'WORLD'
+        CHECK (territory ~ '^(WORLD|[A-Z]{2}( [A-Z]{2})*)$');
+
+-- Items without a price are described by the publication's unpriced_item_type instead.
+-- NOT VALID leaves any existing zero prices in place, but rejects new ones.
+ALTER TABLE price
+    ADD CONSTRAINT price_unit_price_check CHECK (unit_price > 0.0) NOT VALID;
+
+-- Physical dimensions, stored in both metric and imperial units
+ALTER TABLE publication
+    ADD COLUMN width_mm double precision CHECK (width_mm > 0.0),
+    ADD COLUMN width_in double precision CHECK (width_in > 0.0),
+    ADD COLUMN height_mm double precision CHECK (height_mm > 0.0),
+    ADD COLUMN height_in double precision CHECK (height_in > 0.0),
+    ADD COLUMN depth_mm double precision CHECK (depth_mm > 0.0),
+    ADD COLUMN depth_in double precision CHECK (depth_in > 0.0),
+    ADD COLUMN weight_g double precision CHECK (weight_g > 0.0),
+    ADD COLUMN weight_oz double precision CHECK (weight_oz > 0.0),
+    ADD CONSTRAINT publication_width_not_missing
+        CHECK ((width_mm IS NULL) = (width_in IS NULL)),
+    ADD CONSTRAINT publication_height_not_missing
+        CHECK ((height_mm IS NULL) = (height_in IS NULL)),
+    ADD CONSTRAINT publication_depth_not_missing
+        CHECK ((depth_mm IS NULL) = (depth_in IS NULL)),
+    ADD CONSTRAINT publication_weight_not_missing
+        CHECK ((weight_g IS NULL) = (weight_oz IS NULL)),
+    ADD CONSTRAINT publication_non_physical_no_dimensions
+        CHECK (publication_type IN ('Paperback', 'Hardback')
+            OR (width_mm IS NULL AND height_mm IS NULL
+                AND depth_mm IS NULL AND weight_g IS NULL));
+
+CREATE TYPE webhook_event AS ENUM (
+    'work-created',
+    'work-updated',
+    'work-published'
+);
+
+CREATE TABLE webhook (
+    webhook_id          UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    publisher_id        UUID NOT NULL REFERENCES publisher(publisher_id) ON DELETE CASCADE,
+    url                 TEXT NOT NULL CHECK (url ~* '^https?:\/\/'),
+    event_types         webhook_event[] NOT NULL CHECK (cardinality(event_types) >= 1),
+    secret              TEXT NOT NULL CHECK (octet_length(secret) >= 16),
+    created_at          TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at          TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+SELECT diesel_manage_updated_at('webhook');
+
+CREATE TABLE webhook_delivery (
+    webhook_delivery_id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    webhook_id          UUID NOT NULL REFERENCES webhook(webhook_id) ON DELETE CASCADE,
+    event               webhook_event NOT NULL,
+    work_id             UUID NOT NULL,
+    attempt             INTEGER NOT NULL CHECK (attempt >= 1),
+    status_code         INTEGER,
+    error               TEXT,
+    created_at          TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+CREATE INDEX webhook_delivery_webhook_id_idx ON webhook_delivery(webhook_id);
+
+ALTER TABLE work
+    ADD COLUMN cover_format TEXT CHECK (cover_format ~* '^image\/'),
+    ADD COLUMN cover_width INTEGER CHECK (cover_width > 0),
+    ADD COLUMN cover_height INTEGER CHECK (cover_height > 0);
+
+CREATE TYPE abstract_type AS ENUM (
+    'short',
+    'long'
+);
+
+CREATE TABLE work_abstract (
+    abstract_id         UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    work_id             UUID NOT NULL REFERENCES work(work_id) ON DELETE CASCADE,
+    language_code       language_code NOT NULL,
+    abstract_type       abstract_type NOT NULL,
+    text                TEXT NOT NULL CHECK (octet_length(text) >= 1),
+    created_at          TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at          TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+SELECT diesel_manage_updated_at('work_abstract');
+
+-- UNIQ index on combination of work, language and abstract type
+CREATE UNIQUE INDEX work_abstract_uniq_idx ON work_abstract(work_id, language_code, abstract_type);
+
+CREATE TABLE work_abstract_history (
+    abstract_history_id      UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    abstract_id              UUID NOT NULL REFERENCES work_abstract(abstract_id) ON DELETE CASCADE,
+    account_id               UUID NOT NULL REFERENCES account(account_id) ON DELETE CASCADE,
+    data                     JSONB NOT NULL,
+    timestamp                TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+-- Existing abstracts become the default (English) abstracts of their works
+INSERT INTO work_abstract (work_id, language_code, abstract_type, text)
+    SELECT work_id, 'eng', 'short', short_abstract FROM work
+    WHERE short_abstract IS NOT NULL AND short_abstract <> '';
+INSERT INTO work_abstract (work_id, language_code, abstract_type, text)
+    SELECT work_id, 'eng', 'long', long_abstract FROM work
+    WHERE long_abstract IS NOT NULL AND long_abstract <> '';
+
+CREATE TYPE unpriced_item_type AS ENUM (
+    'free-of-charge',
+    'price-to-be-announced',
+    'not-sold-separately',
+    'contact-supplier'
+);
+
+-- Why a publication has no price, e.g. open access editions that are free of charge
+ALTER TABLE publication
+    ADD COLUMN unpriced_item_type unpriced_item_type;
+
+CREATE TYPE title_type AS ENUM (
+    'distinctive',
+    'original',
+    'translated',
+    'alternative'
+);
+
+CREATE TABLE work_title (
+    title_id            UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    work_id             UUID NOT NULL REFERENCES work(work_id) ON DELETE CASCADE,
+    language_code       language_code NOT NULL,
+    title_type          title_type NOT NULL,
+    title               TEXT NOT NULL CHECK (octet_length(title) >= 1),
+    subtitle            TEXT CHECK (octet_length(subtitle) >= 1),
+    created_at          TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at          TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+SELECT diesel_manage_updated_at('work_title');
+
+-- UNIQ index on combination of work, language and title type
+CREATE UNIQUE INDEX work_title_uniq_idx ON work_title(work_id, language_code, title_type);
+
+CREATE TABLE work_title_history (
+    title_history_id         UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    title_id                 UUID NOT NULL REFERENCES work_title(title_id) ON DELETE CASCADE,
+    account_id               UUID NOT NULL REFERENCES account(account_id) ON DELETE CASCADE,
+    data                     JSONB NOT NULL,
+    timestamp                TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+-- Existing titles become the default (English) titles of their works
+INSERT INTO work_title (work_id, language_code, title_type, title, subtitle)
+    SELECT work_id, 'eng', 'distinctive', title, NULLIF(subtitle, '') FROM work;
+
+-- Same pattern as the shortDOI validation in the API
+ALTER TABLE work
+    ADD COLUMN short_doi TEXT CHECK (short_doi ~* '^https:\/\/doi\.org\/(10\/)?[a-z0-9]+$');
+
+-- The imprint preselected for a publisher's new works. It must be one of the publisher's own
+-- imprints, which the API checks.
+ALTER TABLE publisher
+    ADD COLUMN default_imprint_id UUID REFERENCES imprint(imprint_id) ON DELETE SET NULL;
@@ -10,13 +10,19 @@ use uuid::Uuid;
 use crate::account::model::Account;
 use crate::account::model::AccountAccess;
 use crate::account::model::AccountData;
+use crate::account::model::ApiKey;
+use crate::account::model::DecodedApiKey;
 use crate::account::model::DecodedToken;
+use crate::account::model::IssuedApiKey;
 use crate::account::model::LinkedPublisher;
 use crate::account::model::NewAccount;
+use crate::account::model::NewApiKey;
 use crate::account::model::NewPassword;
 use crate::account::model::PublisherAccount;
 use crate::account::model::Token;
 use crate::account::service::get_account;
+use crate::account::util::hash_matches;
+use crate::account::util::make_api_key_secret;
 use crate::account::util::make_hash;
 use crate::account::util::make_salt;
 use crate::db::PgPool;
@@ -119,21 +125,47 @@ impl From<PublisherAccount> for LinkedPublisher {
     }
 }
 
+/// Number of seconds after expiry during which a token may still be refreshed
+pub const TOKEN_REFRESH_GRACE: u64 = 10 * 60;
+
 impl Token {
     pub fn verify(token: &str) -> Result<Token, ThothError> {
+        Token::decode(token, Validation::default())
+    }
+
+    pub fn verify_with_grace(token: &str) -> Result<Token, ThothError> {
+        Token::decode(
+            token,
+            Validation {
+                leeway: TOKEN_REFRESH_GRACE,
+                ..Validation::default()
+            },
+        )
+    }
+
+    fn decode(token: &str, validation: Validation) -> Result<Token, ThothError> {
         dotenv().ok();
         let secret_str = env::var("SECRET_KEY").expect("SECRET_KEY must be set");
         let secret: &[u8] = secret_str.as_bytes();
 
-        let data = decode::<Token>(
-            token,
-            &DecodingKey::from_secret(secret),
-            &Validation::default(),
-        )
-        .map_err(|_| ThothError::InvalidToken)?;
+        let data = decode::<Token>(token, &DecodingKey::from_secret(secret), &validation)
+            .map_err(|_| ThothError::InvalidToken)?;
         Ok(data.claims)
     }
 
+    /// Exchange a valid, or recently expired, token for a new one.
+    ///
+    /// Only the last token issued to an account can be refreshed, so a token
+    /// that has already been exchanged cannot be used to obtain another.
+    pub fn refresh(token: &str, pool: &PgPool) -> Result<String, ThothError> {
+        let claims = Token::verify_with_grace(token)?;
+        let account = get_account(&claims.sub, pool)?;
+        if account.token.as_deref() != Some(token) {
+            return Err(ThothError::InvalidToken);
+        }
+        account.issue_token(pool)
+    }
+
     pub fn account_id(&self, pool: &PgPool) -> Uuid {
         get_account(&self.sub, pool).unwrap().account_id
     }
@@ -170,6 +202,91 @@ impl actix_web::FromRequest for DecodedToken {
     }
 }
 
+impl ApiKey {
+    /// Create a new key for the given publisher, returning the only copy of its secret.
+    ///
+    /// Keys take the form `<api_key_id>.<secret>`; only a salted hash of the secret is stored.
+    pub fn issue(
+        publisher_id: Uuid,
+        account_id: Uuid,
+        label: String,
+        pool: &PgPool,
+    ) -> Result<IssuedApiKey, ThothError> {
+        use crate::schema::api_key::dsl;
+        let connection = pool.get().unwrap();
+
+        let secret = make_api_key_secret();
+        let salt = make_salt();
+        let hash = make_hash(&secret, &salt).to_vec();
+        let new_api_key = NewApiKey {
+            publisher_id,
+            account_id,
+            label,
+            hash,
+            salt,
+        };
+        let api_key = diesel::insert_into(dsl::api_key)
+            .values(&new_api_key)
+            .get_result::<ApiKey>(&connection)?;
+        Ok(IssuedApiKey {
+            api_key_id: api_key.api_key_id,
+            publisher_id: api_key.publisher_id,
+            label: api_key.label,
+            key: format!("{}.{}", api_key.api_key_id, secret),
+        })
+    }
+
+    pub fn verify(key: &str, pool: &PgPool) -> Result<ApiKey, ThothError> {
+        use crate::schema::api_key::dsl;
+        let connection = pool.get().unwrap();
+
+        let mut parts = key.splitn(2, '.');
+        let api_key_id = parts
+            .next()
+            .and_then(|id| Uuid::parse_str(id).ok())
+            .ok_or(ThothError::Unauthorised)?;
+        let secret = parts.next().ok_or(ThothError::Unauthorised)?;
+        let api_key = dsl::api_key
+            .find(api_key_id)
+            .get_result::<ApiKey>(&connection)
+            .map_err(|_| ThothError::Unauthorised)?;
+
+        if hash_matches(secret, &api_key.salt, &api_key.hash) {
+            Ok(api_key)
+        } else {
+            Err(ThothError::Unauthorised)
+        }
+    }
+}
+
+impl DecodedApiKey {
+    /// The key sent with the request, if any. A key that is sent but not valid
+    /// is an error, rather than a request without a key.
+    pub fn verify(&self, pool: &PgPool) -> Result<Option<ApiKey>, ThothError> {
+        self.key
+            .as_ref()
+            .map(|key| ApiKey::verify(key, pool))
+            .transpose()
+    }
+}
+
+impl actix_web::FromRequest for DecodedApiKey {
+    type Error = actix_web::Error;
+    type Future = futures::future::Ready<Result<Self, Self::Error>>;
+    type Config = ();
+
+    fn from_request(req: &actix_web::HttpRequest, _: &mut actix_web::dev::Payload) -> Self::Future {
+        let key = req
+            .headers()
+            .get("X-API-Key")
+            .and_then(|v| v.to_str().ok())
+            .map(|v| v.trim().to_string())
+            .filter(|v| !v.is_empty());
+
+        futures::future::ready(Ok(DecodedApiKey { key }))
+    }
+}
+
 impl NewPassword {
     pub fn new(email: String, password: String) -> Self {
         let salt = make_salt();
@@ -7,6 +7,8 @@ use crate::errors::ThothError;
 #[cfg(feature = "backend")]
 use crate::schema::account;
 #[cfg(feature = "backend")]
+use crate::schema::api_key;
+#[cfg(feature = "backend")]
 use crate::schema::publisher_account;
 
 #[cfg_attr(feature = "backend", derive(Queryable))]
@@ -65,6 +67,45 @@ pub struct NewPublisherAccount {
     pub is_admin: bool,
 }
 
+#[cfg_attr(feature = "backend", derive(Queryable))]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct ApiKey {
+    pub api_key_id: Uuid,
+    pub publisher_id: Uuid,
+    pub account_id: Uuid,
+    pub label: String,
+    #[serde(skip)]
+    pub hash: Vec<u8>,
+    #[serde(skip)]
+    pub salt: String,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+}
+
+#[cfg_attr(feature = "backend", derive(Insertable))]
+#[cfg_attr(feature = "backend", table_name = "api_key")]
+pub struct NewApiKey {
+    pub publisher_id: Uuid,
+    pub account_id: Uuid,
+    pub label: String,
+    pub hash: Vec<u8>,
+    pub salt: String,
+}
+
+#[cfg_attr(feature = "backend", derive(juniper::GraphQLObject))]
+#[cfg_attr(
+    feature = "backend",
+    graphql(description = "A newly created API key. The key itself is only ever shown once")
+)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IssuedApiKey {
+    pub api_key_id: Uuid,
+    pub publisher_id: Uuid,
+    pub label: String,
+    pub key: String,
+}
+
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct AccountAccess {
@@ -98,6 +139,7 @@ pub struct AccountDetails {
     pub surname: String,
     pub email: String,
     pub token: Option<String>,
+    pub token_expiry: Option<i64>,
     pub created_at: NaiveDateTime,
     pub updated_at: NaiveDateTime,
     pub resource_access: AccountAccess,
@@ -108,6 +150,11 @@ pub struct DecodedToken {
     pub jwt: Option<Token>,
 }
 
+#[derive(Debug, Clone)]
+pub struct DecodedApiKey {
+    pub key: Option<String>,
+}
+
 #[derive(Debug, PartialEq, Clone, Deserialize, Serialize, Default)]
 pub struct LoginCredentials {
     pub email: String,
@@ -135,6 +182,19 @@ impl DecodedToken {
     }
 }
 
+impl ApiKey {
+    /// Permissions granted to holders of this key. Keys are read-only, so they are not linked
+    /// to any publisher they could edit; reads are scoped to the key's publisher through
+    /// `Context::api_key` instead.
+    pub fn get_account_access(&self) -> AccountAccess {
+        AccountAccess {
+            is_superuser: false,
+            is_bot: true,
+            linked_publishers: vec![],
+        }
+    }
+}
+
 impl AccountAccess {
     pub fn can_edit(&self, publisher_id: Uuid) -> Result<()> {
         if self.is_superuser {
@@ -150,6 +210,19 @@ impl AccountAccess {
         }
     }
 
+    pub fn can_admin(&self, publisher_id: Uuid) -> Result<()> {
+        if self.is_superuser
+            || self
+                .linked_publishers
+                .iter()
+                .any(|publisher| publisher.publisher_id == publisher_id && publisher.is_admin)
+        {
+            Ok(())
+        } else {
+            Err(ThothError::Unauthorised.into())
+        }
+    }
+
     pub fn restricted_to(&self) -> Option<Vec<String>> {
         if self.is_superuser {
             None
@@ -9,6 +9,7 @@ use crate::account::model::NewAccount;
 use crate::account::model::NewPassword;
 use crate::account::model::NewPublisherAccount;
 use crate::account::model::PublisherAccount;
+use crate::account::model::Token;
 use crate::account::util::verify;
 use crate::db::PgPool;
 use crate::errors::ThothError;
@@ -52,12 +53,18 @@ pub fn get_account_details(email: &str, pool: &PgPool) -> Result<AccountDetails,
     let linked_publishers: Vec<LinkedPublisher> =
         account.get_permissions(&pool).unwrap_or_default();
     let resource_access = account.get_account_access(linked_publishers);
+    let token_expiry = account
+        .token
+        .as_ref()
+        .and_then(|token| Token::verify_with_grace(token).ok())
+        .map(|token| token.exp);
     let account_details = AccountDetails {
         account_id: account.account_id,
         name: account.name,
         surname: account.surname,
         email: account.email,
         token: account.token,
+        token_expiry,
         created_at: account.created_at,
         updated_at: account.updated_at,
         resource_access,
@@ -1,4 +1,5 @@
 use argon2rs::argon2i_simple;
+use argon2rs::verifier::constant_eq;
 
 use super::model::Account;
 
@@ -19,12 +20,33 @@ pub fn make_salt() -> String {
     password
 }
 
+pub fn make_api_key_secret() -> String {
+    use rand::Rng;
+    const CHARSET: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ\
+                            abcdefghijklmnopqrstuvwxyz\
+                            0123456789";
+    const SECRET_LEN: usize = 48;
+    let mut rng = rand::thread_rng();
+
+    (0..SECRET_LEN)
+        .map(|_| {
+            let idx = rng.gen_range(0, CHARSET.len());
+            CHARSET[idx] as char
+        })
+        .collect()
+}
+
 pub fn make_hash(password: &str, salt: &str) -> [u8; argon2rs::defaults::LENGTH] {
     argon2i_simple(password, salt)
 }
 
+/// Whether `secret` hashes to `hash` with `salt`, comparing in constant time
+pub fn hash_matches(secret: &str, salt: &str, hash: &[u8]) -> bool {
+    constant_eq(&make_hash(secret, salt), hash)
+}
+
 pub fn verify(account: &Account, password: &str) -> bool {
     let Account { hash, salt, .. } = account;
 
-    make_hash(password, salt) == hash.as_ref()
+    hash_matches(password, salt, hash)
 }
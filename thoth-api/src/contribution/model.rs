@@ -49,6 +49,7 @@ pub enum ContributionField {
     FirstName,
     LastName,
     FullName,
+    WorkTitle,
 }
 
 #[cfg_attr(feature = "backend", derive(Queryable))]
@@ -4,8 +4,10 @@ use uuid::Uuid;
 
 use crate::contributor::model::Contributor;
 use crate::contributor::model::ContributorHistory;
+use crate::contributor::model::NewContributor;
 use crate::contributor::model::NewContributorHistory;
 use crate::errors::ThothError;
+use crate::schema::contributor;
 use crate::schema::contributor_history;
 
 impl NewContributorHistory {
@@ -27,3 +29,31 @@ impl NewContributorHistory {
         }
     }
 }
+
+impl Contributor {
+    /// The contributor with the given ORCID ID, whether their ORCID is recorded as a bare ID or
+    /// as a URL, and in whatever case
+    pub fn find_by_orcid(
+        orcid_id: &str,
+        connection: &PgConnection,
+    ) -> Result<Option<Contributor>, ThothError> {
+        use crate::schema::contributor::dsl;
+        dsl::contributor
+            .filter(dsl::orcid.ilike(format!("%{}", orcid_id)))
+            .first::<Contributor>(connection)
+            .optional()
+            .map_err(ThothError::from)
+    }
+}
+
+impl NewContributor {
+    pub fn insert(&self, connection: &PgConnection) -> Result<Contributor, ThothError> {
+        match diesel::insert_into(contributor::table)
+            .values(self)
+            .get_result(connection)
+        {
+            Ok(contributor) => Ok(contributor),
+            Err(e) => Err(ThothError::from(e)),
+        }
+    }
+}
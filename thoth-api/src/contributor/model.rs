@@ -2,6 +2,9 @@ use chrono::naive::NaiveDateTime;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+use crate::errors::ThothError;
+use crate::identifier::validate_optional;
+use crate::identifier::Orcid;
 #[cfg(feature = "backend")]
 use crate::schema::contributor;
 #[cfg(feature = "backend")]
@@ -83,3 +86,117 @@ pub struct NewContributorHistory {
     pub account_id: Uuid,
     pub data: serde_json::Value,
 }
+
+/// Public details of a contributor as recorded in their ORCID record
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct OrcidProfile {
+    pub given_names: Option<String>,
+    pub family_name: Option<String>,
+    pub credit_name: Option<String>,
+    pub website: Option<String>,
+}
+
+/// A contributor created from an ORCID, flagging which fields were pre-filled from ORCID
+#[derive(Serialize, Deserialize)]
+pub struct OrcidContributor {
+    pub contributor: Contributor,
+    /// False if a contributor with this ORCID already existed and is returned unchanged
+    pub created: bool,
+    pub autofilled_fields: Vec<String>,
+    pub orcid_error: Option<String>,
+}
+
+impl NewContributor {
+    pub fn validate(&self) -> Result<(), ThothError> {
+        validate_optional::<Orcid>(&self.orcid)
+    }
+
+    /// Build a contributor from an ORCID and, if it could be fetched, its public profile.
+    /// Without a profile the ORCID itself is used as a placeholder for the required names.
+    pub fn from_orcid(
+        orcid: &str,
+        profile: Option<&OrcidProfile>,
+    ) -> (NewContributor, Vec<String>) {
+        let profile = profile.cloned().unwrap_or_default();
+        let mut autofilled_fields = vec![];
+        if profile.given_names.is_some() {
+            autofilled_fields.push("first_name".to_string());
+        }
+        let last_name = match &profile.family_name {
+            Some(family_name) => {
+                autofilled_fields.push("last_name".to_string());
+                family_name.clone()
+            }
+            None => orcid.to_string(),
+        };
+        let full_name = match (
+            &profile.credit_name,
+            &profile.given_names,
+            &profile.family_name,
+        ) {
+            (Some(credit_name), _, _) => Some(credit_name.clone()),
+            (None, Some(given_names), Some(family_name)) => {
+                Some(format!("{} {}", given_names, family_name))
+            }
+            (None, None, Some(family_name)) => Some(family_name.clone()),
+            _ => None,
+        };
+        let full_name = match full_name {
+            Some(full_name) => {
+                autofilled_fields.push("full_name".to_string());
+                full_name
+            }
+            None => orcid.to_string(),
+        };
+        if profile.website.is_some() {
+            autofilled_fields.push("website".to_string());
+        }
+        let contributor = NewContributor {
+            first_name: profile.given_names,
+            last_name,
+            full_name,
+            orcid: Some(format!("https://orcid.org/{}", orcid)),
+            website: profile.website,
+        };
+        (contributor, autofilled_fields)
+    }
+}
+
+impl PatchContributor {
+    pub fn validate(&self) -> Result<(), ThothError> {
+        validate_optional::<Orcid>(&self.orcid)
+    }
+}
+
+#[test]
+fn test_new_contributor_from_orcid_profile() {
+    let profile = OrcidProfile {
+        given_names: Some("Josiah".to_string()),
+        family_name: Some("Carberry".to_string()),
+        credit_name: None,
+        website: Some("https://example.org".to_string()),
+    };
+    let (contributor, autofilled) =
+        NewContributor::from_orcid("0000-0002-1825-0097", Some(&profile));
+    assert_eq!(contributor.first_name, Some("Josiah".to_string()));
+    assert_eq!(contributor.last_name, "Carberry");
+    assert_eq!(contributor.full_name, "Josiah Carberry");
+    assert_eq!(
+        contributor.orcid,
+        Some("https://orcid.org/0000-0002-1825-0097".to_string())
+    );
+    assert_eq!(
+        autofilled,
+        vec!["first_name", "last_name", "full_name", "website"]
+    );
+}
+
+#[test]
+fn test_new_contributor_from_orcid_without_profile() {
+    let (contributor, autofilled) = NewContributor::from_orcid("0000-0002-1825-0097", None);
+    assert_eq!(contributor.first_name, None);
+    assert_eq!(contributor.last_name, "0000-0002-1825-0097");
+    assert_eq!(contributor.full_name, "0000-0002-1825-0097");
+    assert_eq!(contributor.website, None);
+    assert!(autofilled.is_empty());
+}
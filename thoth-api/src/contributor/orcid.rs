@@ -0,0 +1,111 @@
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use crate::contributor::model::OrcidProfile;
+use crate::errors::ThothError;
+use crate::identifier::Orcid;
+
+const ORCID_API_URL: &str = "https://pub.orcid.org/v3.0";
+/// How long a fetched profile is reused before querying ORCID again
+const ORCID_CACHE_TTL: Duration = Duration::from_secs(5 * 60);
+
+lazy_static::lazy_static! {
+    static ref ORCID_CACHE: Mutex<HashMap<String, (Instant, OrcidProfile)>> =
+        Mutex::new(HashMap::new());
+}
+
+#[derive(Deserialize)]
+struct OrcidValue {
+    value: String,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "kebab-case")]
+struct OrcidName {
+    given_names: Option<OrcidValue>,
+    family_name: Option<OrcidValue>,
+    credit_name: Option<OrcidValue>,
+}
+
+#[derive(Deserialize)]
+struct OrcidResearcherUrl {
+    url: Option<OrcidValue>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "kebab-case")]
+struct OrcidResearcherUrls {
+    #[serde(default)]
+    researcher_url: Vec<OrcidResearcherUrl>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "kebab-case")]
+struct OrcidPerson {
+    name: Option<OrcidName>,
+    researcher_urls: Option<OrcidResearcherUrls>,
+}
+
+impl From<OrcidPerson> for OrcidProfile {
+    fn from(person: OrcidPerson) -> OrcidProfile {
+        let value = |v: Option<OrcidValue>| v.map(|v| v.value).filter(|v| !v.trim().is_empty());
+        let (given_names, family_name, credit_name) = match person.name {
+            Some(name) => (
+                value(name.given_names),
+                value(name.family_name),
+                value(name.credit_name),
+            ),
+            None => (None, None, None),
+        };
+        let website = person
+            .researcher_urls
+            .and_then(|urls| urls.researcher_url.into_iter().find_map(|u| value(u.url)));
+        OrcidProfile {
+            given_names,
+            family_name,
+            credit_name,
+            website,
+        }
+    }
+}
+
+/// Extract the bare identifier (e.g. `0000-0002-1825-0097`) from an ORCID or ORCID URL
+pub fn parse_orcid(input: &str) -> Result<String, ThothError> {
+    input.parse::<Orcid>().map(|orcid| orcid.id())
+}
+
+fn cached_profile(orcid: &str) -> Option<OrcidProfile> {
+    let cache = ORCID_CACHE.lock().unwrap();
+    cache
+        .get(orcid)
+        .filter(|(fetched, _)| fetched.elapsed() < ORCID_CACHE_TTL)
+        .map(|(_, profile)| profile.clone())
+}
+
+fn cache_profile(orcid: String, profile: OrcidProfile) {
+    let mut cache = ORCID_CACHE.lock().unwrap();
+    cache.retain(|_, (fetched, _)| fetched.elapsed() < ORCID_CACHE_TTL);
+    cache.insert(orcid, (Instant::now(), profile));
+}
+
+/// Fetch a contributor's public name and website from the ORCID public API.
+/// Responses are cached for `ORCID_CACHE_TTL` to avoid repeated lookups of the same record.
+pub async fn fetch_orcid_profile(orcid: &str) -> Result<OrcidProfile, ThothError> {
+    let orcid = parse_orcid(orcid)?;
+    if let Some(profile) = cached_profile(&orcid) {
+        return Ok(profile);
+    }
+    let person: OrcidPerson = reqwest::Client::new()
+        .get(&format!("{}/{}/person", ORCID_API_URL, orcid))
+        .header("Accept", "application/json")
+        .send()
+        .await?
+        .error_for_status()?
+        .json()
+        .await?;
+    let profile = OrcidProfile::from(person);
+    cache_profile(orcid, profile.clone());
+    Ok(profile)
+}
@@ -28,6 +28,46 @@ pub enum ThothError {
     InvalidSubjectType(String),
     #[fail(display = "{} is not a valid Language Relation", _0)]
     InvalidLanguageRelation(String),
+    #[fail(display = "{} is not a valid Abstract Type", _0)]
+    InvalidAbstractType(String),
+    #[fail(display = "{} is not a valid Title Type", _0)]
+    InvalidTitleType(String),
+    #[fail(display = "{} is not a valid Price Type", _0)]
+    InvalidPriceType(String),
+    #[fail(display = "{} is not a valid Unpriced Item Type", _0)]
+    InvalidUnpricedItemType(String),
+    #[fail(display = "{} is not a valid DOI", _0)]
+    InvalidDoi(String),
+    #[fail(display = "{} is not a valid shortDOI", _0)]
+    InvalidShortDoi(String),
+    #[fail(display = "{} is an alias of {}, not of {}", _0, _2, _1)]
+    ShortDoiMismatch(String, String, String),
+    #[fail(display = "{} is not a valid ORCID", _0)]
+    InvalidOrcid(String),
+    #[fail(display = "{} is not a valid ISBN-13", _0)]
+    IsbnParseError(String),
+    #[fail(display = "{} has an invalid ISBN check digit", _0)]
+    IsbnChecksumError(String),
+    #[fail(display = "{} is not a valid cover image: {}", _0, _1)]
+    InvalidCoverImage(String, String),
+    #[fail(display = "{} is not a valid Webhook Event", _0)]
+    InvalidWebhookEvent(String),
+    #[fail(display = "{} is not a plausible publication date", _0)]
+    InvalidPublicationDate(String),
+    #[fail(display = "{} must be given in both metric and imperial units", _0)]
+    DimensionMissingError(String),
+    #[fail(display = "Only physical publications (Paperback, Hardback) can have dimensions")]
+    DimensionNotPhysicalError,
+    #[fail(display = "Invalid input: {}", _0)]
+    InvalidInput(String),
+    #[fail(display = "Invalid ONIX: {}", _0)]
+    InvalidOnix(String),
+    #[fail(
+        display = "This record has been changed by someone else since it was loaded. Reload it before saving."
+    )]
+    StaleData,
+    #[fail(display = "The requested record does not exist")]
+    EntityNotFound,
     #[fail(display = "Database error: {}", _0)]
     DatabaseError(String),
     #[fail(display = "Internal error: {}", _0)]
@@ -40,36 +80,53 @@ pub enum ThothError {
     CookieError(),
 }
 
-impl juniper::IntoFieldError for ThothError {
-    fn into_field_error(self) -> juniper::FieldError {
+impl ThothError {
+    /// Machine readable code reported as the `type` of a GraphQL error
+    pub fn error_type(&self) -> &'static str {
         match self {
-            ThothError::InvalidSubjectCode { .. } => juniper::FieldError::new(
-                self.to_string(),
-                graphql_value!({
-                    "type": "INVALID_SUBJECT_CODE"
-                }),
-            ),
-            ThothError::Unauthorised => juniper::FieldError::new(
-                "Unauthorized",
-                graphql_value!({
-                    "type": "NO_ACCESS"
-                }),
-            ),
-            _ => juniper::FieldError::new(
-                self.to_string(),
-                graphql_value!({
-                    "type": "INTERNAL_ERROR"
-                }),
-            ),
+            ThothError::InvalidSubjectCode { .. } => "INVALID_SUBJECT_CODE",
+            ThothError::InvalidInput { .. }
+            | ThothError::InvalidOnix { .. }
+            | ThothError::InvalidPublicationDate { .. } => "INVALID_INPUT",
+            ThothError::InvalidDoi { .. }
+            | ThothError::InvalidShortDoi { .. }
+            | ThothError::ShortDoiMismatch { .. }
+            | ThothError::InvalidOrcid { .. }
+            | ThothError::IsbnParseError { .. }
+            | ThothError::IsbnChecksumError { .. } => "INVALID_IDENTIFIER",
+            ThothError::DimensionMissingError { .. } | ThothError::DimensionNotPhysicalError => {
+                "INVALID_DIMENSIONS"
+            }
+            ThothError::StaleData => "STALE_DATA",
+            ThothError::EntityNotFound => "NOT_FOUND",
+            ThothError::DatabaseError { .. } => "DATABASE_ERROR",
+            ThothError::Unauthorised => "NO_ACCESS",
+            _ => "INTERNAL_ERROR",
         }
     }
 }
 
+impl juniper::IntoFieldError for ThothError {
+    fn into_field_error(self) -> juniper::FieldError {
+        let message = match self {
+            ThothError::Unauthorised => "Unauthorized".to_string(),
+            _ => self.to_string(),
+        };
+        juniper::FieldError::new(
+            message,
+            graphql_value!({
+                "type": (self.error_type())
+            }),
+        )
+    }
+}
+
 #[cfg(feature = "backend")]
 impl ResponseError for ThothError {
     fn error_response(&self) -> HttpResponse {
         match self {
             ThothError::Unauthorised => HttpResponse::Unauthorized().json("Unauthorized"),
+            ThothError::EntityNotFound => HttpResponse::NotFound().json("Not found"),
             ThothError::DatabaseError { .. } => {
                 HttpResponse::InternalServerError().json("DB error")
             }
@@ -86,6 +143,7 @@ impl From<DBError> for ThothError {
                 let message = info.details().unwrap_or_else(|| info.message()).to_string();
                 ThothError::DatabaseError(message)
             }
+            DBError::NotFound => ThothError::EntityNotFound,
             _ => ThothError::InternalError("".into()),
         }
     }
@@ -0,0 +1,43 @@
+use serde::Deserialize;
+
+use crate::errors::ThothError;
+use crate::funder::model::FunderCandidate;
+
+const FUNDER_REGISTRY_URL: &str = "https://api.crossref.org/funders";
+/// Maximum number of candidates returned for a single search
+const FUNDER_REGISTRY_ROWS: &str = "10";
+
+#[derive(Deserialize)]
+struct RegistryResponse {
+    message: RegistryMessage,
+}
+
+#[derive(Deserialize)]
+struct RegistryMessage {
+    #[serde(default)]
+    items: Vec<RegistryFunder>,
+}
+
+#[derive(Deserialize)]
+struct RegistryFunder {
+    id: String,
+    name: String,
+}
+
+/// Search the Crossref Funder Registry by name, returning candidates with a valid funder DOI
+pub async fn search_funder_registry(name: &str) -> Result<Vec<FunderCandidate>, ThothError> {
+    let response: RegistryResponse = reqwest::Client::new()
+        .get(FUNDER_REGISTRY_URL)
+        .query(&[("query", name), ("rows", FUNDER_REGISTRY_ROWS)])
+        .send()
+        .await?
+        .error_for_status()?
+        .json()
+        .await?;
+    Ok(response
+        .message
+        .items
+        .into_iter()
+        .filter_map(|funder| FunderCandidate::from_registry(&funder.id, funder.name))
+        .collect())
+}
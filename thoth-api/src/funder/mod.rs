@@ -1,3 +1,5 @@
 #[cfg(feature = "backend")]
+pub mod crossref;
+#[cfg(feature = "backend")]
 pub mod handler;
 pub mod model;
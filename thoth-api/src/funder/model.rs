@@ -1,4 +1,5 @@
 use chrono::naive::NaiveDateTime;
+use regex::Regex;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
@@ -7,6 +8,16 @@ use crate::schema::funder;
 #[cfg(feature = "backend")]
 use crate::schema::funder_history;
 
+/// DOI prefix under which the Crossref Funder Registry assigns funder identifiers
+pub const FUNDER_REGISTRY_DOI_PREFIX: &str = "https://doi.org/10.13039/";
+
+lazy_static::lazy_static! {
+    // Same pattern as the `funder_funder_doi_check` database constraint
+    static ref FUNDER_DOI_REGEXP: Regex =
+        Regex::new(r"(?i)https://doi\.org/10\.\d{4,9}/[-._;()/:a-zA-Z0-9]+$")
+            .expect("Funder DOI regexp failed!");
+}
+
 #[cfg_attr(
     feature = "backend",
     derive(juniper::GraphQLEnum),
@@ -67,3 +78,55 @@ pub struct NewFunderHistory {
     pub account_id: Uuid,
     pub data: serde_json::Value,
 }
+
+/// A funder found in the Crossref Funder Registry
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct FunderCandidate {
+    pub funder_name: String,
+    pub funder_doi: String,
+}
+
+impl FunderCandidate {
+    /// Build a candidate from a registry entry, discarding it if its DOI would be rejected
+    pub fn from_registry(registry_id: &str, funder_name: String) -> Option<FunderCandidate> {
+        let funder_doi = format!("{}{}", FUNDER_REGISTRY_DOI_PREFIX, registry_id);
+        if is_valid_funder_doi(&funder_doi) {
+            Some(FunderCandidate {
+                funder_name,
+                funder_doi,
+            })
+        } else {
+            None
+        }
+    }
+}
+
+pub fn is_valid_funder_doi(funder_doi: &str) -> bool {
+    FUNDER_DOI_REGEXP.is_match(funder_doi)
+}
+
+#[test]
+fn test_is_valid_funder_doi() {
+    assert!(is_valid_funder_doi("https://doi.org/10.13039/501100000780"));
+    assert!(is_valid_funder_doi("https://DOI.org/10.13039/100000001"));
+    assert!(!is_valid_funder_doi(
+        "http://dx.doi.org/10.13039/501100000780"
+    ));
+    assert!(!is_valid_funder_doi("https://doi.org/10.13039/"));
+    assert!(!is_valid_funder_doi("10.13039/501100000780"));
+}
+
+#[test]
+fn test_funder_candidate_from_registry() {
+    assert_eq!(
+        FunderCandidate::from_registry("501100000780", "European Commission".to_string()),
+        Some(FunderCandidate {
+            funder_name: "European Commission".to_string(),
+            funder_doi: "https://doi.org/10.13039/501100000780".to_string(),
+        })
+    );
+    assert_eq!(
+        FunderCandidate::from_registry("", "Unknown".to_string()),
+        None
+    );
+}
@@ -1,14 +1,30 @@
 use chrono::naive::NaiveDate;
 use chrono::naive::NaiveDateTime;
+use chrono::Datelike;
+use diesel::dsl::sql;
+use diesel::pg::Pg;
 use diesel::prelude::*;
+use diesel::sql_types::BigInt;
+use diesel::sql_types::Integer;
 use juniper::FieldError;
 use juniper::FieldResult;
+use juniper::IntoFieldError;
+use juniper::ParseScalarResult;
+use juniper::ParseScalarValue;
 use juniper::RootNode;
+use juniper::Value;
+use std::collections::HashMap;
+use std::convert::TryFrom;
+use std::env;
 use std::sync::Arc;
 use uuid::Uuid;
 
 use crate::account::model::AccountAccess;
+use crate::account::model::ApiKey;
+use crate::account::model::DecodedApiKey;
 use crate::account::model::DecodedToken;
+use crate::account::model::IssuedApiKey;
+use crate::account::model::Token;
 use crate::contribution::model::*;
 use crate::contributor::model::*;
 use crate::db::PgPool;
@@ -16,16 +32,146 @@ use crate::errors::Result;
 use crate::errors::ThothError;
 use crate::funder::model::*;
 use crate::funding::model::*;
+use crate::identifier::Orcid;
+use crate::identifier::ShortDoi;
+use crate::imprint::handler::can_delete_imprint;
+use crate::imprint::handler::can_transfer_imprint;
+use crate::imprint::handler::release_default_imprint;
 use crate::imprint::model::*;
 use crate::issue::model::*;
 use crate::language::model::*;
 use crate::price::model::*;
 use crate::publication::model::*;
+use crate::publisher::handler::can_set_default_imprint;
 use crate::publisher::model::*;
 use crate::schema::*;
 use crate::series::model::*;
 use crate::subject::model::*;
+use crate::webhook::handler::check_webhook_url;
+use crate::webhook::handler::dispatch_work_events;
+use crate::webhook::model::*;
+use crate::work::citation::*;
+use crate::work::cover::fetch_cover_image;
+use crate::work::handler::can_delete_work;
+use crate::work::handler::can_update_work_imprint;
 use crate::work::model::*;
+use crate::work::onix::parse_onix_products;
+use crate::work::short_doi::verify_short_doi;
+use crate::work_abstract::handler::sync_default_abstract;
+use crate::work_abstract::handler::sync_work_abstract_field;
+use crate::work_abstract::model::*;
+use crate::work_title::handler::sync_default_title;
+use crate::work_title::model::*;
+
+/// Largest `limit` accepted by list queries unless overridden with `MAX_QUERY_LIMIT`
+pub const DEFAULT_MAX_LIMIT: i32 = 1000;
+
+lazy_static::lazy_static! {
+    /// Largest `limit` accepted by list queries
+    pub static ref MAX_LIMIT: i32 = env::var("MAX_QUERY_LIMIT")
+        .ok()
+        .and_then(|limit| limit.parse().ok())
+        .filter(|limit| *limit > 0)
+        .unwrap_or(DEFAULT_MAX_LIMIT);
+}
+
+/// Check the pagination arguments of a list query, clamping an oversized limit to `max_limit`
+pub fn page_bounds(
+    limit: i32,
+    offset: i32,
+    max_limit: i32,
+) -> std::result::Result<(i64, i64), ThothError> {
+    if limit < 0 {
+        return Err(ThothError::InvalidInput(format!(
+            "limit must not be negative, got {}",
+            limit
+        )));
+    }
+    if offset < 0 {
+        return Err(ThothError::InvalidInput(format!(
+            "offset must not be negative, got {}",
+            offset
+        )));
+    }
+    Ok((limit.min(max_limit).into(), offset.into()))
+}
+
+/// Build an ILIKE pattern matching `filter` anywhere in a value. LIKE metacharacters in the
+/// filter are escaped so that it is matched literally, as the `filter` arguments document.
+pub fn contains_pattern(filter: &str) -> String {
+    let mut pattern = String::with_capacity(filter.len() + 2);
+    pattern.push('%');
+    for c in filter.chars() {
+        if c == '%' || c == '_' || c == '\\' {
+            pattern.push('\\');
+        }
+        pattern.push(c);
+    }
+    pattern.push('%');
+    pattern
+}
+
+/// How well a search result matches a search, judged on its name: 3 if the whole name matches,
+/// 2 if it starts with the search, and 1 otherwise, as results may match on other fields.
+/// Names are compared ignoring case and surrounding whitespace.
+pub fn search_relevance(name: &str, search: &str) -> i32 {
+    let name = name.trim().to_lowercase();
+    let search = search.trim().to_lowercase();
+    if name == search {
+        3
+    } else if name.starts_with(&search) {
+        2
+    } else {
+        1
+    }
+}
+
+/// Merge lists of results, each paired with its relevance, into one list of at most `limit`
+/// results, most relevant first. Results of equal relevance are interleaved, taking one from each
+/// list in turn, so that no one list crowds out the others.
+pub fn rank_search_results<T>(groups: Vec<Vec<(i32, T)>>, limit: usize) -> Vec<T> {
+    let mut groups: Vec<std::vec::IntoIter<(i32, T)>> =
+        groups.into_iter().map(|group| group.into_iter()).collect();
+    let mut interleaved = Vec::new();
+    loop {
+        let before = interleaved.len();
+        interleaved.extend(groups.iter_mut().filter_map(|group| group.next()));
+        if interleaved.len() == before {
+            break;
+        }
+    }
+    // sort_by is stable, so the interleaved order is kept within each level of relevance
+    interleaved.sort_by(|(a, _), (b, _)| b.cmp(a));
+    interleaved
+        .into_iter()
+        .take(limit)
+        .map(|(_, result)| result)
+        .collect()
+}
+
+/// A number of records. `SELECT COUNT(*)` in postgres returns a BIGINT, which `Int` cannot
+/// hold in full, so counts are given as a string of decimal digits.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Count(pub i64);
+
+juniper::graphql_scalar!(Count where Scalar = <S> {
+    description: "A number of records, given as a string of decimal digits as it may exceed the range of Int"
+
+    resolve(&self) -> Value {
+        Value::scalar(self.0.to_string())
+    }
+
+    from_input_value(v: &InputValue) -> Option<Count> {
+        v.as_string_value()
+            .and_then(|s| s.parse().ok())
+            .or_else(|| v.as_int_value().map(i64::from))
+            .map(Count)
+    }
+
+    from_str<'a>(value: ScalarToken<'a>) -> ParseScalarResult<'a, S> {
+        <String as ParseScalarValue<S>>::from_str(value)
+    }
+});
 
 impl juniper::Context for Context {}
 
@@ -34,15 +180,73 @@ pub struct Context {
     pub db: Arc<PgPool>,
     pub account_access: AccountAccess,
     pub token: DecodedToken,
+    pub api_key: Option<ApiKey>,
 }
 
 impl Context {
-    pub fn new(pool: Arc<PgPool>, token: DecodedToken) -> Self {
-        Self {
+    pub fn new(
+        pool: Arc<PgPool>,
+        token: DecodedToken,
+        api_key: DecodedApiKey,
+    ) -> Result<Self, ThothError> {
+        // API keys are only considered in the absence of a valid token. Since they never
+        // populate `token.jwt` and grant no publisher to edit, any mutation attempted with an
+        // API key is unauthorised.
+        let api_key = match token.jwt {
+            Some(_) => None,
+            None => api_key.verify(&pool)?,
+        };
+        let account_access = match &api_key {
+            Some(key) => key.get_account_access(),
+            None => token.get_user_permissions(),
+        };
+        Ok(Self {
             db: pool,
-            account_access: token.get_user_permissions(),
+            account_access,
             token,
+            api_key,
+        })
+    }
+
+    /// Restrict a list of publishers to the one an API key is tied to, if any
+    pub fn scoped_publishers(&self, publishers: Vec<Uuid>) -> Vec<Uuid> {
+        match &self.api_key {
+            Some(key) => vec![key.publisher_id],
+            None => publishers,
+        }
+    }
+
+    /// Hide a record from an API key tied to another publisher, as if it did not exist. The
+    /// record's publisher is only looked up for requests made with an API key.
+    pub fn check_scope<F>(&self, publisher_id: F) -> FieldResult<()>
+    where
+        F: FnOnce() -> FieldResult<Uuid>,
+    {
+        match &self.api_key {
+            Some(key) if key.publisher_id != publisher_id()? => {
+                Err(ThothError::EntityNotFound.into_field_error())
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Publishers whose records a search across all entities may return: the publisher of the
+    /// API key, or those a logged-in editor who is not a superuser is linked to. `None` if the
+    /// search is not restricted, i.e. for superusers and the public.
+    pub fn searchable_publishers(&self) -> Option<Vec<Uuid>> {
+        if let Some(key) = &self.api_key {
+            return Some(vec![key.publisher_id]);
+        }
+        if self.token.jwt.is_some() && !self.account_access.is_superuser {
+            return Some(
+                self.account_access
+                    .linked_publishers
+                    .iter()
+                    .map(|publisher| publisher.publisher_id)
+                    .collect(),
+            );
         }
+        None
     }
 }
 
@@ -144,6 +348,31 @@ pub struct FundingOrderBy {
     pub direction: Direction,
 }
 
+/// A record found by the `search` query
+pub enum SearchResult {
+    Work(Work),
+    Contributor(Contributor),
+    Publisher(Publisher),
+    Series(Series),
+}
+
+juniper::graphql_union!(SearchResult: Context |&self| {
+    description: "A work, contributor, publisher or series found by a search"
+
+    instance_resolvers: |_| {
+        &Work => match *self { SearchResult::Work(ref work) => Some(work), _ => None },
+        &Contributor => match *self {
+            SearchResult::Contributor(ref contributor) => Some(contributor),
+            _ => None,
+        },
+        &Publisher => match *self {
+            SearchResult::Publisher(ref publisher) => Some(publisher),
+            _ => None,
+        },
+        &Series => match *self { SearchResult::Series(ref series) => Some(series), _ => None },
+    }
+});
+
 pub struct QueryRoot;
 
 #[juniper::object(Context = Context)]
@@ -178,6 +407,18 @@ impl QueryRoot {
         ),
         work_type(description = "A specific type to filter by"),
         work_status(description = "A specific status to filter by"),
+        work_statuses(
+            default = vec![],
+            description = "If set, only shows works with one of these statuses",
+        ),
+        updated_at_after(description = "If set, only shows works last updated at or after this time"),
+        updated_at_before(description = "If set, only shows works last updated before this time"),
+        publication_year(description = "If set, only shows works published in this year"),
+        subject_type(description = "If set, only shows works with a subject of this type"),
+        subject_code(description = "If set, only shows works with a subject with this code"),
+        contributor_orcid(description = "If set, only shows works with a contribution by the contributor with this ORCID, given either bare or as an orcid.org URL"),
+        funder_id(description = "If set, only shows works funded by the funder with this ID"),
+        license(description = "If set, only shows works under this licence. Licence URLs are compared ignoring case, scheme, a www. prefix and trailing slashes"),
     )
   )]
     fn works(
@@ -189,7 +430,19 @@ impl QueryRoot {
         publishers: Vec<Uuid>,
         work_type: Option<WorkType>,
         work_status: Option<WorkStatus>,
-    ) -> Vec<Work> {
+        work_statuses: Vec<WorkStatus>,
+        updated_at_after: Option<NaiveDateTime>,
+        updated_at_before: Option<NaiveDateTime>,
+        publication_year: Option<i32>,
+        subject_type: Option<SubjectType>,
+        subject_code: Option<String>,
+        contributor_orcid: Option<String>,
+        funder_id: Option<Uuid>,
+        license: Option<String>,
+    ) -> FieldResult<Vec<Work>> {
+        let (limit, offset) = page_bounds(limit, offset, *MAX_LIMIT)?;
+        let contributor_orcid = contributor_orcid_filter(contributor_orcid)?;
+        let publication_dates = publication_year_bounds(publication_year)?;
         use crate::schema::work::dsl;
         let connection = context.db.get().unwrap();
         let mut query = dsl::work
@@ -228,6 +481,10 @@ impl QueryRoot {
                 dsl::cover_caption,
                 dsl::created_at,
                 dsl::updated_at,
+                dsl::cover_format,
+                dsl::cover_width,
+                dsl::cover_height,
+                dsl::short_doi,
             ))
             .into_boxed();
         match order.field {
@@ -359,11 +616,24 @@ impl QueryRoot {
                 Direction::ASC => query = query.order(dsl::updated_at.asc()),
                 Direction::DESC => query = query.order(dsl::updated_at.desc()),
             },
+            WorkField::CompletenessScore => match order.direction {
+                Direction::ASC => query = query.order(sql::<Integer>(&completeness_sql()).asc()),
+                Direction::DESC => query = query.order(sql::<Integer>(&completeness_sql()).desc()),
+            },
+            WorkField::ContributionCount => match order.direction {
+                Direction::ASC => query = query.order(sql::<BigInt>(CONTRIBUTION_COUNT_SQL).asc()),
+                Direction::DESC => {
+                    query = query.order(sql::<BigInt>(CONTRIBUTION_COUNT_SQL).desc())
+                }
+            },
         }
+        // Many works can share a value of the sort field: break ties by ID so that pages
+        // neither repeat nor skip works
+        query = query.then_order_by(dsl::work_id.asc());
         // Ordering and construction of filters is important here: result needs to be
         // `WHERE (x = $1 [OR x = $2...]) AND (y ILIKE $3 [OR z ILIKE $3...])`.
         // Interchanging .filter, .or, and .or_filter would result in different bracketing.
-        for pub_id in publishers {
+        for pub_id in context.scoped_publishers(publishers) {
             query = query.or_filter(crate::schema::imprint::publisher_id.eq(pub_id));
         }
         if let Some(wk_type) = work_type {
@@ -372,32 +642,103 @@ impl QueryRoot {
         if let Some(wk_status) = work_status {
             query = query.filter(dsl::work_status.eq(wk_status))
         }
+        if !work_statuses.is_empty() {
+            query = query.filter(dsl::work_status.eq_any(work_statuses))
+        }
+        if let Some(after) = updated_at_after {
+            query = query.filter(dsl::updated_at.ge(after))
+        }
+        if let Some(before) = updated_at_before {
+            query = query.filter(dsl::updated_at.lt(before))
+        }
+        if let Some((first_day, next_year)) = publication_dates {
+            query = query
+                .filter(dsl::publication_date.ge(first_day))
+                .filter(dsl::publication_date.lt(next_year))
+        }
+        if let Some(subject_work_ids) = subject_work_ids(subject_type, subject_code) {
+            query = query.filter(dsl::work_id.eq_any(subject_work_ids))
+        }
+        if let Some(orcid) = contributor_orcid {
+            query = query.filter(dsl::work_id.eq_any(orcid_work_ids(&orcid)))
+        }
+        if let Some(funder) = funder_id {
+            // a subquery rather than a join, so that works with several grants from the funder
+            // are listed once
+            query = query.filter(
+                dsl::work_id.eq_any(
+                    funding::table
+                        .select(funding::work_id)
+                        .filter(funding::funder_id.eq(funder)),
+                ),
+            )
+        }
+        if let Some(license) = license {
+            query = query.filter(dsl::work_id.eq_any(license_work_ids(&license)))
+        }
         query
             .filter(
                 dsl::full_title
-                    .ilike(format!("%{}%", filter))
-                    .or(dsl::doi.ilike(format!("%{}%", filter)))
-                    .or(dsl::reference.ilike(format!("%{}%", filter)))
-                    .or(dsl::short_abstract.ilike(format!("%{}%", filter)))
-                    .or(dsl::long_abstract.ilike(format!("%{}%", filter)))
-                    .or(dsl::landing_page.ilike(format!("%{}%", filter))),
+                    .ilike(contains_pattern(&filter))
+                    .or(dsl::doi.ilike(contains_pattern(&filter)))
+                    .or(dsl::reference.ilike(contains_pattern(&filter)))
+                    .or(dsl::short_abstract.ilike(contains_pattern(&filter)))
+                    .or(dsl::long_abstract.ilike(contains_pattern(&filter)))
+                    .or(dsl::landing_page.ilike(contains_pattern(&filter))),
             )
-            .limit(limit.into())
-            .offset(offset.into())
+            .limit(limit)
+            .offset(offset)
             .load::<Work>(&connection)
-            .expect("Error loading works")
+            .map_err(FieldError::from)
     }
 
     #[graphql(description = "Query a single work using its id")]
     fn work(context: &Context, work_id: Uuid) -> FieldResult<Work> {
         let connection = context.db.get().unwrap();
-        match crate::schema::work::dsl::work
+        let work = crate::schema::work::dsl::work
             .find(work_id)
             .get_result::<Work>(&connection)
-        {
-            Ok(work) => Ok(work),
-            Err(e) => Err(FieldError::from(e)),
-        }
+            .map_err(FieldError::from)?;
+        context.check_scope(|| imprint_publisher_id(work.imprint_id, context))?;
+        Ok(work)
+    }
+
+    #[graphql(
+        description = "Past versions of a work, newest first, each with the fields changed by the edit that replaced it. Only available to editors of the work"
+    )]
+    fn work_history(context: &Context, work_id: Uuid) -> FieldResult<Vec<WorkVersion>> {
+        context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
+        user_can_edit_work(work_id, context)?;
+        let connection = context.db.get().unwrap();
+        let current = crate::schema::work::dsl::work
+            .find(work_id)
+            .get_result::<Work>(&connection)
+            .map_err(FieldError::from)?;
+        let history = work_history::table
+            .inner_join(account::table)
+            .select((work_history::all_columns, account::name, account::surname))
+            .filter(work_history::work_id.eq(work_id))
+            .order(work_history::timestamp.desc())
+            .load::<(WorkHistory, String, String)>(&connection)
+            .map_err(FieldError::from)?;
+        // Each record holds the work as it was before an edit, so it is compared
+        // with the next newer record, or the current work for the latest one
+        let mut replacement = serde_json::to_value(&current).unwrap_or_default();
+        Ok(history
+            .into_iter()
+            .map(|(record, name, surname)| {
+                let snapshot = record.snapshot();
+                let version = WorkVersion {
+                    work_history_id: record.work_history_id,
+                    timestamp: record.timestamp,
+                    editor: format!("{} {}", name, surname),
+                    snapshot: snapshot.to_string(),
+                    changes: diff_versions(&snapshot, &replacement),
+                };
+                replacement = snapshot;
+                version
+            })
+            .collect())
     }
 
     #[graphql(description = "Query a single work using its DOI")]
@@ -407,16 +748,16 @@ impl QueryRoot {
         use diesel::sql_types::Text;
         // Allow case-insensitive searching (DOIs in database may have mixed casing)
         sql_function!(fn lower(x: Nullable<Text>) -> Nullable<Text>);
-        match crate::schema::work::dsl::work
+        let work = crate::schema::work::dsl::work
             .filter(lower(crate::schema::work::dsl::doi).eq(doi.to_lowercase()))
             .get_result::<Work>(&connection)
-        {
-            Ok(work) => Ok(work),
-            Err(e) => Err(FieldError::from(e)),
-        }
+            .map_err(FieldError::from)?;
+        context.check_scope(|| imprint_publisher_id(work.imprint_id, context))?;
+        Ok(work)
     }
 
     #[graphql(
+        deprecated = "Use workTotal, which is not limited to the range of Int",
         description = "Get the total number of works",
         arguments(
             filter(
@@ -429,6 +770,18 @@ impl QueryRoot {
             ),
             work_type(description = "A specific type to filter by"),
             work_status(description = "A specific status to filter by"),
+            work_statuses(
+                default = vec![],
+                description = "If set, only counts works with one of these statuses",
+            ),
+            updated_at_after(description = "If set, only counts works last updated at or after this time"),
+            updated_at_before(description = "If set, only counts works last updated before this time"),
+            publication_year(description = "If set, only counts works published in this year"),
+            subject_type(description = "If set, only counts works with a subject of this type"),
+            subject_code(description = "If set, only counts works with a subject with this code"),
+            contributor_orcid(description = "If set, only counts works with a contribution by the contributor with this ORCID, given either bare or as an orcid.org URL"),
+            funder_id(description = "If set, only counts works funded by the funder with this ID"),
+            license(description = "If set, only counts works under this licence. Licence URLs are compared ignoring case, scheme, a www. prefix and trailing slashes"),
         )
     )]
     fn work_count(
@@ -437,79 +790,254 @@ impl QueryRoot {
         publishers: Vec<Uuid>,
         work_type: Option<WorkType>,
         work_status: Option<WorkStatus>,
-    ) -> i32 {
+        work_statuses: Vec<WorkStatus>,
+        updated_at_after: Option<NaiveDateTime>,
+        updated_at_before: Option<NaiveDateTime>,
+        publication_year: Option<i32>,
+        subject_type: Option<SubjectType>,
+        subject_code: Option<String>,
+        contributor_orcid: Option<String>,
+        funder_id: Option<Uuid>,
+        license: Option<String>,
+    ) -> FieldResult<i32> {
+        load_work_count(
+            context,
+            filter,
+            publishers,
+            work_type,
+            work_status,
+            work_statuses,
+            updated_at_after,
+            updated_at_before,
+            publication_year,
+            subject_type,
+            subject_code,
+            contributor_orcid,
+            funder_id,
+            license,
+        )
+        .map(saturating_count)
+    }
+
+    #[graphql(
+        description = "Get the total number of works",
+        arguments(
+            filter(
+                default = "".to_string(),
+                description = "A query string to search. This argument is a test, do not rely on it. At present it simply searches for case insensitive literals on full_title, doi, reference, short_abstract, long_abstract, and landing_page",
+            ),
+            publishers(
+                default = vec![],
+                description = "If set, only shows results connected to publishers with these IDs",
+            ),
+            work_type(description = "A specific type to filter by"),
+            work_status(description = "A specific status to filter by"),
+            work_statuses(
+                default = vec![],
+                description = "If set, only counts works with one of these statuses",
+            ),
+            updated_at_after(description = "If set, only counts works last updated at or after this time"),
+            updated_at_before(description = "If set, only counts works last updated before this time"),
+            publication_year(description = "If set, only counts works published in this year"),
+            subject_type(description = "If set, only counts works with a subject of this type"),
+            subject_code(description = "If set, only counts works with a subject with this code"),
+            contributor_orcid(description = "If set, only counts works with a contribution by the contributor with this ORCID, given either bare or as an orcid.org URL"),
+            funder_id(description = "If set, only counts works funded by the funder with this ID"),
+            license(description = "If set, only counts works under this licence. Licence URLs are compared ignoring case, scheme, a www. prefix and trailing slashes"),
+        )
+    )]
+    fn work_total(
+        context: &Context,
+        filter: String,
+        publishers: Vec<Uuid>,
+        work_type: Option<WorkType>,
+        work_status: Option<WorkStatus>,
+        work_statuses: Vec<WorkStatus>,
+        updated_at_after: Option<NaiveDateTime>,
+        updated_at_before: Option<NaiveDateTime>,
+        publication_year: Option<i32>,
+        subject_type: Option<SubjectType>,
+        subject_code: Option<String>,
+        contributor_orcid: Option<String>,
+        funder_id: Option<Uuid>,
+        license: Option<String>,
+    ) -> FieldResult<Count> {
+        load_work_count(
+            context,
+            filter,
+            publishers,
+            work_type,
+            work_status,
+            work_statuses,
+            updated_at_after,
+            updated_at_before,
+            publication_year,
+            subject_type,
+            subject_code,
+            contributor_orcid,
+            funder_id,
+            license,
+        )
+        .map(Count)
+    }
+
+    #[graphql(
+        description = "Count the works matching a search by work type and by year of publication",
+        arguments(
+            filter(
+                default = "".to_string(),
+                description = "A query string to search. This argument is a test, do not rely on it. At present it simply searches for case insensitive literals on full_title, doi, reference, short_abstract, long_abstract, and landing_page",
+            ),
+            publishers(
+                default = vec![],
+                description = "If set, only counts works connected to publishers with these IDs",
+            ),
+            work_type(description = "If set, only counts publication years of works of this type"),
+            publication_year(description = "If set, only counts types of works published in this year"),
+        )
+    )]
+    fn work_facets(
+        context: &Context,
+        filter: String,
+        publishers: Vec<Uuid>,
+        work_type: Option<WorkType>,
+        publication_year: Option<i32>,
+    ) -> FieldResult<WorkFacets> {
         use crate::schema::work::dsl;
         let connection = context.db.get().unwrap();
         let mut query = dsl::work
             .inner_join(crate::schema::imprint::table)
-            .select((
-                dsl::work_id,
-                dsl::work_type,
-                dsl::work_status,
-                dsl::full_title,
-                dsl::title,
-                dsl::subtitle,
-                dsl::reference,
-                dsl::edition,
-                dsl::imprint_id,
-                dsl::doi,
-                dsl::publication_date,
-                dsl::place,
-                dsl::width,
-                dsl::height,
-                dsl::page_count,
-                dsl::page_breakdown,
-                dsl::image_count,
-                dsl::table_count,
-                dsl::audio_count,
-                dsl::video_count,
-                dsl::license,
-                dsl::copyright_holder,
-                dsl::landing_page,
-                dsl::lccn,
-                dsl::oclc,
-                dsl::short_abstract,
-                dsl::long_abstract,
-                dsl::general_note,
-                dsl::toc,
-                dsl::cover_url,
-                dsl::cover_caption,
-                dsl::created_at,
-                dsl::updated_at,
-            ))
+            .select((dsl::work_type, dsl::publication_date))
             .into_boxed();
-        // Ordering and construction of filters is important here: result needs to be
-        // `WHERE (x = $1 [OR x = $2...]) AND (y ILIKE $3 [OR z ILIKE $3...])`.
-        // Interchanging .filter, .or, and .or_filter would result in different bracketing.
-        for pub_id in publishers {
+        // see comment in works()
+        for pub_id in context.scoped_publishers(publishers) {
             query = query.or_filter(crate::schema::imprint::publisher_id.eq(pub_id));
         }
-        if let Some(wk_type) = work_type {
-            query = query.filter(dsl::work_type.eq(wk_type))
+        let works = query
+            .filter(
+                dsl::full_title
+                    .ilike(contains_pattern(&filter))
+                    .or(dsl::doi.ilike(contains_pattern(&filter)))
+                    .or(dsl::reference.ilike(contains_pattern(&filter)))
+                    .or(dsl::short_abstract.ilike(contains_pattern(&filter)))
+                    .or(dsl::long_abstract.ilike(contains_pattern(&filter)))
+                    .or(dsl::landing_page.ilike(contains_pattern(&filter))),
+            )
+            .load::<(WorkType, Option<NaiveDate>)>(&connection)
+            .map_err(FieldError::from)?;
+        Ok(WorkFacets::new(works, work_type.as_ref(), publication_year))
+    }
+
+    #[graphql(
+        description = "Search works, contributors, publishers and series at once, matching the same fields as the filter of each of their list queries. Results are ranked by how well their name (a work's full title) matches: whole name first, then names starting with the query, then other matches, interleaving the entity types within each rank. Logged-in editors who are not superusers, and API keys, only find works, publishers and series of their own publishers",
+        arguments(
+            query(description = "The text to search for"),
+            limit(default = 20, description = "The largest number of results to return"),
+        )
+    )]
+    fn search(context: &Context, query: String, limit: i32) -> FieldResult<Vec<SearchResult>> {
+        let (limit, _) = page_bounds(limit, 0, *MAX_LIMIT)?;
+        if query.trim().is_empty() {
+            return Ok(vec![]);
         }
-        if let Some(wk_status) = work_status {
-            query = query.filter(dsl::work_status.eq(wk_status))
+        let pattern = contains_pattern(query.trim());
+        let scope = context.searchable_publishers();
+        let connection = context.db.get().unwrap();
+
+        let mut works = work::table
+            .filter(
+                work::full_title
+                    .ilike(&pattern)
+                    .or(work::doi.ilike(&pattern))
+                    .or(work::reference.ilike(&pattern))
+                    .or(work::short_abstract.ilike(&pattern))
+                    .or(work::long_abstract.ilike(&pattern))
+                    .or(work::landing_page.ilike(&pattern)),
+            )
+            .into_boxed();
+        let mut publishers = publisher::table
+            .filter(
+                publisher::publisher_name
+                    .ilike(&pattern)
+                    .or(publisher::publisher_shortname.ilike(&pattern)),
+            )
+            .into_boxed();
+        let mut serieses = series::table
+            .filter(
+                series::series_name
+                    .ilike(&pattern)
+                    .or(series::issn_print.ilike(&pattern))
+                    .or(series::issn_digital.ilike(&pattern))
+                    .or(series::series_url.ilike(&pattern)),
+            )
+            .into_boxed();
+        if let Some(publisher_ids) = scope {
+            let imprint_ids = || {
+                imprint::table
+                    .select(imprint::imprint_id)
+                    .filter(imprint::publisher_id.eq_any(publisher_ids.clone()))
+            };
+            works = works.filter(work::imprint_id.eq_any(imprint_ids()));
+            serieses = serieses.filter(series::imprint_id.eq_any(imprint_ids()));
+            publishers = publishers.filter(publisher::publisher_id.eq_any(publisher_ids.clone()));
         }
-        // `SELECT COUNT(*)` in postgres returns a BIGINT, which diesel parses as i64. Juniper does
-        // not implement i64 yet, only i32. The only sensible way, albeit shameful, to solve this
-        // is converting i64 to string and then parsing it as i32. This should work until we reach
-        // 2147483647 records - if you are fixing this bug, congratulations on book number 2147483647!
-        query
+        let contributors = contributor::table
             .filter(
-                dsl::full_title
-                    .ilike(format!("%{}%", filter))
-                    .or(dsl::doi.ilike(format!("%{}%", filter)))
-                    .or(dsl::reference.ilike(format!("%{}%", filter)))
-                    .or(dsl::short_abstract.ilike(format!("%{}%", filter)))
-                    .or(dsl::long_abstract.ilike(format!("%{}%", filter)))
-                    .or(dsl::landing_page.ilike(format!("%{}%", filter))),
+                contributor::full_name
+                    .ilike(&pattern)
+                    .or(contributor::orcid.ilike(&pattern)),
             )
-            .count()
-            .get_result::<i64>(&connection)
-            .expect("Error loading work count")
-            .to_string()
-            .parse::<i32>()
-            .unwrap()
+            .limit(limit)
+            .load::<Contributor>(&connection)
+            .map_err(FieldError::from)?;
+        let works = works
+            .limit(limit)
+            .load::<Work>(&connection)
+            .map_err(FieldError::from)?;
+        let publishers = publishers
+            .limit(limit)
+            .load::<Publisher>(&connection)
+            .map_err(FieldError::from)?;
+        let serieses = serieses
+            .limit(limit)
+            .load::<Series>(&connection)
+            .map_err(FieldError::from)?;
+
+        let groups = vec![
+            works
+                .into_iter()
+                .map(|w| {
+                    (
+                        search_relevance(&w.full_title, &query),
+                        SearchResult::Work(w),
+                    )
+                })
+                .collect(),
+            contributors
+                .into_iter()
+                .map(|c| {
+                    let relevance = search_relevance(&c.full_name, &query);
+                    (relevance, SearchResult::Contributor(c))
+                })
+                .collect(),
+            publishers
+                .into_iter()
+                .map(|p| {
+                    let relevance = search_relevance(&p.publisher_name, &query);
+                    (relevance, SearchResult::Publisher(p))
+                })
+                .collect(),
+            serieses
+                .into_iter()
+                .map(|s| {
+                    (
+                        search_relevance(&s.series_name, &query),
+                        SearchResult::Series(s),
+                    )
+                })
+                .collect(),
+        ];
+        Ok(rank_search_results(groups, limit as usize))
     }
 
     #[graphql(
@@ -535,6 +1063,11 @@ impl QueryRoot {
                 description = "If set, only shows results connected to publishers with these IDs",
             ),
             publication_type(description = "A specific type to filter by"),
+            has_isbn(
+                description = "If set, only shows publications with (true) or without (false) an ISBN"
+            ),
+            created_at_after(description = "If set, only shows publications created at or after this time"),
+            updated_at_after(description = "If set, only shows publications last updated at or after this time"),
         )
     )]
     fn publications(
@@ -545,7 +1078,11 @@ impl QueryRoot {
         order: PublicationOrderBy,
         publishers: Vec<Uuid>,
         publication_type: Option<PublicationType>,
-    ) -> Vec<Publication> {
+        has_isbn: Option<bool>,
+        created_at_after: Option<NaiveDateTime>,
+        updated_at_after: Option<NaiveDateTime>,
+    ) -> FieldResult<Vec<Publication>> {
+        let (limit, offset) = page_bounds(limit, offset, *MAX_LIMIT)?;
         use crate::schema::publication::dsl;
         let connection = context.db.get().unwrap();
         let mut query = dsl::publication
@@ -558,6 +1095,15 @@ impl QueryRoot {
                 dsl::publication_url,
                 dsl::created_at,
                 dsl::updated_at,
+                dsl::width_mm,
+                dsl::width_in,
+                dsl::height_mm,
+                dsl::height_in,
+                dsl::depth_mm,
+                dsl::depth_in,
+                dsl::weight_g,
+                dsl::weight_oz,
+                dsl::unpriced_item_type,
             ))
             .into_boxed();
         match order.field {
@@ -589,44 +1135,87 @@ impl QueryRoot {
                 Direction::ASC => query = query.order(dsl::updated_at.asc()),
                 Direction::DESC => query = query.order(dsl::updated_at.desc()),
             },
+            PublicationField::WidthMm => match order.direction {
+                Direction::ASC => query = query.order(dsl::width_mm.asc()),
+                Direction::DESC => query = query.order(dsl::width_mm.desc()),
+            },
+            PublicationField::WidthIn => match order.direction {
+                Direction::ASC => query = query.order(dsl::width_in.asc()),
+                Direction::DESC => query = query.order(dsl::width_in.desc()),
+            },
+            PublicationField::HeightMm => match order.direction {
+                Direction::ASC => query = query.order(dsl::height_mm.asc()),
+                Direction::DESC => query = query.order(dsl::height_mm.desc()),
+            },
+            PublicationField::HeightIn => match order.direction {
+                Direction::ASC => query = query.order(dsl::height_in.asc()),
+                Direction::DESC => query = query.order(dsl::height_in.desc()),
+            },
+            PublicationField::DepthMm => match order.direction {
+                Direction::ASC => query = query.order(dsl::depth_mm.asc()),
+                Direction::DESC => query = query.order(dsl::depth_mm.desc()),
+            },
+            PublicationField::DepthIn => match order.direction {
+                Direction::ASC => query = query.order(dsl::depth_in.asc()),
+                Direction::DESC => query = query.order(dsl::depth_in.desc()),
+            },
+            PublicationField::WeightG => match order.direction {
+                Direction::ASC => query = query.order(dsl::weight_g.asc()),
+                Direction::DESC => query = query.order(dsl::weight_g.desc()),
+            },
+            PublicationField::WeightOz => match order.direction {
+                Direction::ASC => query = query.order(dsl::weight_oz.asc()),
+                Direction::DESC => query = query.order(dsl::weight_oz.desc()),
+            },
         }
         // Ordering and construction of filters is important here: result needs to be
         // `WHERE (x = $1 [OR x = $2...]) AND (y ILIKE $3 [OR z ILIKE $3...])`.
         // Interchanging .filter, .or, and .or_filter would result in different bracketing.
-        for pub_id in publishers {
+        for pub_id in context.scoped_publishers(publishers) {
             query = query.or_filter(crate::schema::imprint::publisher_id.eq(pub_id));
         }
         // ISBN and URL fields are both nullable, so searching with an empty filter could fail
         if !filter.is_empty() {
             query = query.filter(
                 dsl::isbn
-                    .ilike(format!("%{}%", filter))
-                    .or(dsl::publication_url.ilike(format!("%{}%", filter))),
+                    .ilike(contains_pattern(&filter))
+                    .or(dsl::publication_url.ilike(contains_pattern(&filter))),
             );
         }
         if let Some(pub_type) = publication_type {
             query = query.filter(dsl::publication_type.eq(pub_type))
         }
+        match has_isbn {
+            Some(true) => query = query.filter(dsl::isbn.is_not_null()),
+            Some(false) => query = query.filter(dsl::isbn.is_null()),
+            None => {}
+        }
+        if let Some(after) = created_at_after {
+            query = query.filter(dsl::created_at.ge(after))
+        }
+        if let Some(after) = updated_at_after {
+            query = query.filter(dsl::updated_at.ge(after))
+        }
         query
-            .limit(limit.into())
-            .offset(offset.into())
+            .limit(limit)
+            .offset(offset)
             .load::<Publication>(&connection)
-            .expect("Error loading publications")
+            .map_err(FieldError::from)
     }
 
     #[graphql(description = "Query a single publication using its id")]
     fn publication(context: &Context, publication_id: Uuid) -> FieldResult<Publication> {
         let connection = context.db.get().unwrap();
-        match crate::schema::publication::dsl::publication
+        let publication = crate::schema::publication::dsl::publication
             .find(publication_id)
             .get_result::<Publication>(&connection)
-        {
-            Ok(publication) => Ok(publication),
-            Err(e) => Err(FieldError::from(e)),
-        }
+            .map_err(FieldError::from)?;
+        context.check_scope(|| work_publisher_id(publication.work_id, context))?;
+        Ok(publication)
     }
 
     #[graphql(
+        deprecated = "Use publicationTotal, which is not limited to the range of Int",
         description = "Get the total number of publications",
         arguments(
             filter(
@@ -638,6 +1227,11 @@ impl QueryRoot {
                 description = "If set, only shows results connected to publishers with these IDs",
             ),
             publication_type(description = "A specific type to filter by"),
+            has_isbn(
+                description = "If set, only counts publications with (true) or without (false) an ISBN"
+            ),
+            created_at_after(description = "If set, only counts publications created at or after this time"),
+            updated_at_after(description = "If set, only counts publications last updated at or after this time"),
         )
     )]
     fn publication_count(
@@ -645,46 +1239,80 @@ impl QueryRoot {
         filter: String,
         publishers: Vec<Uuid>,
         publication_type: Option<PublicationType>,
+        has_isbn: Option<bool>,
+        created_at_after: Option<NaiveDateTime>,
+        updated_at_after: Option<NaiveDateTime>,
     ) -> i32 {
+        saturating_count(load_publication_count(
+            context,
+            filter,
+            publishers,
+            publication_type,
+            has_isbn,
+            created_at_after,
+            updated_at_after,
+        ))
+    }
+
+    #[graphql(
+        description = "Get the total number of publications",
+        arguments(
+            filter(
+                default = "".to_string(),
+                description = "A query string to search. This argument is a test, do not rely on it. At present it simply searches for case insensitive literals on isbn and publication_url",
+            ),
+            publishers(
+                default = vec![],
+                description = "If set, only shows results connected to publishers with these IDs",
+            ),
+            publication_type(description = "A specific type to filter by"),
+            has_isbn(
+                description = "If set, only counts publications with (true) or without (false) an ISBN"
+            ),
+            created_at_after(description = "If set, only counts publications created at or after this time"),
+            updated_at_after(description = "If set, only counts publications last updated at or after this time"),
+        )
+    )]
+    fn publication_total(
+        context: &Context,
+        filter: String,
+        publishers: Vec<Uuid>,
+        publication_type: Option<PublicationType>,
+        has_isbn: Option<bool>,
+        created_at_after: Option<NaiveDateTime>,
+        updated_at_after: Option<NaiveDateTime>,
+    ) -> Count {
+        Count(load_publication_count(
+            context,
+            filter,
+            publishers,
+            publication_type,
+            has_isbn,
+            created_at_after,
+            updated_at_after,
+        ))
+    }
+
+    #[graphql(
+        description = "Validate the ISBNs of all publications of a publisher, reporting invalid checksums and ISBNs shared between publications"
+    )]
+    fn isbn_report(context: &Context, publisher_id: Uuid) -> FieldResult<IsbnReport> {
+        context.check_scope(|| Ok(publisher_id))?;
         use crate::schema::publication::dsl;
         let connection = context.db.get().unwrap();
-        let mut query = dsl::publication
+        let publications = dsl::publication
             .inner_join(crate::schema::work::table.inner_join(crate::schema::imprint::table))
-            .select((
-                dsl::publication_id,
-                dsl::publication_type,
-                dsl::work_id,
-                dsl::isbn,
-                dsl::publication_url,
-                dsl::created_at,
-                dsl::updated_at,
-            ))
-            .into_boxed();
-        // Ordering and construction of filters is important here: result needs to be
-        // `WHERE (x = $1 [OR x = $2...]) AND (y ILIKE $3 [OR z ILIKE $3...])`.
-        // Interchanging .filter, .or, and .or_filter would result in different bracketing.
-        for pub_id in publishers {
-            query = query.or_filter(crate::schema::imprint::publisher_id.eq(pub_id));
-        }
-        // ISBN and URL fields are both nullable, so searching with an empty filter could fail
-        if !filter.is_empty() {
-            query = query.filter(
-                dsl::isbn
-                    .ilike(format!("%{}%", filter))
-                    .or(dsl::publication_url.ilike(format!("%{}%", filter))),
-            );
-        }
-        if let Some(pub_type) = publication_type {
-            query = query.filter(dsl::publication_type.eq(pub_type))
-        }
-        // see comment in work_count()
-        query
-            .count()
-            .get_result::<i64>(&connection)
-            .expect("Error loading publication count")
-            .to_string()
-            .parse::<i32>()
-            .unwrap()
+            .select((dsl::publication_id, dsl::work_id, dsl::isbn))
+            .filter(crate::schema::imprint::publisher_id.eq(publisher_id))
+            .filter(dsl::isbn.is_not_null())
+            .order(dsl::publication_id.asc())
+            .load::<(Uuid, Uuid, Option<String>)>(&connection)?
+            .into_iter()
+            .filter_map(|(publication_id, work_id, isbn)| {
+                isbn.map(|isbn| (publication_id, work_id, isbn))
+            })
+            .collect();
+        Ok(IsbnReport::new(publisher_id, publications))
     }
 
     #[graphql(
@@ -716,6 +1344,8 @@ impl QueryRoot {
             default = vec![],
             description = "If set, only shows results connected to publishers with these IDs",
         ),
+        created_at_after(description = "If set, only shows publishers created at or after this time"),
+        updated_at_after(description = "If set, only shows publishers last updated at or after this time"),
     )
   )]
     fn publishers(
@@ -725,7 +1355,10 @@ impl QueryRoot {
         filter: String,
         order: PublisherOrderBy,
         publishers: Vec<Uuid>,
-    ) -> Vec<Publisher> {
+        created_at_after: Option<NaiveDateTime>,
+        updated_at_after: Option<NaiveDateTime>,
+    ) -> FieldResult<Vec<Publisher>> {
+        let (limit, offset) = page_bounds(limit, offset, *MAX_LIMIT)?;
         use crate::schema::publisher::dsl::*;
         let connection = context.db.get().unwrap();
         let mut query = publisher.into_boxed();
@@ -758,34 +1391,40 @@ impl QueryRoot {
         // Ordering and construction of filters is important here: result needs to be
         // `WHERE (x = $1 [OR x = $2...]) AND (y ILIKE $3 [OR z ILIKE $3...])`.
         // Interchanging .filter, .or, and .or_filter would result in different bracketing.
-        for pub_id in publishers {
+        for pub_id in context.scoped_publishers(publishers) {
             query = query.or_filter(publisher_id.eq(pub_id));
         }
-        query
+        if let Some(after) = created_at_after {
+            query = query.filter(created_at.ge(after))
+        }
+        if let Some(after) = updated_at_after {
+            query = query.filter(updated_at.ge(after))
+        }
+        query
             .filter(
                 publisher_name
-                    .ilike(format!("%{}%", filter))
-                    .or(publisher_shortname.ilike(format!("%{}%", filter))),
+                    .ilike(contains_pattern(&filter))
+                    .or(publisher_shortname.ilike(contains_pattern(&filter))),
             )
-            .limit(limit.into())
-            .offset(offset.into())
+            .limit(limit)
+            .offset(offset)
             .load::<Publisher>(&connection)
-            .expect("Error loading publishers")
+            .map_err(FieldError::from)
     }
 
     #[graphql(description = "Query a publisher using its id")]
     fn publisher(context: &Context, publisher_id: Uuid) -> FieldResult<Publisher> {
         let connection = context.db.get().unwrap();
-        match crate::schema::publisher::dsl::publisher
+        let publisher = crate::schema::publisher::dsl::publisher
             .find(publisher_id)
             .get_result::<Publisher>(&connection)
-        {
-            Ok(publisher) => Ok(publisher),
-            Err(e) => Err(FieldError::from(e)),
-        }
+            .map_err(FieldError::from)?;
+        context.check_scope(|| Ok(publisher.publisher_id))?;
+        Ok(publisher)
     }
 
     #[graphql(
+        deprecated = "Use publisherTotal, which is not limited to the range of Int",
         description = "Get the total number of publishers",
         arguments(
             filter(
@@ -796,31 +1435,55 @@ impl QueryRoot {
                 default = vec![],
                 description = "If set, only shows results connected to publishers with these IDs",
             ),
+            created_at_after(description = "If set, only counts publishers created at or after this time"),
+            updated_at_after(description = "If set, only counts publishers last updated at or after this time"),
         )
     )]
-    fn publisher_count(context: &Context, filter: String, publishers: Vec<Uuid>) -> i32 {
-        use crate::schema::publisher::dsl::*;
-        let connection = context.db.get().unwrap();
-        let mut query = publisher.into_boxed();
-        // Ordering and construction of filters is important here: result needs to be
-        // `WHERE (x = $1 [OR x = $2...]) AND (y ILIKE $3 [OR z ILIKE $3...])`.
-        // Interchanging .filter, .or, and .or_filter would result in different bracketing.
-        for pub_id in publishers {
-            query = query.or_filter(publisher_id.eq(pub_id));
-        }
-        // see comment in work_count()
-        query
-            .filter(
-                publisher_name
-                    .ilike(format!("%{}%", filter))
-                    .or(publisher_shortname.ilike(format!("%{}%", filter))),
-            )
-            .count()
-            .get_result::<i64>(&connection)
-            .expect("Error loading publisher count")
-            .to_string()
-            .parse::<i32>()
-            .unwrap()
+    fn publisher_count(
+        context: &Context,
+        filter: String,
+        publishers: Vec<Uuid>,
+        created_at_after: Option<NaiveDateTime>,
+        updated_at_after: Option<NaiveDateTime>,
+    ) -> i32 {
+        saturating_count(load_publisher_count(
+            context,
+            filter,
+            publishers,
+            created_at_after,
+            updated_at_after,
+        ))
+    }
+
+    #[graphql(
+        description = "Get the total number of publishers",
+        arguments(
+            filter(
+                default = "".to_string(),
+                description = "A query string to search. This argument is a test, do not rely on it. At present it simply searches for case insensitive literals on publisher_name and publisher_shortname",
+            ),
+            publishers(
+                default = vec![],
+                description = "If set, only shows results connected to publishers with these IDs",
+            ),
+            created_at_after(description = "If set, only counts publishers created at or after this time"),
+            updated_at_after(description = "If set, only counts publishers last updated at or after this time"),
+        )
+    )]
+    fn publisher_total(
+        context: &Context,
+        filter: String,
+        publishers: Vec<Uuid>,
+        created_at_after: Option<NaiveDateTime>,
+        updated_at_after: Option<NaiveDateTime>,
+    ) -> Count {
+        Count(load_publisher_count(
+            context,
+            filter,
+            publishers,
+            created_at_after,
+            updated_at_after,
+        ))
     }
 
     #[graphql(
@@ -854,7 +1517,8 @@ impl QueryRoot {
         filter: String,
         order: ImprintOrderBy,
         publishers: Vec<Uuid>,
-    ) -> Vec<Imprint> {
+    ) -> FieldResult<Vec<Imprint>> {
+        let (limit, offset) = page_bounds(limit, offset, *MAX_LIMIT)?;
         use crate::schema::imprint::dsl::*;
         let connection = context.db.get().unwrap();
         let mut query = imprint.into_boxed();
@@ -883,34 +1547,34 @@ impl QueryRoot {
         // Ordering and construction of filters is important here: result needs to be
         // `WHERE (x = $1 [OR x = $2...]) AND (y ILIKE $3 [OR z ILIKE $3...])`.
         // Interchanging .filter, .or, and .or_filter would result in different bracketing.
-        for pub_id in publishers {
+        for pub_id in context.scoped_publishers(publishers) {
             query = query.or_filter(publisher_id.eq(pub_id));
         }
         query
             .filter(
                 imprint_name
-                    .ilike(format!("%{}%", filter))
-                    .or(imprint_url.ilike(format!("%{}%", filter))),
+                    .ilike(contains_pattern(&filter))
+                    .or(imprint_url.ilike(contains_pattern(&filter))),
             )
-            .limit(limit.into())
-            .offset(offset.into())
+            .limit(limit)
+            .offset(offset)
             .load::<Imprint>(&connection)
-            .expect("Error loading imprints")
+            .map_err(FieldError::from)
     }
 
     #[graphql(description = "Query a single imprint using its id")]
     fn imprint(context: &Context, imprint_id: Uuid) -> FieldResult<Imprint> {
         let connection = context.db.get().unwrap();
-        match crate::schema::imprint::dsl::imprint
+        let imprint = crate::schema::imprint::dsl::imprint
             .find(imprint_id)
             .get_result::<Imprint>(&connection)
-        {
-            Ok(imprint) => Ok(imprint),
-            Err(e) => Err(FieldError::from(e)),
-        }
+            .map_err(FieldError::from)?;
+        context.check_scope(|| Ok(imprint.publisher_id))?;
+        Ok(imprint)
     }
 
     #[graphql(
+        deprecated = "Use imprintTotal, which is not limited to the range of Int",
         description = "Get the total number of imprints",
         arguments(
             filter(
@@ -924,28 +1588,24 @@ impl QueryRoot {
         )
     )]
     fn imprint_count(context: &Context, filter: String, publishers: Vec<Uuid>) -> i32 {
-        use crate::schema::imprint::dsl::*;
-        let connection = context.db.get().unwrap();
-        let mut query = imprint.into_boxed();
-        // Ordering and construction of filters is important here: result needs to be
-        // `WHERE (x = $1 [OR x = $2...]) AND (y ILIKE $3 [OR z ILIKE $3...])`.
-        // Interchanging .filter, .or, and .or_filter would result in different bracketing.
-        for pub_id in publishers {
-            query = query.or_filter(publisher_id.eq(pub_id));
-        }
-        // see comment in work_count()
-        query
-            .filter(
-                imprint_name
-                    .ilike(format!("%{}%", filter))
-                    .or(imprint_url.ilike(format!("%{}%", filter))),
-            )
-            .count()
-            .get_result::<i64>(&connection)
-            .expect("Error loading imprint count")
-            .to_string()
-            .parse::<i32>()
-            .unwrap()
+        saturating_count(load_imprint_count(context, filter, publishers))
+    }
+
+    #[graphql(
+        description = "Get the total number of imprints",
+        arguments(
+            filter(
+                default = "".to_string(),
+                description = "A query string to search. This argument is a test, do not rely on it. At present it simply searches for case insensitive literals on imprint_name and imprint_url",
+            ),
+            publishers(
+                default = vec![],
+                description = "If set, only shows results connected to publishers with these IDs",
+            ),
+        )
+    )]
+    fn imprint_total(context: &Context, filter: String, publishers: Vec<Uuid>) -> Count {
+        Count(load_imprint_count(context, filter, publishers))
     }
 
     #[graphql(
@@ -966,6 +1626,8 @@ impl QueryRoot {
                 },
                 description = "The order in which to sort the results",
             ),
+            created_at_after(description = "If set, only shows contributors created at or after this time"),
+            updated_at_after(description = "If set, only shows contributors last updated at or after this time"),
         )
     )]
     fn contributors(
@@ -974,7 +1636,10 @@ impl QueryRoot {
         offset: i32,
         filter: String,
         order: ContributorOrderBy,
-    ) -> Vec<Contributor> {
+        created_at_after: Option<NaiveDateTime>,
+        updated_at_after: Option<NaiveDateTime>,
+    ) -> FieldResult<Vec<Contributor>> {
+        let (limit, offset) = page_bounds(limit, offset, *MAX_LIMIT)?;
         use crate::schema::contributor::dsl::*;
         let connection = context.db.get().unwrap();
         let mut query = contributor.into_boxed();
@@ -1012,13 +1677,22 @@ impl QueryRoot {
                 Direction::DESC => query = query.order(updated_at.desc()),
             },
         }
+        if let Some(after) = created_at_after {
+            query = query.filter(created_at.ge(after))
+        }
+        if let Some(after) = updated_at_after {
+            query = query.filter(updated_at.ge(after))
+        }
         query
-            .filter(full_name.ilike(format!("%{}%", filter)))
-            .or_filter(orcid.ilike(format!("%{}%", filter)))
-            .limit(limit.into())
-            .offset(offset.into())
+            .filter(
+                full_name
+                    .ilike(contains_pattern(&filter))
+                    .or(orcid.ilike(contains_pattern(&filter))),
+            )
+            .limit(limit)
+            .offset(offset)
             .load::<Contributor>(&connection)
-            .expect("Error loading contributors")
+            .map_err(FieldError::from)
     }
 
     #[graphql(description = "Query a single contributor using its id")]
@@ -1034,27 +1708,54 @@ impl QueryRoot {
     }
 
     #[graphql(
+        deprecated = "Use contributorTotal, which is not limited to the range of Int",
         description = "Get the total number of contributors",
         arguments(
             filter(
                 default = "".to_string(),
                 description = "A query string to search. This argument is a test, do not rely on it. At present it simply searches for case insensitive literals on full_name and orcid",
             ),
+            created_at_after(description = "If set, only counts contributors created at or after this time"),
+            updated_at_after(description = "If set, only counts contributors last updated at or after this time"),
         )
     )]
-    fn contributor_count(context: &Context, filter: String) -> i32 {
-        use crate::schema::contributor::dsl::*;
-        let connection = context.db.get().unwrap();
-        // see comment in work_count()
-        contributor
-            .filter(full_name.ilike(format!("%{}%", filter)))
-            .or_filter(orcid.ilike(format!("%{}%", filter)))
-            .count()
-            .get_result::<i64>(&connection)
-            .expect("Error loading contributor count")
-            .to_string()
-            .parse::<i32>()
-            .unwrap()
+    fn contributor_count(
+        context: &Context,
+        filter: String,
+        created_at_after: Option<NaiveDateTime>,
+        updated_at_after: Option<NaiveDateTime>,
+    ) -> i32 {
+        saturating_count(load_contributor_count(
+            context,
+            filter,
+            created_at_after,
+            updated_at_after,
+        ))
+    }
+
+    #[graphql(
+        description = "Get the total number of contributors",
+        arguments(
+            filter(
+                default = "".to_string(),
+                description = "A query string to search. This argument is a test, do not rely on it. At present it simply searches for case insensitive literals on full_name and orcid",
+            ),
+            created_at_after(description = "If set, only counts contributors created at or after this time"),
+            updated_at_after(description = "If set, only counts contributors last updated at or after this time"),
+        )
+    )]
+    fn contributor_total(
+        context: &Context,
+        filter: String,
+        created_at_after: Option<NaiveDateTime>,
+        updated_at_after: Option<NaiveDateTime>,
+    ) -> Count {
+        Count(load_contributor_count(
+            context,
+            filter,
+            created_at_after,
+            updated_at_after,
+        ))
     }
 
     #[graphql(
@@ -1085,7 +1786,8 @@ impl QueryRoot {
         order: ContributionOrderBy,
         publishers: Vec<Uuid>,
         contribution_type: Option<ContributionType>,
-    ) -> Vec<Contribution> {
+    ) -> FieldResult<Vec<Contribution>> {
+        let (limit, offset) = page_bounds(limit, offset, *MAX_LIMIT)?;
         use crate::schema::contribution::dsl;
         let connection = context.db.get().unwrap();
         let mut query = dsl::contribution
@@ -1149,21 +1851,25 @@ impl QueryRoot {
                 Direction::ASC => query = query.order(dsl::full_name.asc()),
                 Direction::DESC => query = query.order(dsl::full_name.desc()),
             },
+            ContributionField::WorkTitle => match order.direction {
+                Direction::ASC => query = query.order(crate::schema::work::full_title.asc()),
+                Direction::DESC => query = query.order(crate::schema::work::full_title.desc()),
+            },
         }
         // Ordering and construction of filters is important here: result needs to be
         // `WHERE (x = $1 [OR x = $2...]) AND (y ILIKE $3 [OR z ILIKE $3...])`.
         // Interchanging .filter, .or, and .or_filter would result in different bracketing.
-        for pub_id in publishers {
+        for pub_id in context.scoped_publishers(publishers) {
             query = query.or_filter(crate::schema::imprint::publisher_id.eq(pub_id));
         }
         if let Some(cont_type) = contribution_type {
             query = query.filter(dsl::contribution_type.eq(cont_type))
         }
         query
-            .limit(limit.into())
-            .offset(offset.into())
+            .limit(limit)
+            .offset(offset)
             .load::<Contribution>(&connection)
-            .expect("Error loading contributions")
+            .map_err(FieldError::from)
     }
 
     #[graphql(description = "Query a single contribution using its identifiers")]
@@ -1174,33 +1880,27 @@ impl QueryRoot {
         contribution_type: ContributionType,
     ) -> FieldResult<Contribution> {
         let connection = context.db.get().unwrap();
-        match crate::schema::contribution::dsl::contribution
+        let contribution = crate::schema::contribution::dsl::contribution
             .filter(crate::schema::contribution::dsl::work_id.eq(work_id))
             .filter(crate::schema::contribution::dsl::contributor_id.eq(contributor_id))
             .filter(crate::schema::contribution::dsl::contribution_type.eq(contribution_type))
             .get_result::<Contribution>(&connection)
-        {
-            Ok(contribution) => Ok(contribution),
-            Err(e) => Err(FieldError::from(e)),
-        }
+            .map_err(FieldError::from)?;
+        context.check_scope(|| work_publisher_id(contribution.work_id, context))?;
+        Ok(contribution)
     }
 
-    #[graphql(description = "Get the total number of contributions")]
+    #[graphql(
+        deprecated = "Use contributionTotal, which is not limited to the range of Int",
+        description = "Get the total number of contributions"
+    )]
     fn contribution_count(context: &Context, contribution_type: Option<ContributionType>) -> i32 {
-        use crate::schema::contribution::dsl;
-        let connection = context.db.get().unwrap();
-        let mut query = dsl::contribution.into_boxed();
-        if let Some(cont_type) = contribution_type {
-            query = query.filter(dsl::contribution_type.eq(cont_type))
-        }
-        // see comment in work_count()
-        query
-            .count()
-            .get_result::<i64>(&connection)
-            .expect("Error loading contribution count")
-            .to_string()
-            .parse::<i32>()
-            .unwrap()
+        saturating_count(load_contribution_count(context, contribution_type))
+    }
+
+    #[graphql(description = "Get the total number of contributions")]
+    fn contribution_total(context: &Context, contribution_type: Option<ContributionType>) -> Count {
+        Count(load_contribution_count(context, contribution_type))
     }
 
     #[graphql(
@@ -1226,6 +1926,8 @@ impl QueryRoot {
                 description = "If set, only shows results connected to publishers with these IDs",
             ),
             series_type(description = "A specific type to filter by"),
+            created_at_after(description = "If set, only shows series created at or after this time"),
+            updated_at_after(description = "If set, only shows series last updated at or after this time"),
         ),
     )]
     fn serieses(
@@ -1236,7 +1938,10 @@ impl QueryRoot {
         order: SeriesOrderBy,
         publishers: Vec<Uuid>,
         series_type: Option<SeriesType>,
-    ) -> Vec<Series> {
+        created_at_after: Option<NaiveDateTime>,
+        updated_at_after: Option<NaiveDateTime>,
+    ) -> FieldResult<Vec<Series>> {
+        let (limit, offset) = page_bounds(limit, offset, *MAX_LIMIT)?;
         use crate::schema::series::dsl;
         let connection = context.db.get().unwrap();
         let mut query = dsl::series
@@ -1290,39 +1995,45 @@ impl QueryRoot {
         // Ordering and construction of filters is important here: result needs to be
         // `WHERE (x = $1 [OR x = $2...]) AND (y ILIKE $3 [OR z ILIKE $3...])`.
         // Interchanging .filter, .or, and .or_filter would result in different bracketing.
-        for pub_id in publishers {
+        for pub_id in context.scoped_publishers(publishers) {
             query = query.or_filter(crate::schema::imprint::publisher_id.eq(pub_id));
         }
         if let Some(ser_type) = series_type {
             query = query.filter(dsl::series_type.eq(ser_type))
         }
+        if let Some(after) = created_at_after {
+            query = query.filter(dsl::created_at.ge(after))
+        }
+        if let Some(after) = updated_at_after {
+            query = query.filter(dsl::updated_at.ge(after))
+        }
         query
             .filter(
                 dsl::series_name
-                    .ilike(format!("%{}%", filter))
-                    .or(dsl::issn_print.ilike(format!("%{}%", filter)))
-                    .or(dsl::issn_digital.ilike(format!("%{}%", filter)))
-                    .or(dsl::series_url.ilike(format!("%{}%", filter))),
+                    .ilike(contains_pattern(&filter))
+                    .or(dsl::issn_print.ilike(contains_pattern(&filter)))
+                    .or(dsl::issn_digital.ilike(contains_pattern(&filter)))
+                    .or(dsl::series_url.ilike(contains_pattern(&filter))),
             )
-            .limit(limit.into())
-            .offset(offset.into())
+            .limit(limit)
+            .offset(offset)
             .load::<Series>(&connection)
-            .expect("Error loading series")
+            .map_err(FieldError::from)
     }
 
     #[graphql(description = "Query a single series using its id")]
     fn series(context: &Context, series_id: Uuid) -> FieldResult<Series> {
         let connection = context.db.get().unwrap();
-        match crate::schema::series::dsl::series
+        let series = crate::schema::series::dsl::series
             .find(series_id)
             .get_result::<Series>(&connection)
-        {
-            Ok(series) => Ok(series),
-            Err(e) => Err(FieldError::from(e)),
-        }
+            .map_err(FieldError::from)?;
+        context.check_scope(|| imprint_publisher_id(series.imprint_id, context))?;
+        Ok(series)
     }
 
     #[graphql(
+        deprecated = "Use seriesTotal, which is not limited to the range of Int",
         description = "Get the total number of series",
         arguments(
             filter(
@@ -1334,6 +2045,8 @@ impl QueryRoot {
                 description = "If set, only shows results connected to publishers with these IDs",
             ),
             series_type(description = "A specific type to filter by"),
+            created_at_after(description = "If set, only counts series created at or after this time"),
+            updated_at_after(description = "If set, only counts series last updated at or after this time"),
         )
     )]
     fn series_count(
@@ -1341,47 +2054,51 @@ impl QueryRoot {
         filter: String,
         publishers: Vec<Uuid>,
         series_type: Option<SeriesType>,
+        created_at_after: Option<NaiveDateTime>,
+        updated_at_after: Option<NaiveDateTime>,
     ) -> i32 {
-        use crate::schema::series::dsl;
-        let connection = context.db.get().unwrap();
-        let mut query = dsl::series
-            .inner_join(crate::schema::imprint::table)
-            .select((
-                dsl::series_id,
-                dsl::series_type,
-                dsl::series_name,
-                dsl::issn_print,
-                dsl::issn_digital,
-                dsl::series_url,
-                dsl::imprint_id,
-                dsl::created_at,
-                dsl::updated_at,
-            ))
-            .into_boxed();
-        // Ordering and construction of filters is important here: result needs to be
-        // `WHERE (x = $1 [OR x = $2...]) AND (y ILIKE $3 [OR z ILIKE $3...])`.
-        // Interchanging .filter, .or, and .or_filter would result in different bracketing.
-        for pub_id in publishers {
-            query = query.or_filter(crate::schema::imprint::publisher_id.eq(pub_id));
-        }
-        if let Some(ser_type) = series_type {
-            query = query.filter(dsl::series_type.eq(ser_type))
-        }
-        // see comment in work_count()
-        query
-            .filter(
-                dsl::series_name
-                    .ilike(format!("%{}%", filter))
-                    .or(dsl::issn_print.ilike(format!("%{}%", filter)))
-                    .or(dsl::issn_digital.ilike(format!("%{}%", filter)))
-                    .or(dsl::series_url.ilike(format!("%{}%", filter))),
-            )
-            .count()
-            .get_result::<i64>(&connection)
-            .expect("Error loading series count")
-            .to_string()
-            .parse::<i32>()
-            .unwrap()
+        saturating_count(load_series_count(
+            context,
+            filter,
+            publishers,
+            series_type,
+            created_at_after,
+            updated_at_after,
+        ))
+    }
+
+    #[graphql(
+        description = "Get the total number of series",
+        arguments(
+            filter(
+                default = "".to_string(),
+                description = "A query string to search. This argument is a test, do not rely on it. At present it simply searches for case insensitive literals on series_name, issn_print, issn_digital and series_url",
+            ),
+            publishers(
+                default = vec![],
+                description = "If set, only shows results connected to publishers with these IDs",
+            ),
+            series_type(description = "A specific type to filter by"),
+            created_at_after(description = "If set, only counts series created at or after this time"),
+            updated_at_after(description = "If set, only counts series last updated at or after this time"),
+        )
+    )]
+    fn series_total(
+        context: &Context,
+        filter: String,
+        publishers: Vec<Uuid>,
+        series_type: Option<SeriesType>,
+        created_at_after: Option<NaiveDateTime>,
+        updated_at_after: Option<NaiveDateTime>,
+    ) -> Count {
+        Count(load_series_count(
+            context,
+            filter,
+            publishers,
+            series_type,
+            created_at_after,
+            updated_at_after,
+        ))
     }
 
     #[graphql(
@@ -1410,7 +2127,8 @@ impl QueryRoot {
         offset: i32,
         order: IssueOrderBy,
         publishers: Vec<Uuid>,
-    ) -> Vec<Issue> {
+    ) -> FieldResult<Vec<Issue>> {
+        let (limit, offset) = page_bounds(limit, offset, *MAX_LIMIT)?;
         use crate::schema::issue::dsl::*;
         let connection = context.db.get().unwrap();
         let mut query = issue
@@ -1439,41 +2157,39 @@ impl QueryRoot {
                 Direction::DESC => query = query.order(updated_at.desc()),
             },
         }
-        for pub_id in publishers {
+        for pub_id in context.scoped_publishers(publishers) {
             query = query.or_filter(crate::schema::imprint::publisher_id.eq(pub_id));
         }
         query
-            .limit(limit.into())
-            .offset(offset.into())
+            .limit(limit)
+            .offset(offset)
             .load::<Issue>(&connection)
-            .expect("Error loading issues")
+            .map_err(FieldError::from)
     }
 
     #[graphql(description = "Query a single issue using its identifiers")]
     fn issue(context: &Context, series_id: Uuid, work_id: Uuid) -> FieldResult<Issue> {
         let connection = context.db.get().unwrap();
-        match crate::schema::issue::dsl::issue
+        let issue = crate::schema::issue::dsl::issue
             .filter(crate::schema::issue::dsl::series_id.eq(series_id))
             .filter(crate::schema::issue::dsl::work_id.eq(work_id))
             .get_result::<Issue>(&connection)
-        {
-            Ok(issue) => Ok(issue),
-            Err(e) => Err(FieldError::from(e)),
-        }
+            .map_err(FieldError::from)?;
+        context.check_scope(|| work_publisher_id(issue.work_id, context))?;
+        Ok(issue)
     }
 
-    #[graphql(description = "Get the total number of issues")]
+    #[graphql(
+        deprecated = "Use issueTotal, which is not limited to the range of Int",
+        description = "Get the total number of issues"
+    )]
     fn issue_count(context: &Context) -> i32 {
-        use crate::schema::issue::dsl::*;
-        let connection = context.db.get().unwrap();
-        // see comment in work_count()
-        issue
-            .count()
-            .get_result::<i64>(&connection)
-            .expect("Error loading issue count")
-            .to_string()
-            .parse::<i32>()
-            .unwrap()
+        saturating_count(load_issue_count(context))
+    }
+
+    #[graphql(description = "Get the total number of issues")]
+    fn issue_total(context: &Context) -> Count {
+        Count(load_issue_count(context))
     }
 
     #[graphql(
@@ -1506,7 +2222,8 @@ impl QueryRoot {
         publishers: Vec<Uuid>,
         language_code: Option<LanguageCode>,
         language_relation: Option<LanguageRelation>,
-    ) -> Vec<Language> {
+    ) -> FieldResult<Vec<Language>> {
+        let (limit, offset) = page_bounds(limit, offset, *MAX_LIMIT)?;
         use crate::schema::language::dsl;
         let connection = context.db.get().unwrap();
         let mut query = dsl::language
@@ -1554,7 +2271,7 @@ impl QueryRoot {
         // Ordering and construction of filters is important here: result needs to be
         // `WHERE (x = $1 [OR x = $2...]) AND (y ILIKE $3 [OR z ILIKE $3...])`.
         // Interchanging .filter, .or, and .or_filter would result in different bracketing.
-        for pub_id in publishers {
+        for pub_id in context.scoped_publishers(publishers) {
             query = query.or_filter(crate::schema::imprint::publisher_id.eq(pub_id));
         }
         if let Some(lang_code) = language_code {
@@ -1564,47 +2281,50 @@ impl QueryRoot {
             query = query.filter(dsl::language_relation.eq(lang_relation))
         }
         query
-            .limit(limit.into())
-            .offset(offset.into())
+            .limit(limit)
+            .offset(offset)
             .load::<Language>(&connection)
-            .expect("Error loading languages")
+            .map_err(FieldError::from)
     }
 
     #[graphql(description = "Query a single language using its id")]
     fn language(context: &Context, language_id: Uuid) -> FieldResult<Language> {
         let connection = context.db.get().unwrap();
-        match crate::schema::language::dsl::language
+        let language = crate::schema::language::dsl::language
             .find(language_id)
             .get_result::<Language>(&connection)
-        {
-            Ok(language) => Ok(language),
-            Err(e) => Err(FieldError::from(e)),
-        }
+            .map_err(FieldError::from)?;
+        context.check_scope(|| work_publisher_id(language.work_id, context))?;
+        Ok(language)
     }
 
-    #[graphql(description = "Get the total number of languages associated to works")]
+    #[graphql(
+        deprecated = "Use languageTotal, which is not limited to the range of Int",
+        description = "Get the total number of languages associated to works"
+    )]
     fn language_count(
         context: &Context,
         language_code: Option<LanguageCode>,
         language_relation: Option<LanguageRelation>,
     ) -> i32 {
-        use crate::schema::language::dsl;
-        let connection = context.db.get().unwrap();
-        let mut query = dsl::language.into_boxed();
-        if let Some(lang_code) = language_code {
-            query = query.filter(dsl::language_code.eq(lang_code))
-        }
-        if let Some(lang_relation) = language_relation {
-            query = query.filter(dsl::language_relation.eq(lang_relation))
-        }
-        // see comment in work_count()
-        query
-            .count()
-            .get_result::<i64>(&connection)
-            .expect("Error loading language count")
-            .to_string()
-            .parse::<i32>()
-            .unwrap()
+        saturating_count(load_language_count(
+            context,
+            language_code,
+            language_relation,
+        ))
+    }
+
+    #[graphql(description = "Get the total number of languages associated to works")]
+    fn language_total(
+        context: &Context,
+        language_code: Option<LanguageCode>,
+        language_relation: Option<LanguageRelation>,
+    ) -> Count {
+        Count(load_language_count(
+            context,
+            language_code,
+            language_relation,
+        ))
     }
 
     #[graphql(
@@ -1635,7 +2355,8 @@ impl QueryRoot {
         order: PriceOrderBy,
         publishers: Vec<Uuid>,
         currency_code: Option<CurrencyCode>,
-    ) -> Vec<Price> {
+    ) -> FieldResult<Vec<Price>> {
+        let (limit, offset) = page_bounds(limit, offset, *MAX_LIMIT)?;
         use crate::schema::price::dsl;
         let connection = context.db.get().unwrap();
         let mut query =
@@ -1650,6 +2371,8 @@ impl QueryRoot {
                     dsl::unit_price,
                     dsl::created_at,
                     dsl::updated_at,
+                    dsl::price_type,
+                    dsl::territory,
                 ))
                 .into_boxed();
         match order.field {
@@ -1669,6 +2392,14 @@ impl QueryRoot {
                 Direction::ASC => query = query.order(dsl::unit_price.asc()),
                 Direction::DESC => query = query.order(dsl::unit_price.desc()),
             },
+            PriceField::PriceType => match order.direction {
+                Direction::ASC => query = query.order(dsl::price_type.asc()),
+                Direction::DESC => query = query.order(dsl::price_type.desc()),
+            },
+            PriceField::Territory => match order.direction {
+                Direction::ASC => query = query.order(dsl::territory.asc()),
+                Direction::DESC => query = query.order(dsl::territory.desc()),
+            },
             PriceField::CreatedAt => match order.direction {
                 Direction::ASC => query = query.order(dsl::created_at.asc()),
                 Direction::DESC => query = query.order(dsl::created_at.desc()),
@@ -1681,47 +2412,41 @@ impl QueryRoot {
         // Ordering and construction of filters is important here: result needs to be
         // `WHERE (x = $1 [OR x = $2...]) AND (y ILIKE $3 [OR z ILIKE $3...])`.
         // Interchanging .filter, .or, and .or_filter would result in different bracketing.
-        for pub_id in publishers {
+        for pub_id in context.scoped_publishers(publishers) {
             query = query.or_filter(crate::schema::imprint::publisher_id.eq(pub_id));
         }
         if let Some(curr_code) = currency_code {
             query = query.filter(dsl::currency_code.eq(curr_code))
         }
         query
-            .limit(limit.into())
-            .offset(offset.into())
+            .limit(limit)
+            .offset(offset)
             .load::<Price>(&connection)
-            .expect("Error loading prices")
+            .map_err(FieldError::from)
     }
 
     #[graphql(description = "Query a single price using its id")]
     fn price(context: &Context, price_id: Uuid) -> FieldResult<Price> {
         let connection = context.db.get().unwrap();
-        match crate::schema::price::dsl::price
+        let price = crate::schema::price::dsl::price
             .find(price_id)
             .get_result::<Price>(&connection)
-        {
-            Ok(price) => Ok(price),
-            Err(e) => Err(FieldError::from(e)),
-        }
+            .map_err(FieldError::from)?;
+        context.check_scope(|| publication_publisher_id(price.publication_id, context))?;
+        Ok(price)
     }
 
-    #[graphql(description = "Get the total number of prices associated to works")]
+    #[graphql(
+        deprecated = "Use priceTotal, which is not limited to the range of Int",
+        description = "Get the total number of prices associated to works"
+    )]
     fn price_count(context: &Context, currency_code: Option<CurrencyCode>) -> i32 {
-        use crate::schema::price::dsl;
-        let connection = context.db.get().unwrap();
-        let mut query = dsl::price.into_boxed();
-        if let Some(curr_code) = currency_code {
-            query = query.filter(dsl::currency_code.eq(curr_code))
-        }
-        // see comment in work_count()
-        query
-            .count()
-            .get_result::<i64>(&connection)
-            .expect("Error loading price count")
-            .to_string()
-            .parse::<i32>()
-            .unwrap()
+        saturating_count(load_price_count(context, currency_code))
+    }
+
+    #[graphql(description = "Get the total number of prices associated to works")]
+    fn price_total(context: &Context, currency_code: Option<CurrencyCode>) -> Count {
+        Count(load_price_count(context, currency_code))
     }
 
     #[graphql(
@@ -1752,7 +2477,8 @@ impl QueryRoot {
         order: SubjectOrderBy,
         publishers: Vec<Uuid>,
         subject_type: Option<SubjectType>,
-    ) -> Vec<Subject> {
+    ) -> FieldResult<Vec<Subject>> {
+        let (limit, offset) = page_bounds(limit, offset, *MAX_LIMIT)?;
         use crate::schema::subject::dsl;
         let connection = context.db.get().unwrap();
         let mut query = dsl::subject
@@ -1800,47 +2526,68 @@ impl QueryRoot {
         // Ordering and construction of filters is important here: result needs to be
         // `WHERE (x = $1 [OR x = $2...]) AND (y ILIKE $3 [OR z ILIKE $3...])`.
         // Interchanging .filter, .or, and .or_filter would result in different bracketing.
-        for pub_id in publishers {
+        for pub_id in context.scoped_publishers(publishers) {
             query = query.or_filter(crate::schema::imprint::publisher_id.eq(pub_id));
         }
         if let Some(sub_type) = subject_type {
             query = query.filter(dsl::subject_type.eq(sub_type))
         }
         query
-            .limit(limit.into())
-            .offset(offset.into())
+            .limit(limit)
+            .offset(offset)
             .load::<Subject>(&connection)
-            .expect("Error loading subjects")
+            .map_err(FieldError::from)
     }
 
     #[graphql(description = "Query a single subject using its id")]
     fn subject(context: &Context, subject_id: Uuid) -> FieldResult<Subject> {
         let connection = context.db.get().unwrap();
-        match crate::schema::subject::dsl::subject
+        let subject = crate::schema::subject::dsl::subject
             .find(subject_id)
             .get_result::<Subject>(&connection)
-        {
-            Ok(subject) => Ok(subject),
-            Err(e) => Err(FieldError::from(e)),
-        }
+            .map_err(FieldError::from)?;
+        context.check_scope(|| work_publisher_id(subject.work_id, context))?;
+        Ok(subject)
     }
 
-    #[graphql(description = "Get the total number of subjects associated to works")]
+    #[graphql(
+        deprecated = "Use subjectTotal, which is not limited to the range of Int",
+        description = "Get the total number of subjects associated to works"
+    )]
     fn subject_count(context: &Context, subject_type: Option<SubjectType>) -> i32 {
+        saturating_count(load_subject_count(context, subject_type))
+    }
+
+    #[graphql(description = "Get the total number of subjects associated to works")]
+    fn subject_total(context: &Context, subject_type: Option<SubjectType>) -> Count {
+        Count(load_subject_count(context, subject_type))
+    }
+
+    #[graphql(
+        description = "Suggest keywords for a work, ranked by how often they occur in its title and long abstract. Keywords already attached to the work are left out, and no suggestions are made for works without a long abstract",
+        arguments(limit(default = 10, description = "The maximum number of keywords to return"))
+    )]
+    fn suggest_keywords(context: &Context, work_id: Uuid, limit: i32) -> FieldResult<Vec<String>> {
+        let (limit, _) = page_bounds(limit, 0, *MAX_LIMIT)?;
         use crate::schema::subject::dsl;
         let connection = context.db.get().unwrap();
-        let mut query = dsl::subject.into_boxed();
-        if let Some(sub_type) = subject_type {
-            query = query.filter(dsl::subject_type.eq(sub_type))
-        }
-        // see comment in work_count()
-        query
-            .count()
-            .get_result::<i64>(&connection)
-            .expect("Error loading subject count")
-            .to_string()
-            .parse::<i32>()
-            .unwrap()
+        let work = crate::schema::work::dsl::work
+            .find(work_id)
+            .get_result::<Work>(&connection)
+            .map_err(load_error)?;
+        context.check_scope(|| imprint_publisher_id(work.imprint_id, context))?;
+        let existing = dsl::subject
+            .select(dsl::subject_code)
+            .filter(dsl::work_id.eq(work_id))
+            .filter(dsl::subject_type.eq(SubjectType::Keyword))
+            .load::<String>(&connection)
+            .map_err(load_error)?;
+        Ok(crate::subject::model::suggest_keywords(
+            &work.full_title,
+            work.long_abstract.as_deref(),
+            &existing,
+            limit as usize,
+        ))
     }
 
     #[graphql(
@@ -1869,7 +2616,8 @@ impl QueryRoot {
         offset: i32,
         filter: String,
         order: FunderOrderBy,
-    ) -> Vec<Funder> {
+    ) -> FieldResult<Vec<Funder>> {
+        let (limit, offset) = page_bounds(limit, offset, *MAX_LIMIT)?;
         use crate::schema::funder::dsl::*;
         let connection = context.db.get().unwrap();
         let mut query = funder.into_boxed();
@@ -1896,12 +2644,12 @@ impl QueryRoot {
             },
         }
         query
-            .filter(funder_name.ilike(format!("%{}%", filter)))
-            .or_filter(funder_doi.ilike(format!("%{}%", filter)))
-            .limit(limit.into())
-            .offset(offset.into())
+            .filter(funder_name.ilike(contains_pattern(&filter)))
+            .or_filter(funder_doi.ilike(contains_pattern(&filter)))
+            .limit(limit)
+            .offset(offset)
             .load::<Funder>(&connection)
-            .expect("Error loading funders")
+            .map_err(FieldError::from)
     }
 
     #[graphql(description = "Query a single funder using its id")]
@@ -1917,6 +2665,7 @@ impl QueryRoot {
     }
 
     #[graphql(
+        deprecated = "Use funderTotal, which is not limited to the range of Int",
         description = "Get the total number of funders",
         arguments(
             filter(
@@ -1926,18 +2675,20 @@ impl QueryRoot {
         )
     )]
     fn funder_count(context: &Context, filter: String) -> i32 {
-        use crate::schema::funder::dsl::*;
-        let connection = context.db.get().unwrap();
-        // see comment in work_count()
-        funder
-            .filter(funder_name.ilike(format!("%{}%", filter)))
-            .or_filter(funder_doi.ilike(format!("%{}%", filter)))
-            .count()
-            .get_result::<i64>(&connection)
-            .expect("Error loading funder count")
-            .to_string()
-            .parse::<i32>()
-            .unwrap()
+        saturating_count(load_funder_count(context, filter))
+    }
+
+    #[graphql(
+        description = "Get the total number of funders",
+        arguments(
+            filter(
+                default = "".to_string(),
+                description = "A query string to search. This argument is a test, do not rely on it. At present it simply searches for case insensitive literals on funderName and funderDoi",
+            ),
+        )
+    )]
+    fn funder_total(context: &Context, filter: String) -> Count {
+        Count(load_funder_count(context, filter))
     }
 
     #[graphql(
@@ -1966,7 +2717,8 @@ impl QueryRoot {
         offset: i32,
         order: FundingOrderBy,
         publishers: Vec<Uuid>,
-    ) -> Vec<Funding> {
+    ) -> FieldResult<Vec<Funding>> {
+        let (limit, offset) = page_bounds(limit, offset, *MAX_LIMIT)?;
         use crate::schema::funding::dsl::*;
         let connection = context.db.get().unwrap();
         let mut query = funding
@@ -2026,40 +2778,55 @@ impl QueryRoot {
                 Direction::DESC => query = query.order(updated_at.desc()),
             },
         }
-        for pub_id in publishers {
+        for pub_id in context.scoped_publishers(publishers) {
             query = query.or_filter(crate::schema::imprint::publisher_id.eq(pub_id));
         }
         query
-            .limit(limit.into())
-            .offset(offset.into())
+            .limit(limit)
+            .offset(offset)
             .load::<Funding>(&connection)
-            .expect("Error loading fundings")
+            .map_err(FieldError::from)
     }
 
     #[graphql(description = "Query a single funding using its id")]
     fn funding(context: &Context, funding_id: Uuid) -> FieldResult<Funding> {
         let connection = context.db.get().unwrap();
-        match crate::schema::funding::dsl::funding
+        let funding = crate::schema::funding::dsl::funding
             .find(funding_id)
             .get_result::<Funding>(&connection)
-        {
-            Ok(funding) => Ok(funding),
-            Err(e) => Err(FieldError::from(e)),
-        }
+            .map_err(FieldError::from)?;
+        context.check_scope(|| work_publisher_id(funding.work_id, context))?;
+        Ok(funding)
     }
 
-    #[graphql(description = "Get the total number of funding instances associated to works")]
+    #[graphql(
+        deprecated = "Use fundingTotal, which is not limited to the range of Int",
+        description = "Get the total number of funding instances associated to works"
+    )]
     fn funding_count(context: &Context) -> i32 {
-        use crate::schema::funding::dsl::*;
+        saturating_count(load_funding_count(context))
+    }
+
+    #[graphql(description = "Get the total number of funding instances associated to works")]
+    fn funding_total(context: &Context) -> Count {
+        Count(load_funding_count(context))
+    }
+
+    #[graphql(description = "Query the webhooks configured for a publisher")]
+    fn webhooks(context: &Context, publisher_id: Uuid) -> FieldResult<Vec<Webhook>> {
+        context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
+        context
+            .account_access
+            .can_admin(publisher_id)
+            .map_err(access_error)?;
+
+        use crate::schema::webhook::dsl;
         let connection = context.db.get().unwrap();
-        // see comment in work_count()
-        funding
-            .count()
-            .get_result::<i64>(&connection)
-            .expect("Error loading funding count")
-            .to_string()
-            .parse::<i32>()
-            .unwrap()
+        dsl::webhook
+            .filter(dsl::publisher_id.eq(publisher_id))
+            .order(dsl::created_at.asc())
+            .load::<Webhook>(&connection)
+            .map_err(FieldError::from)
     }
 }
 
@@ -2070,18 +2837,202 @@ impl MutationRoot {
     fn create_work(context: &Context, data: NewWork) -> FieldResult<Work> {
         context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
         user_can_edit_imprint(data.imprint_id, context)?;
+        data.validate()?;
 
         let connection = context.db.get().unwrap();
-        match diesel::insert_into(work::table)
-            .values(&data)
-            .get_result(&connection)
-        {
-            Ok(work) => Ok(work),
-            Err(e) => Err(FieldError::from(e)),
+        connection
+            .transaction(|| {
+                let work = diesel::insert_into(work::table)
+                    .values(&data)
+                    .get_result::<Work>(&connection)?;
+                sync_default_abstracts(&connection, &work)?;
+                sync_default_title(
+                    &connection,
+                    work.work_id,
+                    &work.title,
+                    work.subtitle.as_ref(),
+                )?;
+                Ok(work)
+            })
+            .map(|work: Work| {
+                let events = WebhookEvent::for_work_creation(&work.work_status);
+                dispatch_work_events(context.db.clone(), &work, events);
+                work
+            })
+    }
+
+    #[graphql(
+        description = "Create several works, with their publications, at once. Each work is created in its own transaction: works that fail are reported by their position in `data` and do not prevent the rest from being created"
+    )]
+    fn create_works(
+        context: &Context,
+        data: Vec<NewWorkImport>,
+    ) -> FieldResult<Vec<WorkImportResult>> {
+        context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
+
+        let connection = context.db.get().unwrap();
+        let results = data
+            .iter()
+            .enumerate()
+            .map(|(row, import)| {
+                let row = row as i32;
+                let created = user_can_edit_imprint(import.work.imprint_id, context)
+                    .map_err(|_| ThothError::Unauthorised)
+                    .and_then(|_| import.work.validate())
+                    .and_then(|_| {
+                        connection.transaction(|| {
+                            let work = diesel::insert_into(work::table)
+                                .values(&import.work)
+                                .get_result::<Work>(&connection)?;
+                            sync_default_abstracts(&connection, &work).map_err(ThothError::from)?;
+                            sync_default_title(
+                                &connection,
+                                work.work_id,
+                                &work.title,
+                                work.subtitle.as_ref(),
+                            )?;
+                            for publication in &import.publications {
+                                let publication = publication.for_work(work.work_id);
+                                publication.validate()?;
+                                diesel::insert_into(publication::table)
+                                    .values(&publication)
+                                    .execute(&connection)?;
+                            }
+                            Ok(work)
+                        })
+                    });
+                match created {
+                    Ok(work) => {
+                        let events = WebhookEvent::for_work_creation(&work.work_status);
+                        dispatch_work_events(context.db.clone(), &work, events);
+                        WorkImportResult::created(row, work.work_id)
+                    }
+                    Err(e) => WorkImportResult::rejected(row, &e),
+                }
+            })
+            .collect();
+        Ok(results)
+    }
+
+    #[graphql(
+        description = "Create a work, with its contributions, subjects, languages, publication and prices, from a single ONIX 3.0 product given in short or reference tags. Nothing is created if the product uses codes that have no Thoth equivalent or lacks a required field. Contributors are matched to existing records by ORCID, or created",
+        arguments(
+            onix_xml(
+                description = "An ONIX 3.0 message holding one product, or the product alone"
+            ),
+            imprint_id(description = "Imprint to create the work under"),
+        )
+    )]
+    fn create_work_from_onix(
+        context: &Context,
+        onix_xml: String,
+        imprint_id: Uuid,
+    ) -> FieldResult<Work> {
+        context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
+        user_can_edit_imprint(imprint_id, context)?;
+
+        let mut products =
+            parse_onix_products(onix_xml.as_bytes()).map_err(|e| e.into_field_error())?;
+        if products.len() != 1 {
+            return Err(ThothError::InvalidOnix(format!(
+                "expected a single product, found {}",
+                products.len()
+            ))
+            .into_field_error());
         }
+        let product = products.remove(0);
+        let data = product
+            .new_work(imprint_id)
+            .map_err(|e| e.into_field_error())?;
+        data.validate()?;
+
+        let connection = context.db.get().unwrap();
+        connection
+            .transaction(|| {
+                let work = data.insert(&connection).map_err(|e| e.into_field_error())?;
+                sync_default_abstracts(&connection, &work)?;
+                sync_default_title(
+                    &connection,
+                    work.work_id,
+                    &work.title,
+                    work.subtitle.as_ref(),
+                )?;
+                for contribution in &product.contributions {
+                    let contributor = match &contribution.orcid {
+                        Some(orcid) => contributor::table
+                            .filter(contributor::orcid.eq(orcid))
+                            .first::<Contributor>(&connection)
+                            .optional()?,
+                        None => None,
+                    };
+                    let contributor = match contributor {
+                        Some(contributor) => contributor,
+                        None => diesel::insert_into(contributor::table)
+                            .values(&contribution.new_contributor())
+                            .get_result::<Contributor>(&connection)?,
+                    };
+                    diesel::insert_into(contribution::table)
+                        .values(
+                            &contribution
+                                .new_contribution(work.work_id, contributor.contributor_id),
+                        )
+                        .execute(&connection)?;
+                }
+                // Subjects are ordered within each subject type, as given in the product
+                let mut ordinals: HashMap<SubjectType, i32> = HashMap::new();
+                for subject in &product.subjects {
+                    let ordinal = ordinals.entry(subject.subject_type.clone()).or_insert(0);
+                    *ordinal += 1;
+                    diesel::insert_into(subject::table)
+                        .values(&NewSubject {
+                            work_id: work.work_id,
+                            subject_type: subject.subject_type.clone(),
+                            subject_code: subject.subject_code.clone(),
+                            subject_ordinal: *ordinal,
+                        })
+                        .execute(&connection)?;
+                }
+                for (index, language) in product.languages.iter().enumerate() {
+                    diesel::insert_into(language::table)
+                        .values(&language.new_language(work.work_id, index == 0))
+                        .execute(&connection)?;
+                }
+                for onix_publication in &product.publications {
+                    let new_publication = onix_publication.new_publication(work.work_id);
+                    new_publication.validate()?;
+                    let publication = diesel::insert_into(publication::table)
+                        .values(&new_publication)
+                        .get_result::<Publication>(&connection)?;
+                    for price in &onix_publication.prices {
+                        diesel::insert_into(price::table)
+                            .values(&price.new_price(publication.publication_id))
+                            .execute(&connection)?;
+                    }
+                }
+                Ok(work)
+            })
+            .map(|work: Work| {
+                let events = WebhookEvent::for_work_creation(&work.work_status);
+                dispatch_work_events(context.db.clone(), &work, events);
+                work
+            })
     }
 
-    fn create_publisher(context: &Context, data: NewPublisher) -> FieldResult<Publisher> {
+    #[graphql(
+        description = "Create a publisher. Only superusers can create publishers",
+        arguments(
+            data(description = "Values of the new publisher"),
+            default_imprint(
+                default = false,
+                description = "Also create an imprint named after the publisher, and make it the publisher's default imprint",
+            ),
+        )
+    )]
+    fn create_publisher(
+        context: &Context,
+        data: NewPublisher,
+        default_imprint: bool,
+    ) -> FieldResult<Publisher> {
         context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
         // Only superusers can create new publishers - NewPublisher has no ID field
         if !context.account_access.is_superuser {
@@ -2089,18 +3040,34 @@ impl MutationRoot {
         }
 
         let connection = context.db.get().unwrap();
-        match diesel::insert_into(publisher::table)
-            .values(&data)
-            .get_result(&connection)
-        {
-            Ok(publisher) => Ok(publisher),
-            Err(e) => Err(FieldError::from(e)),
-        }
+        connection.transaction(|| {
+            let publisher: Publisher = diesel::insert_into(publisher::table)
+                .values(&data)
+                .get_result(&connection)?;
+            if !default_imprint {
+                return Ok(publisher);
+            }
+            let new_imprint = NewImprint {
+                publisher_id: publisher.publisher_id,
+                imprint_name: publisher.publisher_name.clone(),
+                imprint_url: publisher.publisher_url.clone(),
+            };
+            let imprint: Imprint = diesel::insert_into(imprint::table)
+                .values(&new_imprint)
+                .get_result(&connection)?;
+            diesel::update(publisher::table.find(publisher.publisher_id))
+                .set(publisher::default_imprint_id.eq(imprint.imprint_id))
+                .get_result(&connection)
+                .map_err(FieldError::from)
+        })
     }
 
     fn create_imprint(context: &Context, data: NewImprint) -> FieldResult<Imprint> {
         context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
-        context.account_access.can_edit(data.publisher_id)?;
+        context
+            .account_access
+            .can_edit(data.publisher_id)
+            .map_err(access_error)?;
 
         let connection = context.db.get().unwrap();
         match diesel::insert_into(imprint::table)
@@ -2114,6 +3081,7 @@ impl MutationRoot {
 
     fn create_contributor(context: &Context, data: NewContributor) -> FieldResult<Contributor> {
         context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
+        data.validate()?;
 
         let connection = context.db.get().unwrap();
         match diesel::insert_into(contributor::table)
@@ -2142,6 +3110,7 @@ impl MutationRoot {
     fn create_publication(context: &Context, data: NewPublication) -> FieldResult<Publication> {
         context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
         user_can_edit_work(data.work_id, context)?;
+        data.validate()?;
 
         let connection = context.db.get().unwrap();
         match diesel::insert_into(publication::table)
@@ -2195,6 +3164,42 @@ impl MutationRoot {
         }
     }
 
+    fn create_abstract(context: &Context, data: NewAbstract) -> FieldResult<Abstract> {
+        context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
+        user_can_edit_work(data.work_id, context)?;
+
+        let connection = context.db.get().unwrap();
+        connection.transaction(|| {
+            let work_abstract = diesel::insert_into(work_abstract::table)
+                .values(&data)
+                .get_result::<Abstract>(&connection)?;
+            if work_abstract.is_default_language() {
+                sync_work_abstract_field(
+                    &connection,
+                    work_abstract.work_id,
+                    &work_abstract.abstract_type,
+                    Some(&work_abstract.text),
+                )?;
+            }
+            Ok(work_abstract)
+        })
+    }
+
+    #[graphql(
+        description = "Add a title of a work in another language or role. The work's own title is its default title, and is changed by updating the work"
+    )]
+    fn create_work_title(context: &Context, data: NewWorkTitle) -> FieldResult<WorkTitle> {
+        context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
+        user_can_edit_work(data.work_id, context)?;
+        check_not_default_title(&data.language_code, &data.title_type)?;
+
+        let connection = context.db.get().unwrap();
+        diesel::insert_into(work_title::table)
+            .values(&data)
+            .get_result::<WorkTitle>(&connection)
+            .map_err(FieldError::from)
+    }
+
     fn create_funder(context: &Context, data: NewFunder) -> FieldResult<Funder> {
         context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
 
@@ -2228,7 +3233,7 @@ impl MutationRoot {
 
         let connection = context.db.get().unwrap();
         match diesel::insert_into(price::table)
-            .values(&data)
+            .values(&data.with_defaults())
             .get_result(&connection)
         {
             Ok(price) => Ok(price),
@@ -2236,6 +3241,73 @@ impl MutationRoot {
         }
     }
 
+    #[graphql(
+        description = "Replace all of a publication's prices at once. Prices given for a currency, price type and territory the publication already has a price for update it; others are created, and existing prices not given are deleted",
+        arguments(
+            publication_id(description = "Publication whose prices to replace"),
+            prices(description = "The complete set of prices the publication should have"),
+        )
+    )]
+    fn set_prices(
+        context: &Context,
+        publication_id: Uuid,
+        prices: Vec<PublicationPrice>,
+    ) -> FieldResult<Vec<Price>> {
+        context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
+        user_can_edit_publication(publication_id, context)?;
+        let mut keys = vec![];
+        for price in &prices {
+            price.validate().map_err(|e| e.into_field_error())?;
+            let key = price.key();
+            if keys.contains(&key) {
+                return Err(ThothError::InvalidInput(format!(
+                    "{} {} {} is given more than once",
+                    key.0, key.1, key.2
+                ))
+                .into_field_error());
+            }
+            keys.push(key);
+        }
+
+        use crate::schema::price::dsl;
+        let connection = context.db.get().unwrap();
+        let account_id = context.token.jwt.as_ref().unwrap().account_id(&context.db);
+        connection.transaction(|| {
+            let existing = dsl::price
+                .filter(dsl::publication_id.eq(publication_id))
+                .load::<Price>(&connection)?;
+            let mut result = vec![];
+            for old in existing {
+                match prices.iter().find(|p| old.has_key(&p.key())) {
+                    Some(new) if (new.unit_price - old.unit_price).abs() < f64::EPSILON => {
+                        result.push(old)
+                    }
+                    Some(new) => {
+                        let updated = diesel::update(dsl::price.find(old.price_id))
+                            .set(dsl::unit_price.eq(new.unit_price))
+                            .get_result::<Price>(&connection)?;
+                        NewPriceHistory::new(old, account_id).insert(&connection)?;
+                        result.push(updated);
+                    }
+                    None => {
+                        diesel::delete(dsl::price.find(old.price_id)).execute(&connection)?;
+                    }
+                }
+            }
+            for price in &prices {
+                if !result.iter().any(|p| p.has_key(&price.key())) {
+                    result.push(
+                        diesel::insert_into(price::table)
+                            .values(&price.new_price(publication_id))
+                            .get_result::<Price>(&connection)?,
+                    );
+                }
+            }
+            result.sort_by_key(|p| p.currency_code.to_string());
+            Ok(result)
+        })
+    }
+
     fn create_subject(context: &Context, data: NewSubject) -> FieldResult<Subject> {
         context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
         user_can_edit_work(data.work_id, context)?;
@@ -2252,44 +3324,144 @@ impl MutationRoot {
         }
     }
 
-    fn update_work(context: &Context, data: PatchWork) -> FieldResult<Work> {
+    fn update_work(
+        context: &Context,
+        data: PatchWork,
+        expected_version: Option<String>,
+    ) -> FieldResult<Work> {
         context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
         user_can_edit_imprint(data.imprint_id, context)?;
+        data.validate()?;
 
         let connection = context.db.get().unwrap();
         let target = crate::schema::work::dsl::work.find(data.work_id);
-        let work = target.get_result::<Work>(&connection).unwrap();
+        let work = target.get_result::<Work>(&connection).map_err(load_error)?;
         if !(data.imprint_id == work.imprint_id) {
             user_can_edit_imprint(work.imprint_id, context)?;
+            can_update_work_imprint(work.work_id, &connection)?;
         }
-
-        connection.transaction(
-            || match diesel::update(target).set(&data).get_result(&connection) {
-                Ok(c) => {
-                    let account_id = context.token.jwt.as_ref().unwrap().account_id(&context.db);
-                    match NewWorkHistory::new(work, account_id).insert(&connection) {
-                        Ok(_) => Ok(c),
-                        Err(e) => Err(FieldError::from(e)),
-                    }
+        let previous_status = work.work_status.clone();
+        let cover_changed = data.cover_url != work.cover_url;
+
+        connection
+            .transaction(|| {
+                check_unchanged(
+                    expected_version.as_ref(),
+                    target
+                        .select(crate::schema::work::dsl::updated_at)
+                        .for_update()
+                        .get_result(&connection),
+                )?;
+                let updated = diesel::update(target)
+                    .set(&data)
+                    .get_result::<Work>(&connection)?;
+                // Metadata read from the previous cover would not describe the new one
+                let updated = if cover_changed {
+                    use crate::schema::work::dsl;
+                    diesel::update(target)
+                        .set((
+                            dsl::cover_format.eq(None::<String>),
+                            dsl::cover_width.eq(None::<i32>),
+                            dsl::cover_height.eq(None::<i32>),
+                        ))
+                        .get_result::<Work>(&connection)?
+                } else {
+                    updated
+                };
+                sync_default_abstracts(&connection, &updated)?;
+                sync_default_title(
+                    &connection,
+                    updated.work_id,
+                    &updated.title,
+                    updated.subtitle.as_ref(),
+                )?;
+                let account_id = context.token.jwt.as_ref().unwrap().account_id(&context.db);
+                match NewWorkHistory::new(work, account_id).insert(&connection) {
+                    Ok(_) => Ok(updated),
+                    Err(e) => Err(FieldError::from(e)),
                 }
-                Err(e) => Err(FieldError::from(e)),
-            },
+            })
+            .map(|updated: Work| {
+                let events = WebhookEvent::for_work_update(&previous_status, &updated.work_status);
+                dispatch_work_events(context.db.clone(), &updated, events);
+                updated
+            })
+    }
+
+    #[graphql(
+        description = "Move a work, with its publications, contributions and other records, to an imprint of any publisher. The caller must be able to edit both the work's current publisher and the target imprint's publisher",
+        arguments(
+            work_id(description = "Thoth ID of the work to transfer"),
+            target_imprint_id(description = "Thoth ID of the imprint to move the work to"),
         )
+    )]
+    fn transfer_work(
+        context: &Context,
+        work_id: Uuid,
+        target_imprint_id: Uuid,
+    ) -> FieldResult<Work> {
+        context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
+        user_can_edit_work(work_id, context)?;
+        user_can_edit_imprint(target_imprint_id, context)?;
+
+        let connection = context.db.get().unwrap();
+        let target = crate::schema::work::dsl::work.find(work_id);
+        let work = target.get_result::<Work>(&connection).map_err(load_error)?;
+        if work.imprint_id == target_imprint_id {
+            return Err(ThothError::InvalidInput(
+                "The work already belongs to this imprint".to_string(),
+            )
+            .into_field_error());
+        }
+        can_update_work_imprint(work_id, &connection)?;
+
+        connection.transaction(|| {
+            // publications, contributions and the work's other records follow it by work ID
+            let updated = diesel::update(target)
+                .set(crate::schema::work::dsl::imprint_id.eq(target_imprint_id))
+                .get_result::<Work>(&connection)?;
+            let account_id = context.token.jwt.as_ref().unwrap().account_id(&context.db);
+            match NewWorkHistory::new(work, account_id).insert(&connection) {
+                Ok(_) => Ok(updated),
+                Err(e) => Err(FieldError::from(e)),
+            }
+        })
     }
 
-    fn update_publisher(context: &Context, data: PatchPublisher) -> FieldResult<Publisher> {
+    fn update_publisher(
+        context: &Context,
+        data: PatchPublisher,
+        expected_version: Option<String>,
+    ) -> FieldResult<Publisher> {
         context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
-        context.account_access.can_edit(data.publisher_id)?;
+        context
+            .account_access
+            .can_edit(data.publisher_id)
+            .map_err(access_error)?;
 
         let connection = context.db.get().unwrap();
         let target = crate::schema::publisher::dsl::publisher.find(&data.publisher_id);
-        let publisher = target.get_result::<Publisher>(&connection).unwrap();
+        let publisher = target
+            .get_result::<Publisher>(&connection)
+            .map_err(load_error)?;
         if !(data.publisher_id == publisher.publisher_id) {
-            context.account_access.can_edit(publisher.publisher_id)?;
+            context
+                .account_access
+                .can_edit(publisher.publisher_id)
+                .map_err(access_error)?;
         }
-
-        connection.transaction(
-            || match diesel::update(target).set(&data).get_result(&connection) {
+        can_set_default_imprint(data.publisher_id, data.default_imprint_id, &connection)
+            .map_err(ThothError::into_field_error)?;
+
+        connection.transaction(|| {
+            check_unchanged(
+                expected_version.as_ref(),
+                target
+                    .select(crate::schema::publisher::dsl::updated_at)
+                    .for_update()
+                    .get_result(&connection),
+            )?;
+            match diesel::update(target).set(&data).get_result(&connection) {
                 Ok(c) => {
                     let account_id = context.token.jwt.as_ref().unwrap().account_id(&context.db);
                     match NewPublisherHistory::new(publisher, account_id).insert(&connection) {
@@ -2298,23 +3470,45 @@ impl MutationRoot {
                     }
                 }
                 Err(e) => Err(FieldError::from(e)),
-            },
-        )
+            }
+        })
     }
 
-    fn update_imprint(context: &Context, data: PatchImprint) -> FieldResult<Imprint> {
+    fn update_imprint(
+        context: &Context,
+        data: PatchImprint,
+        expected_version: Option<String>,
+    ) -> FieldResult<Imprint> {
         context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
-        context.account_access.can_edit(data.publisher_id)?;
+        context
+            .account_access
+            .can_edit(data.publisher_id)
+            .map_err(access_error)?;
 
         let connection = context.db.get().unwrap();
         let target = crate::schema::imprint::dsl::imprint.find(&data.imprint_id);
-        let imprint = target.get_result::<Imprint>(&connection).unwrap();
+        let imprint = target
+            .get_result::<Imprint>(&connection)
+            .map_err(load_error)?;
         if !(data.publisher_id == imprint.publisher_id) {
-            context.account_access.can_edit(imprint.publisher_id)?;
+            context
+                .account_access
+                .can_edit(imprint.publisher_id)
+                .map_err(access_error)?;
         }
 
-        connection.transaction(
-            || match diesel::update(target).set(&data).get_result(&connection) {
+        connection.transaction(|| {
+            check_unchanged(
+                expected_version.as_ref(),
+                target
+                    .select(crate::schema::imprint::dsl::updated_at)
+                    .for_update()
+                    .get_result(&connection),
+            )?;
+            if data.publisher_id != imprint.publisher_id {
+                release_default_imprint(imprint.imprint_id, &connection)?;
+            }
+            match diesel::update(target).set(&data).get_result(&connection) {
                 Ok(c) => {
                     let account_id = context.token.jwt.as_ref().unwrap().account_id(&context.db);
                     match NewImprintHistory::new(imprint, account_id).insert(&connection) {
@@ -2323,19 +3517,86 @@ impl MutationRoot {
                     }
                 }
                 Err(e) => Err(FieldError::from(e)),
-            },
+            }
+        })
+    }
+
+    #[graphql(
+        description = "Move an imprint, with all its works and series, to another publisher. Only superusers can transfer imprints. Returns the number of works moved",
+        arguments(
+            imprint_id(description = "Thoth ID of the imprint to transfer"),
+            target_publisher_id(description = "Thoth ID of the publisher to move the imprint to"),
         )
+    )]
+    fn transfer_imprint(
+        context: &Context,
+        imprint_id: Uuid,
+        target_publisher_id: Uuid,
+    ) -> FieldResult<Count> {
+        context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
+        if !context.account_access.is_superuser {
+            return Err(ThothError::Unauthorised.into());
+        }
+
+        let connection = context.db.get().unwrap();
+        let target = crate::schema::imprint::dsl::imprint.find(imprint_id);
+        let imprint = target
+            .get_result::<Imprint>(&connection)
+            .map_err(load_error)?;
+        crate::schema::publisher::dsl::publisher
+            .find(target_publisher_id)
+            .select(crate::schema::publisher::dsl::publisher_id)
+            .get_result::<Uuid>(&connection)
+            .map_err(load_error)?;
+        if imprint.publisher_id == target_publisher_id {
+            return Err(ThothError::InvalidInput(
+                "The imprint already belongs to this publisher".to_string(),
+            )
+            .into_field_error());
+        }
+        can_transfer_imprint(imprint_id, &connection)?;
+
+        connection.transaction(|| {
+            // works and series follow their imprint by imprint ID
+            diesel::update(target)
+                .set(crate::schema::imprint::dsl::publisher_id.eq(target_publisher_id))
+                .execute(&connection)?;
+            release_default_imprint(imprint_id, &connection)?;
+            let works_moved = crate::schema::work::table
+                .filter(crate::schema::work::imprint_id.eq(imprint_id))
+                .count()
+                .get_result::<i64>(&connection)?;
+            let account_id = context.token.jwt.as_ref().unwrap().account_id(&context.db);
+            match NewImprintHistory::new(imprint, account_id).insert(&connection) {
+                Ok(_) => Ok(Count(works_moved)),
+                Err(e) => Err(FieldError::from(e)),
+            }
+        })
     }
 
-    fn update_contributor(context: &Context, data: PatchContributor) -> FieldResult<Contributor> {
+    fn update_contributor(
+        context: &Context,
+        data: PatchContributor,
+        expected_version: Option<String>,
+    ) -> FieldResult<Contributor> {
         context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
+        data.validate()?;
         let connection = context.db.get().unwrap();
 
         let target = crate::schema::contributor::dsl::contributor.find(&data.contributor_id);
-        let contributor = target.get_result::<Contributor>(&connection).unwrap();
-
-        connection.transaction(
-            || match diesel::update(target).set(&data).get_result(&connection) {
+        let contributor = target
+            .get_result::<Contributor>(&connection)
+            .map_err(load_error)?;
+
+        connection.transaction(|| {
+            check_unchanged(
+                expected_version.as_ref(),
+                target
+                    .select(crate::schema::contributor::dsl::updated_at)
+                    .for_update()
+                    .get_result(&connection),
+            )?;
+            match diesel::update(target).set(&data).get_result(&connection) {
                 Ok(c) => {
                     let account_id = context.token.jwt.as_ref().unwrap().account_id(&context.db);
                     match NewContributorHistory::new(contributor, account_id).insert(&connection) {
@@ -2344,13 +3605,14 @@ impl MutationRoot {
                     }
                 }
                 Err(e) => Err(FieldError::from(e)),
-            },
-        )
+            }
+        })
     }
 
     fn update_contribution(
         context: &Context,
         data: PatchContribution,
+        expected_version: Option<String>,
     ) -> FieldResult<Contribution> {
         context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
         user_can_edit_work(data.work_id, context)?;
@@ -2364,14 +3626,21 @@ impl MutationRoot {
             .filter(contributor_id.eq(&data.contributor_id))
             .filter(contribution_type.eq(&data.contribution_type))
             .get_result::<Contribution>(&connection)
-            .unwrap();
+            .map_err(load_error)?;
         let target = contribution
             .filter(work_id.eq(&data.work_id))
             .filter(contributor_id.eq(&data.contributor_id))
             .filter(contribution_type.eq(&data.contribution_type));
 
-        connection.transaction(
-            || match diesel::update(target).set(&data).get_result(&connection) {
+        connection.transaction(|| {
+            check_unchanged(
+                expected_version.as_ref(),
+                target
+                    .select(crate::schema::contribution::dsl::updated_at)
+                    .for_update()
+                    .get_result(&connection),
+            )?;
+            match diesel::update(target).set(&data).get_result(&connection) {
                 Ok(c) => {
                     let account_id = context.token.jwt.as_ref().unwrap().account_id(&context.db);
                     match NewContributionHistory::new(target_contribution, account_id)
@@ -2382,23 +3651,37 @@ impl MutationRoot {
                     }
                 }
                 Err(e) => Err(FieldError::from(e)),
-            },
-        )
+            }
+        })
     }
 
-    fn update_publication(context: &Context, data: PatchPublication) -> FieldResult<Publication> {
+    fn update_publication(
+        context: &Context,
+        data: PatchPublication,
+        expected_version: Option<String>,
+    ) -> FieldResult<Publication> {
         context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
         user_can_edit_work(data.work_id, context)?;
+        data.validate()?;
 
         let connection = context.db.get().unwrap();
         let target = crate::schema::publication::dsl::publication.find(&data.publication_id);
-        let publication = target.get_result::<Publication>(&connection).unwrap();
+        let publication = target
+            .get_result::<Publication>(&connection)
+            .map_err(load_error)?;
         if !(data.work_id == publication.work_id) {
             user_can_edit_work(publication.work_id, context)?;
         }
 
-        connection.transaction(
-            || match diesel::update(target).set(&data).get_result(&connection) {
+        connection.transaction(|| {
+            check_unchanged(
+                expected_version.as_ref(),
+                target
+                    .select(crate::schema::publication::dsl::updated_at)
+                    .for_update()
+                    .get_result(&connection),
+            )?;
+            match diesel::update(target).set(&data).get_result(&connection) {
                 Ok(c) => {
                     let account_id = context.token.jwt.as_ref().unwrap().account_id(&context.db);
                     match NewPublicationHistory::new(publication, account_id).insert(&connection) {
@@ -2407,23 +3690,36 @@ impl MutationRoot {
                     }
                 }
                 Err(e) => Err(FieldError::from(e)),
-            },
-        )
+            }
+        })
     }
 
-    fn update_series(context: &Context, data: PatchSeries) -> FieldResult<Series> {
+    fn update_series(
+        context: &Context,
+        data: PatchSeries,
+        expected_version: Option<String>,
+    ) -> FieldResult<Series> {
         context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
         user_can_edit_imprint(data.imprint_id, context)?;
 
         let connection = context.db.get().unwrap();
         let target = crate::schema::series::dsl::series.find(&data.series_id);
-        let series = target.get_result::<Series>(&connection).unwrap();
+        let series = target
+            .get_result::<Series>(&connection)
+            .map_err(load_error)?;
         if !(data.imprint_id == series.imprint_id) {
             user_can_edit_imprint(series.imprint_id, context)?;
         }
 
-        connection.transaction(
-            || match diesel::update(target).set(&data).get_result(&connection) {
+        connection.transaction(|| {
+            check_unchanged(
+                expected_version.as_ref(),
+                target
+                    .select(crate::schema::series::dsl::updated_at)
+                    .for_update()
+                    .get_result(&connection),
+            )?;
+            match diesel::update(target).set(&data).get_result(&connection) {
                 Ok(c) => {
                     let account_id = context.token.jwt.as_ref().unwrap().account_id(&context.db);
                     match NewSeriesHistory::new(series, account_id).insert(&connection) {
@@ -2432,11 +3728,15 @@ impl MutationRoot {
                     }
                 }
                 Err(e) => Err(FieldError::from(e)),
-            },
-        )
+            }
+        })
     }
 
-    fn update_issue(context: &Context, data: PatchIssue) -> FieldResult<Issue> {
+    fn update_issue(
+        context: &Context,
+        data: PatchIssue,
+        expected_version: Option<String>,
+    ) -> FieldResult<Issue> {
         context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
         user_can_edit_work(data.work_id, context)?;
 
@@ -2446,10 +3746,19 @@ impl MutationRoot {
         let target = issue
             .filter(series_id.eq(&data.series_id))
             .filter(work_id.eq(&data.work_id));
-        let target_issue = target.get_result::<Issue>(&connection).unwrap();
-
-        connection.transaction(
-            || match diesel::update(target).set(&data).get_result(&connection) {
+        let target_issue = target
+            .get_result::<Issue>(&connection)
+            .map_err(load_error)?;
+
+        connection.transaction(|| {
+            check_unchanged(
+                expected_version.as_ref(),
+                target
+                    .select(crate::schema::issue::dsl::updated_at)
+                    .for_update()
+                    .get_result(&connection),
+            )?;
+            match diesel::update(target).set(&data).get_result(&connection) {
                 Ok(c) => {
                     let account_id = context.token.jwt.as_ref().unwrap().account_id(&context.db);
                     match NewIssueHistory::new(target_issue, account_id).insert(&connection) {
@@ -2458,23 +3767,36 @@ impl MutationRoot {
                     }
                 }
                 Err(e) => Err(FieldError::from(e)),
-            },
-        )
+            }
+        })
     }
 
-    fn update_language(context: &Context, data: PatchLanguage) -> FieldResult<Language> {
+    fn update_language(
+        context: &Context,
+        data: PatchLanguage,
+        expected_version: Option<String>,
+    ) -> FieldResult<Language> {
         context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
         user_can_edit_work(data.work_id, context)?;
 
         let connection = context.db.get().unwrap();
         let target = crate::schema::language::dsl::language.find(&data.language_id);
-        let language = target.get_result::<Language>(&connection).unwrap();
+        let language = target
+            .get_result::<Language>(&connection)
+            .map_err(load_error)?;
         if !(data.work_id == language.work_id) {
             user_can_edit_work(language.work_id, context)?;
         }
 
-        connection.transaction(
-            || match diesel::update(target).set(&data).get_result(&connection) {
+        connection.transaction(|| {
+            check_unchanged(
+                expected_version.as_ref(),
+                target
+                    .select(crate::schema::language::dsl::updated_at)
+                    .for_update()
+                    .get_result(&connection),
+            )?;
+            match diesel::update(target).set(&data).get_result(&connection) {
                 Ok(c) => {
                     let account_id = context.token.jwt.as_ref().unwrap().account_id(&context.db);
                     match NewLanguageHistory::new(language, account_id).insert(&connection) {
@@ -2483,18 +3805,122 @@ impl MutationRoot {
                     }
                 }
                 Err(e) => Err(FieldError::from(e)),
-            },
-        )
+            }
+        })
     }
 
-    fn update_funder(context: &Context, data: PatchFunder) -> FieldResult<Funder> {
+    fn update_abstract(
+        context: &Context,
+        data: PatchAbstract,
+        expected_version: Option<String>,
+    ) -> FieldResult<Abstract> {
         context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
+        user_can_edit_work(data.work_id, context)?;
+
         let connection = context.db.get().unwrap();
-        let target = crate::schema::funder::dsl::funder.find(&data.funder_id);
-        let funder = target.get_result::<Funder>(&connection).unwrap();
+        let target = crate::schema::work_abstract::dsl::work_abstract.find(&data.abstract_id);
+        let work_abstract = target.get_result::<Abstract>(&connection)?;
+        if !(data.work_id == work_abstract.work_id) {
+            user_can_edit_work(work_abstract.work_id, context)?;
+        }
+
+        connection.transaction(|| {
+            check_unchanged(
+                expected_version.as_ref(),
+                target
+                    .select(crate::schema::work_abstract::dsl::updated_at)
+                    .for_update()
+                    .get_result(&connection),
+            )?;
+            let updated = diesel::update(target)
+                .set(&data)
+                .get_result::<Abstract>(&connection)?;
+            // Moving the default language abstract elsewhere empties the work field it filled
+            if work_abstract.is_default_language()
+                && (!updated.is_default_language()
+                    || updated.work_id != work_abstract.work_id
+                    || updated.abstract_type != work_abstract.abstract_type)
+            {
+                sync_work_abstract_field(
+                    &connection,
+                    work_abstract.work_id,
+                    &work_abstract.abstract_type,
+                    None,
+                )?;
+            }
+            if updated.is_default_language() {
+                sync_work_abstract_field(
+                    &connection,
+                    updated.work_id,
+                    &updated.abstract_type,
+                    Some(&updated.text),
+                )?;
+            }
+            let account_id = context.token.jwt.as_ref().unwrap().account_id(&context.db);
+            match NewAbstractHistory::new(work_abstract, account_id).insert(&connection) {
+                Ok(_) => Ok(updated),
+                Err(e) => Err(FieldError::from(e)),
+            }
+        })
+    }
+
+    fn update_work_title(
+        context: &Context,
+        data: PatchWorkTitle,
+        expected_version: Option<String>,
+    ) -> FieldResult<WorkTitle> {
+        context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
+        user_can_edit_work(data.work_id, context)?;
+        check_not_default_title(&data.language_code, &data.title_type)?;
+
+        let connection = context.db.get().unwrap();
+        let target = crate::schema::work_title::dsl::work_title.find(&data.title_id);
+        let work_title = target.get_result::<WorkTitle>(&connection)?;
+        check_not_default_title(&work_title.language_code, &work_title.title_type)?;
+        if !(data.work_id == work_title.work_id) {
+            user_can_edit_work(work_title.work_id, context)?;
+        }
+
+        connection.transaction(|| {
+            check_unchanged(
+                expected_version.as_ref(),
+                target
+                    .select(crate::schema::work_title::dsl::updated_at)
+                    .for_update()
+                    .get_result(&connection),
+            )?;
+            let updated = diesel::update(target)
+                .set(&data)
+                .get_result::<WorkTitle>(&connection)?;
+            let account_id = context.token.jwt.as_ref().unwrap().account_id(&context.db);
+            match NewWorkTitleHistory::new(work_title, account_id).insert(&connection) {
+                Ok(_) => Ok(updated),
+                Err(e) => Err(FieldError::from(e)),
+            }
+        })
+    }
 
-        connection.transaction(
-            || match diesel::update(target).set(&data).get_result(&connection) {
+    fn update_funder(
+        context: &Context,
+        data: PatchFunder,
+        expected_version: Option<String>,
+    ) -> FieldResult<Funder> {
+        context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
+        let connection = context.db.get().unwrap();
+        let target = crate::schema::funder::dsl::funder.find(&data.funder_id);
+        let funder = target
+            .get_result::<Funder>(&connection)
+            .map_err(load_error)?;
+
+        connection.transaction(|| {
+            check_unchanged(
+                expected_version.as_ref(),
+                target
+                    .select(crate::schema::funder::dsl::updated_at)
+                    .for_update()
+                    .get_result(&connection),
+            )?;
+            match diesel::update(target).set(&data).get_result(&connection) {
                 Ok(c) => {
                     let account_id = context.token.jwt.as_ref().unwrap().account_id(&context.db);
                     match NewFunderHistory::new(funder, account_id).insert(&connection) {
@@ -2503,23 +3929,36 @@ impl MutationRoot {
                     }
                 }
                 Err(e) => Err(FieldError::from(e)),
-            },
-        )
+            }
+        })
     }
 
-    fn update_funding(context: &Context, data: PatchFunding) -> FieldResult<Funding> {
+    fn update_funding(
+        context: &Context,
+        data: PatchFunding,
+        expected_version: Option<String>,
+    ) -> FieldResult<Funding> {
         context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
         user_can_edit_work(data.work_id, context)?;
 
         let connection = context.db.get().unwrap();
         let target = crate::schema::funding::dsl::funding.find(&data.funding_id);
-        let funding = target.get_result::<Funding>(&connection).unwrap();
+        let funding = target
+            .get_result::<Funding>(&connection)
+            .map_err(load_error)?;
         if !(data.work_id == funding.work_id) {
             user_can_edit_work(funding.work_id, context)?;
         }
 
-        connection.transaction(
-            || match diesel::update(target).set(&data).get_result(&connection) {
+        connection.transaction(|| {
+            check_unchanged(
+                expected_version.as_ref(),
+                target
+                    .select(crate::schema::funding::dsl::updated_at)
+                    .for_update()
+                    .get_result(&connection),
+            )?;
+            match diesel::update(target).set(&data).get_result(&connection) {
                 Ok(c) => {
                     let account_id = context.token.jwt.as_ref().unwrap().account_id(&context.db);
                     match NewFundingHistory::new(funding, account_id).insert(&connection) {
@@ -2528,24 +3967,36 @@ impl MutationRoot {
                     }
                 }
                 Err(e) => Err(FieldError::from(e)),
-            },
-        )
+            }
+        })
     }
 
-    fn update_price(context: &Context, data: PatchPrice) -> FieldResult<Price> {
+    fn update_price(
+        context: &Context,
+        data: PatchPrice,
+        expected_version: Option<String>,
+    ) -> FieldResult<Price> {
         context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
         user_can_edit_publication(data.publication_id, context)?;
+        let data = data.with_defaults();
 
         let connection = context.db.get().unwrap();
         let target = crate::schema::price::dsl::price.find(&data.price_id);
         let result = target.get_result::<Price>(&connection);
-        let price = result.unwrap();
+        let price = result.map_err(load_error)?;
         if !(data.publication_id == price.publication_id) {
             user_can_edit_publication(price.publication_id, context)?;
         }
 
-        connection.transaction(
-            || match diesel::update(target).set(&data).get_result(&connection) {
+        connection.transaction(|| {
+            check_unchanged(
+                expected_version.as_ref(),
+                target
+                    .select(crate::schema::price::dsl::updated_at)
+                    .for_update()
+                    .get_result(&connection),
+            )?;
+            match diesel::update(target).set(&data).get_result(&connection) {
                 Ok(c) => {
                     let account_id = context.token.jwt.as_ref().unwrap().account_id(&context.db);
                     match NewPriceHistory::new(price, account_id).insert(&connection) {
@@ -2554,25 +4005,38 @@ impl MutationRoot {
                     }
                 }
                 Err(e) => Err(FieldError::from(e)),
-            },
-        )
+            }
+        })
     }
 
-    fn update_subject(context: &Context, data: PatchSubject) -> FieldResult<Subject> {
+    fn update_subject(
+        context: &Context,
+        data: PatchSubject,
+        expected_version: Option<String>,
+    ) -> FieldResult<Subject> {
         context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
         user_can_edit_work(data.work_id, context)?;
 
         let connection = context.db.get().unwrap();
         let target = crate::schema::subject::dsl::subject.find(&data.subject_id);
-        let subject = target.get_result::<Subject>(&connection).unwrap();
+        let subject = target
+            .get_result::<Subject>(&connection)
+            .map_err(load_error)?;
         if !(data.work_id == subject.work_id) {
             user_can_edit_work(subject.work_id, context)?;
         }
 
         check_subject(&data.subject_type, &data.subject_code)?;
 
-        connection.transaction(
-            || match diesel::update(target).set(&data).get_result(&connection) {
+        connection.transaction(|| {
+            check_unchanged(
+                expected_version.as_ref(),
+                target
+                    .select(crate::schema::subject::dsl::updated_at)
+                    .for_update()
+                    .get_result(&connection),
+            )?;
+            match diesel::update(target).set(&data).get_result(&connection) {
                 Ok(c) => {
                     let account_id = context.token.jwt.as_ref().unwrap().account_id(&context.db);
                     match NewSubjectHistory::new(subject, account_id).insert(&connection) {
@@ -2581,59 +4045,258 @@ impl MutationRoot {
                     }
                 }
                 Err(e) => Err(FieldError::from(e)),
-            },
-        )
+            }
+        })
     }
 
-    fn delete_work(context: &Context, work_id: Uuid) -> FieldResult<Work> {
+    #[graphql(
+        description = "Set the cover image of a work, checking that the URL serves an image",
+        arguments(
+            work_id(description = "The work to set the cover of"),
+            cover_url(description = "URL of the cover image"),
+            cover_caption(description = "Caption to display with the cover"),
+            verify(
+                default = true,
+                description = "Fetch the start of the image to check its type and read its format and dimensions",
+            ),
+        )
+    )]
+    fn set_cover_image(
+        context: &Context,
+        work_id: Uuid,
+        cover_url: String,
+        cover_caption: Option<String>,
+        verify: bool,
+    ) -> FieldResult<Work> {
         context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
         user_can_edit_work(work_id, context)?;
 
+        let (cover_format, cover_width, cover_height) = if verify {
+            let cover = fetch_cover_image(&cover_url)?;
+            (Some(cover.format), cover.width, cover.height)
+        } else {
+            (None, None, None)
+        };
+
+        use crate::schema::work::dsl;
         let connection = context.db.get().unwrap();
-        let target = crate::schema::work::dsl::work.find(work_id);
-        let result = target.get_result::<Work>(&connection);
-        match diesel::delete(target).execute(&connection) {
-            Ok(c) => Ok(result.unwrap()),
-            Err(e) => Err(FieldError::from(e)),
-        }
+        let target = dsl::work.find(work_id);
+        let work = target.get_result::<Work>(&connection)?;
+        let previous_status = work.work_status.clone();
+
+        connection
+            .transaction(|| {
+                let updated = diesel::update(target)
+                    .set((
+                        dsl::cover_url.eq(Some(cover_url)),
+                        dsl::cover_caption.eq(cover_caption),
+                        dsl::cover_format.eq(cover_format),
+                        dsl::cover_width.eq(cover_width),
+                        dsl::cover_height.eq(cover_height),
+                    ))
+                    .get_result::<Work>(&connection)?;
+                let account_id = context.token.jwt.as_ref().unwrap().account_id(&context.db);
+                match NewWorkHistory::new(work, account_id).insert(&connection) {
+                    Ok(_) => Ok(updated),
+                    Err(e) => Err(FieldError::from(e)),
+                }
+            })
+            .map(|updated: Work| {
+                let events = WebhookEvent::for_work_update(&previous_status, &updated.work_status);
+                dispatch_work_events(context.db.clone(), &updated, events);
+                updated
+            })
     }
 
-    fn delete_publisher(context: &Context, publisher_id: Uuid) -> FieldResult<Publisher> {
+    #[graphql(
+        description = "Set the shortDOI of a work, checking that it is an alias of the work's DOI",
+        arguments(
+            work_id(description = "The work to set the shortDOI of"),
+            short_doi(description = "shortDOI as full URL, or null to remove it"),
+            verify(
+                default = true,
+                description = "Look up the shortDOI with the DOI resolver to check that it is an alias of the work's DOI. Skipped if the work has no DOI",
+            ),
+        )
+    )]
+    fn set_short_doi(
+        context: &Context,
+        work_id: Uuid,
+        short_doi: Option<String>,
+        verify: bool,
+    ) -> FieldResult<Work> {
         context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
-        context.account_access.can_edit(publisher_id)?;
+        user_can_edit_work(work_id, context)?;
 
+        use crate::schema::work::dsl;
         let connection = context.db.get().unwrap();
-        let target = crate::schema::publisher::dsl::publisher.find(publisher_id);
-        let result = target.get_result::<Publisher>(&connection);
-        match diesel::delete(target).execute(&connection) {
-            Ok(c) => Ok(result.unwrap()),
-            Err(e) => Err(FieldError::from(e)),
+        let target = dsl::work.find(work_id);
+        let work = target.get_result::<Work>(&connection)?;
+        if let Some(short_doi) = &short_doi {
+            let short_doi = short_doi.parse::<ShortDoi>()?;
+            if let (true, Some(doi)) = (verify, &work.doi) {
+                verify_short_doi(&short_doi, doi)?;
+            }
         }
+
+        connection.transaction(|| {
+            let updated = diesel::update(target)
+                .set(dsl::short_doi.eq(short_doi))
+                .get_result::<Work>(&connection)?;
+            let account_id = context.token.jwt.as_ref().unwrap().account_id(&context.db);
+            match NewWorkHistory::new(work, account_id).insert(&connection) {
+                Ok(_) => Ok(updated),
+                Err(e) => Err(FieldError::from(e)),
+            }
+        })
     }
 
-    fn delete_imprint(context: &Context, imprint_id: Uuid) -> FieldResult<Imprint> {
+    fn delete_work(context: &Context, work_id: Uuid) -> FieldResult<Work> {
         context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
-        let connection = context.db.get().unwrap();
-
-        let target = crate::schema::imprint::dsl::imprint.find(imprint_id);
-        let result = target.get_result::<Imprint>(&connection);
-        let imprint = result.unwrap();
-        context.account_access.can_edit(imprint.publisher_id)?;
+        user_can_edit_work(work_id, context)?;
 
+        let connection = context.db.get().unwrap();
+        let target = crate::schema::work::dsl::work.find(work_id);
+        let result = target.get_result::<Work>(&connection).map_err(load_error)?;
         match diesel::delete(target).execute(&connection) {
-            Ok(c) => Ok(imprint),
+            Ok(c) => Ok(result),
             Err(e) => Err(FieldError::from(e)),
         }
     }
 
-    fn delete_contributor(context: &Context, contributor_id: Uuid) -> FieldResult<Contributor> {
-        context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
-        let connection = context.db.get().unwrap();
+    #[graphql(
+        description = "Delete several works at once, in a single transaction: either all of them are deleted, or none is. When any work cannot be deleted, the reason is reported by its ID, and the other works are reported as not deleted",
+        arguments(
+            work_ids(description = "IDs of the works to delete"),
+            cascade(
+                default = false,
+                description = "Also delete the contributions, publications, series issues, fundings, languages and subjects of the works. Unless this is set, works that have any of these are not deleted"
+            ),
+        )
+    )]
+    fn delete_works(
+        context: &Context,
+        work_ids: Vec<Uuid>,
+        cascade: bool,
+    ) -> FieldResult<Vec<WorkDeletionResult>> {
+        context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
+
+        let connection = context.db.get().unwrap();
+        connection.transaction(|| {
+            let checks: Vec<(Uuid, Result<(), ThothError>)> = work_ids
+                .iter()
+                .map(|&work_id| {
+                    let check = work::table
+                        .find(work_id)
+                        .get_result::<Work>(&connection)
+                        .map_err(ThothError::from)
+                        .and_then(|_| {
+                            user_can_edit_work(work_id, context)
+                                .map_err(|_| ThothError::Unauthorised)
+                        })
+                        .and_then(|_| can_delete_work(work_id, cascade, &connection));
+                    (work_id, check)
+                })
+                .collect();
+            if checks.iter().any(|(_, check)| check.is_err()) {
+                return Ok(checks
+                    .into_iter()
+                    .map(|(work_id, check)| match check {
+                        Ok(()) => WorkDeletionResult::withheld(work_id),
+                        Err(e) => WorkDeletionResult::rejected(work_id, &e),
+                    })
+                    .collect());
+            }
+            diesel::delete(work::table.filter(work::work_id.eq_any(&work_ids)))
+                .execute(&connection)?;
+            Ok(work_ids
+                .into_iter()
+                .map(WorkDeletionResult::deleted)
+                .collect())
+        })
+    }
+
+    fn delete_publisher(context: &Context, publisher_id: Uuid) -> FieldResult<Publisher> {
+        context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
+        context
+            .account_access
+            .can_edit(publisher_id)
+            .map_err(access_error)?;
+
+        let connection = context.db.get().unwrap();
+        let target = crate::schema::publisher::dsl::publisher.find(publisher_id);
+        let result = target
+            .get_result::<Publisher>(&connection)
+            .map_err(load_error)?;
+        match diesel::delete(target).execute(&connection) {
+            Ok(c) => Ok(result),
+            Err(e) => Err(FieldError::from(e)),
+        }
+    }
+
+    #[graphql(
+        description = "Delete an imprint. An imprint that still has works cannot be deleted, unless they are first moved to another imprint with reassignTo",
+        arguments(
+            imprint_id(description = "Thoth ID of the imprint to delete"),
+            reassign_to(
+                description = "If set, the imprint's works and series are moved to the imprint with this ID, which must belong to the same publisher, before the imprint is deleted"
+            ),
+        )
+    )]
+    fn delete_imprint(
+        context: &Context,
+        imprint_id: Uuid,
+        reassign_to: Option<Uuid>,
+    ) -> FieldResult<Imprint> {
+        context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
+        let connection = context.db.get().unwrap();
+
+        let target = crate::schema::imprint::dsl::imprint.find(imprint_id);
+        let result = target.get_result::<Imprint>(&connection);
+        let imprint = result.map_err(load_error)?;
+        context
+            .account_access
+            .can_edit(imprint.publisher_id)
+            .map_err(access_error)?;
+
+        connection.transaction(|| {
+            if let Some(new_imprint_id) = reassign_to {
+                let new_imprint = crate::schema::imprint::dsl::imprint
+                    .find(new_imprint_id)
+                    .get_result::<Imprint>(&connection)
+                    .map_err(load_error)?;
+                if new_imprint_id == imprint_id || new_imprint.publisher_id != imprint.publisher_id
+                {
+                    return Err(ThothError::InvalidInput(
+                        "Works can only be moved to another imprint of the same publisher"
+                            .to_string(),
+                    )
+                    .into_field_error());
+                }
+                // series move along with the works, so that their issues stay under one imprint
+                diesel::update(work::table.filter(work::imprint_id.eq(imprint_id)))
+                    .set(work::imprint_id.eq(new_imprint_id))
+                    .execute(&connection)?;
+                diesel::update(series::table.filter(series::imprint_id.eq(imprint_id)))
+                    .set(series::imprint_id.eq(new_imprint_id))
+                    .execute(&connection)?;
+            }
+            can_delete_imprint(imprint_id, &connection).map_err(ThothError::into_field_error)?;
+            diesel::delete(target).execute(&connection)?;
+            Ok(imprint)
+        })
+    }
+
+    fn delete_contributor(context: &Context, contributor_id: Uuid) -> FieldResult<Contributor> {
+        context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
+        let connection = context.db.get().unwrap();
 
         let target = crate::schema::contributor::dsl::contributor.find(contributor_id);
-        let result = target.get_result::<Contributor>(&connection);
+        let result = target
+            .get_result::<Contributor>(&connection)
+            .map_err(load_error)?;
         match diesel::delete(target).execute(&connection) {
-            Ok(c) => Ok(result.unwrap()),
+            Ok(c) => Ok(result),
             Err(e) => Err(FieldError::from(e)),
         }
     }
@@ -2658,9 +4321,10 @@ impl MutationRoot {
             .filter(dsl::work_id.eq(&work_id))
             .filter(dsl::contributor_id.eq(&contributor_id))
             .filter(dsl::contribution_type.eq(&contribution_type))
-            .get_result::<Contribution>(&connection);
+            .get_result::<Contribution>(&connection)
+            .map_err(load_error)?;
         match diesel::delete(target).execute(&connection) {
-            Ok(c) => Ok(result.unwrap()),
+            Ok(c) => Ok(result),
             Err(e) => Err(FieldError::from(e)),
         }
     }
@@ -2671,10 +4335,12 @@ impl MutationRoot {
 
         let connection = context.db.get().unwrap();
         let target = crate::schema::publication::dsl::publication.find(publication_id);
-        let result = target.get_result::<Publication>(&connection);
+        let result = target
+            .get_result::<Publication>(&connection)
+            .map_err(load_error)?;
 
         match diesel::delete(target).execute(&connection) {
-            Ok(c) => Ok(result.unwrap()),
+            Ok(c) => Ok(result),
             Err(e) => Err(FieldError::from(e)),
         }
     }
@@ -2685,7 +4351,7 @@ impl MutationRoot {
 
         let target = crate::schema::series::dsl::series.find(series_id);
         let result = target.get_result::<Series>(&connection);
-        let series = result.unwrap();
+        let series = result.map_err(load_error)?;
         user_can_edit_imprint(series.imprint_id, context)?;
 
         match diesel::delete(target).execute(&connection) {
@@ -2707,9 +4373,10 @@ impl MutationRoot {
         let result = dsl::issue
             .filter(dsl::series_id.eq(&series_id))
             .filter(dsl::work_id.eq(&work_id))
-            .get_result::<Issue>(&connection);
+            .get_result::<Issue>(&connection)
+            .map_err(load_error)?;
         match diesel::delete(target).execute(&connection) {
-            Ok(c) => Ok(result.unwrap()),
+            Ok(c) => Ok(result),
             Err(e) => Err(FieldError::from(e)),
         }
     }
@@ -2720,7 +4387,7 @@ impl MutationRoot {
 
         let target = crate::schema::language::dsl::language.find(language_id);
         let result = target.get_result::<Language>(&connection);
-        let language = result.unwrap();
+        let language = result.map_err(load_error)?;
         user_can_edit_work(language.work_id, context)?;
 
         match diesel::delete(target).execute(&connection) {
@@ -2729,14 +4396,53 @@ impl MutationRoot {
         }
     }
 
+    fn delete_abstract(context: &Context, abstract_id: Uuid) -> FieldResult<Abstract> {
+        context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
+        let connection = context.db.get().unwrap();
+
+        let target = crate::schema::work_abstract::dsl::work_abstract.find(abstract_id);
+        let work_abstract = target.get_result::<Abstract>(&connection)?;
+        user_can_edit_work(work_abstract.work_id, context)?;
+
+        connection.transaction(|| {
+            diesel::delete(target).execute(&connection)?;
+            if work_abstract.is_default_language() {
+                sync_work_abstract_field(
+                    &connection,
+                    work_abstract.work_id,
+                    &work_abstract.abstract_type,
+                    None,
+                )?;
+            }
+            Ok(work_abstract)
+        })
+    }
+
+    fn delete_work_title(context: &Context, title_id: Uuid) -> FieldResult<WorkTitle> {
+        context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
+        let connection = context.db.get().unwrap();
+
+        let target = crate::schema::work_title::dsl::work_title.find(title_id);
+        let work_title = target.get_result::<WorkTitle>(&connection)?;
+        user_can_edit_work(work_title.work_id, context)?;
+        check_not_default_title(&work_title.language_code, &work_title.title_type)?;
+
+        match diesel::delete(target).execute(&connection) {
+            Ok(_) => Ok(work_title),
+            Err(e) => Err(FieldError::from(e)),
+        }
+    }
+
     fn delete_funder(context: &Context, funder_id: Uuid) -> FieldResult<Funder> {
         context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
         let connection = context.db.get().unwrap();
 
         let target = crate::schema::funder::dsl::funder.find(funder_id);
-        let result = target.get_result::<Funder>(&connection);
+        let result = target
+            .get_result::<Funder>(&connection)
+            .map_err(load_error)?;
         match diesel::delete(target).execute(&connection) {
-            Ok(c) => Ok(result.unwrap()),
+            Ok(c) => Ok(result),
             Err(e) => Err(FieldError::from(e)),
         }
     }
@@ -2747,7 +4453,7 @@ impl MutationRoot {
 
         let target = crate::schema::funding::dsl::funding.find(funding_id);
         let result = target.get_result::<Funding>(&connection);
-        let funding = result.unwrap();
+        let funding = result.map_err(load_error)?;
         user_can_edit_work(funding.work_id, context)?;
 
         match diesel::delete(target).execute(&connection) {
@@ -2762,7 +4468,7 @@ impl MutationRoot {
 
         let target = crate::schema::price::dsl::price.find(price_id);
         let result = target.get_result::<Price>(&connection);
-        let price = result.unwrap();
+        let price = result.map_err(load_error)?;
         user_can_edit_publication(price.publication_id, context)?;
 
         match diesel::delete(target).execute(&connection) {
@@ -2777,7 +4483,7 @@ impl MutationRoot {
 
         let target = crate::schema::subject::dsl::subject.find(subject_id);
         let result = target.get_result::<Subject>(&connection);
-        let subject = result.unwrap();
+        let subject = result.map_err(load_error)?;
         user_can_edit_work(subject.work_id, context)?;
 
         match diesel::delete(target).execute(&connection) {
@@ -2785,6 +4491,92 @@ impl MutationRoot {
             Err(e) => Err(FieldError::from(e)),
         }
     }
+
+    #[graphql(
+        description = "Exchange a valid, or recently expired, token for a new one with extended expiry",
+        arguments(token(description = "The last token issued to the account"))
+    )]
+    fn refresh_token(context: &Context, token: String) -> FieldResult<String> {
+        Token::refresh(&token, &context.db).map_err(FieldError::from)
+    }
+
+    #[graphql(
+        description = "Create a read-only API key scoped to a publisher",
+        arguments(
+            publisher_id(description = "The publisher whose records the key can read"),
+            label(description = "A name to identify the key's holder"),
+        )
+    )]
+    fn create_api_key(
+        context: &Context,
+        publisher_id: Uuid,
+        label: String,
+    ) -> FieldResult<IssuedApiKey> {
+        context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
+        context
+            .account_access
+            .can_admin(publisher_id)
+            .map_err(access_error)?;
+
+        let account_id = context.token.jwt.as_ref().unwrap().account_id(&context.db);
+        ApiKey::issue(publisher_id, account_id, label, &context.db).map_err(FieldError::from)
+    }
+
+    #[graphql(description = "Revoke an API key so that it can no longer be used")]
+    fn revoke_api_key(context: &Context, api_key_id: Uuid) -> FieldResult<ApiKey> {
+        context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
+        let connection = context.db.get().unwrap();
+
+        let target = crate::schema::api_key::dsl::api_key.find(api_key_id);
+        let api_key = target.get_result::<ApiKey>(&connection)?;
+        context
+            .account_access
+            .can_admin(api_key.publisher_id)
+            .map_err(access_error)?;
+
+        match diesel::delete(target).execute(&connection) {
+            Ok(_c) => Ok(api_key),
+            Err(e) => Err(FieldError::from(e)),
+        }
+    }
+
+    #[graphql(
+        description = "Register a URL to be notified, with a signed JSON payload, when a publisher's works change"
+    )]
+    fn create_webhook(context: &Context, data: NewWebhook) -> FieldResult<Webhook> {
+        context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
+        context
+            .account_access
+            .can_admin(data.publisher_id)
+            .map_err(access_error)?;
+        check_webhook_url(&data.url)?;
+
+        let connection = context.db.get().unwrap();
+        match diesel::insert_into(webhook::table)
+            .values(&data)
+            .get_result(&connection)
+        {
+            Ok(webhook) => Ok(webhook),
+            Err(e) => Err(FieldError::from(e)),
+        }
+    }
+
+    fn delete_webhook(context: &Context, webhook_id: Uuid) -> FieldResult<Webhook> {
+        context.token.jwt.as_ref().ok_or(ThothError::Unauthorised)?;
+        let connection = context.db.get().unwrap();
+
+        let target = crate::schema::webhook::dsl::webhook.find(webhook_id);
+        let webhook = target.get_result::<Webhook>(&connection)?;
+        context
+            .account_access
+            .can_admin(webhook.publisher_id)
+            .map_err(access_error)?;
+
+        match diesel::delete(target).execute(&connection) {
+            Ok(_c) => Ok(webhook),
+            Err(e) => Err(FieldError::from(e)),
+        }
+    }
 }
 
 #[juniper::object(Context = Context, description = "A written text that can be published")]
@@ -2832,6 +4624,13 @@ impl Work {
         self.doi.as_ref()
     }
 
+    #[graphql(
+        description = "shortDOI of the work as full URL (e.g. https://doi.org/cx9x), an alias of its DOI that is easier to read. Metadata deposits use the full DOI"
+    )]
+    pub fn short_doi(&self) -> Option<&String> {
+        self.short_doi.as_ref()
+    }
+
     pub fn publication_date(&self) -> Option<NaiveDate> {
         self.publication_date
     }
@@ -2884,6 +4683,27 @@ impl Work {
         self.landing_page.as_ref()
     }
 
+    #[graphql(
+        description = "The one URL to link to the work by: its landing page, or failing that the URL of its PDF publication (its canonical location), or failing that its DOI. Null only if the work has none of the three"
+    )]
+    pub fn canonical_url(&self, context: &Context) -> FieldResult<Option<String>> {
+        if let Some(landing_page) = &self.landing_page {
+            return Ok(Some(landing_page.clone()));
+        }
+        let connection = context.db.get().unwrap();
+        let canonical_location = publication::table
+            .select(publication::publication_url)
+            .filter(publication::work_id.eq(self.work_id))
+            .filter(publication::publication_type.eq(PublicationType::PDF))
+            .filter(publication::publication_url.is_not_null())
+            .order(publication::created_at.asc())
+            .first::<Option<String>>(&connection)
+            .optional()
+            .map_err(FieldError::from)?
+            .flatten();
+        Ok(canonical_location.or_else(|| self.doi.clone()))
+    }
+
     pub fn lccn(&self) -> Option<&String> {
         self.lccn.as_ref()
     }
@@ -2916,6 +4736,21 @@ impl Work {
         self.cover_caption.as_ref()
     }
 
+    #[graphql(description = "Media type of the cover image, if checked when it was set")]
+    pub fn cover_format(&self) -> Option<&String> {
+        self.cover_format.as_ref()
+    }
+
+    #[graphql(description = "Width of the cover image in pixels, if checked when it was set")]
+    pub fn cover_width(&self) -> Option<i32> {
+        self.cover_width
+    }
+
+    #[graphql(description = "Height of the cover image in pixels, if checked when it was set")]
+    pub fn cover_height(&self) -> Option<i32> {
+        self.cover_height
+    }
+
     pub fn created_at(&self) -> NaiveDateTime {
         self.created_at
     }
@@ -2924,6 +4759,47 @@ impl Work {
         self.updated_at
     }
 
+    #[graphql(
+        description = "Version of the record, which changes on every update. Pass it as expectedVersion when updating the record to have the update rejected if the record has changed since"
+    )]
+    pub fn version(&self) -> String {
+        record_version(self.updated_at)
+    }
+
+    #[graphql(
+        description = "How complete the record is, from 0 to 100, weighing up DOI, abstract, cover, license, contributions, subjects and priced or free publications"
+    )]
+    pub fn completeness_score(&self, context: &Context) -> FieldResult<i32> {
+        Ok(completeness_score(&work_missing_fields(self, context)?))
+    }
+
+    #[graphql(
+        description = "Parts of the record that count towards its completeness score and are not filled in"
+    )]
+    pub fn missing_fields(&self, context: &Context) -> FieldResult<Vec<String>> {
+        Ok(work_missing_fields(self, context)?
+            .iter()
+            .map(|field| field.to_string())
+            .collect())
+    }
+
+    #[graphql(
+        description = "Likely problems with the record that did not stop it being saved, e.g. a short abstract or a missing cover. Request this with the result of createWork or updateWork to review them after saving"
+    )]
+    pub fn warnings(&self) -> Vec<String> {
+        self.advisories(chrono::Utc::today().naive_utc())
+    }
+
+    #[graphql(
+        description = "The completeness score, missing fields and warnings of the record together, so that an editor can review them in one request. Like them, it is only worked out when requested"
+    )]
+    pub fn validation(&self, context: &Context) -> FieldResult<WorkValidation> {
+        Ok(WorkValidation::new(
+            work_missing_fields(self, context)?,
+            self.advisories(chrono::Utc::today().naive_utc()),
+        ))
+    }
+
     pub fn imprint(&self, context: &Context) -> Imprint {
         use crate::schema::imprint::dsl::*;
         let connection = context.db.get().unwrap();
@@ -2942,6 +4818,70 @@ impl Work {
             .expect("Error loading contributions")
     }
 
+    #[graphql(
+        description = "Main contributions to the work, in a stable order: earliest added first, ties broken by full name. The first is the work's primary contribution"
+    )]
+    pub fn main_contributions(&self, context: &Context) -> Vec<Contribution> {
+        use crate::schema::contribution::dsl::*;
+        let connection = context.db.get().unwrap();
+        contribution
+            .filter(work_id.eq(self.work_id))
+            .filter(main_contribution.eq(true))
+            .order((created_at.asc(), full_name.asc()))
+            .load::<Contribution>(&connection)
+            .expect("Error loading contributions")
+    }
+
+    #[graphql(
+        description = "Citation of the work formatted in the given style, built from its main authors (or main editors if it has none), title, edition, place, publisher, year of publication and DOI",
+        arguments(style(description = "Style in which to format the citation"))
+    )]
+    pub fn citation(&self, context: &Context, style: CitationStyle) -> FieldResult<String> {
+        let connection = context.db.get().unwrap();
+        let main_contributions = contribution::table
+            .filter(contribution::work_id.eq(self.work_id))
+            .filter(contribution::main_contribution.eq(true))
+            .order((
+                contribution::created_at.asc(),
+                contribution::full_name.asc(),
+            ))
+            .load::<Contribution>(&connection)
+            .map_err(FieldError::from)?;
+        let of_type = |contribution_type: ContributionType| -> Vec<CitedName> {
+            main_contributions
+                .iter()
+                .filter(|c| c.contribution_type == contribution_type)
+                .map(|c| CitedName {
+                    first_name: c.first_name.clone(),
+                    last_name: c.last_name.clone(),
+                })
+                .collect()
+        };
+        let authors = of_type(ContributionType::Author);
+        let editors = authors.is_empty();
+        let publisher_name = imprint::table
+            .inner_join(publisher::table)
+            .filter(imprint::imprint_id.eq(self.imprint_id))
+            .select(publisher::publisher_name)
+            .first::<String>(&connection)
+            .map_err(FieldError::from)?;
+        let citation = Citation {
+            creators: if editors {
+                of_type(ContributionType::Editor)
+            } else {
+                authors
+            },
+            editors,
+            full_title: self.full_title.clone(),
+            edition: self.edition,
+            place: self.place.clone(),
+            publisher: publisher_name,
+            year: self.publication_date.map(|date| date.year()),
+            doi: self.doi.clone(),
+        };
+        Ok(citation.format(style))
+    }
+
     pub fn languages(&self, context: &Context) -> Vec<Language> {
         use crate::schema::language::dsl::*;
         let connection = context.db.get().unwrap();
@@ -2951,6 +4891,51 @@ impl Work {
             .expect("Error loading languages")
     }
 
+    #[graphql(
+        description = "Languages the work is translated into: those of its languages related to it as TRANSLATED_INTO. Thoth does not link translations to the works they translate; a translation is recorded as a work of its own, whose languages say what it was translated from and into"
+    )]
+    pub fn translations(&self, context: &Context) -> FieldResult<Vec<Language>> {
+        work_translations(self.work_id, context)
+    }
+
+    #[graphql(
+        description = "Number of distinct languages the work is translated into (see translations), 0 if it is not a translation"
+    )]
+    pub fn translation_count(&self, context: &Context) -> FieldResult<i32> {
+        let mut codes: Vec<LanguageCode> = work_translations(self.work_id, context)?
+            .into_iter()
+            .map(|translation| translation.language_code)
+            .collect();
+        codes.dedup();
+        Ok(codes.len() as i32)
+    }
+
+    #[graphql(
+        description = "Titles of the work in every language and role it has them in. The English distinctive title is also given by title and subtitle"
+    )]
+    pub fn titles(&self, context: &Context) -> Vec<WorkTitle> {
+        use crate::schema::work_title::dsl::*;
+        let connection = context.db.get().unwrap();
+        work_title
+            .filter(work_id.eq(self.work_id))
+            .order((title_type.asc(), language_code.asc()))
+            .load::<WorkTitle>(&connection)
+            .expect("Error loading titles")
+    }
+
+    #[graphql(
+        description = "Abstracts of the work in every language it has them in. The English ones are also given by shortAbstract and longAbstract"
+    )]
+    pub fn abstracts(&self, context: &Context) -> Vec<Abstract> {
+        use crate::schema::work_abstract::dsl::*;
+        let connection = context.db.get().unwrap();
+        work_abstract
+            .filter(work_id.eq(self.work_id))
+            .order((language_code.asc(), abstract_type.asc()))
+            .load::<Abstract>(&connection)
+            .expect("Error loading abstracts")
+    }
+
     #[graphql(
         description = "Get publications linked to this work",
         arguments(
@@ -2970,7 +4955,8 @@ impl Work {
         offset: i32,
         filter: String,
         publication_type: Option<PublicationType>,
-    ) -> Vec<Publication> {
+    ) -> FieldResult<Vec<Publication>> {
+        let (limit, offset) = page_bounds(limit, offset, *MAX_LIMIT)?;
         let connection = context.db.get().unwrap();
         use crate::schema::publication::dsl;
         let mut query = dsl::publication
@@ -2983,16 +4969,41 @@ impl Work {
         if !filter.is_empty() {
             query = query.filter(
                 dsl::isbn
-                    .ilike(format!("%{}%", filter))
-                    .or(dsl::publication_url.ilike(format!("%{}%", filter))),
+                    .ilike(contains_pattern(&filter))
+                    .or(dsl::publication_url.ilike(contains_pattern(&filter))),
             );
         }
         query
             .order(dsl::publication_type.asc())
-            .limit(limit.into())
-            .offset(offset.into())
+            .limit(limit)
+            .offset(offset)
             .load::<Publication>(&connection)
-            .expect("Error loading publications")
+            .map_err(FieldError::from)
+    }
+
+    #[graphql(
+        description = "Get the ISBNs of all of this work's publications, in the order of their publication types"
+    )]
+    pub fn isbns(&self, context: &Context) -> FieldResult<Vec<WorkIsbn>> {
+        use crate::schema::publication::dsl;
+        let connection = context.db.get().unwrap();
+        // A single query for the two columns, rather than loading every publication
+        let isbns = dsl::publication
+            .select((dsl::isbn, dsl::publication_type))
+            .filter(dsl::work_id.eq(self.work_id))
+            .filter(dsl::isbn.is_not_null())
+            .order(dsl::publication_type.asc())
+            .load::<(Option<String>, PublicationType)>(&connection)
+            .map_err(FieldError::from)?;
+        Ok(isbns
+            .into_iter()
+            .filter_map(|(isbn, publication_type)| {
+                Some(WorkIsbn {
+                    isbn: isbn?,
+                    publication_type,
+                })
+            })
+            .collect())
     }
 
     #[graphql(
@@ -3012,18 +5023,32 @@ impl Work {
         limit: i32,
         offset: i32,
         filter: String,
-    ) -> Vec<Subject> {
+    ) -> FieldResult<Vec<Subject>> {
+        let (limit, offset) = page_bounds(limit, offset, *MAX_LIMIT)?;
         use crate::schema::subject::dsl::*;
         let connection = context.db.get().unwrap();
         subject
             .filter(work_id.eq(self.work_id))
-            .filter(subject_code.ilike(format!("%{}%", filter)))
+            .filter(subject_code.ilike(contains_pattern(&filter)))
             .order(subject_type.asc())
             .then_order_by(subject_code.asc())
-            .limit(limit.into())
-            .offset(offset.into())
+            .limit(limit)
+            .offset(offset)
+            .load::<Subject>(&connection)
+            .map_err(FieldError::from)
+    }
+
+    #[graphql(
+        description = "Get the main subject of each scheme the work has subjects in: the subject with the lowest ordinal, ties going to the lowest code"
+    )]
+    pub fn main_subjects(&self, context: &Context) -> FieldResult<Vec<Subject>> {
+        use crate::schema::subject::dsl::*;
+        let connection = context.db.get().unwrap();
+        subject
+            .filter(work_id.eq(self.work_id))
             .load::<Subject>(&connection)
-            .expect("Error loading subjects")
+            .map(main_subjects)
+            .map_err(FieldError::from)
     }
 
     pub fn fundings(&self, context: &Context) -> Vec<Funding> {
@@ -3067,6 +5092,53 @@ impl Publication {
         self.publication_url.as_ref()
     }
 
+    #[graphql(description = "Width in millimetres. Only applicable to physical publications")]
+    pub fn width_mm(&self) -> Option<f64> {
+        self.width_mm
+    }
+
+    #[graphql(description = "Width in inches. Only applicable to physical publications")]
+    pub fn width_in(&self) -> Option<f64> {
+        self.width_in
+    }
+
+    #[graphql(description = "Height in millimetres. Only applicable to physical publications")]
+    pub fn height_mm(&self) -> Option<f64> {
+        self.height_mm
+    }
+
+    #[graphql(description = "Height in inches. Only applicable to physical publications")]
+    pub fn height_in(&self) -> Option<f64> {
+        self.height_in
+    }
+
+    #[graphql(description = "Depth in millimetres. Only applicable to physical publications")]
+    pub fn depth_mm(&self) -> Option<f64> {
+        self.depth_mm
+    }
+
+    #[graphql(description = "Depth in inches. Only applicable to physical publications")]
+    pub fn depth_in(&self) -> Option<f64> {
+        self.depth_in
+    }
+
+    #[graphql(description = "Weight in grams. Only applicable to physical publications")]
+    pub fn weight_g(&self) -> Option<f64> {
+        self.weight_g
+    }
+
+    #[graphql(description = "Weight in ounces. Only applicable to physical publications")]
+    pub fn weight_oz(&self) -> Option<f64> {
+        self.weight_oz
+    }
+
+    #[graphql(
+        description = "Why the publication has no price, e.g. FREE_OF_CHARGE for open access editions"
+    )]
+    pub fn unpriced_item_type(&self) -> Option<&UnpricedItemType> {
+        self.unpriced_item_type.as_ref()
+    }
+
     pub fn created_at(&self) -> NaiveDateTime {
         self.created_at
     }
@@ -3075,6 +5147,13 @@ impl Publication {
         self.updated_at
     }
 
+    #[graphql(
+        description = "Version of the record, which changes on every update. Pass it as expectedVersion when updating the record to have the update rejected if the record has changed since"
+    )]
+    pub fn version(&self) -> String {
+        record_version(self.updated_at)
+    }
+
     pub fn prices(&self, context: &Context) -> Vec<Price> {
         use crate::schema::price::dsl::*;
         let connection = context.db.get().unwrap();
@@ -3119,6 +5198,13 @@ impl Publisher {
         self.updated_at
     }
 
+    #[graphql(
+        description = "Version of the record, which changes on every update. Pass it as expectedVersion when updating the record to have the update rejected if the record has changed since"
+    )]
+    pub fn version(&self) -> String {
+        record_version(self.updated_at)
+    }
+
     pub fn imprints(&self, context: &Context) -> Vec<Imprint> {
         use crate::schema::imprint::dsl::*;
         let connection = context.db.get().unwrap();
@@ -3127,6 +5213,24 @@ impl Publisher {
             .load::<Imprint>(&connection)
             .expect("Error loading imprints")
     }
+
+    #[graphql(
+        description = "Thoth ID of the imprint preselected for the publisher's new works, if any"
+    )]
+    pub fn default_imprint_id(&self) -> Option<Uuid> {
+        self.default_imprint_id
+    }
+
+    #[graphql(description = "The imprint preselected for the publisher's new works, if any")]
+    pub fn default_imprint(&self, context: &Context) -> Option<Imprint> {
+        let connection = context.db.get().unwrap();
+        self.default_imprint_id.map(|default_imprint_id| {
+            crate::schema::imprint::dsl::imprint
+                .find(default_imprint_id)
+                .get_result::<Imprint>(&connection)
+                .expect("Error loading default imprint")
+        })
+    }
 }
 
 #[juniper::object(Context = Context, description = "The brand under which a publisher issues works.")]
@@ -3151,6 +5255,13 @@ impl Imprint {
         self.updated_at
     }
 
+    #[graphql(
+        description = "Version of the record, which changes on every update. Pass it as expectedVersion when updating the record to have the update rejected if the record has changed since"
+    )]
+    pub fn version(&self) -> String {
+        record_version(self.updated_at)
+    }
+
     pub fn publisher(&self, context: &Context) -> Publisher {
         use crate::schema::publisher::dsl::*;
         let connection = context.db.get().unwrap();
@@ -3203,14 +5314,51 @@ impl Contributor {
         self.updated_at
     }
 
+    #[graphql(
+        description = "Version of the record, which changes on every update. Pass it as expectedVersion when updating the record to have the update rejected if the record has changed since"
+    )]
+    pub fn version(&self) -> String {
+        record_version(self.updated_at)
+    }
+
     pub fn contributions(&self, context: &Context) -> Vec<Contribution> {
-        use crate::schema::contribution::dsl::*;
         let connection = context.db.get().unwrap();
-        contribution
-            .filter(contributor_id.eq(self.contributor_id))
+        contributor_contributions(self.contributor_id, context.scoped_publishers(vec![]))
             .load::<Contribution>(&connection)
             .expect("Error loading contributions")
     }
+
+    #[graphql(
+        description = "Distinct subjects of the works this contributor has contributed to, most common first, with the number of those works that carry each",
+        arguments(subject_type(
+            description = "If set, only counts subjects of this type. Otherwise counts keywords and BISAC subjects"
+        ))
+    )]
+    pub fn subjects(
+        &self,
+        context: &Context,
+        subject_type: Option<SubjectType>,
+    ) -> FieldResult<Vec<SubjectCount>> {
+        use crate::schema::subject::dsl;
+        let connection = context.db.get().unwrap();
+        let subject_types = match subject_type {
+            Some(subject_type) => vec![subject_type],
+            None => vec![SubjectType::Keyword, SubjectType::Bisac],
+        };
+        let work_ids =
+            contributor_contributions(self.contributor_id, context.scoped_publishers(vec![]))
+                .select(crate::schema::contribution::work_id)
+                .distinct()
+                .load::<Uuid>(&connection)
+                .map_err(FieldError::from)?;
+        let subjects = dsl::subject
+            .select((dsl::work_id, dsl::subject_type, dsl::subject_code))
+            .filter(dsl::work_id.eq_any(work_ids))
+            .filter(dsl::subject_type.eq_any(subject_types))
+            .load::<(Uuid, SubjectType, String)>(&connection)
+            .map_err(FieldError::from)?;
+        Ok(count_subjects(subjects))
+    }
 }
 
 #[juniper::object(Context = Context, description = "A person's involvement in the production of a written text.")]
@@ -3247,6 +5395,13 @@ impl Contribution {
         self.updated_at
     }
 
+    #[graphql(
+        description = "Version of the record, which changes on every update. Pass it as expectedVersion when updating the record to have the update rejected if the record has changed since"
+    )]
+    pub fn version(&self) -> String {
+        record_version(self.updated_at)
+    }
+
     pub fn first_name(&self) -> Option<&String> {
         self.first_name.as_ref()
     }
@@ -3311,6 +5466,13 @@ impl Series {
         self.updated_at
     }
 
+    #[graphql(
+        description = "Version of the record, which changes on every update. Pass it as expectedVersion when updating the record to have the update rejected if the record has changed since"
+    )]
+    pub fn version(&self) -> String {
+        record_version(self.updated_at)
+    }
+
     pub fn imprint(&self, context: &Context) -> Imprint {
         use crate::schema::imprint::dsl::*;
         let connection = context.db.get().unwrap();
@@ -3352,6 +5514,13 @@ impl Issue {
         self.updated_at
     }
 
+    #[graphql(
+        description = "Version of the record, which changes on every update. Pass it as expectedVersion when updating the record to have the update rejected if the record has changed since"
+    )]
+    pub fn version(&self) -> String {
+        record_version(self.updated_at)
+    }
+
     pub fn series(&self, context: &Context) -> Series {
         use crate::schema::series::dsl::*;
         let connection = context.db.get().unwrap();
@@ -3384,7 +5553,12 @@ impl Language {
         &self.language_code
     }
 
-    pub fn language_relation(&self) -> &LanguageRelation {
+    #[graphql(description = "English name of the language")]
+    pub fn language_name(&self) -> &str {
+        self.language_code.name()
+    }
+
+    pub fn language_relation(&self) -> &LanguageRelation {
         &self.language_relation
     }
 
@@ -3400,6 +5574,109 @@ impl Language {
         self.updated_at
     }
 
+    #[graphql(
+        description = "Version of the record, which changes on every update. Pass it as expectedVersion when updating the record to have the update rejected if the record has changed since"
+    )]
+    pub fn version(&self) -> String {
+        record_version(self.updated_at)
+    }
+
+    pub fn work(&self, context: &Context) -> Work {
+        use crate::schema::work::dsl::*;
+        let connection = context.db.get().unwrap();
+        work.find(self.work_id)
+            .first(&connection)
+            .expect("Error loading work")
+    }
+}
+
+#[juniper::object(Context = Context, description = "Summary of a work in a given language.")]
+impl Abstract {
+    pub fn abstract_id(&self) -> Uuid {
+        self.abstract_id
+    }
+
+    pub fn work_id(&self) -> Uuid {
+        self.work_id
+    }
+
+    pub fn language_code(&self) -> &LanguageCode {
+        &self.language_code
+    }
+
+    pub fn abstract_type(&self) -> &AbstractType {
+        &self.abstract_type
+    }
+
+    pub fn text(&self) -> &String {
+        &self.text
+    }
+
+    pub fn created_at(&self) -> NaiveDateTime {
+        self.created_at
+    }
+
+    pub fn updated_at(&self) -> NaiveDateTime {
+        self.updated_at
+    }
+
+    #[graphql(
+        description = "Version of the record, which changes on every update. Pass it as expectedVersion when updating the record to have the update rejected if the record has changed since"
+    )]
+    pub fn version(&self) -> String {
+        record_version(self.updated_at)
+    }
+
+    pub fn work(&self, context: &Context) -> Work {
+        use crate::schema::work::dsl::*;
+        let connection = context.db.get().unwrap();
+        work.find(self.work_id)
+            .first(&connection)
+            .expect("Error loading work")
+    }
+}
+
+#[juniper::object(Context = Context, description = "A title of a work in a given language and role.")]
+impl WorkTitle {
+    pub fn title_id(&self) -> Uuid {
+        self.title_id
+    }
+
+    pub fn work_id(&self) -> Uuid {
+        self.work_id
+    }
+
+    pub fn language_code(&self) -> &LanguageCode {
+        &self.language_code
+    }
+
+    pub fn title_type(&self) -> &TitleType {
+        &self.title_type
+    }
+
+    pub fn title(&self) -> &String {
+        &self.title
+    }
+
+    pub fn subtitle(&self) -> Option<&String> {
+        self.subtitle.as_ref()
+    }
+
+    pub fn created_at(&self) -> NaiveDateTime {
+        self.created_at
+    }
+
+    pub fn updated_at(&self) -> NaiveDateTime {
+        self.updated_at
+    }
+
+    #[graphql(
+        description = "Version of the record, which changes on every update. Pass it as expectedVersion when updating the record to have the update rejected if the record has changed since"
+    )]
+    pub fn version(&self) -> String {
+        record_version(self.updated_at)
+    }
+
     pub fn work(&self, context: &Context) -> Work {
         use crate::schema::work::dsl::*;
         let connection = context.db.get().unwrap();
@@ -3427,6 +5704,31 @@ impl Price {
         self.unit_price
     }
 
+    #[graphql(
+        description = "Symbol of the price's currency, or its code if it has no widely used symbol"
+    )]
+    pub fn currency_symbol(&self) -> String {
+        self.currency_code.symbol()
+    }
+
+    #[graphql(
+        description = "Unit price with its currency symbol and the currency's decimal places (e.g. £9.99, ¥1000)"
+    )]
+    pub fn formatted_price(&self) -> String {
+        self.currency_code.format_amount(self.unit_price)
+    }
+
+    pub fn price_type(&self) -> &PriceType {
+        &self.price_type
+    }
+
+    #[graphql(
+        description = "Space-separated ISO 3166-1 country codes where the price applies, or WORLD"
+    )]
+    pub fn territory(&self) -> &String {
+        &self.territory
+    }
+
     pub fn created_at(&self) -> NaiveDateTime {
         self.created_at
     }
@@ -3435,6 +5737,13 @@ impl Price {
         self.updated_at
     }
 
+    #[graphql(
+        description = "Version of the record, which changes on every update. Pass it as expectedVersion when updating the record to have the update rejected if the record has changed since"
+    )]
+    pub fn version(&self) -> String {
+        record_version(self.updated_at)
+    }
+
     pub fn publication(&self, context: &Context) -> Publication {
         use crate::schema::publication::dsl::*;
         let connection = context.db.get().unwrap();
@@ -3475,6 +5784,13 @@ impl Subject {
         self.updated_at
     }
 
+    #[graphql(
+        description = "Version of the record, which changes on every update. Pass it as expectedVersion when updating the record to have the update rejected if the record has changed since"
+    )]
+    pub fn version(&self) -> String {
+        record_version(self.updated_at)
+    }
+
     pub fn work(&self, context: &Context) -> Work {
         use crate::schema::work::dsl::*;
         let connection = context.db.get().unwrap();
@@ -3506,11 +5822,16 @@ impl Funder {
         self.updated_at
     }
 
+    #[graphql(
+        description = "Version of the record, which changes on every update. Pass it as expectedVersion when updating the record to have the update rejected if the record has changed since"
+    )]
+    pub fn version(&self) -> String {
+        record_version(self.updated_at)
+    }
+
     pub fn fundings(&self, context: &Context) -> Vec<Funding> {
-        use crate::schema::funding::dsl::*;
         let connection = context.db.get().unwrap();
-        funding
-            .filter(funder_id.eq(self.funder_id))
+        funder_fundings(self.funder_id, context.scoped_publishers(vec![]))
             .load::<Funding>(&connection)
             .expect("Error loading fundings")
     }
@@ -3558,6 +5879,13 @@ impl Funding {
         self.updated_at
     }
 
+    #[graphql(
+        description = "Version of the record, which changes on every update. Pass it as expectedVersion when updating the record to have the update rejected if the record has changed since"
+    )]
+    pub fn version(&self) -> String {
+        record_version(self.updated_at)
+    }
+
     pub fn work(&self, context: &Context) -> Work {
         use crate::schema::work::dsl::*;
         let connection = context.db.get().unwrap();
@@ -3576,40 +5904,1083 @@ impl Funding {
     }
 }
 
+#[juniper::object(Context = Context, description = "A read-only key granting programmatic access to a publisher's records.")]
+impl ApiKey {
+    pub fn api_key_id(&self) -> Uuid {
+        self.api_key_id
+    }
+
+    pub fn publisher_id(&self) -> Uuid {
+        self.publisher_id
+    }
+
+    pub fn label(&self) -> &String {
+        &self.label
+    }
+
+    pub fn created_at(&self) -> NaiveDateTime {
+        self.created_at
+    }
+
+    pub fn updated_at(&self) -> NaiveDateTime {
+        self.updated_at
+    }
+
+    pub fn publisher(&self, context: &Context) -> Publisher {
+        use crate::schema::publisher::dsl::*;
+        let connection = context.db.get().unwrap();
+        publisher
+            .find(self.publisher_id)
+            .first(&connection)
+            .expect("Error loading publisher")
+    }
+}
+
+#[juniper::object(Context = Context, description = "A URL notified when a publisher's works are created, updated or published.")]
+impl Webhook {
+    pub fn webhook_id(&self) -> Uuid {
+        self.webhook_id
+    }
+
+    pub fn publisher_id(&self) -> Uuid {
+        self.publisher_id
+    }
+
+    pub fn url(&self) -> &String {
+        &self.url
+    }
+
+    #[graphql(description = "Events that trigger a notification to this webhook")]
+    pub fn event_types(&self) -> &Vec<WebhookEvent> {
+        &self.event_types
+    }
+
+    pub fn created_at(&self) -> NaiveDateTime {
+        self.created_at
+    }
+
+    pub fn updated_at(&self) -> NaiveDateTime {
+        self.updated_at
+    }
+
+    pub fn publisher(&self, context: &Context) -> Publisher {
+        use crate::schema::publisher::dsl::*;
+        let connection = context.db.get().unwrap();
+        publisher
+            .find(self.publisher_id)
+            .first(&connection)
+            .expect("Error loading publisher")
+    }
+
+    #[graphql(
+        description = "Most recent delivery attempts, newest first",
+        arguments(limit(default = 50, description = "The number of attempts to return"))
+    )]
+    pub fn deliveries(&self, context: &Context, limit: i32) -> FieldResult<Vec<WebhookDelivery>> {
+        let (limit, _) = page_bounds(limit, 0, *MAX_LIMIT)?;
+        use crate::schema::webhook_delivery::dsl;
+        let connection = context.db.get().unwrap();
+        dsl::webhook_delivery
+            .filter(dsl::webhook_id.eq(self.webhook_id))
+            .order(dsl::created_at.desc())
+            .limit(limit)
+            .load::<WebhookDelivery>(&connection)
+            .map_err(FieldError::from)
+    }
+}
+
+#[juniper::object(Context = Context, description = "A single attempt to notify a webhook of an event.")]
+impl WebhookDelivery {
+    pub fn webhook_delivery_id(&self) -> Uuid {
+        self.webhook_delivery_id
+    }
+
+    pub fn webhook_id(&self) -> Uuid {
+        self.webhook_id
+    }
+
+    pub fn event(&self) -> &WebhookEvent {
+        &self.event
+    }
+
+    pub fn work_id(&self) -> Uuid {
+        self.work_id
+    }
+
+    #[graphql(description = "Attempt number, starting at 1, for this event")]
+    pub fn attempt(&self) -> i32 {
+        self.attempt
+    }
+
+    #[graphql(description = "HTTP status returned by the receiver, if a response was received")]
+    pub fn status_code(&self) -> Option<i32> {
+        self.status_code
+    }
+
+    #[graphql(description = "Reason the attempt failed, if it did")]
+    pub fn error(&self) -> Option<&String> {
+        self.error.as_ref()
+    }
+
+    pub fn created_at(&self) -> NaiveDateTime {
+        self.created_at
+    }
+}
+
 pub type Schema = RootNode<'static, QueryRoot, MutationRoot>;
 
 pub fn create_schema() -> Schema {
     Schema::new(QueryRoot {}, MutationRoot {})
 }
 
-fn user_can_edit_imprint(imprint_id: Uuid, context: &Context) -> Result<()> {
+/// Narrow a count to an `Int`, for the `*Count` queries that predate `Count`. Counts beyond the
+/// largest `Int` are reported as that value rather than failing the query.
+pub fn saturating_count(count: i64) -> i32 {
+    i32::try_from(count).unwrap_or(i32::MAX)
+}
+
+// The `load_*_count` functions back both the `*Count` and the `*Total` queries, as resolvers
+// cannot call one another
+#[allow(clippy::too_many_arguments)]
+fn load_work_count(
+    context: &Context,
+    filter: String,
+    publishers: Vec<Uuid>,
+    work_type: Option<WorkType>,
+    work_status: Option<WorkStatus>,
+    work_statuses: Vec<WorkStatus>,
+    updated_at_after: Option<NaiveDateTime>,
+    updated_at_before: Option<NaiveDateTime>,
+    publication_year: Option<i32>,
+    subject_type: Option<SubjectType>,
+    subject_code: Option<String>,
+    contributor_orcid: Option<String>,
+    funder_id: Option<Uuid>,
+    license: Option<String>,
+) -> FieldResult<i64> {
+    let contributor_orcid = contributor_orcid_filter(contributor_orcid)?;
+    let publication_dates = publication_year_bounds(publication_year)?;
+    use crate::schema::work::dsl;
+    let connection = context.db.get().unwrap();
+    let mut query = dsl::work
+        .inner_join(crate::schema::imprint::table)
+        .select((
+            dsl::work_id,
+            dsl::work_type,
+            dsl::work_status,
+            dsl::full_title,
+            dsl::title,
+            dsl::subtitle,
+            dsl::reference,
+            dsl::edition,
+            dsl::imprint_id,
+            dsl::doi,
+            dsl::publication_date,
+            dsl::place,
+            dsl::width,
+            dsl::height,
+            dsl::page_count,
+            dsl::page_breakdown,
+            dsl::image_count,
+            dsl::table_count,
+            dsl::audio_count,
+            dsl::video_count,
+            dsl::license,
+            dsl::copyright_holder,
+            dsl::landing_page,
+            dsl::lccn,
+            dsl::oclc,
+            dsl::short_abstract,
+            dsl::long_abstract,
+            dsl::general_note,
+            dsl::toc,
+            dsl::cover_url,
+            dsl::cover_caption,
+            dsl::created_at,
+            dsl::updated_at,
+            dsl::cover_format,
+            dsl::cover_width,
+            dsl::cover_height,
+            dsl::short_doi,
+        ))
+        .into_boxed();
+    // Ordering and construction of filters is important here: result needs to be
+    // `WHERE (x = $1 [OR x = $2...]) AND (y ILIKE $3 [OR z ILIKE $3...])`.
+    // Interchanging .filter, .or, and .or_filter would result in different bracketing.
+    for pub_id in context.scoped_publishers(publishers) {
+        query = query.or_filter(crate::schema::imprint::publisher_id.eq(pub_id));
+    }
+    if let Some(wk_type) = work_type {
+        query = query.filter(dsl::work_type.eq(wk_type))
+    }
+    if let Some(wk_status) = work_status {
+        query = query.filter(dsl::work_status.eq(wk_status))
+    }
+    if !work_statuses.is_empty() {
+        query = query.filter(dsl::work_status.eq_any(work_statuses))
+    }
+    if let Some(after) = updated_at_after {
+        query = query.filter(dsl::updated_at.ge(after))
+    }
+    if let Some(before) = updated_at_before {
+        query = query.filter(dsl::updated_at.lt(before))
+    }
+    if let Some((first_day, next_year)) = publication_dates {
+        query = query
+            .filter(dsl::publication_date.ge(first_day))
+            .filter(dsl::publication_date.lt(next_year))
+    }
+    if let Some(subject_work_ids) = subject_work_ids(subject_type, subject_code) {
+        query = query.filter(dsl::work_id.eq_any(subject_work_ids))
+    }
+    if let Some(orcid) = contributor_orcid {
+        query = query.filter(dsl::work_id.eq_any(orcid_work_ids(&orcid)))
+    }
+    if let Some(funder) = funder_id {
+        // a subquery rather than a join, so that works with several grants from the funder
+        // are listed once
+        query = query.filter(
+            dsl::work_id.eq_any(
+                funding::table
+                    .select(funding::work_id)
+                    .filter(funding::funder_id.eq(funder)),
+            ),
+        )
+    }
+    if let Some(license) = license {
+        query = query.filter(dsl::work_id.eq_any(license_work_ids(&license)))
+    }
+    query
+        .filter(
+            dsl::full_title
+                .ilike(contains_pattern(&filter))
+                .or(dsl::doi.ilike(contains_pattern(&filter)))
+                .or(dsl::reference.ilike(contains_pattern(&filter)))
+                .or(dsl::short_abstract.ilike(contains_pattern(&filter)))
+                .or(dsl::long_abstract.ilike(contains_pattern(&filter)))
+                .or(dsl::landing_page.ilike(contains_pattern(&filter))),
+        )
+        .count()
+        .get_result::<i64>(&connection)
+        .map_err(FieldError::from)
+}
+
+fn load_publication_count(
+    context: &Context,
+    filter: String,
+    publishers: Vec<Uuid>,
+    publication_type: Option<PublicationType>,
+    has_isbn: Option<bool>,
+    created_at_after: Option<NaiveDateTime>,
+    updated_at_after: Option<NaiveDateTime>,
+) -> i64 {
+    use crate::schema::publication::dsl;
+    let connection = context.db.get().unwrap();
+    let mut query = dsl::publication
+        .inner_join(crate::schema::work::table.inner_join(crate::schema::imprint::table))
+        .select((
+            dsl::publication_id,
+            dsl::publication_type,
+            dsl::work_id,
+            dsl::isbn,
+            dsl::publication_url,
+            dsl::created_at,
+            dsl::updated_at,
+            dsl::width_mm,
+            dsl::width_in,
+            dsl::height_mm,
+            dsl::height_in,
+            dsl::depth_mm,
+            dsl::depth_in,
+            dsl::weight_g,
+            dsl::weight_oz,
+            dsl::unpriced_item_type,
+        ))
+        .into_boxed();
+    // Ordering and construction of filters is important here: result needs to be
+    // `WHERE (x = $1 [OR x = $2...]) AND (y ILIKE $3 [OR z ILIKE $3...])`.
+    // Interchanging .filter, .or, and .or_filter would result in different bracketing.
+    for pub_id in context.scoped_publishers(publishers) {
+        query = query.or_filter(crate::schema::imprint::publisher_id.eq(pub_id));
+    }
+    // ISBN and URL fields are both nullable, so searching with an empty filter could fail
+    if !filter.is_empty() {
+        query = query.filter(
+            dsl::isbn
+                .ilike(contains_pattern(&filter))
+                .or(dsl::publication_url.ilike(contains_pattern(&filter))),
+        );
+    }
+    if let Some(pub_type) = publication_type {
+        query = query.filter(dsl::publication_type.eq(pub_type))
+    }
+    match has_isbn {
+        Some(true) => query = query.filter(dsl::isbn.is_not_null()),
+        Some(false) => query = query.filter(dsl::isbn.is_null()),
+        None => {}
+    }
+    if let Some(after) = created_at_after {
+        query = query.filter(dsl::created_at.ge(after))
+    }
+    if let Some(after) = updated_at_after {
+        query = query.filter(dsl::updated_at.ge(after))
+    }
+    // see comment in work_count()
+    query
+        .count()
+        .get_result::<i64>(&connection)
+        .expect("Error loading publication count")
+}
+
+fn load_publisher_count(
+    context: &Context,
+    filter: String,
+    publishers: Vec<Uuid>,
+    created_at_after: Option<NaiveDateTime>,
+    updated_at_after: Option<NaiveDateTime>,
+) -> i64 {
+    use crate::schema::publisher::dsl::*;
+    let connection = context.db.get().unwrap();
+    let mut query = publisher.into_boxed();
+    // Ordering and construction of filters is important here: result needs to be
+    // `WHERE (x = $1 [OR x = $2...]) AND (y ILIKE $3 [OR z ILIKE $3...])`.
+    // Interchanging .filter, .or, and .or_filter would result in different bracketing.
+    for pub_id in context.scoped_publishers(publishers) {
+        query = query.or_filter(publisher_id.eq(pub_id));
+    }
+    if let Some(after) = created_at_after {
+        query = query.filter(created_at.ge(after))
+    }
+    if let Some(after) = updated_at_after {
+        query = query.filter(updated_at.ge(after))
+    }
+    // see comment in work_count()
+    query
+        .filter(
+            publisher_name
+                .ilike(contains_pattern(&filter))
+                .or(publisher_shortname.ilike(contains_pattern(&filter))),
+        )
+        .count()
+        .get_result::<i64>(&connection)
+        .expect("Error loading publisher count")
+}
+
+fn load_imprint_count(context: &Context, filter: String, publishers: Vec<Uuid>) -> i64 {
+    use crate::schema::imprint::dsl::*;
+    let connection = context.db.get().unwrap();
+    let mut query = imprint.into_boxed();
+    // Ordering and construction of filters is important here: result needs to be
+    // `WHERE (x = $1 [OR x = $2...]) AND (y ILIKE $3 [OR z ILIKE $3...])`.
+    // Interchanging .filter, .or, and .or_filter would result in different bracketing.
+    for pub_id in context.scoped_publishers(publishers) {
+        query = query.or_filter(publisher_id.eq(pub_id));
+    }
+    // see comment in work_count()
+    query
+        .filter(
+            imprint_name
+                .ilike(contains_pattern(&filter))
+                .or(imprint_url.ilike(contains_pattern(&filter))),
+        )
+        .count()
+        .get_result::<i64>(&connection)
+        .expect("Error loading imprint count")
+}
+
+fn load_contributor_count(
+    context: &Context,
+    filter: String,
+    created_at_after: Option<NaiveDateTime>,
+    updated_at_after: Option<NaiveDateTime>,
+) -> i64 {
+    use crate::schema::contributor::dsl::*;
+    let connection = context.db.get().unwrap();
+    let mut query = contributor.into_boxed();
+    if let Some(after) = created_at_after {
+        query = query.filter(created_at.ge(after))
+    }
+    if let Some(after) = updated_at_after {
+        query = query.filter(updated_at.ge(after))
+    }
+    // see comment in work_count()
+    query
+        .filter(
+            full_name
+                .ilike(contains_pattern(&filter))
+                .or(orcid.ilike(contains_pattern(&filter))),
+        )
+        .count()
+        .get_result::<i64>(&connection)
+        .expect("Error loading contributor count")
+}
+
+fn load_contribution_count(context: &Context, contribution_type: Option<ContributionType>) -> i64 {
+    use crate::schema::contribution::dsl;
+    let connection = context.db.get().unwrap();
+    let mut query = dsl::contribution
+        .inner_join(crate::schema::work::table.inner_join(crate::schema::imprint::table))
+        .into_boxed();
+    if let Some(cont_type) = contribution_type {
+        query = query.filter(dsl::contribution_type.eq(cont_type))
+    }
+    // only count the rows an API key can list
+    let publishers = context.scoped_publishers(vec![]);
+    if !publishers.is_empty() {
+        query = query.filter(crate::schema::imprint::publisher_id.eq_any(publishers));
+    }
+    // see comment in work_count()
+    query
+        .count()
+        .get_result::<i64>(&connection)
+        .expect("Error loading contribution count")
+}
+
+fn load_series_count(
+    context: &Context,
+    filter: String,
+    publishers: Vec<Uuid>,
+    series_type: Option<SeriesType>,
+    created_at_after: Option<NaiveDateTime>,
+    updated_at_after: Option<NaiveDateTime>,
+) -> i64 {
+    use crate::schema::series::dsl;
+    let connection = context.db.get().unwrap();
+    let mut query = dsl::series
+        .inner_join(crate::schema::imprint::table)
+        .select((
+            dsl::series_id,
+            dsl::series_type,
+            dsl::series_name,
+            dsl::issn_print,
+            dsl::issn_digital,
+            dsl::series_url,
+            dsl::imprint_id,
+            dsl::created_at,
+            dsl::updated_at,
+        ))
+        .into_boxed();
+    // Ordering and construction of filters is important here: result needs to be
+    // `WHERE (x = $1 [OR x = $2...]) AND (y ILIKE $3 [OR z ILIKE $3...])`.
+    // Interchanging .filter, .or, and .or_filter would result in different bracketing.
+    for pub_id in context.scoped_publishers(publishers) {
+        query = query.or_filter(crate::schema::imprint::publisher_id.eq(pub_id));
+    }
+    if let Some(ser_type) = series_type {
+        query = query.filter(dsl::series_type.eq(ser_type))
+    }
+    if let Some(after) = created_at_after {
+        query = query.filter(dsl::created_at.ge(after))
+    }
+    if let Some(after) = updated_at_after {
+        query = query.filter(dsl::updated_at.ge(after))
+    }
+    // see comment in work_count()
+    query
+        .filter(
+            dsl::series_name
+                .ilike(contains_pattern(&filter))
+                .or(dsl::issn_print.ilike(contains_pattern(&filter)))
+                .or(dsl::issn_digital.ilike(contains_pattern(&filter)))
+                .or(dsl::series_url.ilike(contains_pattern(&filter))),
+        )
+        .count()
+        .get_result::<i64>(&connection)
+        .expect("Error loading series count")
+}
+
+fn load_issue_count(context: &Context) -> i64 {
+    use crate::schema::issue::dsl::*;
+    let connection = context.db.get().unwrap();
+    let mut query = issue
+        .inner_join(crate::schema::series::table.inner_join(crate::schema::imprint::table))
+        .into_boxed();
+    // only count the rows an API key can list
+    let publishers = context.scoped_publishers(vec![]);
+    if !publishers.is_empty() {
+        query = query.filter(crate::schema::imprint::publisher_id.eq_any(publishers));
+    }
+    // see comment in work_count()
+    query
+        .count()
+        .get_result::<i64>(&connection)
+        .expect("Error loading issue count")
+}
+
+fn load_language_count(
+    context: &Context,
+    language_code: Option<LanguageCode>,
+    language_relation: Option<LanguageRelation>,
+) -> i64 {
+    use crate::schema::language::dsl;
+    let connection = context.db.get().unwrap();
+    let mut query = dsl::language
+        .inner_join(crate::schema::work::table.inner_join(crate::schema::imprint::table))
+        .into_boxed();
+    if let Some(lang_code) = language_code {
+        query = query.filter(dsl::language_code.eq(lang_code))
+    }
+    if let Some(lang_relation) = language_relation {
+        query = query.filter(dsl::language_relation.eq(lang_relation))
+    }
+    // only count the rows an API key can list
+    let publishers = context.scoped_publishers(vec![]);
+    if !publishers.is_empty() {
+        query = query.filter(crate::schema::imprint::publisher_id.eq_any(publishers));
+    }
+    // see comment in work_count()
+    query
+        .count()
+        .get_result::<i64>(&connection)
+        .expect("Error loading language count")
+}
+
+fn load_price_count(context: &Context, currency_code: Option<CurrencyCode>) -> i64 {
+    use crate::schema::price::dsl;
+    let connection = context.db.get().unwrap();
+    let mut query = dsl::price
+        .inner_join(
+            crate::schema::publication::table
+                .inner_join(crate::schema::work::table.inner_join(crate::schema::imprint::table)),
+        )
+        .into_boxed();
+    if let Some(curr_code) = currency_code {
+        query = query.filter(dsl::currency_code.eq(curr_code))
+    }
+    // only count the rows an API key can list
+    let publishers = context.scoped_publishers(vec![]);
+    if !publishers.is_empty() {
+        query = query.filter(crate::schema::imprint::publisher_id.eq_any(publishers));
+    }
+    // see comment in work_count()
+    query
+        .count()
+        .get_result::<i64>(&connection)
+        .expect("Error loading price count")
+}
+
+fn load_subject_count(context: &Context, subject_type: Option<SubjectType>) -> i64 {
+    use crate::schema::subject::dsl;
+    let connection = context.db.get().unwrap();
+    let mut query = dsl::subject
+        .inner_join(crate::schema::work::table.inner_join(crate::schema::imprint::table))
+        .into_boxed();
+    if let Some(sub_type) = subject_type {
+        query = query.filter(dsl::subject_type.eq(sub_type))
+    }
+    // only count the rows an API key can list
+    let publishers = context.scoped_publishers(vec![]);
+    if !publishers.is_empty() {
+        query = query.filter(crate::schema::imprint::publisher_id.eq_any(publishers));
+    }
+    // see comment in work_count()
+    query
+        .count()
+        .get_result::<i64>(&connection)
+        .expect("Error loading subject count")
+}
+
+fn load_funder_count(context: &Context, filter: String) -> i64 {
+    use crate::schema::funder::dsl::*;
+    let connection = context.db.get().unwrap();
+    // see comment in work_count()
+    funder
+        .filter(funder_name.ilike(contains_pattern(&filter)))
+        .or_filter(funder_doi.ilike(contains_pattern(&filter)))
+        .count()
+        .get_result::<i64>(&connection)
+        .expect("Error loading funder count")
+}
+
+fn load_funding_count(context: &Context) -> i64 {
+    use crate::schema::funding::dsl::*;
+    let connection = context.db.get().unwrap();
+    let mut query = funding
+        .inner_join(crate::schema::work::table.inner_join(crate::schema::imprint::table))
+        .into_boxed();
+    // only count the rows an API key can list
+    let publishers = context.scoped_publishers(vec![]);
+    if !publishers.is_empty() {
+        query = query.filter(crate::schema::imprint::publisher_id.eq_any(publishers));
+    }
+    // see comment in work_count()
+    query
+        .count()
+        .get_result::<i64>(&connection)
+        .expect("Error loading funding count")
+}
+
+/// Report a failed permission check with its error type, which the `?` conversion would drop
+fn access_error(error: failure::Error) -> FieldError {
+    ThothError::from(error).into_field_error()
+}
+
+/// Report a record that could not be loaded, e.g. because it does not exist, with its error type
+fn load_error(error: diesel::result::Error) -> FieldError {
+    ThothError::from(error).into_field_error()
+}
+
+fn imprint_publisher_id(imprint_id: Uuid, context: &Context) -> FieldResult<Uuid> {
     use crate::schema::imprint::dsl;
-    let pub_id = dsl::imprint
+    dsl::imprint
         .select(dsl::publisher_id)
         .filter(dsl::imprint_id.eq(imprint_id))
         .first::<Uuid>(&context.db.get().unwrap())
-        .expect("Error checking permissions");
-    context.account_access.can_edit(pub_id)
+        .map_err(load_error)
+}
+
+fn user_can_edit_imprint(imprint_id: Uuid, context: &Context) -> FieldResult<()> {
+    let pub_id = imprint_publisher_id(imprint_id, context)?;
+    context
+        .account_access
+        .can_edit(pub_id)
+        .map_err(access_error)
+}
+
+/// Mirror the work's own short and long abstracts into its default language abstracts
+fn sync_default_abstracts(connection: &PgConnection, work: &Work) -> Result<()> {
+    sync_default_abstract(
+        connection,
+        work.work_id,
+        AbstractType::Short,
+        work.short_abstract.as_ref(),
+    )?;
+    sync_default_abstract(
+        connection,
+        work.work_id,
+        AbstractType::Long,
+        work.long_abstract.as_ref(),
+    )?;
+    Ok(())
+}
+
+/// Version of a record for optimistic locking: its `updated_at` to the microsecond, the
+/// precision with which it is stored
+fn record_version(updated_at: NaiveDateTime) -> String {
+    updated_at.format("%Y-%m-%dT%H:%M:%S%.6f").to_string()
+}
+
+/// Reject an update if the record has changed since the client loaded it. Takes the record's
+/// `updated_at` read `FOR UPDATE` within the update's transaction, so no other update can commit
+/// between the check and the write.
+fn check_unchanged(
+    expected_version: Option<&String>,
+    locked_updated_at: QueryResult<NaiveDateTime>,
+) -> FieldResult<()> {
+    let updated_at = locked_updated_at.map_err(load_error)?;
+    match expected_version {
+        Some(expected) if *expected != record_version(updated_at) => {
+            Err(ThothError::StaleData.into_field_error())
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Subquery selecting the IDs of works with a subject of the given type and/or code, or `None`
+/// if neither is given. Filtering on it rather than joining `subject` counts a work only once,
+/// however many matching subjects it has.
+fn subject_work_ids(
+    subject_type: Option<SubjectType>,
+    subject_code: Option<String>,
+) -> Option<subject::BoxedQuery<'static, Pg, diesel::sql_types::Uuid>> {
+    if subject_type.is_none() && subject_code.is_none() {
+        return None;
+    }
+    let mut query = subject::table.select(subject::work_id).into_boxed();
+    if let Some(s_type) = subject_type {
+        query = query.filter(subject::subject_type.eq(s_type));
+    }
+    if let Some(code) = subject_code {
+        query = query.filter(subject::subject_code.eq(code));
+    }
+    Some(query)
+}
+
+/// First day of the year a works query's `publication_year` filter names and of the year after.
+/// Shared by the list and the counts of works so that they reject the same years.
+fn publication_year_bounds(
+    publication_year: Option<i32>,
+) -> std::result::Result<Option<(NaiveDate, NaiveDate)>, ThothError> {
+    publication_year
+        .map(|year| {
+            year_bounds(year).ok_or_else(|| {
+                ThothError::InvalidInput(format!("{} is not a supported publication year", year))
+            })
+        })
+        .transpose()
 }
 
-fn user_can_edit_work(work_id: Uuid, context: &Context) -> Result<()> {
+/// The ORCID a works query's `contributor_orcid` filter gives. Shared by the list and the counts
+/// of works so that they reject the same ORCIDs.
+fn contributor_orcid_filter(
+    contributor_orcid: Option<String>,
+) -> std::result::Result<Option<Orcid>, ThothError> {
+    contributor_orcid.map(|orcid| orcid.parse()).transpose()
+}
+
+/// Subquery selecting the IDs of works with a contribution by the contributor with the given
+/// ORCID. The ORCID is matched case-insensitively against the bare identifier and its URL forms,
+/// as contributors may have been saved with either.
+fn orcid_work_ids(orcid: &Orcid) -> contribution::BoxedQuery<'static, Pg, diesel::sql_types::Uuid> {
+    use diesel::sql_types::Nullable;
+    use diesel::sql_types::Text;
+    sql_function!(fn lower(x: Nullable<Text>) -> Nullable<Text>);
+    let id = orcid.id().to_lowercase();
+    let forms = vec![
+        format!("https://orcid.org/{}", id),
+        format!("http://orcid.org/{}", id),
+        id,
+    ];
+    let contributor_ids = contributor::table
+        .select(contributor::contributor_id)
+        .filter(lower(contributor::orcid).eq_any(forms))
+        .into_boxed();
+    contribution::table
+        .select(contribution::work_id)
+        .filter(contribution::contributor_id.eq_any(contributor_ids))
+        .into_boxed()
+}
+
+/// Subquery selecting the IDs of works under the given licence. Licence URLs are compared in
+/// the form given by `normalise_license`, which the database reproduces with the same pattern.
+fn license_work_ids(license: &str) -> work::BoxedQuery<'static, Pg, diesel::sql_types::Uuid> {
+    use diesel::sql_types::Nullable;
+    use diesel::sql_types::Text;
+    sql_function!(fn lower(x: Nullable<Text>) -> Nullable<Text>);
+    sql_function!(
+        fn regexp_replace(
+            source: Nullable<Text>,
+            pattern: Text,
+            replacement: Text,
+            flags: Text
+        ) -> Nullable<Text>
+    );
+    work::table
+        .select(work::work_id)
+        .filter(
+            regexp_replace(lower(work::license), LICENSE_URL_NOISE, "", "g")
+                .eq(normalise_license(license)),
+        )
+        .into_boxed()
+}
+
+/// Subquery selecting the IDs of works of the given publishers
+fn publisher_work_ids(
+    publishers: Vec<Uuid>,
+) -> work::BoxedQuery<'static, Pg, diesel::sql_types::Uuid> {
+    let imprint_ids = imprint::table
+        .select(imprint::imprint_id)
+        .filter(imprint::publisher_id.eq_any(publishers))
+        .into_boxed();
+    work::table
+        .select(work::work_id)
+        .filter(work::imprint_id.eq_any(imprint_ids))
+        .into_boxed()
+}
+
+/// Contributions by a contributor, only to works of the given publishers if there are any, as
+/// for an API key scoped to a publisher
+fn contributor_contributions(
+    contributor_id: Uuid,
+    publishers: Vec<Uuid>,
+) -> contribution::BoxedQuery<'static, Pg> {
+    let mut query = contribution::table
+        .filter(contribution::contributor_id.eq(contributor_id))
+        .into_boxed();
+    if !publishers.is_empty() {
+        query = query.filter(contribution::work_id.eq_any(publisher_work_ids(publishers)));
+    }
+    query
+}
+
+/// Fundings from a funder, only of works of the given publishers if there are any, as for an
+/// API key scoped to a publisher
+fn funder_fundings(funder_id: Uuid, publishers: Vec<Uuid>) -> funding::BoxedQuery<'static, Pg> {
+    let mut query = funding::table
+        .filter(funding::funder_id.eq(funder_id))
+        .into_boxed();
+    if !publishers.is_empty() {
+        query = query.filter(funding::work_id.eq_any(publisher_work_ids(publishers)));
+    }
+    query
+}
+
+const CONTRIBUTION_COUNT_SQL: &str =
+    "(SELECT COUNT(*) FROM contribution WHERE contribution.work_id = work.work_id)";
+
+/// SQL condition that holds for rows of a query on `work` that have the given part filled in.
+/// It must agree with the conditions in `Work::missing_completeness_fields`.
+fn completeness_condition(field: CompletenessField) -> &'static str {
+    match field {
+        CompletenessField::Doi => "work.doi IS NOT NULL",
+        CompletenessField::Abstract => {
+            "(work.short_abstract IS NOT NULL OR work.long_abstract IS NOT NULL)"
+        }
+        CompletenessField::Cover => "work.cover_url IS NOT NULL",
+        CompletenessField::License => "work.license IS NOT NULL",
+        CompletenessField::Contributions => {
+            "EXISTS (SELECT 1 FROM contribution WHERE contribution.work_id = work.work_id)"
+        }
+        CompletenessField::Subjects => {
+            "EXISTS (SELECT 1 FROM subject WHERE subject.work_id = work.work_id)"
+        }
+        CompletenessField::Publications => {
+            "EXISTS (SELECT 1 FROM publication WHERE publication.work_id = work.work_id \
+             AND (publication.unpriced_item_type = 'free-of-charge' \
+             OR EXISTS (SELECT 1 FROM price \
+             WHERE price.publication_id = publication.publication_id)))"
+        }
+    }
+}
+
+/// SQL expression giving the completeness score of each row of a query on `work`
+fn completeness_sql() -> String {
+    CompletenessField::ALL
+        .iter()
+        .map(|field| {
+            format!(
+                "(CASE WHEN {} THEN {} ELSE 0 END)",
+                completeness_condition(*field),
+                field.weight()
+            )
+        })
+        .collect::<Vec<String>>()
+        .join(" + ")
+}
+
+fn work_translations(work_id: Uuid, context: &Context) -> FieldResult<Vec<Language>> {
+    let connection = context.db.get().unwrap();
+    language::table
+        .filter(language::work_id.eq(work_id))
+        .filter(language::language_relation.eq(LanguageRelation::TranslatedInto))
+        .order((language::language_code.asc(), language::created_at.asc()))
+        .load::<Language>(&connection)
+        .map_err(FieldError::from)
+}
+
+fn work_missing_fields(work: &Work, context: &Context) -> FieldResult<Vec<CompletenessField>> {
+    let connection = context.db.get().unwrap();
+    let contribution_count = crate::schema::contribution::table
+        .filter(crate::schema::contribution::work_id.eq(work.work_id))
+        .count()
+        .get_result::<i64>(&connection)
+        .map_err(FieldError::from)?;
+    let subject_count = crate::schema::subject::table
+        .filter(crate::schema::subject::work_id.eq(work.work_id))
+        .count()
+        .get_result::<i64>(&connection)
+        .map_err(FieldError::from)?;
+    let priced_count = crate::schema::price::table
+        .inner_join(crate::schema::publication::table)
+        .filter(crate::schema::publication::work_id.eq(work.work_id))
+        .count()
+        .get_result::<i64>(&connection)
+        .map_err(FieldError::from)?;
+    let free_count = crate::schema::publication::table
+        .filter(crate::schema::publication::work_id.eq(work.work_id))
+        .filter(crate::schema::publication::unpriced_item_type.eq(UnpricedItemType::FreeOfCharge))
+        .count()
+        .get_result::<i64>(&connection)
+        .map_err(FieldError::from)?;
+    Ok(work.missing_completeness_fields(
+        contribution_count > 0,
+        subject_count > 0,
+        priced_count + free_count > 0,
+    ))
+}
+
+/// Reject changes made through title mutations to a work's default title, which follows the
+/// title fields of the work itself
+fn check_not_default_title(
+    language_code: &LanguageCode,
+    title_type: &TitleType,
+) -> FieldResult<()> {
+    if is_default_title(language_code, title_type) {
+        Err(ThothError::InvalidInput(format!(
+            "The {} {} title is the work's own title: change it by updating the work",
+            DEFAULT_TITLE_LANGUAGE, title_type
+        ))
+        .into_field_error())
+    } else {
+        Ok(())
+    }
+}
+
+fn work_publisher_id(work_id: Uuid, context: &Context) -> FieldResult<Uuid> {
     use crate::schema::imprint::dsl::*;
-    let pub_id = imprint
+    imprint
         .inner_join(crate::schema::work::table)
         .select(publisher_id)
         .filter(crate::schema::work::work_id.eq(work_id))
         .first::<Uuid>(&context.db.get().unwrap())
-        .expect("Error checking permissions");
-    context.account_access.can_edit(pub_id)
+        .map_err(load_error)
+}
+
+fn user_can_edit_work(work_id: Uuid, context: &Context) -> FieldResult<()> {
+    let pub_id = work_publisher_id(work_id, context)?;
+    context
+        .account_access
+        .can_edit(pub_id)
+        .map_err(access_error)
 }
 
-fn user_can_edit_publication(publication_id: Uuid, context: &Context) -> Result<()> {
+fn publication_publisher_id(publication_id: Uuid, context: &Context) -> FieldResult<Uuid> {
     use crate::schema::imprint::dsl::*;
-    let pub_id = imprint
+    imprint
         .inner_join(crate::schema::work::table.inner_join(crate::schema::publication::table))
         .select(publisher_id)
         .filter(crate::schema::publication::publication_id.eq(publication_id))
         .first::<Uuid>(&context.db.get().unwrap())
-        .expect("Error checking permissions");
-    context.account_access.can_edit(pub_id)
+        .map_err(load_error)
+}
+
+fn user_can_edit_publication(publication_id: Uuid, context: &Context) -> FieldResult<()> {
+    let pub_id = publication_publisher_id(publication_id, context)?;
+    context
+        .account_access
+        .can_edit(pub_id)
+        .map_err(access_error)
+}
+
+#[test]
+fn test_page_bounds_clamps_oversized_limit() {
+    assert_eq!(page_bounds(100, 0, 1000).unwrap(), (100, 0));
+    assert_eq!(page_bounds(1000, 20, 1000).unwrap(), (1000, 20));
+    assert_eq!(page_bounds(1_000_000, 20, 1000).unwrap(), (1000, 20));
+    assert_eq!(page_bounds(0, 0, 1000).unwrap(), (0, 0));
+}
+
+#[test]
+fn test_page_bounds_rejects_negative_input() {
+    assert!(matches!(
+        page_bounds(-1, 0, 1000),
+        Err(ThothError::InvalidInput(_))
+    ));
+    assert!(matches!(
+        page_bounds(10, -5, 1000),
+        Err(ThothError::InvalidInput(_))
+    ));
+}
+
+#[test]
+fn test_search_relevance() {
+    assert_eq!(
+        search_relevance("Open Book Publishers", "open book publishers "),
+        3
+    );
+    assert_eq!(search_relevance("Open Book Publishers", "Open"), 2);
+    assert_eq!(search_relevance("Reopening the book", "open"), 1);
+}
+
+#[test]
+fn test_rank_search_results() {
+    let groups = vec![
+        vec![(1, "work 1"), (1, "work 2"), (3, "work 3")],
+        vec![(1, "contributor 1")],
+        vec![(2, "publisher 1"), (1, "publisher 2")],
+    ];
+    assert_eq!(
+        rank_search_results(groups.clone(), 10),
+        vec![
+            "work 3",
+            "publisher 1",
+            "work 1",
+            "contributor 1",
+            "work 2",
+            "publisher 2",
+        ]
+    );
+    assert_eq!(
+        rank_search_results(groups, 3),
+        vec!["work 3", "publisher 1", "work 1"]
+    );
+    assert!(rank_search_results::<&str>(vec![], 10).is_empty());
+}
+
+#[test]
+fn test_contains_pattern_escapes_wildcards() {
+    assert_eq!(contains_pattern(""), "%%");
+    assert_eq!(contains_pattern("Open Book"), "%Open Book%");
+    // A lone `%` must only match values containing a literal percent sign
+    assert_eq!(contains_pattern("%"), r"%\%%");
+    assert_eq!(contains_pattern("100%_free"), r"%100\%\_free%");
+    assert_eq!(contains_pattern(r"back\slash"), r"%back\\slash%");
+}
+
+#[test]
+fn test_saturating_count() {
+    assert_eq!(saturating_count(0), 0);
+    assert_eq!(saturating_count(2147483647), i32::MAX);
+    assert_eq!(saturating_count(2147483648), i32::MAX);
+    assert_eq!(saturating_count(i64::MAX), i32::MAX);
+}
+
+#[test]
+fn test_record_version_keeps_microseconds() {
+    let updated_at = NaiveDate::from_ymd(2021, 3, 4).and_hms_micro(5, 6, 7, 890_123);
+    assert_eq!(record_version(updated_at), "2021-03-04T05:06:07.890123");
+    assert_ne!(
+        record_version(updated_at),
+        record_version(updated_at + chrono::Duration::microseconds(1))
+    );
+    assert_eq!(
+        record_version(NaiveDate::from_ymd(2021, 3, 4).and_hms(5, 6, 7)),
+        "2021-03-04T05:06:07.000000"
+    );
+}
+
+#[test]
+fn test_completeness_sql_weighs_every_part() {
+    let sql = completeness_sql();
+    for field in CompletenessField::ALL.iter() {
+        assert!(sql.contains(&format!(
+            "(CASE WHEN {} THEN {} ELSE 0 END)",
+            completeness_condition(*field),
+            field.weight()
+        )));
+    }
+    assert_eq!(
+        sql.matches("CASE WHEN").count(),
+        CompletenessField::ALL.len()
+    );
+}
+
+#[test]
+fn test_nested_lists_are_scoped_to_publishers() {
+    use diesel::debug_query;
+
+    let id = Uuid::new_v4();
+    let publisher_id = Uuid::new_v4();
+    let unscoped = debug_query::<Pg, _>(&contributor_contributions(id, vec![])).to_string();
+    assert!(!unscoped.contains("\"imprint\".\"publisher_id\""));
+    let scoped =
+        debug_query::<Pg, _>(&contributor_contributions(id, vec![publisher_id])).to_string();
+    assert!(scoped.contains("\"imprint\".\"publisher_id\""));
+    assert!(scoped.contains(&publisher_id.to_string()));
+
+    let unscoped = debug_query::<Pg, _>(&funder_fundings(id, vec![])).to_string();
+    assert!(!unscoped.contains("\"imprint\".\"publisher_id\""));
+    let scoped = debug_query::<Pg, _>(&funder_fundings(id, vec![publisher_id])).to_string();
+    assert!(scoped.contains("\"imprint\".\"publisher_id\""));
+    assert!(scoped.contains(&publisher_id.to_string()));
+}
+
+#[test]
+fn test_work_filters_reject_invalid_input() {
+    assert_eq!(publication_year_bounds(None).unwrap(), None);
+    assert!(publication_year_bounds(Some(2020)).unwrap().is_some());
+    assert!(matches!(
+        publication_year_bounds(Some(i32::MAX)),
+        Err(ThothError::InvalidInput(_))
+    ));
+    assert!(contributor_orcid_filter(None).unwrap().is_none());
+    assert!(
+        contributor_orcid_filter(Some("0000-0002-1825-0097".to_string()))
+            .unwrap()
+            .is_some()
+    );
+    assert!(matches!(
+        contributor_orcid_filter(Some("not an orcid".to_string())),
+        Err(ThothError::InvalidOrcid(_))
+    ));
 }
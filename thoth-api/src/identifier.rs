@@ -0,0 +1,226 @@
+use regex::Regex;
+use std::fmt;
+use std::str::FromStr;
+
+use crate::errors::ThothError;
+
+lazy_static::lazy_static! {
+    // Same pattern as the `work_doi_check` database constraint
+    static ref DOI_REGEXP: Regex =
+        Regex::new(r"(?i)https://doi\.org/10\.\d{4,9}/[-._;()/:a-zA-Z0-9]+$")
+            .expect("DOI regexp failed!");
+    static ref SHORT_DOI_REGEXP: Regex =
+        Regex::new(r"(?i)^https://doi\.org/(?:10/)?([a-z0-9]+)$")
+            .expect("Short DOI regexp failed!");
+    static ref ORCID_REGEXP: Regex =
+        Regex::new(r"^(?:https?://orcid\.org/)?(\d{4}-\d{4}-\d{4}-\d{3}[\dX])$")
+            .expect("ORCID regexp failed!");
+}
+
+/// A DOI given as a `https://doi.org/` URL, as stored against works.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Doi(String);
+
+/// A shortDOI given as a `https://doi.org/` URL, with or without its `10/` prefix. It is an
+/// alias of a full DOI, e.g. `https://doi.org/cx9x` for `https://doi.org/10.1000/182`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ShortDoi(String);
+
+/// An ISBN-13 whose check digit has been verified.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Isbn(String);
+
+/// An ORCID iD, given either bare or as an `orcid.org` URL.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Orcid(String);
+
+impl ShortDoi {
+    /// The handle the shortDOI is registered as, e.g. `10/cx9x`
+    pub fn handle(&self) -> String {
+        let code = SHORT_DOI_REGEXP
+            .captures(&self.0)
+            .and_then(|captures| captures.get(1))
+            .map(|code| code.as_str())
+            .unwrap_or_default();
+        format!("10/{}", code.to_lowercase())
+    }
+}
+
+impl Isbn {
+    /// The ISBN without hyphens or spaces, used to compare differently hyphenated values.
+    pub fn digits(&self) -> String {
+        self.0.chars().filter(char::is_ascii_digit).collect()
+    }
+}
+
+impl Orcid {
+    /// The bare identifier (e.g. `0000-0002-1825-0097`), without the `orcid.org` URL.
+    pub fn id(&self) -> String {
+        ORCID_REGEXP
+            .captures(&self.0)
+            .and_then(|captures| captures.get(1))
+            .map(|orcid| orcid.as_str().to_string())
+            .unwrap_or_default()
+    }
+}
+
+impl fmt::Display for Doi {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl fmt::Display for ShortDoi {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl fmt::Display for Isbn {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl fmt::Display for Orcid {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl FromStr for Doi {
+    type Err = ThothError;
+
+    fn from_str(input: &str) -> Result<Doi, ThothError> {
+        if DOI_REGEXP.is_match(input) {
+            Ok(Doi(input.to_string()))
+        } else {
+            Err(ThothError::InvalidDoi(input.to_string()))
+        }
+    }
+}
+
+impl FromStr for ShortDoi {
+    type Err = ThothError;
+
+    fn from_str(input: &str) -> Result<ShortDoi, ThothError> {
+        if SHORT_DOI_REGEXP.is_match(input) {
+            Ok(ShortDoi(input.to_string()))
+        } else {
+            Err(ThothError::InvalidShortDoi(input.to_string()))
+        }
+    }
+}
+
+impl FromStr for Isbn {
+    type Err = ThothError;
+
+    fn from_str(input: &str) -> Result<Isbn, ThothError> {
+        let digits: Vec<u32> = input
+            .chars()
+            .filter(|c| *c != '-' && *c != ' ')
+            .map(|c| c.to_digit(10))
+            .collect::<Option<Vec<u32>>>()
+            .ok_or_else(|| ThothError::IsbnParseError(input.to_string()))?;
+        if digits.len() != 13 || !(digits.starts_with(&[9, 7, 8]) || digits.starts_with(&[9, 7, 9]))
+        {
+            return Err(ThothError::IsbnParseError(input.to_string()));
+        }
+        let sum: u32 = digits
+            .iter()
+            .enumerate()
+            .map(|(i, d)| if i % 2 == 0 { *d } else { d * 3 })
+            .sum();
+        if sum % 10 != 0 {
+            return Err(ThothError::IsbnChecksumError(input.to_string()));
+        }
+        Ok(Isbn(input.to_string()))
+    }
+}
+
+impl FromStr for Orcid {
+    type Err = ThothError;
+
+    fn from_str(input: &str) -> Result<Orcid, ThothError> {
+        let orcid = input.trim();
+        if ORCID_REGEXP.is_match(orcid) {
+            Ok(Orcid(orcid.to_string()))
+        } else {
+            Err(ThothError::InvalidOrcid(input.to_string()))
+        }
+    }
+}
+
+/// Check an optional identifier, treating a missing value as valid
+pub fn validate_optional<T: FromStr<Err = ThothError>>(
+    value: &Option<String>,
+) -> Result<(), ThothError> {
+    match value {
+        Some(value) => value.parse::<T>().map(|_| ()),
+        None => Ok(()),
+    }
+}
+
+#[test]
+fn test_doi_fromstr() {
+    assert!(Doi::from_str("https://doi.org/10.11647/OBP.0001").is_ok());
+    assert!(Doi::from_str("https://doi.org/10.1000/182").is_ok());
+    assert!(Doi::from_str("10.11647/OBP.0001").is_err());
+    assert!(Doi::from_str("https://doi.org/11.11647/OBP.0001").is_err());
+    assert!(Doi::from_str("https://doi.org/10.11647/OBP 0001").is_err());
+    assert!(Doi::from_str("").is_err());
+}
+
+#[test]
+fn test_short_doi_fromstr() {
+    assert_eq!(
+        ShortDoi::from_str("https://doi.org/cx9x").unwrap().handle(),
+        "10/cx9x"
+    );
+    assert_eq!(
+        ShortDoi::from_str("https://doi.org/10/CX9X")
+            .unwrap()
+            .handle(),
+        "10/cx9x"
+    );
+    assert!(ShortDoi::from_str("https://doi.org/10.1000/182").is_err());
+    assert!(ShortDoi::from_str("10/cx9x").is_err());
+    assert!(ShortDoi::from_str("https://doi.org/").is_err());
+}
+
+#[test]
+fn test_isbn_fromstr() {
+    assert_eq!(
+        Isbn::from_str("978-3-16-148410-0").unwrap().to_string(),
+        "978-3-16-148410-0"
+    );
+    assert!(Isbn::from_str("9783161484100").is_ok());
+    assert!(Isbn::from_str("979-10-90636-07-1").is_ok());
+    assert!(Isbn::from_str("978-3-16-148410-1").is_err());
+    assert!(Isbn::from_str("3-16-148410-X").is_err());
+    assert!(Isbn::from_str("123-4-56-789012-3").is_err());
+    assert!(Isbn::from_str("").is_err());
+}
+
+#[test]
+fn test_orcid_fromstr() {
+    assert_eq!(
+        Orcid::from_str("https://orcid.org/0000-0002-1825-0097")
+            .unwrap()
+            .id(),
+        "0000-0002-1825-0097"
+    );
+    assert_eq!(
+        Orcid::from_str(" 0000-0002-1694-233X ").unwrap().id(),
+        "0000-0002-1694-233X"
+    );
+    assert!(Orcid::from_str("https://example.org/0000-0002-1825-0097").is_err());
+    assert!(Orcid::from_str("0000-0002-1825").is_err());
+}
+
+#[test]
+fn test_validate_optional() {
+    assert!(validate_optional::<Doi>(&None).is_ok());
+    assert!(validate_optional::<Doi>(&Some("https://doi.org/10.1000/182".to_string())).is_ok());
+    assert!(validate_optional::<Isbn>(&Some("978-3-16-148410-1".to_string())).is_err());
+}
@@ -1,5 +1,8 @@
+use diesel::pg::Pg;
 use diesel::pg::PgConnection;
 use diesel::prelude::*;
+use diesel::query_builder::QueryFragment;
+use diesel::query_dsl::methods::ExecuteDsl;
 use uuid::Uuid;
 
 use crate::errors::ThothError;
@@ -7,6 +10,10 @@ use crate::imprint::model::Imprint;
 use crate::imprint::model::ImprintHistory;
 use crate::imprint::model::NewImprintHistory;
 use crate::schema::imprint_history;
+use crate::schema::issue;
+use crate::schema::publisher;
+use crate::schema::series;
+use crate::schema::work;
 
 impl NewImprintHistory {
     pub fn new(imprint: Imprint, account_id: Uuid) -> Self {
@@ -27,3 +34,70 @@ impl NewImprintHistory {
         }
     }
 }
+
+/// Works and series are moved along with their imprint, so an imprint can only change publisher
+/// if none of its works is an issue of another imprint's series, and none of its series has
+/// issues from another imprint
+pub fn can_transfer_imprint(imprint_id: Uuid, connection: &PgConnection) -> Result<(), ThothError> {
+    let mismatched_issues = issue::table
+        .inner_join(work::table)
+        .inner_join(series::table)
+        .filter(
+            work::imprint_id
+                .eq(imprint_id)
+                .or(series::imprint_id.eq(imprint_id)),
+        )
+        .filter(work::imprint_id.ne(series::imprint_id))
+        .count()
+        .get_result::<i64>(connection)?;
+    if mismatched_issues > 0 {
+        return Err(ThothError::InvalidInput(format!(
+            "{} issues link this imprint's works or series to another imprint. Remove them before transferring the imprint.",
+            mismatched_issues
+        )));
+    }
+    Ok(())
+}
+
+/// A publisher's default imprint must be one of its own imprints, so an imprint that changes
+/// publisher stops being the default of the publisher it leaves. Returns the number of
+/// publishers updated.
+pub fn release_default_imprint(
+    imprint_id: Uuid,
+    connection: &PgConnection,
+) -> Result<usize, ThothError> {
+    ExecuteDsl::execute(release_default_imprint_statement(imprint_id), connection)
+        .map_err(ThothError::from)
+}
+
+fn release_default_imprint_statement(
+    imprint_id: Uuid,
+) -> impl ExecuteDsl<PgConnection> + QueryFragment<Pg> {
+    diesel::update(publisher::table.filter(publisher::default_imprint_id.eq(imprint_id)))
+        .set(publisher::default_imprint_id.eq(None::<Uuid>))
+}
+
+/// Deleting an imprint also deletes its works, so an imprint can only be deleted once it has none
+pub fn can_delete_imprint(imprint_id: Uuid, connection: &PgConnection) -> Result<(), ThothError> {
+    let work_count = work::table
+        .filter(work::imprint_id.eq(imprint_id))
+        .count()
+        .get_result::<i64>(connection)?;
+    if work_count > 0 {
+        return Err(ThothError::InvalidInput(format!(
+            "Imprint has {} works. Move them to another imprint, or delete them, before deleting the imprint.",
+            work_count
+        )));
+    }
+    Ok(())
+}
+
+#[test]
+fn test_release_default_imprint() {
+    let imprint_id = Uuid::new_v4();
+    let sql =
+        diesel::debug_query::<Pg, _>(&release_default_imprint_statement(imprint_id)).to_string();
+    assert!(sql.starts_with("UPDATE \"publisher\" SET \"default_imprint_id\" = $1"));
+    assert!(sql.contains("WHERE \"publisher\".\"default_imprint_id\" = $2"));
+    assert!(sql.contains(&imprint_id.to_string()));
+}
@@ -21,9 +21,12 @@ pub mod funder;
 pub mod funding;
 #[cfg(feature = "backend")]
 pub mod graphql;
+pub mod identifier;
 pub mod imprint;
 pub mod issue;
 pub mod language;
+#[cfg(feature = "backend")]
+pub mod outbound;
 pub mod price;
 pub mod publication;
 pub mod publisher;
@@ -31,7 +34,10 @@ pub mod publisher;
 mod schema;
 pub mod series;
 pub mod subject;
+pub mod webhook;
 pub mod work;
+pub mod work_abstract;
+pub mod work_title;
 
 macro_rules! apis {
     ($($name:ident => $content:expr,)*) => (
@@ -0,0 +1,187 @@
+use reqwest_outbound::blocking::{Client, RequestBuilder, Response};
+use reqwest_outbound::header::LOCATION;
+use reqwest_outbound::redirect::Policy;
+use reqwest_outbound::Url;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, ToSocketAddrs};
+use std::time::Duration;
+
+/// Most redirects followed from a URL given by a user
+const MAX_REDIRECTS: usize = 5;
+/// Time allowed to connect to a URL given by a user, within the overall request timeout
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(3);
+
+/// Whether an address can be reached from the public internet. Requests to URLs given by users
+/// must not reach the server's own network, e.g. a database or a cloud metadata service.
+pub fn is_public_ip(ip: IpAddr) -> bool {
+    match ip {
+        IpAddr::V4(ip) => is_public_ipv4(ip),
+        IpAddr::V6(ip) => is_public_ipv6(ip),
+    }
+}
+
+fn is_public_ipv4(ip: Ipv4Addr) -> bool {
+    let [a, b, _, _] = ip.octets();
+    !(ip.is_private()
+        || ip.is_loopback()
+        || ip.is_link_local()
+        || ip.is_unspecified()
+        || ip.is_broadcast()
+        || ip.is_documentation()
+        || ip.is_multicast()
+        // "this network", shared address space (RFC 6598) and reserved ranges
+        || a == 0
+        || (a == 100 && (64..128).contains(&b))
+        || a >= 240)
+}
+
+fn is_public_ipv6(ip: Ipv6Addr) -> bool {
+    let segments = ip.segments();
+    // IPv4-mapped (::ffff:0:0/96), NAT64 (64:ff9b::/96) and 6to4 (2002::/16) addresses reach
+    // the IPv4 host they embed
+    let embedded = if segments[..6] == [0, 0, 0, 0, 0, 0xffff]
+        || segments[..6] == [0x64, 0xff9b, 0, 0, 0, 0]
+    {
+        Some((segments[6], segments[7]))
+    } else if segments[0] == 0x2002 {
+        Some((segments[1], segments[2]))
+    } else {
+        None
+    };
+    if let Some((high, low)) = embedded {
+        let [a, b] = high.to_be_bytes();
+        let [c, d] = low.to_be_bytes();
+        return is_public_ipv4(Ipv4Addr::new(a, b, c, d));
+    }
+    !(ip.is_loopback()
+        || ip.is_unspecified()
+        || ip.is_multicast()
+        // unique local (fc00::/7) and link-local (fe80::/10) addresses
+        || (segments[0] & 0xfe00) == 0xfc00
+        || (segments[0] & 0xffc0) == 0xfe80)
+}
+
+/// Check that a URL given by a user is an HTTP(S) URL whose host only resolves to public
+/// addresses, and return those addresses. Returns the reason it is refused otherwise.
+pub fn check_public_url(url: &Url) -> Result<Vec<SocketAddr>, String> {
+    if url.scheme() != "http" && url.scheme() != "https" {
+        return Err(format!("{} URLs are not supported", url.scheme()));
+    }
+    let host = url
+        .host_str()
+        .ok_or_else(|| "URL has no host".to_string())?;
+    let port = url.port_or_known_default().unwrap_or(80);
+    // IPv6 literals keep their brackets in the host
+    let literal = host.trim_start_matches('[').trim_end_matches(']');
+    let addresses: Vec<SocketAddr> = match literal.parse() {
+        Ok(ip) => vec![SocketAddr::new(ip, port)],
+        Err(_) => (host, port)
+            .to_socket_addrs()
+            .map_err(|e| format!("{} could not be resolved: {}", host, e))?
+            .collect(),
+    };
+    if addresses.is_empty() {
+        return Err(format!("{} could not be resolved", host));
+    }
+    match addresses.iter().find(|address| !is_public_ip(address.ip())) {
+        Some(address) => Err(format!(
+            "{} resolves to a non-public address ({})",
+            host,
+            address.ip()
+        )),
+        None => Ok(addresses),
+    }
+}
+
+/// Client for requests to a URL given by a user. It connects only to the addresses
+/// `check_public_url` accepted for the URL's host, rather than resolving the host again, where
+/// a DNS rebinding server could answer with a private address. Redirects are not followed, as
+/// their targets would be resolved by the client; `send_public` follows them.
+pub fn public_client(url: &Url, timeout: Duration) -> Result<Client, String> {
+    let addresses = check_public_url(url)?;
+    let mut builder = Client::builder()
+        .timeout(timeout)
+        .connect_timeout(CONNECT_TIMEOUT.min(timeout))
+        .redirect(Policy::none())
+        // a proxy would resolve the host itself
+        .no_proxy();
+    if let Some(domain) = url.domain() {
+        builder = builder.resolve_to_addrs(domain, &addresses);
+    }
+    builder.build().map_err(|e| e.to_string())
+}
+
+/// Send a request to a URL given by a user, built by `request` from a client made with
+/// `public_client` for the URL. Redirects are followed by building the request again for their
+/// target, which is checked like the URL itself.
+pub fn send_public<F>(url: &Url, timeout: Duration, request: F) -> Result<Response, String>
+where
+    F: Fn(&Client, Url) -> RequestBuilder,
+{
+    let mut url = url.clone();
+    for _ in 0..=MAX_REDIRECTS {
+        let client = public_client(&url, timeout)?;
+        let response = request(&client, url.clone())
+            .send()
+            .map_err(|e| e.to_string())?;
+        let location = match response.headers().get(LOCATION) {
+            Some(location) if response.status().is_redirection() => location,
+            _ => return Ok(response),
+        };
+        url = location
+            .to_str()
+            .map_err(|e| e.to_string())
+            .and_then(|location| url.join(location).map_err(|e| e.to_string()))?;
+    }
+    Err("too many redirects".to_string())
+}
+
+#[test]
+fn test_is_public_ip() {
+    for ip in &[
+        "127.0.0.1",
+        "10.1.2.3",
+        "172.16.0.1",
+        "192.168.1.1",
+        "169.254.169.254",
+        "0.0.0.0",
+        "100.64.0.1",
+        "255.255.255.255",
+        "::1",
+        "::",
+        "fd00::1",
+        "fe80::1",
+        "::ffff:127.0.0.1",
+        "::ffff:10.0.0.1",
+        "64:ff9b::7f00:1",
+        "64:ff9b::a9fe:a9fe",
+        "2002:7f00:1::1",
+        "2002:c0a8:101::",
+    ] {
+        assert!(!is_public_ip(ip.parse().unwrap()), "{} is not public", ip);
+    }
+    for ip in &[
+        "8.8.8.8",
+        "93.184.216.34",
+        "2606:4700::1111",
+        "::ffff:8.8.8.8",
+        "64:ff9b::808:808",
+        "2002:808:808::1",
+    ] {
+        assert!(is_public_ip(ip.parse().unwrap()), "{} is public", ip);
+    }
+}
+
+#[test]
+fn test_check_public_url() {
+    let check = |url: &str| check_public_url(&Url::parse(url).unwrap());
+    assert!(check("http://127.0.0.1/cover.jpg").is_err());
+    assert!(check("http://[::1]:8000/cover.jpg").is_err());
+    assert!(check("http://169.254.169.254/latest/meta-data/").is_err());
+    assert!(check("http://localhost/cover.jpg").is_err());
+    assert!(check("ftp://93.184.216.34/cover.jpg").is_err());
+    assert!(check("file:///etc/passwd").is_err());
+    assert_eq!(
+        check("https://93.184.216.34/cover.jpg"),
+        Ok(vec!["93.184.216.34:443".parse().unwrap()])
+    );
+}
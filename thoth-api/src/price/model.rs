@@ -20,6 +20,8 @@ pub enum PriceField {
     PublicationID,
     CurrencyCode,
     UnitPrice,
+    PriceType,
+    Territory,
     CreatedAt,
     UpdatedAt,
 }
@@ -33,6 +35,8 @@ pub struct Price {
     pub unit_price: f64,
     pub created_at: NaiveDateTime,
     pub updated_at: NaiveDateTime,
+    pub price_type: PriceType,
+    pub territory: String,
 }
 
 #[cfg_attr(
@@ -44,6 +48,26 @@ pub struct NewPrice {
     pub publication_id: Uuid,
     pub currency_code: CurrencyCode,
     pub unit_price: f64,
+    /// Defaults to RRP excluding tax
+    pub price_type: Option<PriceType>,
+    /// Defaults to WORLD
+    pub territory: Option<String>,
+}
+
+#[cfg_attr(
+    feature = "backend",
+    derive(juniper::GraphQLInputObject),
+    graphql(
+        description = "A price to set on a publication. It replaces the publication's price in the same currency, price type and territory, if there is one"
+    )
+)]
+pub struct PublicationPrice {
+    pub currency_code: CurrencyCode,
+    pub unit_price: f64,
+    /// Defaults to RRP excluding tax
+    pub price_type: Option<PriceType>,
+    /// Defaults to WORLD
+    pub territory: Option<String>,
 }
 
 #[cfg_attr(
@@ -57,6 +81,29 @@ pub struct PatchPrice {
     pub publication_id: Uuid,
     pub currency_code: CurrencyCode,
     pub unit_price: f64,
+    /// Defaults to RRP excluding tax
+    pub price_type: Option<PriceType>,
+    /// Defaults to WORLD
+    pub territory: Option<String>,
+}
+
+#[cfg_attr(feature = "backend", derive(DbEnum, juniper::GraphQLEnum))]
+#[cfg_attr(feature = "backend", DieselType = "Price_type")]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum PriceType {
+    #[cfg_attr(feature = "backend", db_rename = "rrp-excluding-tax")]
+    RrpExcludingTax,
+    #[cfg_attr(feature = "backend", db_rename = "rrp-including-tax")]
+    RrpIncludingTax,
+    #[cfg_attr(feature = "backend", db_rename = "fixed-retail-excluding-tax")]
+    FixedRetailExcludingTax,
+    #[cfg_attr(feature = "backend", db_rename = "fixed-retail-including-tax")]
+    FixedRetailIncludingTax,
+    #[cfg_attr(feature = "backend", db_rename = "supplier-net-excluding-tax")]
+    SupplierNetExcludingTax,
+    #[cfg_attr(feature = "backend", db_rename = "supplier-net-including-tax")]
+    SupplierNetIncludingTax,
 }
 
 #[cfg_attr(feature = "backend", derive(DbEnum, juniper::GraphQLEnum))]
@@ -384,6 +431,41 @@ pub struct NewPriceHistory {
     pub data: serde_json::Value,
 }
 
+impl Default for PriceType {
+    fn default() -> PriceType {
+        PriceType::RrpExcludingTax
+    }
+}
+
+impl fmt::Display for PriceType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            PriceType::RrpExcludingTax => write!(f, "RRP excluding tax"),
+            PriceType::RrpIncludingTax => write!(f, "RRP including tax"),
+            PriceType::FixedRetailExcludingTax => write!(f, "Fixed retail excluding tax"),
+            PriceType::FixedRetailIncludingTax => write!(f, "Fixed retail including tax"),
+            PriceType::SupplierNetExcludingTax => write!(f, "Supplier's net excluding tax"),
+            PriceType::SupplierNetIncludingTax => write!(f, "Supplier's net including tax"),
+        }
+    }
+}
+
+impl FromStr for PriceType {
+    type Err = ThothError;
+
+    fn from_str(input: &str) -> Result<PriceType, ThothError> {
+        match input {
+            "RRP excluding tax" => Ok(PriceType::RrpExcludingTax),
+            "RRP including tax" => Ok(PriceType::RrpIncludingTax),
+            "Fixed retail excluding tax" => Ok(PriceType::FixedRetailExcludingTax),
+            "Fixed retail including tax" => Ok(PriceType::FixedRetailIncludingTax),
+            "Supplier's net excluding tax" => Ok(PriceType::SupplierNetExcludingTax),
+            "Supplier's net including tax" => Ok(PriceType::SupplierNetIncludingTax),
+            _ => Err(ThothError::InvalidPriceType(input.to_string())),
+        }
+    }
+}
+
 impl Default for CurrencyCode {
     fn default() -> CurrencyCode {
         CurrencyCode::Gbp
@@ -1011,6 +1093,438 @@ impl FromStr for CurrencyCode {
     }
 }
 
+impl CurrencyCode {
+    /// Symbol, where one is widely used, and ISO 4217 minor unit of the currency
+    fn formatting(&self) -> (Option<&'static str>, usize) {
+        match self {
+            CurrencyCode::Adp => (None, 0),
+            CurrencyCode::Aed => (None, 2),
+            CurrencyCode::Afa => (None, 2),
+            CurrencyCode::Afn => (None, 2),
+            CurrencyCode::Alk => (None, 2),
+            CurrencyCode::All => (None, 2),
+            CurrencyCode::Amd => (None, 2),
+            CurrencyCode::Ang => (None, 2),
+            CurrencyCode::Aoa => (None, 2),
+            CurrencyCode::Aok => (None, 2),
+            CurrencyCode::Aon => (None, 2),
+            CurrencyCode::Aor => (None, 2),
+            CurrencyCode::Ara => (None, 2),
+            CurrencyCode::Arp => (None, 2),
+            CurrencyCode::Ars => (None, 2),
+            CurrencyCode::Ary => (None, 2),
+            CurrencyCode::Ats => (None, 2),
+            CurrencyCode::Aud => (Some("A$"), 2),
+            CurrencyCode::Awg => (None, 2),
+            CurrencyCode::Aym => (None, 2),
+            CurrencyCode::Azm => (None, 2),
+            CurrencyCode::Azn => (None, 2),
+            CurrencyCode::Bad => (None, 2),
+            CurrencyCode::Bam => (None, 2),
+            CurrencyCode::Bbd => (None, 2),
+            CurrencyCode::Bdt => (None, 2),
+            CurrencyCode::Bec => (None, 2),
+            CurrencyCode::Bef => (None, 0),
+            CurrencyCode::Bel => (None, 2),
+            CurrencyCode::Bgj => (None, 2),
+            CurrencyCode::Bgk => (None, 2),
+            CurrencyCode::Bgl => (None, 2),
+            CurrencyCode::Bgn => (None, 2),
+            CurrencyCode::Bhd => (None, 3),
+            CurrencyCode::Bif => (None, 0),
+            CurrencyCode::Bmd => (None, 2),
+            CurrencyCode::Bnd => (None, 2),
+            CurrencyCode::Bob => (None, 2),
+            CurrencyCode::Bop => (None, 2),
+            CurrencyCode::Bov => (None, 2),
+            CurrencyCode::Brb => (None, 2),
+            CurrencyCode::Brc => (None, 2),
+            CurrencyCode::Bre => (None, 2),
+            CurrencyCode::Brl => (Some("R$"), 2),
+            CurrencyCode::Brn => (None, 2),
+            CurrencyCode::Brr => (None, 2),
+            CurrencyCode::Bsd => (None, 2),
+            CurrencyCode::Btn => (None, 2),
+            CurrencyCode::Buk => (None, 2),
+            CurrencyCode::Bwp => (None, 2),
+            CurrencyCode::Byb => (None, 2),
+            CurrencyCode::Byn => (None, 2),
+            CurrencyCode::Byr => (None, 0),
+            CurrencyCode::Bzd => (None, 2),
+            CurrencyCode::Cad => (Some("CA$"), 2),
+            CurrencyCode::Cdf => (None, 2),
+            CurrencyCode::Chc => (None, 2),
+            CurrencyCode::Che => (None, 2),
+            CurrencyCode::Chf => (None, 2),
+            CurrencyCode::Chw => (None, 2),
+            CurrencyCode::Clf => (None, 4),
+            CurrencyCode::Clp => (None, 0),
+            CurrencyCode::Cny => (Some("CN¥"), 2),
+            CurrencyCode::Cop => (None, 2),
+            CurrencyCode::Cou => (None, 2),
+            CurrencyCode::Crc => (None, 2),
+            CurrencyCode::Csd => (None, 2),
+            CurrencyCode::Csj => (None, 2),
+            CurrencyCode::Csk => (None, 2),
+            CurrencyCode::Cuc => (None, 2),
+            CurrencyCode::Cup => (None, 2),
+            CurrencyCode::Cve => (None, 2),
+            CurrencyCode::Cyp => (None, 2),
+            CurrencyCode::Czk => (None, 2),
+            CurrencyCode::Ddm => (None, 2),
+            CurrencyCode::Dem => (None, 2),
+            CurrencyCode::Djf => (None, 0),
+            CurrencyCode::Dkk => (None, 2),
+            CurrencyCode::Dop => (None, 2),
+            CurrencyCode::Dzd => (None, 2),
+            CurrencyCode::Ecs => (None, 2),
+            CurrencyCode::Ecv => (None, 2),
+            CurrencyCode::Eek => (None, 2),
+            CurrencyCode::Egp => (None, 2),
+            CurrencyCode::Ern => (None, 2),
+            CurrencyCode::Esa => (None, 2),
+            CurrencyCode::Esb => (None, 2),
+            CurrencyCode::Esp => (None, 0),
+            CurrencyCode::Etb => (None, 2),
+            CurrencyCode::Eur => (Some("€"), 2),
+            CurrencyCode::Fim => (None, 2),
+            CurrencyCode::Fjd => (None, 2),
+            CurrencyCode::Fkp => (None, 2),
+            CurrencyCode::Frf => (None, 2),
+            CurrencyCode::Gbp => (Some("£"), 2),
+            CurrencyCode::Gek => (None, 2),
+            CurrencyCode::Gel => (None, 2),
+            CurrencyCode::Ghc => (None, 2),
+            CurrencyCode::Ghp => (None, 2),
+            CurrencyCode::Ghs => (None, 2),
+            CurrencyCode::Gip => (None, 2),
+            CurrencyCode::Gmd => (None, 2),
+            CurrencyCode::Gne => (None, 2),
+            CurrencyCode::Gnf => (None, 0),
+            CurrencyCode::Gns => (None, 2),
+            CurrencyCode::Gqe => (None, 2),
+            CurrencyCode::Grd => (None, 2),
+            CurrencyCode::Gtq => (None, 2),
+            CurrencyCode::Gwe => (None, 2),
+            CurrencyCode::Gwp => (None, 2),
+            CurrencyCode::Gyd => (None, 2),
+            CurrencyCode::Hkd => (Some("HK$"), 2),
+            CurrencyCode::Hnl => (None, 2),
+            CurrencyCode::Hrd => (None, 2),
+            CurrencyCode::Hrk => (None, 2),
+            CurrencyCode::Htg => (None, 2),
+            CurrencyCode::Huf => (None, 2),
+            CurrencyCode::Idr => (None, 2),
+            CurrencyCode::Iep => (None, 2),
+            CurrencyCode::Ilp => (None, 2),
+            CurrencyCode::Ilr => (None, 2),
+            CurrencyCode::Ils => (Some("₪"), 2),
+            CurrencyCode::Inr => (Some("₹"), 2),
+            CurrencyCode::Iqd => (None, 3),
+            CurrencyCode::Irr => (None, 2),
+            CurrencyCode::Isj => (None, 2),
+            CurrencyCode::Isk => (None, 0),
+            CurrencyCode::Itl => (None, 0),
+            CurrencyCode::Jmd => (None, 2),
+            CurrencyCode::Jod => (None, 3),
+            CurrencyCode::Jpy => (Some("¥"), 0),
+            CurrencyCode::Kes => (None, 2),
+            CurrencyCode::Kgs => (None, 2),
+            CurrencyCode::Khr => (None, 2),
+            CurrencyCode::Kmf => (None, 0),
+            CurrencyCode::Kpw => (None, 2),
+            CurrencyCode::Krw => (Some("₩"), 0),
+            CurrencyCode::Kwd => (None, 3),
+            CurrencyCode::Kyd => (None, 2),
+            CurrencyCode::Kzt => (None, 2),
+            CurrencyCode::Laj => (None, 2),
+            CurrencyCode::Lak => (None, 2),
+            CurrencyCode::Lbp => (None, 2),
+            CurrencyCode::Lkr => (None, 2),
+            CurrencyCode::Lrd => (None, 2),
+            CurrencyCode::Lsl => (None, 2),
+            CurrencyCode::Lsm => (None, 2),
+            CurrencyCode::Ltl => (None, 2),
+            CurrencyCode::Ltt => (None, 2),
+            CurrencyCode::Luc => (None, 2),
+            CurrencyCode::Luf => (None, 0),
+            CurrencyCode::Lul => (None, 2),
+            CurrencyCode::Lvl => (None, 2),
+            CurrencyCode::Lvr => (None, 2),
+            CurrencyCode::Lyd => (None, 3),
+            CurrencyCode::Mad => (None, 2),
+            CurrencyCode::Mdl => (None, 2),
+            CurrencyCode::Mga => (None, 2),
+            CurrencyCode::Mgf => (None, 0),
+            CurrencyCode::Mkd => (None, 2),
+            CurrencyCode::Mlf => (None, 2),
+            CurrencyCode::Mmk => (None, 2),
+            CurrencyCode::Mnt => (None, 2),
+            CurrencyCode::Mop => (None, 2),
+            CurrencyCode::Mro => (None, 2),
+            CurrencyCode::Mru => (None, 2),
+            CurrencyCode::Mtl => (None, 2),
+            CurrencyCode::Mtp => (None, 2),
+            CurrencyCode::Mur => (None, 2),
+            CurrencyCode::Mvq => (None, 2),
+            CurrencyCode::Mvr => (None, 2),
+            CurrencyCode::Mwk => (None, 2),
+            CurrencyCode::Mxn => (Some("MX$"), 2),
+            CurrencyCode::Mxp => (None, 2),
+            CurrencyCode::Mxv => (None, 2),
+            CurrencyCode::Myr => (None, 2),
+            CurrencyCode::Mze => (None, 2),
+            CurrencyCode::Mzm => (None, 2),
+            CurrencyCode::Mzn => (None, 2),
+            CurrencyCode::Nad => (None, 2),
+            CurrencyCode::Ngn => (None, 2),
+            CurrencyCode::Nic => (None, 2),
+            CurrencyCode::Nio => (None, 2),
+            CurrencyCode::Nlg => (None, 2),
+            CurrencyCode::Nok => (None, 2),
+            CurrencyCode::Npr => (None, 2),
+            CurrencyCode::Nzd => (Some("NZ$"), 2),
+            CurrencyCode::Omr => (None, 3),
+            CurrencyCode::Pab => (None, 2),
+            CurrencyCode::Peh => (None, 2),
+            CurrencyCode::Pei => (None, 2),
+            CurrencyCode::Pen => (None, 2),
+            CurrencyCode::Pes => (None, 2),
+            CurrencyCode::Pgk => (None, 2),
+            CurrencyCode::Php => (Some("₱"), 2),
+            CurrencyCode::Pkr => (None, 2),
+            CurrencyCode::Pln => (None, 2),
+            CurrencyCode::Plz => (None, 2),
+            CurrencyCode::Pte => (None, 2),
+            CurrencyCode::Pyg => (None, 0),
+            CurrencyCode::Qar => (None, 2),
+            CurrencyCode::Rhd => (None, 2),
+            CurrencyCode::Rok => (None, 2),
+            CurrencyCode::Rol => (None, 2),
+            CurrencyCode::Ron => (None, 2),
+            CurrencyCode::Rsd => (None, 2),
+            CurrencyCode::Rub => (None, 2),
+            CurrencyCode::Rur => (None, 2),
+            CurrencyCode::Rwf => (None, 0),
+            CurrencyCode::Sar => (None, 2),
+            CurrencyCode::Sbd => (None, 2),
+            CurrencyCode::Scr => (None, 2),
+            CurrencyCode::Sdd => (None, 2),
+            CurrencyCode::Sdg => (None, 2),
+            CurrencyCode::Sdp => (None, 2),
+            CurrencyCode::Sek => (None, 2),
+            CurrencyCode::Sgd => (None, 2),
+            CurrencyCode::Shp => (None, 2),
+            CurrencyCode::Sit => (None, 2),
+            CurrencyCode::Skk => (None, 2),
+            CurrencyCode::Sll => (None, 2),
+            CurrencyCode::Sos => (None, 2),
+            CurrencyCode::Srd => (None, 2),
+            CurrencyCode::Srg => (None, 2),
+            CurrencyCode::Ssp => (None, 2),
+            CurrencyCode::Std => (None, 2),
+            CurrencyCode::Stn => (None, 2),
+            CurrencyCode::Sur => (None, 2),
+            CurrencyCode::Svc => (None, 2),
+            CurrencyCode::Syp => (None, 2),
+            CurrencyCode::Szl => (None, 2),
+            CurrencyCode::Thb => (None, 2),
+            CurrencyCode::Tjr => (None, 2),
+            CurrencyCode::Tjs => (None, 2),
+            CurrencyCode::Tmm => (None, 2),
+            CurrencyCode::Tmt => (None, 2),
+            CurrencyCode::Tnd => (None, 3),
+            CurrencyCode::Top => (None, 2),
+            CurrencyCode::Tpe => (None, 0),
+            CurrencyCode::Trl => (None, 0),
+            CurrencyCode::Try => (None, 2),
+            CurrencyCode::Ttd => (None, 2),
+            CurrencyCode::Twd => (Some("NT$"), 2),
+            CurrencyCode::Tzs => (None, 2),
+            CurrencyCode::Uah => (None, 2),
+            CurrencyCode::Uak => (None, 2),
+            CurrencyCode::Ugs => (None, 2),
+            CurrencyCode::Ugw => (None, 2),
+            CurrencyCode::Ugx => (None, 0),
+            CurrencyCode::Usd => (Some("$"), 2),
+            CurrencyCode::Usn => (None, 2),
+            CurrencyCode::Uss => (None, 2),
+            CurrencyCode::Uyi => (None, 0),
+            CurrencyCode::Uyn => (None, 2),
+            CurrencyCode::Uyp => (None, 2),
+            CurrencyCode::Uyu => (None, 2),
+            CurrencyCode::Uyw => (None, 4),
+            CurrencyCode::Uzs => (None, 2),
+            CurrencyCode::Veb => (None, 2),
+            CurrencyCode::Vef => (None, 2),
+            CurrencyCode::Ves => (None, 2),
+            CurrencyCode::Vnc => (None, 2),
+            CurrencyCode::Vnd => (Some("₫"), 0),
+            CurrencyCode::Vuv => (None, 0),
+            CurrencyCode::Wst => (None, 2),
+            CurrencyCode::Xaf => (Some("FCFA"), 0),
+            CurrencyCode::Xag => (None, 2),
+            CurrencyCode::Xau => (None, 2),
+            CurrencyCode::Xba => (None, 2),
+            CurrencyCode::Xbb => (None, 2),
+            CurrencyCode::Xbc => (None, 2),
+            CurrencyCode::Xbd => (None, 2),
+            CurrencyCode::Xcd => (Some("EC$"), 2),
+            CurrencyCode::Xdr => (None, 2),
+            CurrencyCode::Xeu => (None, 2),
+            CurrencyCode::Xfo => (None, 2),
+            CurrencyCode::Xfu => (None, 2),
+            CurrencyCode::Xof => (Some("F CFA"), 0),
+            CurrencyCode::Xpd => (None, 2),
+            CurrencyCode::Xpf => (Some("CFPF"), 0),
+            CurrencyCode::Xpt => (None, 2),
+            CurrencyCode::Xre => (None, 2),
+            CurrencyCode::Xsu => (None, 2),
+            CurrencyCode::Xts => (None, 2),
+            CurrencyCode::Xua => (None, 2),
+            CurrencyCode::Xxx => (None, 2),
+            CurrencyCode::Ydd => (None, 2),
+            CurrencyCode::Yer => (None, 2),
+            CurrencyCode::Yud => (None, 2),
+            CurrencyCode::Yum => (None, 2),
+            CurrencyCode::Yun => (None, 2),
+            CurrencyCode::Zal => (None, 2),
+            CurrencyCode::Zar => (None, 2),
+            CurrencyCode::Zmk => (None, 2),
+            CurrencyCode::Zmw => (None, 2),
+            CurrencyCode::Zrn => (None, 2),
+            CurrencyCode::Zrz => (None, 2),
+            CurrencyCode::Zwc => (None, 2),
+            CurrencyCode::Zwd => (None, 2),
+            CurrencyCode::Zwl => (None, 2),
+            CurrencyCode::Zwn => (None, 2),
+            CurrencyCode::Zwr => (None, 2),
+        }
+    }
+
+    /// Symbol used to display amounts in this currency, or the code itself if it has none
+    pub fn symbol(&self) -> String {
+        match self.formatting() {
+            (Some(symbol), _) => symbol.to_string(),
+            (None, _) => self.to_string(),
+        }
+    }
+
+    /// Number of decimal places amounts in this currency are given to
+    pub fn decimal_places(&self) -> usize {
+        self.formatting().1
+    }
+
+    /// Render an amount with the currency's symbol and decimal places (e.g. `£9.99`, `¥1000`)
+    pub fn format_amount(&self, amount: f64) -> String {
+        match self.formatting() {
+            (Some(symbol), places) => format!("{}{:.*}", symbol, places, amount),
+            (None, places) => format!("{} {:.*}", self, places, amount),
+        }
+    }
+}
+
+/// Territory of prices that do not name one
+pub const DEFAULT_PRICE_TERRITORY: &str = "WORLD";
+
+/// A price's type and territory, defaulting to RRP excluding tax in the whole world
+fn price_type_and_territory(
+    price_type: &Option<PriceType>,
+    territory: &Option<String>,
+) -> (PriceType, String) {
+    (
+        price_type.clone().unwrap_or_default(),
+        territory
+            .clone()
+            .unwrap_or_else(|| DEFAULT_PRICE_TERRITORY.to_string()),
+    )
+}
+
+impl NewPrice {
+    /// The price with its type and territory set, applying the defaults where they are not given
+    pub fn with_defaults(self) -> NewPrice {
+        let (price_type, territory) = price_type_and_territory(&self.price_type, &self.territory);
+        NewPrice {
+            price_type: Some(price_type),
+            territory: Some(territory),
+            ..self
+        }
+    }
+}
+
+impl PatchPrice {
+    /// The price with its type and territory set, applying the defaults where they are not given
+    pub fn with_defaults(self) -> PatchPrice {
+        let (price_type, territory) = price_type_and_territory(&self.price_type, &self.territory);
+        PatchPrice {
+            price_type: Some(price_type),
+            territory: Some(territory),
+            ..self
+        }
+    }
+}
+
+impl PublicationPrice {
+    /// Currency, price type and territory: a publication has at most one price for each
+    pub fn key(&self) -> (CurrencyCode, PriceType, String) {
+        let (price_type, territory) = price_type_and_territory(&self.price_type, &self.territory);
+        (self.currency_code.clone(), price_type, territory)
+    }
+
+    pub fn validate(&self) -> Result<(), ThothError> {
+        if self.unit_price > 0.0 {
+            Ok(())
+        } else {
+            Err(ThothError::InvalidInput(format!(
+                "{} is not a valid {} price: prices must be greater than zero, and items without a price described by their unpriced item type",
+                self.unit_price, self.currency_code
+            )))
+        }
+    }
+
+    pub fn new_price(&self, publication_id: Uuid) -> NewPrice {
+        let (currency_code, price_type, territory) = self.key();
+        NewPrice {
+            publication_id,
+            currency_code,
+            unit_price: self.unit_price,
+            price_type: Some(price_type),
+            territory: Some(territory),
+        }
+    }
+}
+
+impl Price {
+    /// Whether this is the price `key` identifies
+    pub fn has_key(&self, key: &(CurrencyCode, PriceType, String)) -> bool {
+        self.currency_code == key.0 && self.price_type == key.1 && self.territory == key.2
+    }
+}
+
+#[test]
+fn test_publication_price_defaults() {
+    let price = PublicationPrice {
+        currency_code: CurrencyCode::Eur,
+        unit_price: 10.0,
+        price_type: None,
+        territory: None,
+    };
+    assert_eq!(
+        price.key(),
+        (
+            CurrencyCode::Eur,
+            PriceType::RrpExcludingTax,
+            "WORLD".to_string()
+        )
+    );
+    assert!(price.validate().is_ok());
+    let free = PublicationPrice {
+        unit_price: 0.0,
+        ..price
+    };
+    assert!(free.validate().is_err());
+}
+
 #[test]
 fn test_currencycode_default() {
     let currencycode: CurrencyCode = Default::default();
@@ -1628,3 +2142,102 @@ fn test_currencycode_fromstr() {
     assert_eq!(CurrencyCode::from_str("ZWN").unwrap(), CurrencyCode::Zwn);
     assert_eq!(CurrencyCode::from_str("ZWR").unwrap(), CurrencyCode::Zwr);
 }
+
+#[test]
+fn test_currencycode_format_amount() {
+    assert_eq!(CurrencyCode::Gbp.format_amount(9.99), "£9.99");
+    assert_eq!(CurrencyCode::Usd.format_amount(10.0), "$10.00");
+    assert_eq!(CurrencyCode::Jpy.format_amount(1000.0), "¥1000");
+    assert_eq!(CurrencyCode::Kwd.format_amount(5.5), "KWD 5.500");
+    assert_eq!(CurrencyCode::Chf.format_amount(12.5), "CHF 12.50");
+    assert_eq!(CurrencyCode::Chf.symbol(), "CHF");
+    assert_eq!(CurrencyCode::Eur.symbol(), "€");
+    assert_eq!(CurrencyCode::Jpy.decimal_places(), 0);
+}
+
+#[test]
+fn test_pricetype_default() {
+    let pricetype: PriceType = Default::default();
+    assert_eq!(pricetype, PriceType::RrpExcludingTax);
+}
+
+#[test]
+fn test_pricetype_display() {
+    assert_eq!(
+        format!("{}", PriceType::RrpExcludingTax),
+        "RRP excluding tax"
+    );
+    assert_eq!(
+        format!("{}", PriceType::RrpIncludingTax),
+        "RRP including tax"
+    );
+    assert_eq!(
+        format!("{}", PriceType::FixedRetailExcludingTax),
+        "Fixed retail excluding tax"
+    );
+    assert_eq!(
+        format!("{}", PriceType::FixedRetailIncludingTax),
+        "Fixed retail including tax"
+    );
+    assert_eq!(
+        format!("{}", PriceType::SupplierNetExcludingTax),
+        "Supplier's net excluding tax"
+    );
+    assert_eq!(
+        format!("{}", PriceType::SupplierNetIncludingTax),
+        "Supplier's net including tax"
+    );
+}
+
+#[test]
+fn test_pricetype_fromstr() {
+    assert_eq!(
+        PriceType::from_str("RRP excluding tax").unwrap(),
+        PriceType::RrpExcludingTax
+    );
+    assert_eq!(
+        PriceType::from_str("RRP including tax").unwrap(),
+        PriceType::RrpIncludingTax
+    );
+    assert_eq!(
+        PriceType::from_str("Fixed retail excluding tax").unwrap(),
+        PriceType::FixedRetailExcludingTax
+    );
+    assert_eq!(
+        PriceType::from_str("Fixed retail including tax").unwrap(),
+        PriceType::FixedRetailIncludingTax
+    );
+    assert_eq!(
+        PriceType::from_str("Supplier's net excluding tax").unwrap(),
+        PriceType::SupplierNetExcludingTax
+    );
+    assert_eq!(
+        PriceType::from_str("Supplier's net including tax").unwrap(),
+        PriceType::SupplierNetIncludingTax
+    );
+
+    assert!(PriceType::from_str("RRP").is_err());
+    assert!(PriceType::from_str("Wholesale").is_err());
+}
+
+#[test]
+fn test_new_price_defaults() {
+    let price = NewPrice {
+        publication_id: Uuid::new_v4(),
+        currency_code: CurrencyCode::Gbp,
+        unit_price: 25.0,
+        price_type: None,
+        territory: None,
+    }
+    .with_defaults();
+    assert_eq!(price.price_type, Some(PriceType::RrpExcludingTax));
+    assert_eq!(price.territory, Some("WORLD".to_string()));
+    let price = NewPrice {
+        price_type: Some(PriceType::RrpIncludingTax),
+        territory: Some("GB".to_string()),
+        ..price
+    }
+    .with_defaults();
+    assert_eq!(price.price_type, Some(PriceType::RrpIncludingTax));
+    assert_eq!(price.territory, Some("GB".to_string()));
+}
@@ -1,10 +1,13 @@
 use chrono::naive::NaiveDateTime;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::fmt;
 use std::str::FromStr;
 use uuid::Uuid;
 
 use crate::errors::ThothError;
+use crate::identifier::validate_optional;
+pub use crate::identifier::Isbn;
 #[cfg(feature = "backend")]
 use crate::schema::publication;
 #[cfg(feature = "backend")]
@@ -34,6 +37,25 @@ pub enum PublicationType {
     Mobi,
 }
 
+#[cfg_attr(feature = "backend", derive(DbEnum, juniper::GraphQLEnum))]
+#[cfg_attr(feature = "backend", DieselType = "Unpriced_item_type")]
+#[cfg_attr(
+    feature = "backend",
+    graphql(description = "Reason a publication is not given a price (ONIX code list 57)")
+)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum UnpricedItemType {
+    #[cfg_attr(feature = "backend", db_rename = "free-of-charge")]
+    FreeOfCharge,
+    #[cfg_attr(feature = "backend", db_rename = "price-to-be-announced")]
+    PriceToBeAnnounced,
+    #[cfg_attr(feature = "backend", db_rename = "not-sold-separately")]
+    NotSoldSeparately,
+    #[cfg_attr(feature = "backend", db_rename = "contact-supplier")]
+    ContactSupplier,
+}
+
 #[cfg_attr(
     feature = "backend",
     derive(juniper::GraphQLEnum),
@@ -47,6 +69,14 @@ pub enum PublicationField {
     PublicationURL,
     CreatedAt,
     UpdatedAt,
+    WidthMm,
+    WidthIn,
+    HeightMm,
+    HeightIn,
+    DepthMm,
+    DepthIn,
+    WeightG,
+    WeightOz,
 }
 
 #[cfg_attr(feature = "backend", derive(Queryable))]
@@ -59,6 +89,15 @@ pub struct Publication {
     pub publication_url: Option<String>,
     pub created_at: NaiveDateTime,
     pub updated_at: NaiveDateTime,
+    pub width_mm: Option<f64>,
+    pub width_in: Option<f64>,
+    pub height_mm: Option<f64>,
+    pub height_in: Option<f64>,
+    pub depth_mm: Option<f64>,
+    pub depth_in: Option<f64>,
+    pub weight_g: Option<f64>,
+    pub weight_oz: Option<f64>,
+    pub unpriced_item_type: Option<UnpricedItemType>,
 }
 
 #[cfg_attr(
@@ -71,6 +110,37 @@ pub struct NewPublication {
     pub work_id: Uuid,
     pub isbn: Option<String>,
     pub publication_url: Option<String>,
+    pub width_mm: Option<f64>,
+    pub width_in: Option<f64>,
+    pub height_mm: Option<f64>,
+    pub height_in: Option<f64>,
+    pub depth_mm: Option<f64>,
+    pub depth_in: Option<f64>,
+    pub weight_g: Option<f64>,
+    pub weight_oz: Option<f64>,
+    pub unpriced_item_type: Option<UnpricedItemType>,
+}
+
+#[cfg_attr(
+    feature = "backend",
+    derive(juniper::GraphQLInputObject),
+    graphql(description = "A publication to create along with its work in a bulk import")
+)]
+pub struct NewWorkPublication {
+    pub publication_type: PublicationType,
+    pub isbn: Option<String>,
+    pub publication_url: Option<String>,
+}
+
+#[cfg_attr(
+    feature = "backend",
+    derive(juniper::GraphQLObject),
+    graphql(description = "An ISBN of a work, with the type of the publication it identifies")
+)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WorkIsbn {
+    pub isbn: String,
+    pub publication_type: PublicationType,
 }
 
 #[cfg_attr(
@@ -85,6 +155,15 @@ pub struct PatchPublication {
     pub work_id: Uuid,
     pub isbn: Option<String>,
     pub publication_url: Option<String>,
+    pub width_mm: Option<f64>,
+    pub width_in: Option<f64>,
+    pub height_mm: Option<f64>,
+    pub height_in: Option<f64>,
+    pub depth_mm: Option<f64>,
+    pub depth_in: Option<f64>,
+    pub weight_g: Option<f64>,
+    pub weight_oz: Option<f64>,
+    pub unpriced_item_type: Option<UnpricedItemType>,
 }
 
 #[cfg_attr(feature = "backend", derive(Queryable))]
@@ -107,6 +186,153 @@ pub struct NewPublicationHistory {
     pub data: serde_json::Value,
 }
 
+#[cfg_attr(
+    feature = "backend",
+    derive(juniper::GraphQLObject),
+    graphql(description = "A publication whose ISBN fails format or checksum validation")
+)]
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct InvalidIsbn {
+    pub publication_id: Uuid,
+    pub work_id: Uuid,
+    pub isbn: String,
+    pub error: String,
+}
+
+#[cfg_attr(
+    feature = "backend",
+    derive(juniper::GraphQLObject),
+    graphql(description = "An ISBN assigned to more than one publication")
+)]
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct DuplicateIsbn {
+    pub isbn: String,
+    pub publication_ids: Vec<Uuid>,
+}
+
+#[cfg_attr(
+    feature = "backend",
+    derive(juniper::GraphQLObject),
+    graphql(description = "Validation report of all ISBNs in a publisher's catalogue")
+)]
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct IsbnReport {
+    pub publisher_id: Uuid,
+    pub invalid_isbns: Vec<InvalidIsbn>,
+    pub duplicate_isbns: Vec<DuplicateIsbn>,
+}
+
+impl IsbnReport {
+    /// Build a report from `(publication_id, work_id, isbn)` triples belonging to one publisher.
+    pub fn new(publisher_id: Uuid, publications: Vec<(Uuid, Uuid, String)>) -> IsbnReport {
+        let mut invalid_isbns = vec![];
+        let mut seen: BTreeMap<String, (String, Vec<Uuid>)> = BTreeMap::new();
+        for (publication_id, work_id, isbn) in publications {
+            match isbn.parse::<Isbn>() {
+                Ok(parsed) => {
+                    seen.entry(parsed.digits())
+                        .or_insert_with(|| (isbn.clone(), vec![]))
+                        .1
+                        .push(publication_id);
+                }
+                Err(e) => invalid_isbns.push(InvalidIsbn {
+                    publication_id,
+                    work_id,
+                    isbn,
+                    error: e.to_string(),
+                }),
+            }
+        }
+        let duplicate_isbns = seen
+            .into_iter()
+            .filter(|(_, (_, ids))| ids.len() > 1)
+            .map(|(_, (isbn, publication_ids))| DuplicateIsbn {
+                isbn,
+                publication_ids,
+            })
+            .collect();
+        IsbnReport {
+            publisher_id,
+            invalid_isbns,
+            duplicate_isbns,
+        }
+    }
+}
+
+impl PublicationType {
+    pub fn is_physical(&self) -> bool {
+        matches!(self, PublicationType::Paperback | PublicationType::Hardback)
+    }
+}
+
+/// Check that each dimension is given in both units or neither, and only for physical types.
+/// These mirror the `*_not_missing` and `non_physical_no_dimensions` database constraints.
+fn validate_dimensions(
+    publication_type: &PublicationType,
+    dimensions: &[(&str, Option<f64>, Option<f64>)],
+) -> Result<(), ThothError> {
+    for (name, metric, imperial) in dimensions {
+        if metric.is_some() != imperial.is_some() {
+            return Err(ThothError::DimensionMissingError(name.to_string()));
+        }
+        if metric.is_some() && !publication_type.is_physical() {
+            return Err(ThothError::DimensionNotPhysicalError);
+        }
+    }
+    Ok(())
+}
+
+impl NewPublication {
+    pub fn validate(&self) -> Result<(), ThothError> {
+        validate_optional::<Isbn>(&self.isbn)?;
+        validate_dimensions(
+            &self.publication_type,
+            &[
+                ("Width", self.width_mm, self.width_in),
+                ("Height", self.height_mm, self.height_in),
+                ("Depth", self.depth_mm, self.depth_in),
+                ("Weight", self.weight_g, self.weight_oz),
+            ],
+        )
+    }
+}
+
+impl NewWorkPublication {
+    /// The publication to insert once the work it belongs to has been created
+    pub fn for_work(&self, work_id: Uuid) -> NewPublication {
+        NewPublication {
+            publication_type: self.publication_type.clone(),
+            work_id,
+            isbn: self.isbn.clone(),
+            publication_url: self.publication_url.clone(),
+            width_mm: None,
+            width_in: None,
+            height_mm: None,
+            height_in: None,
+            depth_mm: None,
+            depth_in: None,
+            weight_g: None,
+            weight_oz: None,
+            unpriced_item_type: None,
+        }
+    }
+}
+
+impl PatchPublication {
+    pub fn validate(&self) -> Result<(), ThothError> {
+        validate_optional::<Isbn>(&self.isbn)?;
+        validate_dimensions(
+            &self.publication_type,
+            &[
+                ("Width", self.width_mm, self.width_in),
+                ("Height", self.height_mm, self.height_in),
+                ("Depth", self.depth_mm, self.depth_in),
+                ("Weight", self.weight_g, self.weight_oz),
+            ],
+        )
+    }
+}
+
 impl Default for PublicationType {
     fn default() -> PublicationType {
         PublicationType::Paperback
@@ -144,6 +370,31 @@ impl FromStr for PublicationType {
     }
 }
 
+impl fmt::Display for UnpricedItemType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            UnpricedItemType::FreeOfCharge => write!(f, "Free of charge"),
+            UnpricedItemType::PriceToBeAnnounced => write!(f, "Price to be announced"),
+            UnpricedItemType::NotSoldSeparately => write!(f, "Not sold separately"),
+            UnpricedItemType::ContactSupplier => write!(f, "Contact supplier"),
+        }
+    }
+}
+
+impl FromStr for UnpricedItemType {
+    type Err = ThothError;
+
+    fn from_str(input: &str) -> Result<UnpricedItemType, ThothError> {
+        match input {
+            "Free of charge" => Ok(UnpricedItemType::FreeOfCharge),
+            "Price to be announced" => Ok(UnpricedItemType::PriceToBeAnnounced),
+            "Not sold separately" => Ok(UnpricedItemType::NotSoldSeparately),
+            "Contact supplier" => Ok(UnpricedItemType::ContactSupplier),
+            _ => Err(ThothError::InvalidUnpricedItemType(input.to_string())),
+        }
+    }
+}
+
 #[test]
 fn test_publicationtype_default() {
     let pubtype: PublicationType = Default::default();
@@ -195,3 +446,106 @@ fn test_publicationtype_fromstr() {
     assert!(PublicationType::from_str("PNG").is_err());
     assert!(PublicationType::from_str("Latex").is_err());
 }
+
+#[test]
+fn test_unpriceditemtype_display() {
+    assert_eq!(
+        format!("{}", UnpricedItemType::FreeOfCharge),
+        "Free of charge"
+    );
+    assert_eq!(
+        format!("{}", UnpricedItemType::PriceToBeAnnounced),
+        "Price to be announced"
+    );
+    assert_eq!(
+        format!("{}", UnpricedItemType::NotSoldSeparately),
+        "Not sold separately"
+    );
+    assert_eq!(
+        format!("{}", UnpricedItemType::ContactSupplier),
+        "Contact supplier"
+    );
+}
+
+#[test]
+fn test_unpriceditemtype_fromstr() {
+    assert_eq!(
+        UnpricedItemType::from_str("Free of charge").unwrap(),
+        UnpricedItemType::FreeOfCharge
+    );
+    assert_eq!(
+        UnpricedItemType::from_str("Contact supplier").unwrap(),
+        UnpricedItemType::ContactSupplier
+    );
+    assert!(UnpricedItemType::from_str("Free").is_err());
+}
+
+#[cfg(test)]
+fn new_publication(publication_type: PublicationType) -> NewPublication {
+    NewPublication {
+        publication_type,
+        work_id: Default::default(),
+        isbn: None,
+        publication_url: None,
+        width_mm: None,
+        width_in: None,
+        height_mm: None,
+        height_in: None,
+        depth_mm: None,
+        depth_in: None,
+        weight_g: None,
+        weight_oz: None,
+        unpriced_item_type: None,
+    }
+}
+
+#[test]
+fn test_publication_validate_dimensions() {
+    let mut publication = new_publication(PublicationType::Paperback);
+    assert!(publication.validate().is_ok());
+
+    publication.width_mm = Some(156.0);
+    assert!(publication.validate().is_err());
+    publication.width_in = Some(6.14);
+    assert!(publication.validate().is_ok());
+
+    publication.weight_oz = Some(12.5);
+    assert!(publication.validate().is_err());
+    publication.weight_g = Some(354.0);
+    assert!(publication.validate().is_ok());
+}
+
+#[test]
+fn test_publication_validate_non_physical() {
+    let mut publication = new_publication(PublicationType::PDF);
+    assert!(publication.validate().is_ok());
+
+    publication.height_mm = Some(234.0);
+    publication.height_in = Some(9.21);
+    assert!(publication.validate().is_err());
+}
+
+#[test]
+fn test_isbn_report() {
+    let publisher_id = Uuid::new_v4();
+    let work_id = Uuid::new_v4();
+    let (first, second, third) = (Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4());
+    let report = IsbnReport::new(
+        publisher_id,
+        vec![
+            (first, work_id, "978-3-16-148410-0".to_string()),
+            (second, work_id, "978-31-6148410-0".to_string()),
+            (third, work_id, "978-3-16-148410-1".to_string()),
+        ],
+    );
+    assert_eq!(report.publisher_id, publisher_id);
+    assert_eq!(report.invalid_isbns.len(), 1);
+    assert_eq!(report.invalid_isbns[0].publication_id, third);
+    assert_eq!(
+        report.duplicate_isbns,
+        vec![DuplicateIsbn {
+            isbn: "978-3-16-148410-0".to_string(),
+            publication_ids: vec![first, second],
+        }]
+    );
+}
@@ -6,6 +6,7 @@ use crate::errors::ThothError;
 use crate::publisher::model::NewPublisherHistory;
 use crate::publisher::model::Publisher;
 use crate::publisher::model::PublisherHistory;
+use crate::schema::imprint;
 use crate::schema::publisher_history;
 
 impl NewPublisherHistory {
@@ -27,3 +28,23 @@ impl NewPublisherHistory {
         }
     }
 }
+
+/// A publisher's default imprint must be one of its own imprints
+pub fn can_set_default_imprint(
+    publisher_id: Uuid,
+    default_imprint_id: Option<Uuid>,
+    connection: &PgConnection,
+) -> Result<(), ThothError> {
+    if let Some(default_imprint_id) = default_imprint_id {
+        let imprint_publisher_id = imprint::table
+            .select(imprint::publisher_id)
+            .filter(imprint::imprint_id.eq(default_imprint_id))
+            .first::<Uuid>(connection)?;
+        if imprint_publisher_id != publisher_id {
+            return Err(ThothError::InvalidInput(
+                "The default imprint must be one of the publisher's own imprints".to_string(),
+            ));
+        }
+    }
+    Ok(())
+}
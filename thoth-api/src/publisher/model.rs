@@ -31,6 +31,7 @@ pub struct Publisher {
     pub publisher_url: Option<String>,
     pub created_at: NaiveDateTime,
     pub updated_at: NaiveDateTime,
+    pub default_imprint_id: Option<Uuid>,
 }
 
 #[cfg_attr(
@@ -55,6 +56,7 @@ pub struct PatchPublisher {
     pub publisher_name: String,
     pub publisher_shortname: Option<String>,
     pub publisher_url: Option<String>,
+    pub default_imprint_id: Option<Uuid>,
 }
 
 #[cfg_attr(feature = "backend", derive(Queryable))]
@@ -17,6 +17,21 @@ table! {
     }
 }
 
+table! {
+    use diesel::sql_types::*;
+
+    api_key (api_key_id) {
+        api_key_id -> Uuid,
+        publisher_id -> Uuid,
+        account_id -> Uuid,
+        label -> Text,
+        hash -> Bytea,
+        salt -> Text,
+        created_at -> Timestamp,
+        updated_at -> Timestamp,
+    }
+}
+
 table! {
     use diesel::sql_types::*;
     use crate::contribution::model::Contribution_type;
@@ -212,6 +227,7 @@ table! {
 table! {
     use diesel::sql_types::*;
     use crate::price::model::Currency_code;
+    use crate::price::model::Price_type;
 
     price (price_id) {
         price_id -> Uuid,
@@ -220,6 +236,8 @@ table! {
         unit_price -> Float8,
         created_at -> Timestamp,
         updated_at -> Timestamp,
+        price_type -> Price_type,
+        territory -> Text,
     }
 }
 
@@ -238,6 +256,7 @@ table! {
 table! {
     use diesel::sql_types::*;
     use crate::publication::model::Publication_type;
+    use crate::publication::model::Unpriced_item_type;
 
     publication (publication_id) {
         publication_id -> Uuid,
@@ -247,6 +266,15 @@ table! {
         publication_url -> Nullable<Text>,
         created_at -> Timestamp,
         updated_at -> Timestamp,
+        width_mm -> Nullable<Float8>,
+        width_in -> Nullable<Float8>,
+        height_mm -> Nullable<Float8>,
+        height_in -> Nullable<Float8>,
+        depth_mm -> Nullable<Float8>,
+        depth_in -> Nullable<Float8>,
+        weight_g -> Nullable<Float8>,
+        weight_oz -> Nullable<Float8>,
+        unpriced_item_type -> Nullable<Unpriced_item_type>,
     }
 }
 
@@ -272,6 +300,7 @@ table! {
         publisher_url -> Nullable<Text>,
         created_at -> Timestamp,
         updated_at -> Timestamp,
+        default_imprint_id -> Nullable<Uuid>,
     }
 }
 
@@ -355,6 +384,37 @@ table! {
     }
 }
 
+table! {
+    use diesel::sql_types::*;
+    use crate::webhook::model::Webhook_event;
+
+    webhook (webhook_id) {
+        webhook_id -> Uuid,
+        publisher_id -> Uuid,
+        url -> Text,
+        event_types -> Array<Webhook_event>,
+        secret -> Text,
+        created_at -> Timestamp,
+        updated_at -> Timestamp,
+    }
+}
+
+table! {
+    use diesel::sql_types::*;
+    use crate::webhook::model::Webhook_event;
+
+    webhook_delivery (webhook_delivery_id) {
+        webhook_delivery_id -> Uuid,
+        webhook_id -> Uuid,
+        event -> Webhook_event,
+        work_id -> Uuid,
+        attempt -> Int4,
+        status_code -> Nullable<Int4>,
+        error -> Nullable<Text>,
+        created_at -> Timestamp,
+    }
+}
+
 table! {
     use diesel::sql_types::*;
     use crate::work::model::Work_type;
@@ -394,6 +454,67 @@ table! {
         cover_caption -> Nullable<Text>,
         created_at -> Timestamp,
         updated_at -> Timestamp,
+        cover_format -> Nullable<Text>,
+        cover_width -> Nullable<Int4>,
+        cover_height -> Nullable<Int4>,
+        short_doi -> Nullable<Text>,
+    }
+}
+
+table! {
+    use diesel::sql_types::*;
+    use crate::language::model::Language_code;
+    use crate::work_abstract::model::Abstract_type;
+
+    work_abstract (abstract_id) {
+        abstract_id -> Uuid,
+        work_id -> Uuid,
+        language_code -> Language_code,
+        abstract_type -> Abstract_type,
+        text -> Text,
+        created_at -> Timestamp,
+        updated_at -> Timestamp,
+    }
+}
+
+table! {
+    use diesel::sql_types::*;
+
+    work_abstract_history (abstract_history_id) {
+        abstract_history_id -> Uuid,
+        abstract_id -> Uuid,
+        account_id -> Uuid,
+        data -> Jsonb,
+        timestamp -> Timestamp,
+    }
+}
+
+table! {
+    use diesel::sql_types::*;
+    use crate::language::model::Language_code;
+    use crate::work_title::model::Title_type;
+
+    work_title (title_id) {
+        title_id -> Uuid,
+        work_id -> Uuid,
+        language_code -> Language_code,
+        title_type -> Title_type,
+        title -> Text,
+        subtitle -> Nullable<Text>,
+        created_at -> Timestamp,
+        updated_at -> Timestamp,
+    }
+}
+
+table! {
+    use diesel::sql_types::*;
+
+    work_title_history (title_history_id) {
+        title_history_id -> Uuid,
+        title_id -> Uuid,
+        account_id -> Uuid,
+        data -> Jsonb,
+        timestamp -> Timestamp,
     }
 }
 
@@ -409,6 +530,8 @@ table! {
     }
 }
 
+joinable!(api_key -> account (account_id));
+joinable!(api_key -> publisher (publisher_id));
 joinable!(contribution -> contributor (contributor_id));
 joinable!(contribution -> work (work_id));
 joinable!(contribution_history -> account (account_id));
@@ -445,12 +568,21 @@ joinable!(series_history -> series (series_id));
 joinable!(subject -> work (work_id));
 joinable!(subject_history -> account (account_id));
 joinable!(subject_history -> subject (subject_id));
+joinable!(webhook -> publisher (publisher_id));
+joinable!(webhook_delivery -> webhook (webhook_id));
 joinable!(work -> imprint (imprint_id));
+joinable!(work_abstract -> work (work_id));
+joinable!(work_abstract_history -> account (account_id));
+joinable!(work_abstract_history -> work_abstract (abstract_id));
 joinable!(work_history -> account (account_id));
 joinable!(work_history -> work (work_id));
+joinable!(work_title -> work (work_id));
+joinable!(work_title_history -> account (account_id));
+joinable!(work_title_history -> work_title (title_id));
 
 allow_tables_to_appear_in_same_query!(
     account,
+    api_key,
     contribution,
     contribution_history,
     contributor,
@@ -476,6 +608,12 @@ allow_tables_to_appear_in_same_query!(
     series_history,
     subject,
     subject_history,
+    webhook,
+    webhook_delivery,
     work,
+    work_abstract,
+    work_abstract_history,
     work_history,
+    work_title,
+    work_title_history,
 );
@@ -2,6 +2,8 @@ use chrono::naive::NaiveDateTime;
 use phf::phf_map;
 use phf::Map;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::collections::HashSet;
 use std::fmt;
 use std::str::FromStr;
 use uuid::Uuid;
@@ -15,7 +17,7 @@ use crate::schema::subject_history;
 
 #[cfg_attr(feature = "backend", derive(DbEnum, juniper::GraphQLEnum))]
 #[cfg_attr(feature = "backend", DieselType = "Subject_type")]
-#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum SubjectType {
     Bic,
@@ -99,6 +101,18 @@ pub struct NewSubjectHistory {
     pub data: serde_json::Value,
 }
 
+#[cfg_attr(
+    feature = "backend",
+    derive(juniper::GraphQLObject),
+    graphql(description = "A subject and the number of works in a set that carry it")
+)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SubjectCount {
+    pub subject_type: SubjectType,
+    pub subject_code: String,
+    pub work_count: i32,
+}
+
 pub fn check_subject(subject_type: &SubjectType, code: &str) -> Result<()> {
     let valid = match &subject_type {
         SubjectType::Bic => true,
@@ -115,6 +129,119 @@ pub fn check_subject(subject_type: &SubjectType, code: &str) -> Result<()> {
     }
 }
 
+/// Default number of candidates returned by `suggest_keywords`
+pub const DEFAULT_KEYWORD_SUGGESTIONS: usize = 10;
+/// Words shorter than this are too vague to be useful keywords
+const MIN_KEYWORD_LENGTH: usize = 3;
+/// Occurrences in the title count this many times more than those in the abstract
+const TITLE_WEIGHT: usize = 2;
+const STOPWORDS: &str = "\
+    about above across after again against all almost also although among and another any \
+    are around because been before being below between both but can could did does doing \
+    down during each either even ever every few for from further had has have having her \
+    here hers herself him himself his how however into its itself just least less many may \
+    might more most much must neither nor not now off once one only other others our ours \
+    ourselves out over own per rather same several she should since some still such than \
+    that the their theirs them themselves then there therefore these they this those \
+    though through thus too under until upon very was way well were what when where \
+    whether which while who whom whose why will with within without would yet you your \
+    yours";
+
+/// Rank candidate keywords for a work by how often they occur in its title and long abstract,
+/// leaving out stopwords and any keyword already in `existing`. Returns nothing without an abstract
+pub fn suggest_keywords(
+    title: &str,
+    long_abstract: Option<&str>,
+    existing: &[String],
+    limit: usize,
+) -> Vec<String> {
+    let long_abstract = match long_abstract.map(str::trim) {
+        Some(text) if !text.is_empty() => text,
+        _ => return vec![],
+    };
+    let existing: Vec<String> = existing.iter().map(|k| k.trim().to_lowercase()).collect();
+    // word => (weighted frequency, position of first occurrence)
+    let mut scores: HashMap<String, (usize, usize)> = HashMap::new();
+    let title_words = keyword_candidates(title).map(|w| (w, TITLE_WEIGHT));
+    let abstract_words = keyword_candidates(long_abstract).map(|w| (w, 1));
+    for (position, (word, weight)) in title_words.chain(abstract_words).enumerate() {
+        if existing.contains(&word) {
+            continue;
+        }
+        scores.entry(word).or_insert((0, position)).0 += weight;
+    }
+    let mut ranked: Vec<(String, (usize, usize))> = scores.into_iter().collect();
+    ranked.sort_by(|(_, (a_score, a_first)), (_, (b_score, b_first))| {
+        b_score.cmp(a_score).then(a_first.cmp(b_first))
+    });
+    ranked
+        .into_iter()
+        .take(limit)
+        .map(|(word, _)| word)
+        .collect()
+}
+
+fn keyword_candidates(text: &str) -> impl Iterator<Item = String> + '_ {
+    text.split(|c: char| !(c.is_alphanumeric() || c == '-'))
+        .map(|word| word.trim_matches('-').to_lowercase())
+        .filter(|word| {
+            word.chars().count() >= MIN_KEYWORD_LENGTH
+                && word.chars().any(char::is_alphabetic)
+                && !STOPWORDS
+                    .split_whitespace()
+                    .any(|stopword| stopword == word.as_str())
+        })
+}
+
+/// Count how many distinct works carry each subject, given `(work_id, subject_type, subject_code)`
+/// rows. The most common subjects come first; ties are ordered by type and code.
+pub fn count_subjects(subjects: Vec<(Uuid, SubjectType, String)>) -> Vec<SubjectCount> {
+    let mut works: HashMap<(SubjectType, String), HashSet<Uuid>> = HashMap::new();
+    for (work_id, subject_type, subject_code) in subjects {
+        works
+            .entry((subject_type, subject_code))
+            .or_insert_with(HashSet::new)
+            .insert(work_id);
+    }
+    let mut counts: Vec<SubjectCount> = works
+        .into_iter()
+        .map(|((subject_type, subject_code), work_ids)| SubjectCount {
+            subject_type,
+            subject_code,
+            work_count: work_ids.len() as i32,
+        })
+        .collect();
+    counts.sort_by(|a, b| {
+        b.work_count
+            .cmp(&a.work_count)
+            .then_with(|| a.subject_type.to_string().cmp(&b.subject_type.to_string()))
+            .then_with(|| a.subject_code.cmp(&b.subject_code))
+    });
+    counts
+}
+
+/// The main subject of each scheme among a work's subjects, as ONIX flags them with
+/// `<MainSubject/>`: the one with the lowest ordinal, ties going to the lowest code. The result
+/// holds at most one subject per type, ordered by type.
+pub fn main_subjects(subjects: Vec<Subject>) -> Vec<Subject> {
+    let mut main: HashMap<SubjectType, Subject> = HashMap::new();
+    for subject in subjects {
+        let replace = match main.get(&subject.subject_type) {
+            Some(current) => {
+                (subject.subject_ordinal, &subject.subject_code)
+                    < (current.subject_ordinal, &current.subject_code)
+            }
+            None => true,
+        };
+        if replace {
+            main.insert(subject.subject_type.clone(), subject);
+        }
+    }
+    let mut main: Vec<Subject> = main.into_iter().map(|(_, subject)| subject).collect();
+    main.sort_by(|a, b| a.subject_type.to_string().cmp(&b.subject_type.to_string()));
+    main
+}
+
 impl Default for SubjectType {
     fn default() -> SubjectType {
         SubjectType::Keyword
@@ -202,6 +329,117 @@ fn test_check_subject() {
     assert!(check_subject(&SubjectType::Thema, "AHBW").is_err());
 }
 
+#[test]
+fn test_suggest_keywords() {
+    let title = "Open Access Publishing";
+    let long_abstract = Some(
+        "This book examines open access publishing in the humanities. \
+         Publishing practices in the humanities differ from those of the sciences, \
+         and open access monographs face particular challenges.",
+    );
+    let keywords = suggest_keywords(title, long_abstract, &[], DEFAULT_KEYWORD_SUGGESTIONS);
+    // Ties are broken by first occurrence
+    assert_eq!(keywords[0], "open");
+    assert_eq!(keywords[1], "access");
+    assert_eq!(keywords[2], "publishing");
+    assert_eq!(keywords[3], "humanities");
+    assert!(!keywords.contains(&"this".to_string()));
+    assert!(!keywords.contains(&"the".to_string()));
+
+    // Keywords already attached to the work are not suggested again
+    let existing = vec!["Open".to_string(), "humanities".to_string()];
+    let keywords = suggest_keywords(title, long_abstract, &existing, 2);
+    assert_eq!(
+        keywords,
+        vec!["access".to_string(), "publishing".to_string()]
+    );
+
+    // No abstract, no suggestions
+    assert!(suggest_keywords(title, None, &[], DEFAULT_KEYWORD_SUGGESTIONS).is_empty());
+    assert!(suggest_keywords(title, Some("  "), &[], DEFAULT_KEYWORD_SUGGESTIONS).is_empty());
+}
+
+#[test]
+fn test_count_subjects() {
+    let first_work = Uuid::parse_str("00000000-0000-0000-0000-000000000001").unwrap();
+    let second_work = Uuid::parse_str("00000000-0000-0000-0000-000000000002").unwrap();
+    let counts = count_subjects(vec![
+        (first_work, SubjectType::Keyword, "open access".to_string()),
+        (first_work, SubjectType::Bisac, "SOC000000".to_string()),
+        (second_work, SubjectType::Keyword, "open access".to_string()),
+        (second_work, SubjectType::Keyword, "humanities".to_string()),
+        // the same subject twice on one work is counted once
+        (second_work, SubjectType::Keyword, "humanities".to_string()),
+    ]);
+    assert_eq!(
+        counts,
+        vec![
+            SubjectCount {
+                subject_type: SubjectType::Keyword,
+                subject_code: "open access".to_string(),
+                work_count: 2,
+            },
+            SubjectCount {
+                subject_type: SubjectType::Bisac,
+                subject_code: "SOC000000".to_string(),
+                work_count: 1,
+            },
+            SubjectCount {
+                subject_type: SubjectType::Keyword,
+                subject_code: "humanities".to_string(),
+                work_count: 1,
+            },
+        ]
+    );
+    assert!(count_subjects(vec![]).is_empty());
+}
+
+#[cfg(test)]
+fn test_subject(subject_type: SubjectType, subject_code: &str, subject_ordinal: i32) -> Subject {
+    Subject {
+        subject_id: Uuid::new_v4(),
+        work_id: Uuid::parse_str("00000000-0000-0000-0000-000000000001").unwrap(),
+        subject_type,
+        subject_code: subject_code.to_string(),
+        subject_ordinal,
+        created_at: NaiveDateTime::from_timestamp(0, 0),
+        updated_at: NaiveDateTime::from_timestamp(0, 0),
+    }
+}
+
+#[test]
+fn test_main_subjects() {
+    let main = main_subjects(vec![
+        test_subject(SubjectType::Bisac, "SOC026000", 2),
+        test_subject(SubjectType::Bisac, "SOC000000", 1),
+        test_subject(SubjectType::Bisac, "HIS000000", 3),
+        test_subject(SubjectType::Keyword, "open access", 1),
+        test_subject(SubjectType::Thema, "JBSL", 1),
+    ]);
+    let main: Vec<(SubjectType, &str)> = main
+        .iter()
+        .map(|s| (s.subject_type.clone(), s.subject_code.as_str()))
+        .collect();
+    assert_eq!(
+        main,
+        vec![
+            (SubjectType::Bisac, "SOC000000"),
+            (SubjectType::Keyword, "open access"),
+            (SubjectType::Thema, "JBSL"),
+        ]
+    );
+
+    // subjects sharing the lowest ordinal are told apart by their code
+    let main = main_subjects(vec![
+        test_subject(SubjectType::Bisac, "SOC026000", 1),
+        test_subject(SubjectType::Bisac, "HIS000000", 1),
+    ]);
+    assert_eq!(main.len(), 1);
+    assert_eq!(main[0].subject_code, "HIS000000");
+
+    assert!(main_subjects(vec![]).is_empty());
+}
+
 static THEMA_CODES: Map<&'static str, &'static str> = phf_map! {
     "A" => "The Arts",
     "AB" => "The arts: general issues",
@@ -0,0 +1,222 @@
+use chrono::Utc;
+use diesel::prelude::*;
+use reqwest_outbound::Url;
+use std::sync::mpsc::{sync_channel, SyncSender, TrySendError};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+use uuid::Uuid;
+
+use crate::db::PgPool;
+use crate::errors::ThothError;
+use crate::outbound::{check_public_url, send_public};
+use crate::schema::webhook_delivery;
+use crate::webhook::model::sign_payload;
+use crate::webhook::model::NewWebhookDelivery;
+use crate::webhook::model::Webhook;
+use crate::webhook::model::WebhookDelivery;
+use crate::webhook::model::WebhookEvent;
+use crate::webhook::model::WebhookPayload;
+use crate::work::model::Work;
+
+pub const SIGNATURE_HEADER: &str = "X-Thoth-Signature";
+const MAX_DELIVERY_ATTEMPTS: i32 = 3;
+/// Delay before the first retry, doubled after each further failure
+const RETRY_DELAY: Duration = Duration::from_secs(5);
+const DELIVERY_TIMEOUT: Duration = Duration::from_secs(10);
+/// Deliveries waiting for a worker. Once it is full, further deliveries are logged as failed
+/// instead of holding up the mutation that triggered them.
+const DELIVERY_QUEUE_SIZE: usize = 256;
+/// Threads sending deliveries, shared by every webhook
+const DELIVERY_WORKERS: usize = 4;
+
+lazy_static::lazy_static! {
+    static ref DELIVERY_QUEUE: Mutex<SyncSender<(Delivery, Arc<PgPool>)>> =
+        Mutex::new(start_delivery_workers());
+}
+
+fn start_delivery_workers() -> SyncSender<(Delivery, Arc<PgPool>)> {
+    let (sender, receiver) = sync_channel::<(Delivery, Arc<PgPool>)>(DELIVERY_QUEUE_SIZE);
+    let receiver = Arc::new(Mutex::new(receiver));
+    for _ in 0..DELIVERY_WORKERS {
+        let receiver = receiver.clone();
+        thread::spawn(move || loop {
+            // the lock is only held while waiting for the next delivery, not while sending it
+            let next = receiver.lock().map(|receiver| receiver.recv());
+            match next {
+                Ok(Ok((delivery, pool))) => delivery.send(&pool),
+                _ => return,
+            }
+        });
+    }
+    sender
+}
+
+impl NewWebhookDelivery {
+    pub fn insert(&self, connection: &PgConnection) -> Result<WebhookDelivery, ThothError> {
+        match diesel::insert_into(webhook_delivery::table)
+            .values(self)
+            .get_result(connection)
+        {
+            Ok(delivery) => Ok(delivery),
+            Err(e) => Err(ThothError::from(e)),
+        }
+    }
+}
+
+/// Check that a webhook URL can be delivered to, i.e. that it is an HTTP(S) URL of a public
+/// host, so that a webhook that could never be delivered is not saved
+pub fn check_webhook_url(url: &str) -> Result<(), ThothError> {
+    let invalid = |reason: String| {
+        ThothError::InvalidInput(format!("Webhook URL {} is not allowed: {}", url, reason))
+    };
+    let parsed = Url::parse(url).map_err(|e| invalid(e.to_string()))?;
+    check_public_url(&parsed).map_err(invalid)?;
+    Ok(())
+}
+
+/// Notify every webhook of the work's publisher subscribed to any of `events`.
+/// Deliveries are queued for a fixed pool of workers so that slow receivers do not hold up
+/// the mutation.
+pub fn dispatch_work_events(pool: Arc<PgPool>, work: &Work, events: Vec<WebhookEvent>) {
+    let connection = match pool.get() {
+        Ok(connection) => connection,
+        Err(_) => return,
+    };
+    let publisher_id = match crate::schema::imprint::table
+        .select(crate::schema::imprint::publisher_id)
+        .filter(crate::schema::imprint::imprint_id.eq(work.imprint_id))
+        .first::<Uuid>(&connection)
+    {
+        Ok(publisher_id) => publisher_id,
+        Err(_) => return,
+    };
+    let webhooks = crate::schema::webhook::table
+        .filter(crate::schema::webhook::publisher_id.eq(publisher_id))
+        .load::<Webhook>(&connection)
+        .unwrap_or_default();
+
+    for event in events {
+        let payload = WebhookPayload {
+            event: &event,
+            publisher_id,
+            timestamp: Utc::now().naive_utc(),
+            work,
+        };
+        let body = match serde_json::to_string(&payload) {
+            Ok(body) => body,
+            Err(_) => continue,
+        };
+        for webhook in webhooks.iter().filter(|w| w.event_types.contains(&event)) {
+            let pool = pool.clone();
+            let delivery = Delivery {
+                webhook_id: webhook.webhook_id,
+                url: webhook.url.clone(),
+                signature: sign_payload(&webhook.secret, body.as_bytes()),
+                event: event.clone(),
+                work_id: work.work_id,
+                body: body.clone(),
+            };
+            queue_delivery(delivery, pool);
+        }
+    }
+}
+
+fn queue_delivery(delivery: Delivery, pool: Arc<PgPool>) {
+    let queued = match DELIVERY_QUEUE.lock() {
+        Ok(queue) => queue.try_send((delivery, pool)),
+        Err(_) => return,
+    };
+    match queued {
+        Ok(()) => {}
+        Err(TrySendError::Full((delivery, pool))) => {
+            delivery.log_attempt(&pool, 1, None, Some("Delivery queue is full".to_string()))
+        }
+        Err(TrySendError::Disconnected((delivery, pool))) => delivery.log_attempt(
+            &pool,
+            1,
+            None,
+            Some("Delivery workers have stopped".to_string()),
+        ),
+    }
+}
+
+struct Delivery {
+    webhook_id: Uuid,
+    url: String,
+    signature: String,
+    event: WebhookEvent,
+    work_id: Uuid,
+    body: String,
+}
+
+impl Delivery {
+    fn log_attempt(
+        &self,
+        pool: &PgPool,
+        attempt: i32,
+        status_code: Option<i32>,
+        error: Option<String>,
+    ) {
+        if let Ok(connection) = pool.get() {
+            NewWebhookDelivery {
+                webhook_id: self.webhook_id,
+                event: self.event.clone(),
+                work_id: self.work_id,
+                attempt,
+                status_code,
+                error,
+            }
+            .insert(&connection)
+            .ok();
+        }
+    }
+
+    /// POST the payload, retrying with backoff on failure and logging every attempt. URLs
+    /// that lead to a non-public address are not sent to.
+    fn send(self, pool: &PgPool) {
+        let url = match Url::parse(&self.url) {
+            Ok(url) => url,
+            Err(e) => return self.log_attempt(pool, 1, None, Some(e.to_string())),
+        };
+        let mut delay = RETRY_DELAY;
+        for attempt in 1..=MAX_DELIVERY_ATTEMPTS {
+            let result = send_public(&url, DELIVERY_TIMEOUT, |client, url| {
+                client
+                    .post(url)
+                    .header("Content-Type", "application/json")
+                    .header(SIGNATURE_HEADER, &self.signature)
+                    .body(self.body.clone())
+            });
+            let (status_code, error) = match result {
+                Ok(response) if response.status().is_success() => {
+                    (Some(response.status().as_u16() as i32), None)
+                }
+                Ok(response) => (
+                    Some(response.status().as_u16() as i32),
+                    Some(format!("Unexpected response status {}", response.status())),
+                ),
+                Err(error) => (None, Some(error)),
+            };
+            let delivered = error.is_none();
+            self.log_attempt(pool, attempt, status_code, error);
+            if delivered {
+                return;
+            }
+            if attempt < MAX_DELIVERY_ATTEMPTS {
+                thread::sleep(delay);
+                delay *= 2;
+            }
+        }
+    }
+}
+
+#[test]
+fn test_check_webhook_url() {
+    assert!(check_webhook_url("http://169.254.169.254/latest/meta-data/").is_err());
+    assert!(check_webhook_url("http://localhost:8000/hook").is_err());
+    assert!(check_webhook_url("http://[::1]/hook").is_err());
+    assert!(check_webhook_url("ftp://93.184.216.34/hook").is_err());
+    assert!(check_webhook_url("not a url").is_err());
+    assert!(check_webhook_url("https://93.184.216.34/hook").is_ok());
+}
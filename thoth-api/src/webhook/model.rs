@@ -0,0 +1,206 @@
+use chrono::naive::NaiveDateTime;
+#[cfg(feature = "backend")]
+use hmac::{Hmac, Mac, NewMac};
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "backend")]
+use sha2::Sha256;
+use std::fmt;
+use std::str::FromStr;
+use uuid::Uuid;
+
+use crate::errors::ThothError;
+#[cfg(feature = "backend")]
+use crate::schema::webhook;
+#[cfg(feature = "backend")]
+use crate::schema::webhook_delivery;
+use crate::work::model::WorkStatus;
+
+#[cfg_attr(feature = "backend", derive(DbEnum, juniper::GraphQLEnum))]
+#[cfg_attr(feature = "backend", DieselType = "Webhook_event")]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum WebhookEvent {
+    #[cfg_attr(feature = "backend", db_rename = "work-created")]
+    WorkCreated,
+    #[cfg_attr(feature = "backend", db_rename = "work-updated")]
+    WorkUpdated,
+    #[cfg_attr(feature = "backend", db_rename = "work-published")]
+    WorkPublished,
+}
+
+#[cfg_attr(feature = "backend", derive(Queryable))]
+pub struct Webhook {
+    pub webhook_id: Uuid,
+    pub publisher_id: Uuid,
+    pub url: String,
+    pub event_types: Vec<WebhookEvent>,
+    pub secret: String,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+}
+
+#[cfg_attr(
+    feature = "backend",
+    derive(juniper::GraphQLInputObject, Insertable),
+    table_name = "webhook"
+)]
+pub struct NewWebhook {
+    pub publisher_id: Uuid,
+    pub url: String,
+    pub event_types: Vec<WebhookEvent>,
+    pub secret: String,
+}
+
+#[cfg_attr(feature = "backend", derive(Queryable))]
+pub struct WebhookDelivery {
+    pub webhook_delivery_id: Uuid,
+    pub webhook_id: Uuid,
+    pub event: WebhookEvent,
+    pub work_id: Uuid,
+    pub attempt: i32,
+    pub status_code: Option<i32>,
+    pub error: Option<String>,
+    pub created_at: NaiveDateTime,
+}
+
+#[cfg_attr(
+    feature = "backend",
+    derive(Insertable),
+    table_name = "webhook_delivery"
+)]
+pub struct NewWebhookDelivery {
+    pub webhook_id: Uuid,
+    pub event: WebhookEvent,
+    pub work_id: Uuid,
+    pub attempt: i32,
+    pub status_code: Option<i32>,
+    pub error: Option<String>,
+}
+
+/// Body POSTed to a webhook's URL when one of its events occurs
+#[derive(Serialize)]
+pub struct WebhookPayload<'a, T: Serialize> {
+    pub event: &'a WebhookEvent,
+    pub publisher_id: Uuid,
+    pub timestamp: NaiveDateTime,
+    pub work: &'a T,
+}
+
+impl WebhookEvent {
+    /// Events raised by an update that changed a work's status from `old` to `new`
+    pub fn for_work_update(old: &WorkStatus, new: &WorkStatus) -> Vec<WebhookEvent> {
+        let mut events = vec![WebhookEvent::WorkUpdated];
+        if new == &WorkStatus::Active && old != &WorkStatus::Active {
+            events.push(WebhookEvent::WorkPublished);
+        }
+        events
+    }
+
+    /// Events raised by creating a work with the given status
+    pub fn for_work_creation(status: &WorkStatus) -> Vec<WebhookEvent> {
+        let mut events = vec![WebhookEvent::WorkCreated];
+        if status == &WorkStatus::Active {
+            events.push(WebhookEvent::WorkPublished);
+        }
+        events
+    }
+}
+
+/// Hex-encoded HMAC-SHA256 of `body` keyed with the webhook's secret, prefixed with `sha256=`
+#[cfg(feature = "backend")]
+pub fn sign_payload(secret: &str, body: &[u8]) -> String {
+    let mut mac =
+        Hmac::<Sha256>::new_varkey(secret.as_bytes()).expect("HMAC accepts keys of any length");
+    mac.update(body);
+    let signature: String = mac
+        .finalize()
+        .into_bytes()
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect();
+    format!("sha256={}", signature)
+}
+
+impl Default for WebhookEvent {
+    fn default() -> WebhookEvent {
+        WebhookEvent::WorkUpdated
+    }
+}
+
+impl fmt::Display for WebhookEvent {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            WebhookEvent::WorkCreated => write!(f, "Work Created"),
+            WebhookEvent::WorkUpdated => write!(f, "Work Updated"),
+            WebhookEvent::WorkPublished => write!(f, "Work Published"),
+        }
+    }
+}
+
+impl FromStr for WebhookEvent {
+    type Err = ThothError;
+
+    fn from_str(input: &str) -> Result<WebhookEvent, ThothError> {
+        match input {
+            "Work Created" => Ok(WebhookEvent::WorkCreated),
+            "Work Updated" => Ok(WebhookEvent::WorkUpdated),
+            "Work Published" => Ok(WebhookEvent::WorkPublished),
+            _ => Err(ThothError::InvalidWebhookEvent(input.to_string())),
+        }
+    }
+}
+
+#[test]
+fn test_webhookevent_default() {
+    let webhookevent: WebhookEvent = Default::default();
+    assert_eq!(webhookevent, WebhookEvent::WorkUpdated);
+}
+
+#[test]
+fn test_webhookevent_display() {
+    assert_eq!(format!("{}", WebhookEvent::WorkCreated), "Work Created");
+    assert_eq!(format!("{}", WebhookEvent::WorkUpdated), "Work Updated");
+    assert_eq!(format!("{}", WebhookEvent::WorkPublished), "Work Published");
+}
+
+#[test]
+fn test_webhookevent_fromstr() {
+    assert_eq!(
+        WebhookEvent::from_str("Work Created").unwrap(),
+        WebhookEvent::WorkCreated
+    );
+    assert_eq!(
+        WebhookEvent::from_str("Work Updated").unwrap(),
+        WebhookEvent::WorkUpdated
+    );
+    assert_eq!(
+        WebhookEvent::from_str("Work Published").unwrap(),
+        WebhookEvent::WorkPublished
+    );
+    assert!(WebhookEvent::from_str("Work Deleted").is_err());
+}
+
+#[test]
+fn test_webhookevent_for_work_update() {
+    assert_eq!(
+        WebhookEvent::for_work_update(&WorkStatus::Forthcoming, &WorkStatus::Active),
+        vec![WebhookEvent::WorkUpdated, WebhookEvent::WorkPublished]
+    );
+    assert_eq!(
+        WebhookEvent::for_work_update(&WorkStatus::Active, &WorkStatus::Active),
+        vec![WebhookEvent::WorkUpdated]
+    );
+    assert_eq!(
+        WebhookEvent::for_work_creation(&WorkStatus::Forthcoming),
+        vec![WebhookEvent::WorkCreated]
+    );
+}
+
+#[cfg(feature = "backend")]
+#[test]
+fn test_sign_payload() {
+    assert_eq!(
+        sign_payload("key", b"The quick brown fox jumps over the lazy dog"),
+        "sha256=f7bc83f430538424b13298e6aa6fb143ef4d59a14946175997479dbc2d1a3cd8"
+    );
+}
@@ -0,0 +1,430 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+#[cfg_attr(
+    feature = "backend",
+    derive(juniper::GraphQLEnum),
+    graphql(description = "Style in which to format a citation of a work")
+)]
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum CitationStyle {
+    #[cfg_attr(feature = "backend", graphql(description = "APA, 7th edition"))]
+    Apa,
+    #[cfg_attr(
+        feature = "backend",
+        graphql(description = "Chicago Manual of Style, 17th edition (bibliography)")
+    )]
+    Chicago,
+    #[cfg_attr(
+        feature = "backend",
+        graphql(description = "Harvard (Cite Them Right)")
+    )]
+    Harvard,
+    #[cfg_attr(feature = "backend", graphql(description = "MLA, 9th edition"))]
+    Mla,
+}
+
+/// A person credited in a citation
+#[derive(Debug, Clone, PartialEq)]
+pub struct CitedName {
+    pub first_name: Option<String>,
+    pub last_name: String,
+}
+
+/// Everything a citation of a work is built from
+#[derive(Debug, Clone, PartialEq)]
+pub struct Citation {
+    /// The work's main authors, or its main editors if it has no authors
+    pub creators: Vec<CitedName>,
+    pub editors: bool,
+    pub full_title: String,
+    pub edition: i32,
+    pub place: Option<String>,
+    pub publisher: String,
+    pub year: Option<i32>,
+    /// Full DOI URL, as stored by Thoth
+    pub doi: Option<String>,
+}
+
+impl fmt::Display for CitationStyle {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CitationStyle::Apa => write!(f, "APA"),
+            CitationStyle::Chicago => write!(f, "Chicago"),
+            CitationStyle::Harvard => write!(f, "Harvard"),
+            CitationStyle::Mla => write!(f, "MLA"),
+        }
+    }
+}
+
+impl CitedName {
+    /// e.g. "Smith, J. A.", or "Smith, J.A." without `spaced`
+    fn inverted_initials(&self, spaced: bool) -> String {
+        match &self.first_name {
+            Some(first_name) => format!(
+                "{}, {}",
+                self.last_name,
+                initials(first_name, if spaced { " " } else { "" })
+            ),
+            None => self.last_name.clone(),
+        }
+    }
+
+    /// e.g. "Smith, John A."
+    fn inverted(&self) -> String {
+        match &self.first_name {
+            Some(first_name) => format!("{}, {}", self.last_name, first_name),
+            None => self.last_name.clone(),
+        }
+    }
+
+    /// e.g. "John A. Smith"
+    fn natural(&self) -> String {
+        match &self.first_name {
+            Some(first_name) => format!("{} {}", first_name, self.last_name),
+            None => self.last_name.clone(),
+        }
+    }
+}
+
+impl Citation {
+    pub fn format(&self, style: CitationStyle) -> String {
+        match style {
+            CitationStyle::Apa => self.apa(),
+            CitationStyle::Chicago => self.chicago(),
+            CitationStyle::Harvard => self.harvard(),
+            CitationStyle::Mla => self.mla(),
+        }
+    }
+
+    /// Smith, J. A., & Jones, B. (2020). Title: Subtitle (2nd ed.). Publisher. https://doi.org/…
+    fn apa(&self) -> String {
+        let names: Vec<String> = self
+            .creators
+            .iter()
+            .map(|c| c.inverted_initials(true))
+            .collect();
+        let mut citation = String::new();
+        if !names.is_empty() {
+            citation.push_str(&join_names(&names, ", ", ", & ", ", & "));
+            if self.editors {
+                citation.push_str(if names.len() == 1 {
+                    " (Ed.)"
+                } else {
+                    " (Eds.)"
+                });
+            }
+            end_sentence(&mut citation);
+            citation.push(' ');
+        }
+        match self.year {
+            Some(year) => citation.push_str(&format!("({}). ", year)),
+            None => citation.push_str("(n.d.). "),
+        }
+        citation.push_str(&self.full_title);
+        if self.edition > 1 {
+            citation.push_str(&format!(" ({} ed.)", ordinal(self.edition)));
+        }
+        end_sentence(&mut citation);
+        citation.push_str(&format!(" {}", self.publisher));
+        end_sentence(&mut citation);
+        if let Some(doi) = &self.doi {
+            citation.push_str(&format!(" {}", doi));
+        }
+        citation
+    }
+
+    /// Smith, John A., and Bob Jones. Title: Subtitle. 2nd ed. Place: Publisher, 2020. https://doi.org/…
+    fn chicago(&self) -> String {
+        let names = self.first_inverted_names();
+        let mut citation = String::new();
+        if !names.is_empty() {
+            citation.push_str(&join_names(&names, ", ", ", and ", ", and "));
+            if self.editors {
+                citation.push_str(if names.len() == 1 { ", ed" } else { ", eds" });
+            }
+            end_sentence(&mut citation);
+            citation.push(' ');
+        }
+        citation.push_str(&self.full_title);
+        end_sentence(&mut citation);
+        if self.edition > 1 {
+            citation.push_str(&format!(" {} ed.", ordinal(self.edition)));
+        }
+        citation.push(' ');
+        if let Some(place) = &self.place {
+            citation.push_str(&format!("{}: ", place));
+        }
+        citation.push_str(&self.publisher);
+        if let Some(year) = self.year {
+            citation.push_str(&format!(", {}", year));
+        }
+        end_sentence(&mut citation);
+        if let Some(doi) = &self.doi {
+            citation.push_str(&format!(" {}", doi));
+        }
+        citation
+    }
+
+    /// Smith, J.A. and Jones, B. (2020) Title: Subtitle. 2nd edn. Place: Publisher. Available at: https://doi.org/…
+    fn harvard(&self) -> String {
+        let mut names: Vec<String> = self
+            .creators
+            .iter()
+            .map(|c| c.inverted_initials(false))
+            .collect();
+        // Cite Them Right gives up to three names, and only the first of four or more
+        if names.len() > 3 {
+            names = vec![format!("{} et al.", names[0])];
+        }
+        let mut citation = String::new();
+        if !names.is_empty() {
+            citation.push_str(&join_names(&names, ", ", " and ", " and "));
+            if self.editors {
+                citation.push_str(if self.creators.len() == 1 {
+                    " (ed.)"
+                } else {
+                    " (eds)"
+                });
+            }
+            citation.push(' ');
+        }
+        match self.year {
+            Some(year) => citation.push_str(&format!("({}) ", year)),
+            None => citation.push_str("(no date) "),
+        }
+        citation.push_str(&self.full_title);
+        end_sentence(&mut citation);
+        if self.edition > 1 {
+            citation.push_str(&format!(" {} edn.", ordinal(self.edition)));
+        }
+        citation.push(' ');
+        if let Some(place) = &self.place {
+            citation.push_str(&format!("{}: ", place));
+        }
+        citation.push_str(&self.publisher);
+        end_sentence(&mut citation);
+        if let Some(doi) = &self.doi {
+            citation.push_str(&format!(" Available at: {}", doi));
+        }
+        citation
+    }
+
+    /// Smith, John A., and Bob Jones. Title: Subtitle. 2nd ed., Publisher, 2020. https://doi.org/…
+    fn mla(&self) -> String {
+        let mut names = self.first_inverted_names();
+        // MLA gives up to two names, and only the first of three or more
+        if names.len() > 2 {
+            names = vec![format!("{}, et al.", names[0])];
+        }
+        let mut citation = String::new();
+        if !names.is_empty() {
+            citation.push_str(&join_names(&names, ", ", ", and ", ", and "));
+            if self.editors {
+                citation.push_str(if self.creators.len() == 1 {
+                    ", editor"
+                } else {
+                    ", editors"
+                });
+            }
+            end_sentence(&mut citation);
+            citation.push(' ');
+        }
+        citation.push_str(&self.full_title);
+        end_sentence(&mut citation);
+        if self.edition > 1 {
+            citation.push_str(&format!(" {} ed.,", ordinal(self.edition)));
+        }
+        citation.push_str(&format!(" {}", self.publisher));
+        if let Some(year) = self.year {
+            citation.push_str(&format!(", {}", year));
+        }
+        end_sentence(&mut citation);
+        if let Some(doi) = &self.doi {
+            citation.push_str(&format!(" {}", doi));
+        }
+        citation
+    }
+
+    /// The first name inverted, for alphabetising, and the others in natural order
+    fn first_inverted_names(&self) -> Vec<String> {
+        self.creators
+            .iter()
+            .enumerate()
+            .map(|(i, c)| if i == 0 { c.inverted() } else { c.natural() })
+            .collect()
+    }
+}
+
+/// Join names with `separator`, and with `last` before the final one. A pair of names is
+/// joined with `two` instead.
+fn join_names(names: &[String], separator: &str, last: &str, two: &str) -> String {
+    match names {
+        [] => String::new(),
+        [name] => name.clone(),
+        [first, second] => format!("{}{}{}", first, two, second),
+        [rest @ .., final_name] => format!("{}{}{}", rest.join(separator), last, final_name),
+    }
+}
+
+/// Close a sentence with a full stop, unless it already ends with one (e.g. after an initial)
+fn end_sentence(text: &mut String) {
+    if !text.ends_with('.') && !text.ends_with('?') && !text.ends_with('!') {
+        text.push('.');
+    }
+}
+
+/// e.g. "John Alan" becomes "J. A." (or "J.A." when `separator` is empty), and "Jean-Paul"
+/// becomes "J.-P."
+fn initials(first_name: &str, separator: &str) -> String {
+    first_name
+        .split_whitespace()
+        .map(|name| {
+            name.split('-')
+                .filter_map(|part| part.chars().next())
+                .map(|c| format!("{}.", c))
+                .collect::<Vec<String>>()
+                .join("-")
+        })
+        .collect::<Vec<String>>()
+        .join(separator)
+}
+
+fn ordinal(number: i32) -> String {
+    let suffix = match (number % 10, number % 100) {
+        (_, 11..=13) => "th",
+        (1, _) => "st",
+        (2, _) => "nd",
+        (3, _) => "rd",
+        _ => "th",
+    };
+    format!("{}{}", number, suffix)
+}
+
+#[cfg(test)]
+fn test_name(first_name: &str, last_name: &str) -> CitedName {
+    CitedName {
+        first_name: Some(first_name.to_string()),
+        last_name: last_name.to_string(),
+    }
+}
+
+#[cfg(test)]
+fn test_citation(creators: Vec<CitedName>, editors: bool) -> Citation {
+    Citation {
+        creators,
+        editors,
+        full_title: "Feeding the City: Work and Food Culture of the Mumbai Dabbawalas".to_string(),
+        edition: 1,
+        place: Some("Cambridge, UK".to_string()),
+        publisher: "Open Book Publishers".to_string(),
+        year: Some(2019),
+        doi: Some("https://doi.org/10.11647/OBP.0173".to_string()),
+    }
+}
+
+#[test]
+fn test_apa_citation() {
+    let citation = test_citation(vec![test_name("Sara", "Roncaglia")], false);
+    assert_eq!(
+        citation.format(CitationStyle::Apa),
+        "Roncaglia, S. (2019). Feeding the City: Work and Food Culture of the Mumbai Dabbawalas. Open Book Publishers. https://doi.org/10.11647/OBP.0173"
+    );
+    let mut citation = test_citation(
+        vec![test_name("John Alan", "Smith"), test_name("Bob", "Jones")],
+        true,
+    );
+    citation.edition = 2;
+    citation.year = None;
+    citation.doi = None;
+    assert_eq!(
+        citation.format(CitationStyle::Apa),
+        "Smith, J. A., & Jones, B. (Eds.). (n.d.). Feeding the City: Work and Food Culture of the Mumbai Dabbawalas (2nd ed.). Open Book Publishers."
+    );
+}
+
+#[test]
+fn test_chicago_citation() {
+    let citation = test_citation(
+        vec![
+            test_name("Sara", "Roncaglia"),
+            test_name("Angela", "Arnone"),
+            test_name("Bob", "Jones"),
+        ],
+        false,
+    );
+    assert_eq!(
+        citation.format(CitationStyle::Chicago),
+        "Roncaglia, Sara, Angela Arnone, and Bob Jones. Feeding the City: Work and Food Culture of the Mumbai Dabbawalas. Cambridge, UK: Open Book Publishers, 2019. https://doi.org/10.11647/OBP.0173"
+    );
+    let mut citation = test_citation(vec![test_name("Sara", "Roncaglia")], true);
+    citation.edition = 3;
+    assert_eq!(
+        citation.format(CitationStyle::Chicago),
+        "Roncaglia, Sara, ed. Feeding the City: Work and Food Culture of the Mumbai Dabbawalas. 3rd ed. Cambridge, UK: Open Book Publishers, 2019. https://doi.org/10.11647/OBP.0173"
+    );
+}
+
+#[test]
+fn test_harvard_citation() {
+    let citation = test_citation(
+        vec![test_name("John Alan", "Smith"), test_name("Bob", "Jones")],
+        false,
+    );
+    assert_eq!(
+        citation.format(CitationStyle::Harvard),
+        "Smith, J.A. and Jones, B. (2019) Feeding the City: Work and Food Culture of the Mumbai Dabbawalas. Cambridge, UK: Open Book Publishers. Available at: https://doi.org/10.11647/OBP.0173"
+    );
+    let citation = test_citation(
+        vec![
+            test_name("Sara", "Roncaglia"),
+            test_name("Angela", "Arnone"),
+            test_name("Bob", "Jones"),
+            test_name("Jean-Paul", "Dupont"),
+        ],
+        true,
+    );
+    assert_eq!(
+        citation.format(CitationStyle::Harvard),
+        "Roncaglia, S. et al. (eds) (2019) Feeding the City: Work and Food Culture of the Mumbai Dabbawalas. Cambridge, UK: Open Book Publishers. Available at: https://doi.org/10.11647/OBP.0173"
+    );
+}
+
+#[test]
+fn test_mla_citation() {
+    let citation = test_citation(
+        vec![
+            test_name("Sara", "Roncaglia"),
+            test_name("Angela", "Arnone"),
+        ],
+        false,
+    );
+    assert_eq!(
+        citation.format(CitationStyle::Mla),
+        "Roncaglia, Sara, and Angela Arnone. Feeding the City: Work and Food Culture of the Mumbai Dabbawalas. Open Book Publishers, 2019. https://doi.org/10.11647/OBP.0173"
+    );
+    let mut citation = test_citation(
+        vec![
+            test_name("Sara", "Roncaglia"),
+            test_name("Angela", "Arnone"),
+            test_name("Bob", "Jones"),
+        ],
+        true,
+    );
+    citation.edition = 2;
+    assert_eq!(
+        citation.format(CitationStyle::Mla),
+        "Roncaglia, Sara, et al., editors. Feeding the City: Work and Food Culture of the Mumbai Dabbawalas. 2nd ed., Open Book Publishers, 2019. https://doi.org/10.11647/OBP.0173"
+    );
+}
+
+#[test]
+fn test_initials() {
+    assert_eq!(initials("John Alan", " "), "J. A.");
+    assert_eq!(initials("John Alan", ""), "J.A.");
+    assert_eq!(initials("Jean-Paul", " "), "J.-P.");
+    assert_eq!(ordinal(2), "2nd");
+    assert_eq!(ordinal(11), "11th");
+    assert_eq!(ordinal(23), "23rd");
+}
@@ -0,0 +1,59 @@
+use reqwest_outbound::blocking::Response;
+use reqwest_outbound::header::{CONTENT_TYPE, RANGE};
+use reqwest_outbound::Url;
+use std::io::Read;
+use std::time::Duration;
+
+use crate::errors::ThothError;
+use crate::outbound::send_public;
+use crate::work::model::CoverImage;
+
+/// Time allowed for each request, as the mutation waits on them
+const COVER_REQUEST_TIMEOUT: Duration = Duration::from_secs(5);
+/// Number of bytes fetched from the start of a cover to read its dimensions
+const COVER_HEADER_BYTES: u64 = 64 * 1024;
+
+fn content_type(response: &Response) -> String {
+    response
+        .headers()
+        .get(CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .unwrap_or_default()
+        .to_string()
+}
+
+/// Check that a URL serves an image, and read its format and dimensions.
+/// Only the start of the file is downloaded, and only from public addresses.
+pub fn fetch_cover_image(url: &str) -> Result<CoverImage, ThothError> {
+    let invalid = |reason: String| ThothError::InvalidCoverImage(url.to_string(), reason);
+    let parsed = Url::parse(url).map_err(|e| invalid(e.to_string()))?;
+
+    let not_an_image =
+        |content_type: &str| invalid(format!("{} is not an image type", content_type));
+    let head = send_public(&parsed, COVER_REQUEST_TIMEOUT, |client, url| {
+        client.head(url)
+    })
+    .map_err(invalid)?;
+    let head_type = content_type(&head);
+    // Some servers do not implement HEAD, in which case the ranged GET below decides
+    if head.status().is_success() && !head_type.is_empty() {
+        CoverImage::new(&head_type, &[]).ok_or_else(|| not_an_image(&head_type))?;
+    }
+
+    let response = send_public(&parsed, COVER_REQUEST_TIMEOUT, |client, url| {
+        client
+            .get(url)
+            .header(RANGE, format!("bytes=0-{}", COVER_HEADER_BYTES - 1))
+    })
+    .map_err(invalid)?;
+    if !response.status().is_success() {
+        return Err(invalid(format!("server responded {}", response.status())));
+    }
+    let response_type = content_type(&response);
+    let mut header = Vec::new();
+    response
+        .take(COVER_HEADER_BYTES)
+        .read_to_end(&mut header)
+        .map_err(|e| invalid(e.to_string()))?;
+    CoverImage::new(&response_type, &header).ok_or_else(|| not_an_image(&response_type))
+}
@@ -1,12 +1,26 @@
 use diesel::pg::PgConnection;
 use diesel::prelude::*;
+use diesel::result::{DatabaseErrorKind, Error as DBError};
 use uuid::Uuid;
 
 use crate::errors::ThothError;
+use crate::schema::contribution;
+use crate::schema::funding;
+use crate::schema::issue;
+use crate::schema::language;
+use crate::schema::publication;
+use crate::schema::subject;
+use crate::schema::work;
+use crate::schema::work_abstract;
 use crate::schema::work_history;
+use crate::schema::work_title;
+use crate::work::model::NewWork;
 use crate::work::model::NewWorkHistory;
 use crate::work::model::Work;
 use crate::work::model::WorkHistory;
+use crate::work_abstract::model::DEFAULT_ABSTRACT_LANGUAGE;
+use crate::work_title::model::TitleType;
+use crate::work_title::model::DEFAULT_TITLE_LANGUAGE;
 
 impl NewWorkHistory {
     pub fn new(work: Work, account_id: Uuid) -> Self {
@@ -27,3 +41,153 @@ impl NewWorkHistory {
         }
     }
 }
+
+/// Name of the case-insensitive unique index on work DOIs
+const DOI_UNIQUE_INDEX: &str = "doi_uniq_idx";
+
+impl NewWork {
+    /// Insert the work, explaining a DOI that is already taken by another work rather than
+    /// reporting the database constraint it violates
+    pub fn insert(&self, connection: &PgConnection) -> Result<Work, ThothError> {
+        match diesel::insert_into(work::table)
+            .values(self)
+            .get_result(connection)
+        {
+            Ok(work) => Ok(work),
+            Err(DBError::DatabaseError(DatabaseErrorKind::UniqueViolation, info))
+                if info.constraint_name() == Some(DOI_UNIQUE_INDEX) =>
+            {
+                Err(ThothError::InvalidInput(format!(
+                    "Another work already has the DOI {}",
+                    self.doi.as_deref().unwrap_or_default()
+                )))
+            }
+            Err(e) => Err(ThothError::from(e)),
+        }
+    }
+}
+
+/// A work that belongs to a series can only move to another imprint once it has been removed
+/// from the series: series belong to an imprint, and its issues must stay under it
+pub fn can_update_work_imprint(work_id: Uuid, connection: &PgConnection) -> Result<(), ThothError> {
+    let issue_count = issue::table
+        .filter(issue::work_id.eq(work_id))
+        .count()
+        .get_result::<i64>(connection)?;
+    if issue_count > 0 {
+        return Err(ThothError::InvalidInput(
+            "A work that is part of a series cannot change imprint. Remove it from its series first."
+                .to_string(),
+        ));
+    }
+    Ok(())
+}
+
+/// Deleting a work also deletes the records linked to it. A work that has any is only deleted
+/// when the caller asks for this explicitly with `cascade`, so that they are never lost unawares.
+/// The default title and abstracts mirror the work's own fields, so only the others count.
+pub fn can_delete_work(
+    work_id: Uuid,
+    cascade: bool,
+    connection: &PgConnection,
+) -> Result<(), ThothError> {
+    if cascade {
+        return Ok(());
+    }
+    let linked_records = [
+        (
+            "contributions",
+            contribution::table
+                .filter(contribution::work_id.eq(work_id))
+                .count()
+                .get_result::<i64>(connection)?,
+        ),
+        (
+            "publications",
+            publication::table
+                .filter(publication::work_id.eq(work_id))
+                .count()
+                .get_result::<i64>(connection)?,
+        ),
+        (
+            "series issues",
+            issue::table
+                .filter(issue::work_id.eq(work_id))
+                .count()
+                .get_result::<i64>(connection)?,
+        ),
+        (
+            "fundings",
+            funding::table
+                .filter(funding::work_id.eq(work_id))
+                .count()
+                .get_result::<i64>(connection)?,
+        ),
+        (
+            "languages",
+            language::table
+                .filter(language::work_id.eq(work_id))
+                .count()
+                .get_result::<i64>(connection)?,
+        ),
+        (
+            "subjects",
+            subject::table
+                .filter(subject::work_id.eq(work_id))
+                .count()
+                .get_result::<i64>(connection)?,
+        ),
+        (
+            "other titles",
+            work_title::table
+                .filter(work_title::work_id.eq(work_id))
+                .filter(
+                    work_title::language_code
+                        .ne(DEFAULT_TITLE_LANGUAGE)
+                        .or(work_title::title_type.ne(TitleType::Distinctive)),
+                )
+                .count()
+                .get_result::<i64>(connection)?,
+        ),
+        (
+            "other abstracts",
+            work_abstract::table
+                .filter(work_abstract::work_id.eq(work_id))
+                .filter(work_abstract::language_code.ne(DEFAULT_ABSTRACT_LANGUAGE))
+                .count()
+                .get_result::<i64>(connection)?,
+        ),
+    ];
+    check_linked_records(&linked_records)
+}
+
+fn check_linked_records(linked_records: &[(&str, i64)]) -> Result<(), ThothError> {
+    let linked: Vec<String> = linked_records
+        .iter()
+        .filter(|(_, count)| *count > 0)
+        .map(|(records, count)| format!("{}: {}", records, count))
+        .collect();
+    if linked.is_empty() {
+        return Ok(());
+    }
+    Err(ThothError::InvalidInput(format!(
+        "The work has linked records ({}). Remove them first, or delete with cascade to delete them too.",
+        linked.join(", ")
+    )))
+}
+
+#[test]
+fn test_check_linked_records() {
+    assert!(check_linked_records(&[("contributions", 0), ("other titles", 0)]).is_ok());
+    match check_linked_records(&[
+        ("contributions", 0),
+        ("other titles", 2),
+        ("other abstracts", 1),
+    ]) {
+        Err(ThothError::InvalidInput(message)) => {
+            assert!(message
+                .starts_with("The work has linked records (other titles: 2, other abstracts: 1)."))
+        }
+        other => panic!("expected the delete to be refused, got {:?}", other),
+    }
+}
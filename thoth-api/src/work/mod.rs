@@ -1,3 +1,9 @@
+pub mod citation;
+#[cfg(feature = "backend")]
+pub mod cover;
 #[cfg(feature = "backend")]
 pub mod handler;
 pub mod model;
+pub mod onix;
+#[cfg(feature = "backend")]
+pub mod short_doi;
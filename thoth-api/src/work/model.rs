@@ -1,11 +1,18 @@
 use chrono::naive::NaiveDate;
 use chrono::naive::NaiveDateTime;
+use chrono::Datelike;
+use regex::Regex;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::fmt;
 use std::str::FromStr;
 use uuid::Uuid;
 
 use crate::errors::ThothError;
+use crate::identifier::validate_optional;
+use crate::identifier::Doi;
+use crate::identifier::ShortDoi;
+use crate::publication::model::NewWorkPublication;
 #[cfg(feature = "backend")]
 use crate::schema::work;
 #[cfg(feature = "backend")]
@@ -91,6 +98,8 @@ pub enum WorkField {
     CoverCaption,
     CreatedAt,
     UpdatedAt,
+    CompletenessScore,
+    ContributionCount,
 }
 
 #[cfg_attr(feature = "backend", derive(Queryable))]
@@ -129,6 +138,10 @@ pub struct Work {
     pub cover_caption: Option<String>,
     pub created_at: NaiveDateTime,
     pub updated_at: NaiveDateTime,
+    pub cover_format: Option<String>,
+    pub cover_width: Option<i32>,
+    pub cover_height: Option<i32>,
+    pub short_doi: Option<String>,
 }
 
 #[cfg_attr(
@@ -167,6 +180,7 @@ pub struct NewWork {
     pub toc: Option<String>,
     pub cover_url: Option<String>,
     pub cover_caption: Option<String>,
+    pub short_doi: Option<String>,
 }
 
 #[cfg_attr(
@@ -207,6 +221,7 @@ pub struct PatchWork {
     pub toc: Option<String>,
     pub cover_url: Option<String>,
     pub cover_caption: Option<String>,
+    pub short_doi: Option<String>,
 }
 
 #[cfg_attr(feature = "backend", derive(Queryable))]
@@ -309,6 +324,557 @@ impl FromStr for WorkStatus {
     }
 }
 
+#[cfg_attr(
+    feature = "backend",
+    derive(juniper::GraphQLInputObject),
+    graphql(description = "A work to create, with its publications, in a bulk import")
+)]
+pub struct NewWorkImport {
+    pub work: NewWork,
+    pub publications: Vec<NewWorkPublication>,
+}
+
+#[cfg_attr(
+    feature = "backend",
+    derive(juniper::GraphQLObject),
+    graphql(
+        description = "Outcome of importing one work: the ID it was created with, or the reason it was rejected"
+    )
+)]
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct WorkImportResult {
+    pub row: i32,
+    pub work_id: Option<Uuid>,
+    pub error_type: Option<String>,
+    pub error_message: Option<String>,
+}
+
+#[cfg_attr(
+    feature = "backend",
+    derive(juniper::GraphQLObject),
+    graphql(
+        description = "Outcome of deleting one work: whether it was deleted, or the reason it was not"
+    )
+)]
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct WorkDeletionResult {
+    pub work_id: Uuid,
+    pub deleted: bool,
+    pub error_type: Option<String>,
+    pub error_message: Option<String>,
+}
+
+impl NewWork {
+    pub fn validate(&self) -> Result<(), ThothError> {
+        validate_optional::<Doi>(&self.doi)?;
+        validate_optional::<ShortDoi>(&self.short_doi)?;
+        validate_publication_date(self.publication_date)
+    }
+}
+
+impl PatchWork {
+    pub fn validate(&self) -> Result<(), ThothError> {
+        validate_optional::<Doi>(&self.doi)?;
+        validate_optional::<ShortDoi>(&self.short_doi)?;
+        validate_publication_date(self.publication_date)
+    }
+}
+
+impl WorkDeletionResult {
+    pub fn deleted(work_id: Uuid) -> WorkDeletionResult {
+        WorkDeletionResult {
+            work_id,
+            deleted: true,
+            error_type: None,
+            error_message: None,
+        }
+    }
+
+    pub fn rejected(work_id: Uuid, error: &ThothError) -> WorkDeletionResult {
+        WorkDeletionResult {
+            work_id,
+            deleted: false,
+            error_type: Some(error.error_type().to_string()),
+            error_message: Some(error.to_string()),
+        }
+    }
+
+    /// A work that could have been deleted, but was kept as others deleted along with it
+    /// could not be
+    pub fn withheld(work_id: Uuid) -> WorkDeletionResult {
+        WorkDeletionResult::rejected(
+            work_id,
+            &ThothError::InvalidInput(
+                "Not deleted, as other works in the request cannot be deleted".to_string(),
+            ),
+        )
+    }
+}
+
+/// Earliest and latest years accepted in a publication date. Dates outside them are mistyped
+/// years (e.g. `202` or `20201`), and could not be written as the four-digit years of ONIX.
+pub const PUBLICATION_YEAR_RANGE: (i32, i32) = (1450, 9999);
+
+/// Reject publication dates that cannot be right whatever the status of the work. Dates that
+/// only look wrong for the status, e.g. a past date on a forthcoming work, are reported among
+/// the work's advisories instead, as the status may be the part that is out of date.
+pub fn validate_publication_date(publication_date: Option<NaiveDate>) -> Result<(), ThothError> {
+    let (earliest, latest) = PUBLICATION_YEAR_RANGE;
+    match publication_date {
+        Some(date) if date.year() < earliest || date.year() > latest => {
+            Err(ThothError::InvalidPublicationDate(date.to_string()))
+        }
+        _ => Ok(()),
+    }
+}
+
+impl WorkImportResult {
+    pub fn created(row: i32, work_id: Uuid) -> WorkImportResult {
+        WorkImportResult {
+            row,
+            work_id: Some(work_id),
+            error_type: None,
+            error_message: None,
+        }
+    }
+
+    pub fn rejected(row: i32, error: &ThothError) -> WorkImportResult {
+        WorkImportResult {
+            row,
+            work_id: None,
+            error_type: Some(error.error_type().to_string()),
+            error_message: Some(error.to_string()),
+        }
+    }
+}
+
+#[cfg_attr(
+    feature = "backend",
+    derive(juniper::GraphQLObject),
+    graphql(description = "Number of matching works of a given type")
+)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WorkTypeFacet {
+    pub work_type: WorkType,
+    pub count: i32,
+}
+
+#[cfg_attr(
+    feature = "backend",
+    derive(juniper::GraphQLObject),
+    graphql(description = "Number of matching works published in a given year")
+)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PublicationYearFacet {
+    pub year: i32,
+    pub count: i32,
+}
+
+#[cfg_attr(
+    feature = "backend",
+    derive(juniper::GraphQLObject),
+    graphql(
+        description = "Counts used to narrow down a list of works by type and year of publication"
+    )
+)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WorkFacets {
+    pub work_types: Vec<WorkTypeFacet>,
+    pub publication_years: Vec<PublicationYearFacet>,
+}
+
+impl WorkFacets {
+    /// Count `(work_type, publication_date)` pairs by type and by year of publication.
+    /// Each facet is narrowed by the selection made in the other, so choosing a type
+    /// shows the years in which works of that type were published, and vice versa.
+    pub fn new(
+        works: Vec<(WorkType, Option<NaiveDate>)>,
+        work_type: Option<&WorkType>,
+        publication_year: Option<i32>,
+    ) -> WorkFacets {
+        let mut work_types: Vec<WorkTypeFacet> = vec![];
+        let mut years: BTreeMap<i32, i32> = BTreeMap::new();
+        for (wk_type, publication_date) in works {
+            let year = publication_date.map(|date| date.year());
+            if publication_year.is_none() || year == publication_year {
+                match work_types.iter_mut().find(|f| f.work_type == wk_type) {
+                    Some(facet) => facet.count += 1,
+                    None => work_types.push(WorkTypeFacet {
+                        work_type: wk_type.clone(),
+                        count: 1,
+                    }),
+                }
+            }
+            if let Some(year) = year {
+                if work_type.map_or(true, |selected| *selected == wk_type) {
+                    *years.entry(year).or_insert(0) += 1;
+                }
+            }
+        }
+        work_types.sort_by(|a, b| {
+            b.count
+                .cmp(&a.count)
+                .then_with(|| a.work_type.to_string().cmp(&b.work_type.to_string()))
+        });
+        // Most recent years first
+        let publication_years = years
+            .into_iter()
+            .rev()
+            .map(|(year, count)| PublicationYearFacet { year, count })
+            .collect();
+        WorkFacets {
+            work_types,
+            publication_years,
+        }
+    }
+}
+
+/// Parts of a licence URL that do not tell licences apart: the scheme, a `www.` prefix and
+/// trailing slashes. The pattern is also run by the database, so it must mean the same to
+/// PostgreSQL as to the `regex` crate.
+pub const LICENSE_URL_NOISE: &str = r"^https?://(www\.)?|/+$";
+
+lazy_static::lazy_static! {
+    static ref LICENSE_URL_NOISE_REGEXP: Regex =
+        Regex::new(LICENSE_URL_NOISE).expect("Licence URL regexp failed!");
+}
+
+/// Reduce a licence URL to what identifies the licence, so that near-identical URLs compare
+/// equal, e.g. `http://creativecommons.org/licenses/by/4.0` and
+/// `https://creativecommons.org/licenses/by/4.0/`. Stored URLs are lowercased first, then
+/// stripped of `LICENSE_URL_NOISE`, in the same way.
+pub fn normalise_license(license: &str) -> String {
+    LICENSE_URL_NOISE_REGEXP
+        .replace_all(&license.trim().to_lowercase(), "")
+        .into_owned()
+}
+
+/// First day of a calendar year and of the one after it, to filter publication dates with.
+/// Returns `None` for years outside the range of supported dates.
+pub fn year_bounds(year: i32) -> Option<(NaiveDate, NaiveDate)> {
+    Some((
+        NaiveDate::from_ymd_opt(year, 1, 1)?,
+        NaiveDate::from_ymd_opt(year.checked_add(1)?, 1, 1)?,
+    ))
+}
+
+/// Format and, when it can be read from the file header, pixel size of a cover image
+#[derive(Debug, Clone, PartialEq)]
+pub struct CoverImage {
+    pub format: String,
+    pub width: Option<i32>,
+    pub height: Option<i32>,
+}
+
+impl CoverImage {
+    /// Describe an image from its `Content-Type` header and the first bytes of the file.
+    /// Returns `None` if the content type is not an image.
+    pub fn new(content_type: &str, header: &[u8]) -> Option<Self> {
+        let format = content_type
+            .split(';')
+            .next()
+            .unwrap_or_default()
+            .trim()
+            .to_lowercase();
+        if !format.starts_with("image/") {
+            return None;
+        }
+        let (width, height) = match image_dimensions(header) {
+            Some((width, height)) => (Some(width), Some(height)),
+            None => (None, None),
+        };
+        Some(CoverImage {
+            format,
+            width,
+            height,
+        })
+    }
+}
+
+/// Read the width and height of a PNG, GIF or JPEG image from the start of its file
+pub fn image_dimensions(data: &[u8]) -> Option<(i32, i32)> {
+    let be_u16 = |i: usize| {
+        data.get(i..i + 2)
+            .map(|b| u16::from_be_bytes([b[0], b[1]]) as i32)
+    };
+    let le_u16 = |i: usize| {
+        data.get(i..i + 2)
+            .map(|b| u16::from_le_bytes([b[0], b[1]]) as i32)
+    };
+    let be_u32 = |i: usize| {
+        data.get(i..i + 4)
+            .map(|b| u32::from_be_bytes([b[0], b[1], b[2], b[3]]) as i32)
+    };
+    let dimensions = if data.starts_with(b"\x89PNG\r\n\x1a\n") {
+        // The IHDR chunk always comes first
+        (be_u32(16)?, be_u32(20)?)
+    } else if data.starts_with(b"GIF87a") || data.starts_with(b"GIF89a") {
+        (le_u16(6)?, le_u16(8)?)
+    } else if data.starts_with(&[0xFF, 0xD8]) {
+        // Walk the JPEG segments until a start of frame marker
+        let mut i = 2;
+        loop {
+            while *data.get(i)? != 0xFF {
+                i += 1;
+            }
+            while *data.get(i)? == 0xFF {
+                i += 1;
+            }
+            let marker = *data.get(i)?;
+            i += 1;
+            if (0xC0..=0xCF).contains(&marker) && ![0xC4, 0xC8, 0xCC].contains(&marker) {
+                break (be_u16(i + 5)?, be_u16(i + 3)?);
+            }
+            i += be_u16(i)? as usize;
+        }
+    } else {
+        return None;
+    };
+    match dimensions {
+        (width, height) if width > 0 && height > 0 => Some((width, height)),
+        _ => None,
+    }
+}
+
+#[cfg_attr(
+    feature = "backend",
+    derive(juniper::GraphQLObject),
+    graphql(
+        description = "A field whose value differs between a past version of a work and the version that replaced it"
+    )
+)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FieldChange {
+    pub field: String,
+    pub previous: Option<String>,
+    pub replacement: Option<String>,
+}
+
+#[cfg_attr(
+    feature = "backend",
+    derive(juniper::GraphQLObject),
+    graphql(description = "A past version of a work, as it stood before an edit replaced it")
+)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WorkVersion {
+    pub work_history_id: Uuid,
+    pub timestamp: NaiveDateTime,
+    pub editor: String,
+    pub snapshot: String,
+    pub changes: Vec<FieldChange>,
+}
+
+impl WorkHistory {
+    /// The recorded work as a JSON object. History is stored as a serialised string.
+    pub fn snapshot(&self) -> serde_json::Value {
+        match &self.data {
+            serde_json::Value::String(data) => {
+                serde_json::from_str(data).unwrap_or(serde_json::Value::Null)
+            }
+            data => data.clone(),
+        }
+    }
+}
+
+/// Fields that change on every edit and so say nothing about what the editor changed
+const UNVERSIONED_FIELDS: [&str; 2] = ["created_at", "updated_at"];
+
+fn field_value(
+    version: &serde_json::Map<String, serde_json::Value>,
+    field: &str,
+) -> Option<String> {
+    match version.get(field) {
+        None | Some(serde_json::Value::Null) => None,
+        Some(serde_json::Value::String(value)) => Some(value.to_string()),
+        Some(value) => Some(value.to_string()),
+    }
+}
+
+/// Compare two serialised versions of a work field by field
+pub fn diff_versions(
+    previous: &serde_json::Value,
+    replacement: &serde_json::Value,
+) -> Vec<FieldChange> {
+    let empty = serde_json::Map::new();
+    let previous = previous.as_object().unwrap_or(&empty);
+    let replacement = replacement.as_object().unwrap_or(&empty);
+    let mut fields: Vec<&String> = previous.keys().chain(replacement.keys()).collect();
+    fields.sort();
+    fields.dedup();
+    fields
+        .into_iter()
+        .filter(|field| !UNVERSIONED_FIELDS.contains(&field.as_str()))
+        .filter_map(|field| {
+            let change = FieldChange {
+                field: field.to_string(),
+                previous: field_value(previous, field),
+                replacement: field_value(replacement, field),
+            };
+            if change.previous == change.replacement {
+                None
+            } else {
+                Some(change)
+            }
+        })
+        .collect()
+}
+
+/// Shortest abstract that is not reported as a likely mistake, in characters
+pub const MIN_ABSTRACT_LENGTH: usize = 50;
+
+impl Work {
+    /// Likely problems with the record that are not serious enough to stop it being saved
+    pub fn advisories(&self, today: NaiveDate) -> Vec<String> {
+        let mut advisories = Vec::new();
+        if let Some(publication_date) = self.publication_date {
+            if self.work_status == WorkStatus::Active && publication_date > today {
+                advisories
+                    .push("Work is active but its publication date is in the future".to_string());
+            }
+            if self.work_status == WorkStatus::Forthcoming && publication_date <= today {
+                advisories
+                    .push("Work is forthcoming but its publication date has passed".to_string());
+            }
+        }
+        for (name, text) in &[
+            ("Short abstract", &self.short_abstract),
+            ("Long abstract", &self.long_abstract),
+        ] {
+            if let Some(text) = text {
+                if text.trim().chars().count() < MIN_ABSTRACT_LENGTH {
+                    advisories.push(format!(
+                        "{} is shorter than {} characters",
+                        name, MIN_ABSTRACT_LENGTH
+                    ));
+                }
+            }
+        }
+        if self.cover_url.is_none() {
+            advisories.push("Work has no cover image".to_string());
+        }
+        advisories
+    }
+}
+
+/// A part of a work record that counts towards its completeness score:
+/// - `Doi`, `Abstract` (short or long), `Cover` and `License` are fields of the work itself
+/// - `Contributions` and `Subjects` need at least one linked record
+/// - `Publications` needs at least one publication that is priced or free of charge
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CompletenessField {
+    Doi,
+    Abstract,
+    Cover,
+    License,
+    Contributions,
+    Subjects,
+    Publications,
+}
+
+impl CompletenessField {
+    pub const ALL: [CompletenessField; 7] = [
+        CompletenessField::Doi,
+        CompletenessField::Abstract,
+        CompletenessField::Cover,
+        CompletenessField::License,
+        CompletenessField::Contributions,
+        CompletenessField::Subjects,
+        CompletenessField::Publications,
+    ];
+
+    /// Weight of the part in the completeness score. Weights add up to 100, so a record with
+    /// every part filled in scores 100. Tune these to change what editors are nudged to
+    /// complete first.
+    pub fn weight(self) -> i32 {
+        match self {
+            CompletenessField::Doi => 15,
+            CompletenessField::Abstract => 15,
+            CompletenessField::Cover => 10,
+            CompletenessField::License => 10,
+            CompletenessField::Contributions => 20,
+            CompletenessField::Subjects => 15,
+            CompletenessField::Publications => 15,
+        }
+    }
+}
+
+impl fmt::Display for CompletenessField {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CompletenessField::Doi => write!(f, "doi"),
+            CompletenessField::Abstract => write!(f, "abstract"),
+            CompletenessField::Cover => write!(f, "cover"),
+            CompletenessField::License => write!(f, "license"),
+            CompletenessField::Contributions => write!(f, "contributions"),
+            CompletenessField::Subjects => write!(f, "subjects"),
+            CompletenessField::Publications => write!(f, "publications"),
+        }
+    }
+}
+
+impl Work {
+    /// Parts of the record that are not filled in. Whether the work has linked records is
+    /// looked up by the caller.
+    pub fn missing_completeness_fields(
+        &self,
+        has_contributions: bool,
+        has_subjects: bool,
+        has_offered_publications: bool,
+    ) -> Vec<CompletenessField> {
+        CompletenessField::ALL
+            .iter()
+            .copied()
+            .filter(|field| !match field {
+                CompletenessField::Doi => self.doi.is_some(),
+                CompletenessField::Abstract => {
+                    self.short_abstract.is_some() || self.long_abstract.is_some()
+                }
+                CompletenessField::Cover => self.cover_url.is_some(),
+                CompletenessField::License => self.license.is_some(),
+                CompletenessField::Contributions => has_contributions,
+                CompletenessField::Subjects => has_subjects,
+                CompletenessField::Publications => has_offered_publications,
+            })
+            .collect()
+    }
+}
+
+/// Score out of 100 of a record missing the given parts
+pub fn completeness_score(missing_fields: &[CompletenessField]) -> i32 {
+    CompletenessField::ALL
+        .iter()
+        .filter(|field| !missing_fields.contains(field))
+        .map(|field| field.weight())
+        .sum()
+}
+
+#[cfg_attr(
+    feature = "backend",
+    derive(juniper::GraphQLObject),
+    graphql(
+        description = "What a work's record lacks: its completeness score, the parts counting towards it that are not filled in, and likely problems that did not stop it being saved"
+    )
+)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WorkValidation {
+    pub completeness_score: i32,
+    pub missing_fields: Vec<String>,
+    pub warnings: Vec<String>,
+}
+
+impl WorkValidation {
+    pub fn new(missing_fields: Vec<CompletenessField>, warnings: Vec<String>) -> WorkValidation {
+        WorkValidation {
+            completeness_score: completeness_score(&missing_fields),
+            missing_fields: missing_fields
+                .iter()
+                .map(|field| field.to_string())
+                .collect(),
+            warnings,
+        }
+    }
+}
+
 #[test]
 fn test_worktype_default() {
     let worktype: WorkType = Default::default();
@@ -439,3 +1005,304 @@ fn test_workstatus_fromstr() {
     assert!(WorkStatus::from_str("Published").is_err());
     assert!(WorkStatus::from_str("Unpublished").is_err());
 }
+
+#[test]
+fn test_image_dimensions() {
+    let mut png = b"\x89PNG\r\n\x1a\n\x00\x00\x00\x0dIHDR".to_vec();
+    png.extend(&[0, 0, 0x02, 0x58, 0, 0, 0x03, 0x84]);
+    assert_eq!(image_dimensions(&png), Some((600, 900)));
+
+    let mut gif = b"GIF89a".to_vec();
+    gif.extend(&[0x58, 0x02, 0x84, 0x03]);
+    assert_eq!(image_dimensions(&gif), Some((600, 900)));
+
+    // SOI, an APP0 segment of 16 bytes, then a baseline SOF0 segment
+    let mut jpeg = vec![0xFF, 0xD8, 0xFF, 0xE0, 0x00, 0x10];
+    jpeg.extend(&[0; 14]);
+    jpeg.extend(&[0xFF, 0xC0, 0x00, 0x11, 0x08, 0x03, 0x84, 0x02, 0x58]);
+    assert_eq!(image_dimensions(&jpeg), Some((600, 900)));
+
+    assert_eq!(image_dimensions(&png[..20]), None);
+    assert_eq!(image_dimensions(b"<!DOCTYPE html>"), None);
+    assert_eq!(image_dimensions(&[]), None);
+}
+
+#[test]
+fn test_cover_image_new() {
+    let mut gif = b"GIF87a".to_vec();
+    gif.extend(&[0x58, 0x02, 0x84, 0x03]);
+    assert_eq!(
+        CoverImage::new("image/gif", &gif),
+        Some(CoverImage {
+            format: "image/gif".to_string(),
+            width: Some(600),
+            height: Some(900),
+        })
+    );
+    assert_eq!(
+        CoverImage::new("Image/WebP; charset=binary", &[]),
+        Some(CoverImage {
+            format: "image/webp".to_string(),
+            width: None,
+            height: None,
+        })
+    );
+    assert_eq!(CoverImage::new("text/html; charset=utf-8", &gif), None);
+    assert_eq!(CoverImage::new("", &gif), None);
+}
+
+#[test]
+fn test_work_facets_new() {
+    let date = |year| NaiveDate::from_ymd_opt(year, 6, 1);
+    let works = vec![
+        (WorkType::Monograph, date(2019)),
+        (WorkType::Monograph, date(2020)),
+        (WorkType::EditedBook, date(2020)),
+        (WorkType::Monograph, None),
+        (WorkType::BookChapter, date(2020)),
+        (WorkType::Textbook, date(2018)),
+    ];
+    let facets = WorkFacets::new(works.clone(), None, None);
+    assert_eq!(
+        facets.work_types[0],
+        WorkTypeFacet {
+            work_type: WorkType::Monograph,
+            count: 3,
+        }
+    );
+    // Ties are listed alphabetically
+    assert_eq!(facets.work_types[1].work_type, WorkType::BookChapter);
+    assert_eq!(facets.work_types.len(), 4);
+    assert_eq!(
+        facets.publication_years,
+        vec![
+            PublicationYearFacet {
+                year: 2020,
+                count: 3,
+            },
+            PublicationYearFacet {
+                year: 2019,
+                count: 1,
+            },
+            PublicationYearFacet {
+                year: 2018,
+                count: 1,
+            },
+        ]
+    );
+
+    // A selected year narrows the types, and a selected type narrows the years
+    let facets = WorkFacets::new(works, Some(&WorkType::Monograph), Some(2020));
+    assert_eq!(facets.work_types.len(), 3);
+    assert!(facets.work_types.iter().all(|f| f.count == 1));
+    assert_eq!(
+        facets.publication_years,
+        vec![
+            PublicationYearFacet {
+                year: 2020,
+                count: 1,
+            },
+            PublicationYearFacet {
+                year: 2019,
+                count: 1,
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_year_bounds() {
+    assert_eq!(
+        year_bounds(2020),
+        Some((
+            NaiveDate::from_ymd(2020, 1, 1),
+            NaiveDate::from_ymd(2021, 1, 1)
+        ))
+    );
+    assert_eq!(year_bounds(i32::MAX), None);
+}
+
+#[test]
+fn test_work_import_result_rejected() {
+    let error = ThothError::DimensionNotPhysicalError;
+    assert_eq!(
+        WorkImportResult::rejected(3, &error),
+        WorkImportResult {
+            row: 3,
+            work_id: None,
+            error_type: Some("INVALID_DIMENSIONS".to_string()),
+            error_message: Some(
+                "Only physical publications (Paperback, Hardback) can have dimensions".to_string()
+            ),
+        }
+    );
+}
+
+#[test]
+fn test_work_deletion_result_rejected() {
+    let work_id = Uuid::parse_str("00000000-0000-0000-AAAA-000000000001").unwrap();
+    assert_eq!(
+        WorkDeletionResult::rejected(work_id, &ThothError::Unauthorised),
+        WorkDeletionResult {
+            work_id,
+            deleted: false,
+            error_type: Some("NO_ACCESS".to_string()),
+            error_message: Some("Invalid credentials.".to_string()),
+        }
+    );
+    assert!(WorkDeletionResult::deleted(work_id).deleted);
+    assert!(!WorkDeletionResult::withheld(work_id).deleted);
+}
+
+#[test]
+fn test_diff_versions() {
+    let previous = serde_json::json!({
+        "title": "Old title",
+        "doi": null,
+        "edition": 1,
+        "place": "Cambridge",
+        "updated_at": "2021-01-01T00:00:00",
+    });
+    let replacement = serde_json::json!({
+        "title": "New title",
+        "doi": "https://doi.org/10.11647/OBP.0001",
+        "edition": 2,
+        "place": "Cambridge",
+        "updated_at": "2021-02-01T00:00:00",
+        "cover_format": null,
+    });
+    assert_eq!(
+        diff_versions(&previous, &replacement),
+        vec![
+            FieldChange {
+                field: "doi".to_string(),
+                previous: None,
+                replacement: Some("https://doi.org/10.11647/OBP.0001".to_string()),
+            },
+            FieldChange {
+                field: "edition".to_string(),
+                previous: Some("1".to_string()),
+                replacement: Some("2".to_string()),
+            },
+            FieldChange {
+                field: "title".to_string(),
+                previous: Some("Old title".to_string()),
+                replacement: Some("New title".to_string()),
+            },
+        ]
+    );
+    assert!(diff_versions(&previous, &previous).is_empty());
+}
+
+#[test]
+fn test_completeness_score() {
+    assert_eq!(
+        CompletenessField::ALL
+            .iter()
+            .map(|field| field.weight())
+            .sum::<i32>(),
+        100
+    );
+    assert_eq!(completeness_score(&[]), 100);
+    assert_eq!(
+        completeness_score(&[CompletenessField::Doi, CompletenessField::Contributions]),
+        65
+    );
+    assert_eq!(completeness_score(&CompletenessField::ALL), 0);
+}
+
+#[test]
+fn test_work_validation_new() {
+    let validation = WorkValidation::new(
+        vec![CompletenessField::Doi, CompletenessField::Contributions],
+        vec!["No cover image".to_string()],
+    );
+    assert_eq!(validation.completeness_score, 65);
+    assert_eq!(validation.missing_fields, vec!["doi", "contributions"]);
+    assert_eq!(validation.warnings, vec!["No cover image"]);
+    assert_eq!(WorkValidation::new(vec![], vec![]).completeness_score, 100);
+}
+
+#[test]
+fn test_work_advisories() {
+    let today = NaiveDate::from_ymd(2021, 3, 1);
+    let mut work = Work {
+        work_id: Default::default(),
+        work_type: WorkType::Monograph,
+        work_status: WorkStatus::Active,
+        full_title: "Some title".to_string(),
+        title: "Some title".to_string(),
+        subtitle: None,
+        reference: None,
+        edition: 1,
+        imprint_id: Default::default(),
+        doi: None,
+        publication_date: Some(NaiveDate::from_ymd(2021, 4, 1)),
+        place: None,
+        width: None,
+        height: None,
+        page_count: None,
+        page_breakdown: None,
+        image_count: None,
+        table_count: None,
+        audio_count: None,
+        video_count: None,
+        license: None,
+        copyright_holder: "Some holder".to_string(),
+        landing_page: None,
+        lccn: None,
+        oclc: None,
+        short_abstract: Some("Too short".to_string()),
+        long_abstract: None,
+        general_note: None,
+        toc: None,
+        cover_url: None,
+        cover_caption: None,
+        created_at: NaiveDateTime::from_timestamp(0, 0),
+        updated_at: NaiveDateTime::from_timestamp(0, 0),
+        cover_format: None,
+        cover_width: None,
+        cover_height: None,
+        short_doi: None,
+    };
+    assert_eq!(work.advisories(today).len(), 3);
+    work.work_status = WorkStatus::Forthcoming;
+    work.short_abstract = Some("A".repeat(MIN_ABSTRACT_LENGTH));
+    work.cover_url = Some("https://example.com/cover.jpg".to_string());
+    assert!(work.advisories(today).is_empty());
+    work.publication_date = Some(today);
+    assert_eq!(
+        work.advisories(today),
+        vec!["Work is forthcoming but its publication date has passed".to_string()]
+    );
+}
+
+#[test]
+fn test_normalise_license() {
+    let by = "creativecommons.org/licenses/by/4.0";
+    assert_eq!(
+        normalise_license("https://creativecommons.org/licenses/by/4.0/"),
+        by
+    );
+    assert_eq!(
+        normalise_license("http://creativecommons.org/licenses/by/4.0"),
+        by
+    );
+    assert_eq!(
+        normalise_license(" HTTPS://www.CreativeCommons.org/licenses/by/4.0// "),
+        by
+    );
+    assert_ne!(
+        normalise_license("https://creativecommons.org/licenses/by-nc/4.0/"),
+        by
+    );
+}
+
+#[test]
+fn test_validate_publication_date() {
+    assert!(validate_publication_date(None).is_ok());
+    assert!(validate_publication_date(Some(NaiveDate::from_ymd(2021, 2, 28))).is_ok());
+    assert!(validate_publication_date(Some(NaiveDate::from_ymd(1450, 1, 1))).is_ok());
+    assert!(validate_publication_date(Some(NaiveDate::from_ymd(202, 2, 28))).is_err());
+    assert!(validate_publication_date(Some(NaiveDate::from_ymd(20210, 2, 28))).is_err());
+}
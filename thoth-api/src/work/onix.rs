@@ -0,0 +1,1269 @@
+use chrono::naive::NaiveDate;
+use serde::Serialize;
+use std::str::FromStr;
+use uuid::Uuid;
+use xml::reader::{EventReader, XmlEvent};
+
+use crate::contribution::model::{ContributionType, NewContribution};
+use crate::contributor::model::NewContributor;
+use crate::errors::ThothError;
+use crate::identifier::{Doi, Isbn, Orcid};
+use crate::language::model::{LanguageCode, LanguageRelation, NewLanguage};
+use crate::price::model::{CurrencyCode, NewPrice, PriceType};
+use crate::publication::model::{NewPublication, PublicationType, UnpricedItemType};
+use crate::subject::model::SubjectType;
+use crate::work::model::{NewWork, WorkStatus, WorkType};
+
+/// Fields a work cannot be created without
+const REQUIRED_FIELDS: [&str; 4] = ["title", "work_type", "work_status", "copyright_holder"];
+/// Edition given to works whose product has no `EditionNumber`
+pub const DEFAULT_EDITION: i32 = 1;
+
+/// An element of an ONIX document, named by its reference tag whichever tag set was used
+#[derive(Debug, Clone, Default)]
+struct OnixElement {
+    name: String,
+    attributes: Vec<(String, String)>,
+    text: String,
+    children: Vec<OnixElement>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct OnixContribution {
+    pub contribution_type: ContributionType,
+    pub main_contribution: bool,
+    pub first_name: Option<String>,
+    pub last_name: String,
+    pub full_name: String,
+    pub orcid: Option<String>,
+    pub biography: Option<String>,
+    pub institution: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct OnixSubject {
+    pub subject_type: SubjectType,
+    pub subject_code: String,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct OnixLanguage {
+    pub language_code: LanguageCode,
+    pub language_relation: LanguageRelation,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct OnixPrice {
+    pub currency_code: CurrencyCode,
+    pub unit_price: f64,
+    pub price_type: PriceType,
+    pub territory: String,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct OnixPublication {
+    pub publication_type: PublicationType,
+    pub isbn: Option<String>,
+    pub unpriced_item_type: Option<UnpricedItemType>,
+    pub prices: Vec<OnixPrice>,
+}
+
+/// A problem found while reading a field. Unmappable values prevent the product from being
+/// imported; other issues only explain how a field was, or was not, populated.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OnixIssue {
+    pub field: String,
+    pub message: String,
+    pub unmappable: bool,
+}
+
+/// The Thoth data described by an ONIX 3.0 `<Product>`
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct OnixProduct {
+    pub record_reference: Option<String>,
+    pub work_type: Option<WorkType>,
+    pub work_status: Option<WorkStatus>,
+    pub title: Option<String>,
+    pub subtitle: Option<String>,
+    pub edition: Option<i32>,
+    pub doi: Option<String>,
+    pub publication_date: Option<NaiveDate>,
+    pub place: Option<String>,
+    pub page_count: Option<i32>,
+    pub license: Option<String>,
+    pub copyright_holder: Option<String>,
+    pub landing_page: Option<String>,
+    pub short_abstract: Option<String>,
+    pub long_abstract: Option<String>,
+    pub toc: Option<String>,
+    pub contributions: Vec<OnixContribution>,
+    pub subjects: Vec<OnixSubject>,
+    pub languages: Vec<OnixLanguage>,
+    pub publications: Vec<OnixPublication>,
+    pub issues: Vec<OnixIssue>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum OnixFieldStatus {
+    /// The product gives a value Thoth can store
+    Populated,
+    /// The product gives no value for the field
+    Missing,
+    /// The product gives a value that has no Thoth equivalent
+    Unmappable,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct OnixFieldResult {
+    pub field: String,
+    pub status: OnixFieldStatus,
+    pub required: bool,
+    pub value: Option<String>,
+    pub message: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct OnixValidationReport {
+    pub record_reference: Option<String>,
+    pub valid: bool,
+    pub fields: Vec<OnixFieldResult>,
+}
+
+impl OnixElement {
+    fn children<'a>(&'a self, name: &'a str) -> impl Iterator<Item = &'a OnixElement> + 'a {
+        self.children.iter().filter(move |c| c.name == name)
+    }
+
+    fn child(&self, name: &str) -> Option<&OnixElement> {
+        self.children(name).next()
+    }
+
+    fn value(&self) -> Option<String> {
+        let text = self.text.trim();
+        if text.is_empty() {
+            None
+        } else {
+            Some(text.to_string())
+        }
+    }
+
+    fn child_value(&self, name: &str) -> Option<String> {
+        self.child(name).and_then(OnixElement::value)
+    }
+
+    fn attribute(&self, name: &str) -> Option<&str> {
+        self.attributes
+            .iter()
+            .find(|(n, _)| n == name)
+            .map(|(_, v)| v.as_str())
+    }
+
+    /// Value of the first identifier composite (e.g. `ProductIdentifier`) of the given type
+    fn identifier(&self, composite: &str, type_tag: &str, id_type: &str) -> Option<String> {
+        self.children(composite)
+            .find(|c| c.child_value(type_tag).as_deref() == Some(id_type))
+            .and_then(|c| c.child_value("IDValue"))
+    }
+}
+
+/// Reference name of an ONIX 3.0 short tag. Only the elements Thoth reads are translated;
+/// reference tags, and short tags Thoth has no use for, are returned unchanged.
+fn reference_name(tag: &str) -> &str {
+    match tag {
+        "ONIXmessage" => "ONIXMessage",
+        "product" => "Product",
+        "a001" => "RecordReference",
+        "productidentifier" => "ProductIdentifier",
+        "b221" => "ProductIDType",
+        "b244" => "IDValue",
+        "descriptivedetail" => "DescriptiveDetail",
+        "b012" => "ProductForm",
+        "b333" => "ProductFormDetail",
+        "epublicense" => "EpubLicense",
+        "epublicenseexpression" => "EpubLicenseExpression",
+        "x510" => "EpubLicenseExpressionLink",
+        "titledetail" => "TitleDetail",
+        "b202" => "TitleType",
+        "titleelement" => "TitleElement",
+        "x409" => "TitleElementLevel",
+        "b203" => "TitleText",
+        "b030" => "TitlePrefix",
+        "b031" => "TitleWithoutPrefix",
+        "b029" => "Subtitle",
+        "contributor" => "Contributor",
+        "b035" => "ContributorRole",
+        "nameidentifier" => "NameIdentifier",
+        "x415" => "NameIDType",
+        "b036" => "PersonName",
+        "b039" => "NamesBeforeKey",
+        "b040" => "KeyNames",
+        "b047" => "CorporateName",
+        "b044" => "BiographicalNote",
+        "professionalaffiliation" => "ProfessionalAffiliation",
+        "b046" => "Affiliation",
+        "b057" => "EditionNumber",
+        "language" => "Language",
+        "b253" => "LanguageRole",
+        "b252" => "LanguageCode",
+        "extent" => "Extent",
+        "b218" => "ExtentType",
+        "b219" => "ExtentValue",
+        "b220" => "ExtentUnit",
+        "subject" => "Subject",
+        "b067" => "SubjectSchemeIdentifier",
+        "b069" => "SubjectCode",
+        "b070" => "SubjectHeadingText",
+        "collateraldetail" => "CollateralDetail",
+        "textcontent" => "TextContent",
+        "x426" => "TextType",
+        "d104" => "Text",
+        "publishingdetail" => "PublishingDetail",
+        "publisher" => "Publisher",
+        "b291" => "PublishingRole",
+        "b081" => "PublisherName",
+        "website" => "Website",
+        "b367" => "WebsiteRole",
+        "b295" => "WebsiteLink",
+        "b209" => "CityOfPublication",
+        "b394" => "PublishingStatus",
+        "publishingdate" => "PublishingDate",
+        "x448" => "PublishingDateRole",
+        "b306" => "Date",
+        "copyrightstatement" => "CopyrightStatement",
+        "copyrightowner" => "CopyrightOwner",
+        "productsupply" => "ProductSupply",
+        "supplydetail" => "SupplyDetail",
+        "j192" => "UnpricedItemType",
+        "price" => "Price",
+        "x462" => "PriceType",
+        "j151" => "PriceAmount",
+        "j152" => "CurrencyCode",
+        "territory" => "Territory",
+        "x449" => "CountriesIncluded",
+        "x450" => "RegionsIncluded",
+        _ => tag,
+    }
+}
+
+fn parse_document(input: &[u8]) -> Result<OnixElement, ThothError> {
+    let mut stack: Vec<OnixElement> = vec![];
+    let mut root = None;
+    for event in EventReader::new(input) {
+        match event.map_err(|e| ThothError::InvalidOnix(e.to_string()))? {
+            XmlEvent::StartElement {
+                name, attributes, ..
+            } => stack.push(OnixElement {
+                name: reference_name(&name.local_name).to_string(),
+                attributes: attributes
+                    .into_iter()
+                    .map(|a| (a.name.local_name, a.value))
+                    .collect(),
+                ..Default::default()
+            }),
+            // Text is kept on every open element so that marked up text (e.g. an XHTML
+            // abstract) reads as a whole from its ONIX element
+            XmlEvent::Characters(text) | XmlEvent::CData(text) | XmlEvent::Whitespace(text) => {
+                for element in stack.iter_mut() {
+                    element.text.push_str(&text);
+                }
+            }
+            XmlEvent::EndElement { .. } => {
+                if let Some(element) = stack.pop() {
+                    match stack.last_mut() {
+                        Some(parent) => parent.children.push(element),
+                        None => root = Some(element),
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+    root.ok_or_else(|| ThothError::InvalidOnix("the document has no root element".to_string()))
+}
+
+/// Read the products of an ONIX 3.0 message, or a lone `<Product>`, in either short or
+/// reference tags
+pub fn parse_onix_products(input: &[u8]) -> Result<Vec<OnixProduct>, ThothError> {
+    let root = parse_document(input)?;
+    match root.name.as_str() {
+        "Product" => Ok(vec![OnixProduct::from_element(&root)]),
+        "ONIXMessage" => Ok(root
+            .children("Product")
+            .map(OnixProduct::from_element)
+            .collect()),
+        name => Err(ThothError::InvalidOnix(format!(
+            "expected an ONIXMessage or Product element, found {}",
+            name
+        ))),
+    }
+}
+
+fn work_status_from_code(code: &str) -> Option<WorkStatus> {
+    match code {
+        "00" => Some(WorkStatus::Unspecified),
+        "01" => Some(WorkStatus::Cancelled),
+        "02" => Some(WorkStatus::Forthcoming),
+        "03" => Some(WorkStatus::PostponedIndefinitely),
+        "04" => Some(WorkStatus::Active),
+        "05" => Some(WorkStatus::NoLongerOurProduct),
+        "06" => Some(WorkStatus::OutOfStockIndefinitely),
+        "07" => Some(WorkStatus::OutOfPrint),
+        "08" => Some(WorkStatus::Inactive),
+        "09" => Some(WorkStatus::Unknown),
+        "10" => Some(WorkStatus::Remaindered),
+        "11" => Some(WorkStatus::WithdrawnFromSale),
+        "15" => Some(WorkStatus::Recalled),
+        _ => None,
+    }
+}
+
+fn contribution_type_from_role(role: &str) -> Option<ContributionType> {
+    match role {
+        "A01" => Some(ContributionType::Author),
+        "B01" => Some(ContributionType::Editor),
+        "B06" => Some(ContributionType::Translator),
+        "A13" => Some(ContributionType::Photographer),
+        "A12" => Some(ContributionType::Ilustrator),
+        "B25" => Some(ContributionType::MusicEditor),
+        "A23" => Some(ContributionType::ForewordBy),
+        "A24" => Some(ContributionType::IntroductionBy),
+        "A19" => Some(ContributionType::AfterwordBy),
+        "A15" => Some(ContributionType::PrefaceBy),
+        _ => None,
+    }
+}
+
+fn subject_type_from_scheme(scheme: &str) -> Option<SubjectType> {
+    match scheme {
+        "12" => Some(SubjectType::Bic),
+        "10" => Some(SubjectType::Bisac),
+        "20" => Some(SubjectType::Keyword),
+        "04" => Some(SubjectType::Lcc),
+        "93" => Some(SubjectType::Thema),
+        "B2" => Some(SubjectType::Custom),
+        _ => None,
+    }
+}
+
+/// Publication type of a `ProductForm`, using its `ProductFormDetail`s to tell digital
+/// formats apart
+fn publication_type_from_form(form: &str, details: &[String]) -> Option<PublicationType> {
+    match form {
+        "BC" => Some(PublicationType::Paperback),
+        "BB" => Some(PublicationType::Hardback),
+        "EA" | "EB" | "EC" | "ED" => {
+            let detail_type = details.iter().find_map(|detail| match detail.as_str() {
+                "E107" => Some(PublicationType::PDF),
+                "E105" => Some(PublicationType::HTML),
+                "E101" => Some(PublicationType::Epub),
+                "E127" => Some(PublicationType::Mobi),
+                _ => None,
+            });
+            match (form, detail_type) {
+                (_, Some(publication_type)) => Some(publication_type),
+                // Thoth describes XML publications by their form alone
+                ("EB", None) => Some(PublicationType::XML),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+fn price_type_from_code(code: &str) -> Option<PriceType> {
+    match code {
+        "01" => Some(PriceType::RrpExcludingTax),
+        "02" => Some(PriceType::RrpIncludingTax),
+        "03" => Some(PriceType::FixedRetailExcludingTax),
+        "04" => Some(PriceType::FixedRetailIncludingTax),
+        "05" => Some(PriceType::SupplierNetExcludingTax),
+        "07" => Some(PriceType::SupplierNetIncludingTax),
+        _ => None,
+    }
+}
+
+fn unpriced_item_type_from_code(code: &str) -> Option<UnpricedItemType> {
+    match code {
+        "01" => Some(UnpricedItemType::FreeOfCharge),
+        "02" => Some(UnpricedItemType::PriceToBeAnnounced),
+        "03" => Some(UnpricedItemType::NotSoldSeparately),
+        "04" => Some(UnpricedItemType::ContactSupplier),
+        _ => None,
+    }
+}
+
+/// Explain why a code (or a value that should be one) cannot be imported
+fn code_issue(code: &str, kind: &str) -> String {
+    if code.is_empty() {
+        format!("No {} is given", kind)
+    } else {
+        format!("{} is not a {} Thoth supports", code, kind)
+    }
+}
+
+/// Name of a person or organisation given in ONIX name elements, as (first, last, full)
+fn onix_name(element: &OnixElement) -> Option<(Option<String>, String, String)> {
+    let first_name = element.child_value("NamesBeforeKey");
+    let key_names = element.child_value("KeyNames");
+    let full_name = element
+        .child_value("PersonName")
+        .or_else(|| match (&first_name, &key_names) {
+            (Some(first), Some(last)) => Some(format!("{} {}", first, last)),
+            (None, Some(last)) => Some(last.clone()),
+            _ => None,
+        })
+        .or_else(|| element.child_value("CorporateName"))?;
+    let last_name = key_names.unwrap_or_else(|| full_name.clone());
+    Some((first_name, last_name, full_name))
+}
+
+impl OnixContribution {
+    pub fn new_contributor(&self) -> NewContributor {
+        NewContributor {
+            first_name: self.first_name.clone(),
+            last_name: self.last_name.clone(),
+            full_name: self.full_name.clone(),
+            orcid: self.orcid.clone(),
+            website: None,
+        }
+    }
+
+    pub fn new_contribution(&self, work_id: Uuid, contributor_id: Uuid) -> NewContribution {
+        NewContribution {
+            work_id,
+            contributor_id,
+            contribution_type: self.contribution_type,
+            main_contribution: self.main_contribution,
+            biography: self.biography.clone(),
+            institution: self.institution.clone(),
+            first_name: self.first_name.clone(),
+            last_name: self.last_name.clone(),
+            full_name: self.full_name.clone(),
+        }
+    }
+}
+
+impl OnixLanguage {
+    pub fn new_language(&self, work_id: Uuid, main_language: bool) -> NewLanguage {
+        NewLanguage {
+            work_id,
+            language_code: self.language_code.clone(),
+            language_relation: self.language_relation.clone(),
+            main_language,
+        }
+    }
+}
+
+impl OnixPublication {
+    pub fn new_publication(&self, work_id: Uuid) -> NewPublication {
+        NewPublication {
+            publication_type: self.publication_type.clone(),
+            work_id,
+            isbn: self.isbn.clone(),
+            publication_url: None,
+            width_mm: None,
+            width_in: None,
+            height_mm: None,
+            height_in: None,
+            depth_mm: None,
+            depth_in: None,
+            weight_g: None,
+            weight_oz: None,
+            unpriced_item_type: self.unpriced_item_type.clone(),
+        }
+    }
+}
+
+impl OnixPrice {
+    pub fn new_price(&self, publication_id: Uuid) -> NewPrice {
+        NewPrice {
+            publication_id,
+            currency_code: self.currency_code.clone(),
+            unit_price: self.unit_price,
+            price_type: Some(self.price_type.clone()),
+            territory: Some(self.territory.clone()),
+        }
+    }
+}
+
+impl OnixProduct {
+    fn from_element(product: &OnixElement) -> OnixProduct {
+        let mut onix_product = OnixProduct {
+            record_reference: product.child_value("RecordReference"),
+            ..Default::default()
+        };
+        if let Some(descriptive) = product.child("DescriptiveDetail") {
+            onix_product.read_descriptive_detail(descriptive);
+        }
+        if let Some(collateral) = product.child("CollateralDetail") {
+            onix_product.read_collateral_detail(collateral);
+        }
+        if let Some(publishing) = product.child("PublishingDetail") {
+            onix_product.read_publishing_detail(publishing);
+        }
+        onix_product.read_identifiers(product);
+        onix_product.read_publication(product);
+        onix_product.work_type = Some(onix_product.inferred_work_type());
+        onix_product.issue(
+            "work_type",
+            "ONIX has no work type; it is inferred from the contributor roles",
+            false,
+        );
+        if onix_product.edition.is_none() {
+            onix_product.issue(
+                "edition",
+                &format!("Thoth will use edition {}", DEFAULT_EDITION),
+                false,
+            );
+        }
+        onix_product
+    }
+
+    fn issue(&mut self, field: &str, message: &str, unmappable: bool) {
+        self.issues.push(OnixIssue {
+            field: field.to_string(),
+            message: message.to_string(),
+            unmappable,
+        });
+    }
+
+    /// Edited books are those credited to editors and no authors
+    fn inferred_work_type(&self) -> WorkType {
+        let has_type = |contribution_type: ContributionType| {
+            self.contributions
+                .iter()
+                .any(|c| c.contribution_type == contribution_type)
+        };
+        if has_type(ContributionType::Editor) && !has_type(ContributionType::Author) {
+            WorkType::EditedBook
+        } else {
+            WorkType::Monograph
+        }
+    }
+
+    fn read_identifiers(&mut self, product: &OnixElement) {
+        if let Some(doi) = product.identifier("ProductIdentifier", "ProductIDType", "06") {
+            let doi = if doi.starts_with("https://doi.org/") {
+                doi
+            } else {
+                format!("https://doi.org/{}", doi)
+            };
+            match Doi::from_str(&doi) {
+                Ok(doi) => self.doi = Some(doi.to_string()),
+                Err(e) => self.issue("doi", &e.to_string(), true),
+            }
+        }
+    }
+
+    fn read_descriptive_detail(&mut self, descriptive: &OnixElement) {
+        let title_detail = descriptive
+            .children("TitleDetail")
+            .find(|t| t.child_value("TitleType").as_deref() == Some("01"))
+            .or_else(|| descriptive.child("TitleDetail"));
+        if let Some(title_element) = title_detail.and_then(|t| {
+            t.children("TitleElement")
+                .find(|e| e.child_value("TitleElementLevel").as_deref() == Some("01"))
+                .or_else(|| t.child("TitleElement"))
+        }) {
+            self.title = title_element.child_value("TitleText").or_else(|| {
+                let without_prefix = title_element.child_value("TitleWithoutPrefix")?;
+                Some(match title_element.child_value("TitlePrefix") {
+                    Some(prefix) => format!("{} {}", prefix, without_prefix),
+                    None => without_prefix,
+                })
+            });
+            self.subtitle = title_element.child_value("Subtitle");
+        }
+
+        for contributor in descriptive.children("Contributor") {
+            self.read_contributor(contributor);
+        }
+
+        if let Some(edition) = descriptive.child_value("EditionNumber") {
+            match edition.parse::<i32>() {
+                Ok(edition) => self.edition = Some(edition),
+                Err(_) => self.issue("edition", &format!("{} is not a number", edition), true),
+            }
+        }
+
+        self.license = descriptive
+            .children("EpubLicense")
+            .flat_map(|l| l.children("EpubLicenseExpression"))
+            .find_map(|e| e.child_value("EpubLicenseExpressionLink"));
+
+        self.page_count = descriptive
+            .children("Extent")
+            .filter(|e| {
+                matches!(
+                    e.child_value("ExtentType").as_deref(),
+                    Some("00") | Some("11")
+                ) && e.child_value("ExtentUnit").as_deref() == Some("03")
+            })
+            .find_map(|e| e.child_value("ExtentValue")?.parse::<i32>().ok());
+
+        for subject in descriptive.children("Subject") {
+            self.read_subject(subject);
+        }
+
+        let languages: Vec<(String, String)> = descriptive
+            .children("Language")
+            .filter_map(|l| {
+                Some((
+                    l.child_value("LanguageRole")?,
+                    l.child_value("LanguageCode")?,
+                ))
+            })
+            .collect();
+        let is_translation = languages.iter().any(|(role, _)| role == "02");
+        for (role, code) in languages {
+            // Roles other than these describe parts of the product Thoth does not record
+            let language_relation = match (role.as_str(), is_translation) {
+                ("01", false) => LanguageRelation::Original,
+                ("01", true) => LanguageRelation::TranslatedInto,
+                ("02", _) => LanguageRelation::TranslatedFrom,
+                _ => continue,
+            };
+            match LanguageCode::from_str(&code.to_uppercase()) {
+                Ok(language_code) => self.languages.push(OnixLanguage {
+                    language_code,
+                    language_relation,
+                }),
+                Err(e) => self.issue("languages", &e.to_string(), true),
+            }
+        }
+    }
+
+    fn read_contributor(&mut self, contributor: &OnixElement) {
+        let role = contributor
+            .child_value("ContributorRole")
+            .unwrap_or_default();
+        let contribution_type = match contribution_type_from_role(&role) {
+            Some(contribution_type) => contribution_type,
+            None => {
+                let message = code_issue(&role, "contributor role");
+                return self.issue("contributions", &message, true);
+            }
+        };
+        let (first_name, last_name, full_name) = match onix_name(contributor) {
+            Some(name) => name,
+            None => return self.issue("contributions", "A contributor has no name", true),
+        };
+        let orcid = match contributor.identifier("NameIdentifier", "NameIDType", "21") {
+            Some(orcid) => match Orcid::from_str(&orcid) {
+                Ok(orcid) => Some(format!("https://orcid.org/{}", orcid.id())),
+                Err(e) => {
+                    self.issue("contributions", &e.to_string(), true);
+                    None
+                }
+            },
+            None => None,
+        };
+        self.contributions.push(OnixContribution {
+            main_contribution: contribution_type == ContributionType::Author
+                || contribution_type == ContributionType::Editor,
+            contribution_type,
+            first_name,
+            last_name,
+            full_name,
+            orcid,
+            biography: contributor.child_value("BiographicalNote"),
+            institution: contributor
+                .child("ProfessionalAffiliation")
+                .and_then(|a| a.child_value("Affiliation")),
+        });
+    }
+
+    fn read_subject(&mut self, subject: &OnixElement) {
+        let scheme = subject
+            .child_value("SubjectSchemeIdentifier")
+            .unwrap_or_default();
+        let subject_type = match subject_type_from_scheme(&scheme) {
+            Some(subject_type) => subject_type,
+            None => {
+                let message = code_issue(&scheme, "subject scheme");
+                return self.issue("subjects", &message, true);
+            }
+        };
+        let codes = match subject
+            .child_value("SubjectCode")
+            .or_else(|| subject.child_value("SubjectHeadingText"))
+        {
+            // ONIX lists keywords in a single heading, separated by semicolons
+            Some(text) if subject_type == SubjectType::Keyword => text
+                .split(';')
+                .map(|k| k.trim().to_string())
+                .filter(|k| !k.is_empty())
+                .collect(),
+            Some(code) => vec![code],
+            None => vec![],
+        };
+        for subject_code in codes {
+            self.subjects.push(OnixSubject {
+                subject_type: subject_type.clone(),
+                subject_code,
+            });
+        }
+    }
+
+    fn read_collateral_detail(&mut self, collateral: &OnixElement) {
+        for text_content in collateral.children("TextContent") {
+            let text = text_content.child_value("Text");
+            match text_content.child_value("TextType").as_deref() {
+                Some("02") => self.short_abstract = self.short_abstract.take().or(text),
+                Some("03") => self.long_abstract = self.long_abstract.take().or(text),
+                Some("04") => self.toc = self.toc.take().or(text),
+                _ => {}
+            }
+        }
+    }
+
+    fn read_publishing_detail(&mut self, publishing: &OnixElement) {
+        let publisher = publishing
+            .children("Publisher")
+            .find(|p| p.child_value("PublishingRole").as_deref() == Some("01"))
+            .or_else(|| publishing.child("Publisher"));
+        self.landing_page = publishing
+            .children("Publisher")
+            .flat_map(|p| p.children("Website"))
+            .find(|w| w.child_value("WebsiteRole").as_deref() == Some("02"))
+            .and_then(|w| w.child_value("WebsiteLink"));
+        self.place = publishing.child_value("CityOfPublication");
+
+        if let Some(status) = publishing.child_value("PublishingStatus") {
+            self.work_status = work_status_from_code(&status);
+            if self.work_status.is_none() {
+                let message = code_issue(&status, "publishing status");
+                self.issue("work_status", &message, true);
+            }
+        }
+
+        self.copyright_holder = publishing
+            .children("CopyrightStatement")
+            .flat_map(|s| s.children("CopyrightOwner"))
+            .find_map(|o| onix_name(o).map(|(_, _, full_name)| full_name));
+        if self.copyright_holder.is_none() {
+            self.copyright_holder = publisher.and_then(|p| p.child_value("PublisherName"));
+            if self.copyright_holder.is_some() {
+                self.issue(
+                    "copyright_holder",
+                    "No copyright owner is given; the publisher's name is used",
+                    false,
+                );
+            }
+        }
+
+        // Prefer the publication date, falling back to that of the print counterpart
+        let publishing_date = ["01", "19"].iter().find_map(|role| {
+            publishing
+                .children("PublishingDate")
+                .find(|d| d.child_value("PublishingDateRole").as_deref() == Some(*role))
+        });
+        if let Some(date) = publishing_date.and_then(|d| d.child("Date")) {
+            let value = date.value().unwrap_or_default();
+            match date.attribute("dateformat").unwrap_or("00") {
+                "00" => match NaiveDate::parse_from_str(&value, "%Y%m%d") {
+                    Ok(date) => self.publication_date = Some(date),
+                    Err(_) => {
+                        let message = format!("{} is not a valid date", value);
+                        self.issue("publication_date", &message, true);
+                    }
+                },
+                _ => self.issue(
+                    "publication_date",
+                    &format!(
+                        "{} is not a full date (YYYYMMDD) and is not imported",
+                        value
+                    ),
+                    false,
+                ),
+            }
+        }
+    }
+
+    fn read_publication(&mut self, product: &OnixElement) {
+        let descriptive = match product.child("DescriptiveDetail") {
+            Some(descriptive) => descriptive,
+            None => return,
+        };
+        let form = match descriptive.child_value("ProductForm") {
+            Some(form) => form,
+            None => return,
+        };
+        let details: Vec<String> = descriptive
+            .children("ProductFormDetail")
+            .filter_map(OnixElement::value)
+            .collect();
+        let publication_type = match publication_type_from_form(&form, &details) {
+            Some(publication_type) => publication_type,
+            None => {
+                let form = std::iter::once(form).chain(details).collect::<Vec<_>>();
+                let message = code_issue(&form.join(" "), "product form");
+                return self.issue("publications", &message, true);
+            }
+        };
+        let isbn = match product.identifier("ProductIdentifier", "ProductIDType", "15") {
+            Some(isbn) => match Isbn::from_str(&isbn) {
+                Ok(isbn) => Some(isbn.to_string()),
+                Err(e) => {
+                    self.issue("publications", &e.to_string(), true);
+                    None
+                }
+            },
+            None => None,
+        };
+
+        let mut unpriced_item_type = None;
+        let mut prices = vec![];
+        for supply in product
+            .children("ProductSupply")
+            .flat_map(|s| s.children("SupplyDetail"))
+        {
+            if let Some(code) = supply.child_value("UnpricedItemType") {
+                match unpriced_item_type_from_code(&code) {
+                    Some(unpriced) => unpriced_item_type = Some(unpriced),
+                    None => {
+                        let message = code_issue(&code, "unpriced item type");
+                        self.issue("publications", &message, true);
+                    }
+                }
+            }
+            for price in supply.children("Price") {
+                if let Some(price) = self.read_price(price) {
+                    // Suppliers often repeat the same price
+                    if !prices.contains(&price) {
+                        prices.push(price);
+                    }
+                }
+            }
+        }
+        self.publications.push(OnixPublication {
+            publication_type,
+            isbn,
+            unpriced_item_type,
+            prices,
+        });
+    }
+
+    fn read_price(&mut self, price: &OnixElement) -> Option<OnixPrice> {
+        let price_type = price.child_value("PriceType").unwrap_or_default();
+        let price_type = match price_type_from_code(&price_type) {
+            Some(price_type) => price_type,
+            None => {
+                self.issue("publications", &code_issue(&price_type, "price type"), true);
+                return None;
+            }
+        };
+        let amount = price.child_value("PriceAmount").unwrap_or_default();
+        let unit_price = match amount.parse::<f64>() {
+            Ok(unit_price) => unit_price,
+            Err(_) => {
+                self.issue("publications", &code_issue(&amount, "price amount"), true);
+                return None;
+            }
+        };
+        let currency = price.child_value("CurrencyCode").unwrap_or_default();
+        let currency_code = match CurrencyCode::from_str(&currency.to_uppercase()) {
+            Ok(currency_code) => currency_code,
+            Err(e) => {
+                self.issue("publications", &e.to_string(), true);
+                return None;
+            }
+        };
+        let territory = price
+            .child("Territory")
+            .and_then(|t| {
+                t.child_value("CountriesIncluded")
+                    .or_else(|| t.child_value("RegionsIncluded"))
+            })
+            .unwrap_or_else(|| "WORLD".to_string());
+        Some(OnixPrice {
+            currency_code,
+            unit_price,
+            price_type,
+            territory,
+        })
+    }
+
+    /// Describe, field by field, what importing the product would record in Thoth
+    pub fn validation_report(&self) -> OnixValidationReport {
+        let list = |values: Vec<String>| {
+            if values.is_empty() {
+                None
+            } else {
+                Some(values.join("; "))
+            }
+        };
+        let fields = vec![
+            self.field_result("title", self.title.clone()),
+            self.field_result("subtitle", self.subtitle.clone()),
+            self.field_result("work_type", self.work_type.as_ref().map(|t| t.to_string())),
+            self.field_result(
+                "work_status",
+                self.work_status.as_ref().map(|s| s.to_string()),
+            ),
+            self.field_result("edition", self.edition.map(|e| e.to_string())),
+            self.field_result("doi", self.doi.clone()),
+            self.field_result(
+                "publication_date",
+                self.publication_date.map(|d| d.to_string()),
+            ),
+            self.field_result("place", self.place.clone()),
+            self.field_result("page_count", self.page_count.map(|p| p.to_string())),
+            self.field_result("license", self.license.clone()),
+            self.field_result("copyright_holder", self.copyright_holder.clone()),
+            self.field_result("landing_page", self.landing_page.clone()),
+            self.field_result("short_abstract", self.short_abstract.clone()),
+            self.field_result("long_abstract", self.long_abstract.clone()),
+            self.field_result("toc", self.toc.clone()),
+            self.field_result(
+                "contributions",
+                list(
+                    self.contributions
+                        .iter()
+                        .map(|c| format!("{} ({})", c.full_name, c.contribution_type))
+                        .collect(),
+                ),
+            ),
+            self.field_result(
+                "subjects",
+                list(
+                    self.subjects
+                        .iter()
+                        .map(|s| format!("{} {}", s.subject_type, s.subject_code))
+                        .collect(),
+                ),
+            ),
+            self.field_result(
+                "languages",
+                list(
+                    self.languages
+                        .iter()
+                        .map(|l| format!("{} ({})", l.language_code, l.language_relation))
+                        .collect(),
+                ),
+            ),
+            self.field_result(
+                "publications",
+                list(
+                    self.publications
+                        .iter()
+                        .map(|p| match &p.isbn {
+                            Some(isbn) => format!("{} {}", p.publication_type, isbn),
+                            None => p.publication_type.to_string(),
+                        })
+                        .collect(),
+                ),
+            ),
+        ];
+        OnixValidationReport {
+            record_reference: self.record_reference.clone(),
+            valid: fields.iter().all(|f| {
+                f.status != OnixFieldStatus::Unmappable
+                    && !(f.required && f.status == OnixFieldStatus::Missing)
+            }),
+            fields,
+        }
+    }
+
+    /// Reasons the product cannot be imported: codes with no Thoth equivalent, and missing
+    /// required fields
+    pub fn import_errors(&self) -> Vec<String> {
+        self.validation_report()
+            .fields
+            .into_iter()
+            .filter_map(|f| match f.status {
+                OnixFieldStatus::Unmappable => {
+                    Some(format!("{}: {}", f.field, f.message.unwrap_or_default()))
+                }
+                OnixFieldStatus::Missing if f.required => {
+                    Some(format!("{}: a value is required", f.field))
+                }
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// The work to create for the product, or the reasons it cannot be imported
+    pub fn new_work(&self, imprint_id: Uuid) -> Result<NewWork, ThothError> {
+        let errors = self.import_errors();
+        if !errors.is_empty() {
+            return Err(ThothError::InvalidOnix(errors.join("; ")));
+        }
+        // Required fields are known to be present once there are no import errors
+        let title = self.title.clone().unwrap_or_default();
+        let full_title = match &self.subtitle {
+            Some(subtitle) => format!("{}: {}", title, subtitle),
+            None => title.clone(),
+        };
+        Ok(NewWork {
+            work_type: self.work_type.clone().unwrap_or(WorkType::Monograph),
+            work_status: self.work_status.clone().unwrap_or(WorkStatus::Unspecified),
+            full_title,
+            title,
+            subtitle: self.subtitle.clone(),
+            reference: None,
+            edition: self.edition.unwrap_or(DEFAULT_EDITION),
+            imprint_id,
+            doi: self.doi.clone(),
+            publication_date: self.publication_date,
+            place: self.place.clone(),
+            width: None,
+            height: None,
+            page_count: self.page_count,
+            page_breakdown: None,
+            image_count: None,
+            table_count: None,
+            audio_count: None,
+            video_count: None,
+            license: self.license.clone(),
+            copyright_holder: self.copyright_holder.clone().unwrap_or_default(),
+            landing_page: self.landing_page.clone(),
+            lccn: None,
+            oclc: None,
+            short_abstract: self.short_abstract.clone(),
+            long_abstract: self.long_abstract.clone(),
+            general_note: None,
+            toc: self.toc.clone(),
+            cover_url: None,
+            cover_caption: None,
+            short_doi: None,
+        })
+    }
+
+    fn field_result(&self, field: &str, value: Option<String>) -> OnixFieldResult {
+        let issues: Vec<&OnixIssue> = self.issues.iter().filter(|i| i.field == field).collect();
+        let status = if issues.iter().any(|i| i.unmappable) {
+            OnixFieldStatus::Unmappable
+        } else if value.is_some() {
+            OnixFieldStatus::Populated
+        } else {
+            OnixFieldStatus::Missing
+        };
+        let messages: Vec<&str> = issues.iter().map(|i| i.message.as_str()).collect();
+        OnixFieldResult {
+            field: field.to_string(),
+            status,
+            required: REQUIRED_FIELDS.contains(&field),
+            value,
+            message: if messages.is_empty() {
+                None
+            } else {
+                Some(messages.join("; "))
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+const REFERENCE_PRODUCT: &str = r#"<?xml version="1.0" encoding="utf-8"?>
+<ONIXMessage release="3.0" xmlns="http://ns.editeur.org/onix/3.0/reference">
+  <Product>
+    <RecordReference>urn:uuid:00000000-0000-0000-aaaa-000000000001</RecordReference>
+    <ProductIdentifier>
+      <ProductIDType>15</ProductIDType>
+      <IDValue>9781783740574</IDValue>
+    </ProductIdentifier>
+    <ProductIdentifier>
+      <ProductIDType>06</ProductIDType>
+      <IDValue>10.11647/OBP.0001</IDValue>
+    </ProductIdentifier>
+    <DescriptiveDetail>
+      <ProductForm>EB</ProductForm>
+      <ProductFormDetail>E107</ProductFormDetail>
+      <TitleDetail>
+        <TitleType>01</TitleType>
+        <TitleElement>
+          <TitleElementLevel>01</TitleElementLevel>
+          <TitleText>Book Title</TitleText>
+          <Subtitle>Book Subtitle</Subtitle>
+        </TitleElement>
+      </TitleDetail>
+      <Contributor>
+        <ContributorRole>B01</ContributorRole>
+        <NamesBeforeKey>Jane</NamesBeforeKey>
+        <KeyNames>Doe</KeyNames>
+      </Contributor>
+      <Contributor>
+        <ContributorRole>Z99</ContributorRole>
+        <PersonName>Someone Else</PersonName>
+      </Contributor>
+      <Language>
+        <LanguageRole>01</LanguageRole>
+        <LanguageCode>eng</LanguageCode>
+      </Language>
+      <Subject>
+        <SubjectSchemeIdentifier>20</SubjectSchemeIdentifier>
+        <SubjectHeadingText>history; archives</SubjectHeadingText>
+      </Subject>
+    </DescriptiveDetail>
+    <PublishingDetail>
+      <Publisher>
+        <PublishingRole>01</PublishingRole>
+        <PublisherName>Open Book Publishers</PublisherName>
+      </Publisher>
+      <PublishingStatus>04</PublishingStatus>
+      <PublishingDate>
+        <PublishingDateRole>01</PublishingDateRole>
+        <Date dateformat="00">20200131</Date>
+      </PublishingDate>
+    </PublishingDetail>
+    <ProductSupply>
+      <SupplyDetail>
+        <Price>
+          <PriceType>02</PriceType>
+          <PriceAmount>9.99</PriceAmount>
+          <CurrencyCode>GBP</CurrencyCode>
+        </Price>
+      </SupplyDetail>
+    </ProductSupply>
+  </Product>
+</ONIXMessage>"#;
+
+#[cfg(test)]
+const SHORT_PRODUCT: &str = r#"<?xml version="1.0" encoding="utf-8"?>
+<ONIXmessage release="3.0" xmlns="http://ns.editeur.org/onix/3.0/short">
+  <product>
+    <a001>urn:uuid:00000000-0000-0000-aaaa-000000000001</a001>
+    <productidentifier>
+      <b221>15</b221>
+      <b244>9781783740574</b244>
+    </productidentifier>
+    <productidentifier>
+      <b221>06</b221>
+      <b244>10.11647/OBP.0001</b244>
+    </productidentifier>
+    <descriptivedetail>
+      <b012>EB</b012>
+      <b333>E107</b333>
+      <titledetail>
+        <b202>01</b202>
+        <titleelement>
+          <x409>01</x409>
+          <b203>Book Title</b203>
+          <b029>Book Subtitle</b029>
+        </titleelement>
+      </titledetail>
+      <contributor>
+        <b035>B01</b035>
+        <b039>Jane</b039>
+        <b040>Doe</b040>
+      </contributor>
+      <contributor>
+        <b035>Z99</b035>
+        <b036>Someone Else</b036>
+      </contributor>
+      <language>
+        <b253>01</b253>
+        <b252>eng</b252>
+      </language>
+      <subject>
+        <b067>20</b067>
+        <b070>history; archives</b070>
+      </subject>
+    </descriptivedetail>
+    <publishingdetail>
+      <publisher>
+        <b291>01</b291>
+        <b081>Open Book Publishers</b081>
+      </publisher>
+      <b394>04</b394>
+      <publishingdate>
+        <x448>01</x448>
+        <b306 dateformat="00">20200131</b306>
+      </publishingdate>
+    </publishingdetail>
+    <productsupply>
+      <supplydetail>
+        <price>
+          <x462>02</x462>
+          <j151>9.99</j151>
+          <j152>GBP</j152>
+        </price>
+      </supplydetail>
+    </productsupply>
+  </product>
+</ONIXmessage>"#;
+
+#[test]
+fn test_parse_onix_products() {
+    let products = parse_onix_products(REFERENCE_PRODUCT.as_bytes()).unwrap();
+    assert_eq!(products.len(), 1);
+    let product = &products[0];
+    assert_eq!(product.title, Some("Book Title".to_string()));
+    assert_eq!(product.subtitle, Some("Book Subtitle".to_string()));
+    assert_eq!(product.work_type, Some(WorkType::EditedBook));
+    assert_eq!(product.work_status, Some(WorkStatus::Active));
+    assert_eq!(
+        product.doi,
+        Some("https://doi.org/10.11647/OBP.0001".to_string())
+    );
+    assert_eq!(
+        product.publication_date,
+        Some(NaiveDate::from_ymd(2020, 1, 31))
+    );
+    assert_eq!(
+        product.copyright_holder,
+        Some("Open Book Publishers".to_string())
+    );
+    assert_eq!(product.contributions.len(), 1);
+    assert_eq!(product.contributions[0].full_name, "Jane Doe");
+    assert_eq!(product.contributions[0].last_name, "Doe");
+    assert_eq!(product.subjects.len(), 2);
+    assert_eq!(product.languages[0].language_code, LanguageCode::Eng);
+    assert_eq!(product.publications.len(), 1);
+    assert_eq!(
+        product.publications[0].publication_type,
+        PublicationType::PDF
+    );
+    assert_eq!(product.publications[0].prices[0].territory, "WORLD");
+}
+
+#[test]
+fn test_parse_onix_short_tags() {
+    assert_eq!(
+        parse_onix_products(SHORT_PRODUCT.as_bytes()).unwrap(),
+        parse_onix_products(REFERENCE_PRODUCT.as_bytes()).unwrap()
+    );
+}
+
+#[test]
+fn test_parse_onix_invalid() {
+    assert!(parse_onix_products(b"<NotOnix/>").is_err());
+    assert!(parse_onix_products(b"<Product>").is_err());
+}
+
+#[test]
+fn test_onix_validation_report() {
+    let products = parse_onix_products(REFERENCE_PRODUCT.as_bytes()).unwrap();
+    let report = products[0].validation_report();
+    let field = |name: &str| report.fields.iter().find(|f| f.field == name).unwrap();
+    assert!(!report.valid);
+    assert_eq!(field("title").status, OnixFieldStatus::Populated);
+    assert_eq!(field("contributions").status, OnixFieldStatus::Unmappable);
+    assert_eq!(field("edition").status, OnixFieldStatus::Missing);
+    assert!(!field("edition").required);
+    assert_eq!(field("page_count").status, OnixFieldStatus::Missing);
+    assert!(field("copyright_holder").message.is_some());
+}
+
+#[test]
+fn test_onix_new_work() {
+    let products = parse_onix_products(REFERENCE_PRODUCT.as_bytes()).unwrap();
+    let imprint_id = Uuid::new_v4();
+    match products[0].new_work(imprint_id) {
+        Err(ThothError::InvalidOnix(message)) => assert_eq!(
+            message,
+            "contributions: Z99 is not a contributor role Thoth supports"
+        ),
+        _ => panic!("A product with an unmappable role was imported"),
+    }
+
+    let mut product = products[0].clone();
+    product.issues.retain(|i| !i.unmappable);
+    let work = product.new_work(imprint_id).unwrap();
+    assert_eq!(work.full_title, "Book Title: Book Subtitle");
+    assert_eq!(work.edition, DEFAULT_EDITION);
+    assert_eq!(work.imprint_id, imprint_id);
+    assert_eq!(work.copyright_holder, "Open Book Publishers");
+}
@@ -0,0 +1,65 @@
+use reqwest::blocking::Client;
+use serde::Deserialize;
+use std::time::Duration;
+
+use crate::errors::ThothError;
+use crate::identifier::ShortDoi;
+
+/// Handle System REST API of the DOI resolver, which lists the values a handle is registered with
+const HANDLE_API_URL: &str = "https://doi.org/api/handles/";
+const HANDLE_REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+/// Type of the handle value holding the DOI a shortDOI is an alias of
+const ALIAS_TYPE: &str = "HS_ALIAS";
+const DOI_URL_PREFIX: &str = "https://doi.org/";
+
+#[derive(Deserialize)]
+struct HandleResponse {
+    #[serde(default)]
+    values: Vec<HandleValue>,
+}
+
+#[derive(Deserialize)]
+struct HandleValue {
+    #[serde(rename = "type")]
+    value_type: String,
+    data: HandleData,
+}
+
+#[derive(Deserialize)]
+struct HandleData {
+    value: serde_json::Value,
+}
+
+/// Look up the DOI a shortDOI is an alias of, as a full `https://doi.org/` URL
+pub fn resolve_short_doi(short_doi: &ShortDoi) -> Result<String, ThothError> {
+    let client = Client::builder().timeout(HANDLE_REQUEST_TIMEOUT).build()?;
+    let response = client
+        .get(&format!("{}{}", HANDLE_API_URL, short_doi.handle()))
+        .send()?;
+    if !response.status().is_success() {
+        return Err(ThothError::InvalidShortDoi(short_doi.to_string()));
+    }
+    response
+        .json::<HandleResponse>()?
+        .values
+        .into_iter()
+        .find(|v| v.value_type == ALIAS_TYPE)
+        .and_then(|v| v.data.value.as_str().map(|doi| doi.to_string()))
+        .map(|doi| format!("{}{}", DOI_URL_PREFIX, doi))
+        .ok_or_else(|| ThothError::InvalidShortDoi(short_doi.to_string()))
+}
+
+/// Check that a shortDOI is registered as an alias of the given DOI
+pub fn verify_short_doi(short_doi: &ShortDoi, doi: &str) -> Result<(), ThothError> {
+    let resolved = resolve_short_doi(short_doi)?;
+    // DOIs are case insensitive
+    if resolved.to_lowercase() == doi.to_lowercase() {
+        Ok(())
+    } else {
+        Err(ThothError::ShortDoiMismatch(
+            short_doi.to_string(),
+            doi.to_string(),
+            resolved,
+        ))
+    }
+}
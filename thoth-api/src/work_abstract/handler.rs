@@ -0,0 +1,86 @@
+use diesel::pg::PgConnection;
+use diesel::prelude::*;
+use uuid::Uuid;
+
+use crate::errors::ThothError;
+use crate::schema::work_abstract_history;
+use crate::work_abstract::model::Abstract;
+use crate::work_abstract::model::AbstractHistory;
+use crate::work_abstract::model::AbstractType;
+use crate::work_abstract::model::NewAbstract;
+use crate::work_abstract::model::NewAbstractHistory;
+use crate::work_abstract::model::DEFAULT_ABSTRACT_LANGUAGE;
+
+impl NewAbstractHistory {
+    pub fn new(work_abstract: Abstract, account_id: Uuid) -> Self {
+        Self {
+            abstract_id: work_abstract.abstract_id,
+            account_id,
+            data: serde_json::Value::String(serde_json::to_string(&work_abstract).unwrap()),
+        }
+    }
+
+    pub fn insert(&self, connection: &PgConnection) -> Result<AbstractHistory, ThothError> {
+        match diesel::insert_into(work_abstract_history::table)
+            .values(self)
+            .get_result(connection)
+        {
+            Ok(history) => Ok(history),
+            Err(e) => Err(ThothError::from(e)),
+        }
+    }
+}
+
+/// Mirror a work's own short or long abstract into its default language abstract,
+/// creating, updating or removing that abstract as needed
+pub fn sync_default_abstract(
+    connection: &PgConnection,
+    work_id: Uuid,
+    abstract_type: AbstractType,
+    text: Option<&String>,
+) -> Result<(), ThothError> {
+    use crate::schema::work_abstract::dsl;
+    match text.filter(|text| !text.is_empty()) {
+        Some(text) => diesel::insert_into(dsl::work_abstract)
+            .values(&NewAbstract {
+                work_id,
+                language_code: DEFAULT_ABSTRACT_LANGUAGE,
+                abstract_type,
+                text: text.to_string(),
+            })
+            .on_conflict((dsl::work_id, dsl::language_code, dsl::abstract_type))
+            .do_update()
+            .set(dsl::text.eq(text))
+            .execute(connection),
+        None => diesel::delete(
+            dsl::work_abstract
+                .filter(dsl::work_id.eq(work_id))
+                .filter(dsl::language_code.eq(DEFAULT_ABSTRACT_LANGUAGE))
+                .filter(dsl::abstract_type.eq(abstract_type)),
+        )
+        .execute(connection),
+    }
+    .map(|_| ())
+    .map_err(ThothError::from)
+}
+
+/// Copy a default language abstract back into the matching abstract field of its work
+pub fn sync_work_abstract_field(
+    connection: &PgConnection,
+    work_id: Uuid,
+    abstract_type: &AbstractType,
+    text: Option<&String>,
+) -> Result<(), ThothError> {
+    use crate::schema::work::dsl;
+    let target = dsl::work.find(work_id);
+    match abstract_type {
+        AbstractType::Short => diesel::update(target)
+            .set(dsl::short_abstract.eq(text))
+            .execute(connection),
+        AbstractType::Long => diesel::update(target)
+            .set(dsl::long_abstract.eq(text))
+            .execute(connection),
+    }
+    .map(|_| ())
+    .map_err(ThothError::from)
+}
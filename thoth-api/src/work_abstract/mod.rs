@@ -0,0 +1,3 @@
+#[cfg(feature = "backend")]
+pub mod handler;
+pub mod model;
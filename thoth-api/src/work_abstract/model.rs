@@ -0,0 +1,140 @@
+use chrono::naive::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+use uuid::Uuid;
+
+use crate::errors::ThothError;
+use crate::language::model::LanguageCode;
+#[cfg(feature = "backend")]
+use crate::schema::work_abstract;
+#[cfg(feature = "backend")]
+use crate::schema::work_abstract_history;
+
+/// Language of the abstracts stored directly on a work as `short_abstract` and `long_abstract`
+pub const DEFAULT_ABSTRACT_LANGUAGE: LanguageCode = LanguageCode::Eng;
+
+#[cfg_attr(feature = "backend", derive(DbEnum, juniper::GraphQLEnum))]
+#[cfg_attr(feature = "backend", DieselType = "Abstract_type")]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum AbstractType {
+    Short,
+    Long,
+}
+
+#[cfg_attr(feature = "backend", derive(Queryable))]
+#[derive(Serialize, Deserialize)]
+pub struct Abstract {
+    pub abstract_id: Uuid,
+    pub work_id: Uuid,
+    pub language_code: LanguageCode,
+    pub abstract_type: AbstractType,
+    pub text: String,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+}
+
+#[cfg_attr(
+    feature = "backend",
+    derive(juniper::GraphQLInputObject, Insertable),
+    table_name = "work_abstract"
+)]
+pub struct NewAbstract {
+    pub work_id: Uuid,
+    pub language_code: LanguageCode,
+    pub abstract_type: AbstractType,
+    pub text: String,
+}
+
+#[cfg_attr(
+    feature = "backend",
+    derive(juniper::GraphQLInputObject, AsChangeset),
+    changeset_options(treat_none_as_null = "true"),
+    table_name = "work_abstract"
+)]
+pub struct PatchAbstract {
+    pub abstract_id: Uuid,
+    pub work_id: Uuid,
+    pub language_code: LanguageCode,
+    pub abstract_type: AbstractType,
+    pub text: String,
+}
+
+#[cfg_attr(feature = "backend", derive(Queryable))]
+pub struct AbstractHistory {
+    pub abstract_history_id: Uuid,
+    pub abstract_id: Uuid,
+    pub account_id: Uuid,
+    pub data: serde_json::Value,
+    pub timestamp: NaiveDateTime,
+}
+
+#[cfg_attr(
+    feature = "backend",
+    derive(Insertable),
+    table_name = "work_abstract_history"
+)]
+pub struct NewAbstractHistory {
+    pub abstract_id: Uuid,
+    pub account_id: Uuid,
+    pub data: serde_json::Value,
+}
+
+impl Default for AbstractType {
+    fn default() -> AbstractType {
+        AbstractType::Long
+    }
+}
+
+impl fmt::Display for AbstractType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AbstractType::Short => write!(f, "Short"),
+            AbstractType::Long => write!(f, "Long"),
+        }
+    }
+}
+
+impl FromStr for AbstractType {
+    type Err = ThothError;
+
+    fn from_str(input: &str) -> std::result::Result<AbstractType, ThothError> {
+        match input {
+            "Short" => Ok(AbstractType::Short),
+            "Long" => Ok(AbstractType::Long),
+            _ => Err(ThothError::InvalidAbstractType(input.to_string())),
+        }
+    }
+}
+
+impl Abstract {
+    /// Whether this abstract is the one mirrored in the work's own abstract fields
+    pub fn is_default_language(&self) -> bool {
+        self.language_code == DEFAULT_ABSTRACT_LANGUAGE
+    }
+}
+
+#[test]
+fn test_abstracttype_default() {
+    let abstracttype: AbstractType = Default::default();
+    assert_eq!(abstracttype, AbstractType::Long);
+}
+
+#[test]
+fn test_abstracttype_display() {
+    assert_eq!(format!("{}", AbstractType::Short), "Short");
+    assert_eq!(format!("{}", AbstractType::Long), "Long");
+}
+
+#[test]
+fn test_abstracttype_fromstr() {
+    assert_eq!(
+        AbstractType::from_str("Short").unwrap(),
+        AbstractType::Short
+    );
+    assert_eq!(AbstractType::from_str("Long").unwrap(), AbstractType::Long);
+
+    assert!(AbstractType::from_str("Medium").is_err());
+    assert!(AbstractType::from_str("long").is_err());
+}
@@ -0,0 +1,58 @@
+use diesel::pg::PgConnection;
+use diesel::prelude::*;
+use uuid::Uuid;
+
+use crate::errors::ThothError;
+use crate::schema::work_title_history;
+use crate::work_title::model::NewWorkTitle;
+use crate::work_title::model::NewWorkTitleHistory;
+use crate::work_title::model::TitleType;
+use crate::work_title::model::WorkTitle;
+use crate::work_title::model::WorkTitleHistory;
+use crate::work_title::model::DEFAULT_TITLE_LANGUAGE;
+
+impl NewWorkTitleHistory {
+    pub fn new(work_title: WorkTitle, account_id: Uuid) -> Self {
+        Self {
+            title_id: work_title.title_id,
+            account_id,
+            data: serde_json::Value::String(serde_json::to_string(&work_title).unwrap()),
+        }
+    }
+
+    pub fn insert(&self, connection: &PgConnection) -> Result<WorkTitleHistory, ThothError> {
+        match diesel::insert_into(work_title_history::table)
+            .values(self)
+            .get_result(connection)
+        {
+            Ok(history) => Ok(history),
+            Err(e) => Err(ThothError::from(e)),
+        }
+    }
+}
+
+/// Mirror a work's own title and subtitle into its default language title,
+/// creating that title if the work does not have it yet
+pub fn sync_default_title(
+    connection: &PgConnection,
+    work_id: Uuid,
+    title: &str,
+    subtitle: Option<&String>,
+) -> Result<(), ThothError> {
+    use crate::schema::work_title::dsl;
+    let subtitle = subtitle.filter(|subtitle| !subtitle.is_empty());
+    diesel::insert_into(dsl::work_title)
+        .values(&NewWorkTitle {
+            work_id,
+            language_code: DEFAULT_TITLE_LANGUAGE,
+            title_type: TitleType::Distinctive,
+            title: title.to_string(),
+            subtitle: subtitle.cloned(),
+        })
+        .on_conflict((dsl::work_id, dsl::language_code, dsl::title_type))
+        .do_update()
+        .set((dsl::title.eq(title), dsl::subtitle.eq(subtitle)))
+        .execute(connection)
+        .map(|_| ())
+        .map_err(ThothError::from)
+}
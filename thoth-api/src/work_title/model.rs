@@ -0,0 +1,181 @@
+use chrono::naive::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+use uuid::Uuid;
+
+use crate::errors::ThothError;
+use crate::language::model::LanguageCode;
+#[cfg(feature = "backend")]
+use crate::schema::work_title;
+#[cfg(feature = "backend")]
+use crate::schema::work_title_history;
+
+/// Language of the title stored directly on a work as `title` and `subtitle`
+pub const DEFAULT_TITLE_LANGUAGE: LanguageCode = LanguageCode::Eng;
+
+#[cfg_attr(feature = "backend", derive(DbEnum, juniper::GraphQLEnum))]
+#[cfg_attr(feature = "backend", DieselType = "Title_type")]
+#[cfg_attr(
+    feature = "backend",
+    graphql(description = "Role of a title of a work (ONIX code list 15)")
+)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum TitleType {
+    /// The title the work is published under
+    Distinctive,
+    /// Title of the original of a translated work
+    Original,
+    /// Translation of the title into another language
+    Translated,
+    Alternative,
+}
+
+#[cfg_attr(feature = "backend", derive(Queryable))]
+#[derive(Serialize, Deserialize)]
+pub struct WorkTitle {
+    pub title_id: Uuid,
+    pub work_id: Uuid,
+    pub language_code: LanguageCode,
+    pub title_type: TitleType,
+    pub title: String,
+    pub subtitle: Option<String>,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+}
+
+#[cfg_attr(
+    feature = "backend",
+    derive(juniper::GraphQLInputObject, Insertable),
+    table_name = "work_title"
+)]
+pub struct NewWorkTitle {
+    pub work_id: Uuid,
+    pub language_code: LanguageCode,
+    pub title_type: TitleType,
+    pub title: String,
+    pub subtitle: Option<String>,
+}
+
+#[cfg_attr(
+    feature = "backend",
+    derive(juniper::GraphQLInputObject, AsChangeset),
+    changeset_options(treat_none_as_null = "true"),
+    table_name = "work_title"
+)]
+pub struct PatchWorkTitle {
+    pub title_id: Uuid,
+    pub work_id: Uuid,
+    pub language_code: LanguageCode,
+    pub title_type: TitleType,
+    pub title: String,
+    pub subtitle: Option<String>,
+}
+
+#[cfg_attr(feature = "backend", derive(Queryable))]
+pub struct WorkTitleHistory {
+    pub title_history_id: Uuid,
+    pub title_id: Uuid,
+    pub account_id: Uuid,
+    pub data: serde_json::Value,
+    pub timestamp: NaiveDateTime,
+}
+
+#[cfg_attr(
+    feature = "backend",
+    derive(Insertable),
+    table_name = "work_title_history"
+)]
+pub struct NewWorkTitleHistory {
+    pub title_id: Uuid,
+    pub account_id: Uuid,
+    pub data: serde_json::Value,
+}
+
+impl Default for TitleType {
+    fn default() -> TitleType {
+        TitleType::Translated
+    }
+}
+
+impl fmt::Display for TitleType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            TitleType::Distinctive => write!(f, "Distinctive"),
+            TitleType::Original => write!(f, "Original"),
+            TitleType::Translated => write!(f, "Translated"),
+            TitleType::Alternative => write!(f, "Alternative"),
+        }
+    }
+}
+
+impl FromStr for TitleType {
+    type Err = ThothError;
+
+    fn from_str(input: &str) -> std::result::Result<TitleType, ThothError> {
+        match input {
+            "Distinctive" => Ok(TitleType::Distinctive),
+            "Original" => Ok(TitleType::Original),
+            "Translated" => Ok(TitleType::Translated),
+            "Alternative" => Ok(TitleType::Alternative),
+            _ => Err(ThothError::InvalidTitleType(input.to_string())),
+        }
+    }
+}
+
+/// Whether a title in this language and role is the one stored on the work itself
+pub fn is_default_title(language_code: &LanguageCode, title_type: &TitleType) -> bool {
+    *language_code == DEFAULT_TITLE_LANGUAGE && *title_type == TitleType::Distinctive
+}
+
+impl WorkTitle {
+    /// Whether this title mirrors the work's own title fields
+    pub fn is_default(&self) -> bool {
+        is_default_title(&self.language_code, &self.title_type)
+    }
+}
+
+#[test]
+fn test_titletype_default() {
+    let titletype: TitleType = Default::default();
+    assert_eq!(titletype, TitleType::Translated);
+}
+
+#[test]
+fn test_titletype_display() {
+    assert_eq!(format!("{}", TitleType::Distinctive), "Distinctive");
+    assert_eq!(format!("{}", TitleType::Original), "Original");
+    assert_eq!(format!("{}", TitleType::Translated), "Translated");
+    assert_eq!(format!("{}", TitleType::Alternative), "Alternative");
+}
+
+#[test]
+fn test_titletype_fromstr() {
+    assert_eq!(
+        TitleType::from_str("Distinctive").unwrap(),
+        TitleType::Distinctive
+    );
+    assert_eq!(
+        TitleType::from_str("Translated").unwrap(),
+        TitleType::Translated
+    );
+    assert!(TitleType::from_str("Subtitle").is_err());
+    assert!(TitleType::from_str("translated").is_err());
+}
+
+#[test]
+fn test_is_default_title() {
+    assert!(is_default_title(
+        &DEFAULT_TITLE_LANGUAGE,
+        &TitleType::Distinctive
+    ));
+    assert!(!is_default_title(
+        &DEFAULT_TITLE_LANGUAGE,
+        &TitleType::Translated
+    ));
+    assert!(!is_default_title(
+        &LanguageCode::Fre,
+        &TitleType::Distinctive
+    ));
+}
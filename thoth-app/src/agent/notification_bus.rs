@@ -27,6 +27,18 @@ impl fmt::Display for NotificationStatus {
     }
 }
 
+/// Notification for a record that was saved, as a warning if saving reported likely problems
+pub fn saved_notification(title: &str, warnings: &[String]) -> (String, NotificationStatus) {
+    if warnings.is_empty() {
+        (format!("Saved {}", title), NotificationStatus::Success)
+    } else {
+        (
+            format!("Saved {}. {}", title, warnings.join(". ")),
+            NotificationStatus::Warning,
+        )
+    }
+}
+
 pub struct NotificationBus {
     link: AgentLink<NotificationBus>,
     subscribers: HashSet<HandlerId>,
@@ -31,11 +31,11 @@ use crate::component::publishers::PublishersComponent;
 use crate::component::series::SeriesComponent;
 use crate::component::serieses::SeriesesComponent;
 use crate::component::work::WorkComponent;
+use crate::component::work_import::WorkImportComponent;
 use crate::component::works::WorksComponent;
 use crate::route::AdminRoute;
 use crate::route::AppRoute;
 use crate::service::account::AccountService;
-use crate::string::PERMISSIONS_ERROR;
 
 pub struct AdminComponent {
     props: Props,
@@ -84,7 +84,7 @@ impl Component for AdminComponent {
         {
             // Raise an error if user's permission set is empty
             self.notification_bus.send(Request::NotificationBusMsg((
-                PERMISSIONS_ERROR.into(),
+                t!("permissions_error").into(),
                 NotificationStatus::Danger,
             )));
         }
@@ -139,6 +139,7 @@ impl Component for AdminComponent {
                                 AdminRoute::Works => html!{<WorksComponent current_user = self.props.current_user.as_ref().unwrap() />},
                                 AdminRoute::Work(id) => html!{<WorkComponent work_id = id, current_user = self.props.current_user.as_ref().unwrap() />},
                                 AdminRoute::NewWork => html!{<NewWorkComponent current_user = self.props.current_user.as_ref().unwrap() />},
+                                AdminRoute::ImportWorks => html!{<WorkImportComponent current_user = self.props.current_user.as_ref().unwrap() />},
                                 AdminRoute::Publishers => html!{<PublishersComponent current_user = self.props.current_user.as_ref().unwrap() />},
                                 AdminRoute::Publisher(id) => html!{<PublisherComponent publisher_id = id, current_user = self.props.current_user.as_ref().unwrap() />},
                                 AdminRoute::NewPublisher => html!{<NewPublisherComponent/>},
@@ -1,17 +1,39 @@
+use std::str::FromStr;
+use std::time::Duration;
+use thoth_api::work::model::WorkType;
+use url::form_urlencoded;
+use yew::agent::Bridged;
 use yew::html;
 use yew::prelude::Component;
 use yew::prelude::FocusEvent;
 use yew::prelude::Html;
 use yew::prelude::InputData;
 use yew::prelude::ShouldRender;
+use yew::services::timeout::TimeoutTask;
+use yew::services::TimeoutService;
+use yew::Bridge;
 use yew::ComponentLink;
+use yew_router::agent::RouteAgentDispatcher;
+use yew_router::agent::RouteRequest;
+use yew_router::prelude::RouteAgent;
+use yew_router::route::Route;
+use yew_router::service::RouteService;
 use yewtil::fetch::Fetch;
 use yewtil::fetch::FetchAction;
 use yewtil::fetch::FetchState;
 use yewtil::future::LinkFuture;
 
+use crate::component::utils::FetchError;
 use crate::component::utils::Loader;
 use crate::component::utils::Reloader;
+use crate::models::work::work_facets_query::FetchActionWorkFacets;
+use crate::models::work::work_facets_query::FetchWorkFacets;
+use crate::models::work::work_facets_query::PublicationYearFacet;
+use crate::models::work::work_facets_query::Variables as FacetsVariables;
+use crate::models::work::work_facets_query::WorkFacets;
+use crate::models::work::work_facets_query::WorkFacetsRequest;
+use crate::models::work::work_facets_query::WorkFacetsRequestBody;
+use crate::models::work::work_facets_query::WorkTypeFacet;
 use crate::models::work::works_query::FetchActionWorks;
 use crate::models::work::works_query::FetchWorks;
 use crate::models::work::works_query::Variables;
@@ -24,24 +46,49 @@ pub struct CatalogueComponent {
     offset: i32,
     page_size: i32,
     search_term: String,
+    work_type: Option<WorkType>,
+    publication_year: Option<i32>,
     data: Vec<Work>,
     result_count: i32,
+    facets: WorkFacets,
     fetch_data: FetchWorks,
+    fetch_facets: FetchWorkFacets,
+    // count of fetches started, so that responses to superseded ones can be told apart
+    data_generation: u32,
+    facets_generation: u32,
+    // search scheduled while typing; dropping the task cancels it
+    search_timeout: Option<TimeoutTask>,
+    router: RouteAgentDispatcher<()>,
+    _route_listener: Box<dyn Bridge<RouteAgent>>,
     link: ComponentLink<Self>,
 }
 
-pagination_helpers! {CatalogueComponent, PAGINATION_COUNT_WORKS, SEARCH_WORKS}
+// Names of the query string parameters the catalogue's state is kept in
+const SEARCH_PARAM: &str = "q";
+const PAGE_PARAM: &str = "page";
+const WORK_TYPE_PARAM: &str = "type";
+const YEAR_PARAM: &str = "year";
+/// Time without typing after which the catalogue searches for what has been typed so far
+const SEARCH_DEBOUNCE: Duration = Duration::from_millis(300);
+
+pagination_helpers! {CatalogueComponent, "pagination_count_works", "search_works"}
 
 pub enum Msg {
-    SetFetchState(FetchActionWorks),
+    SetFetchState(u32, FetchActionWorks),
     GetData,
     PaginateData,
+    SetFacetsFetchState(u32, FetchActionWorkFacets),
+    GetFacets,
+    ToggleWorkTypeFacet(WorkType),
+    SetYearFilter(Option<i32>),
     #[allow(dead_code)]
     Search(String),
     ChangeSearchTerm(String),
     TriggerSearch,
     NextPage,
     PreviousPage,
+    UpdateUrl,
+    RouteChanged(Route),
 }
 
 impl Component for CatalogueComponent {
@@ -55,25 +102,46 @@ impl Component for CatalogueComponent {
         let search_term: String = Default::default();
         let result_count: i32 = Default::default();
         let data = Default::default();
+        let facets = Default::default();
         let fetch_data = Default::default();
+        let fetch_facets = Default::default();
+        let router = RouteAgentDispatcher::new();
+        let _route_listener = RouteAgent::bridge(link.callback(Msg::RouteChanged));
 
         link.send_message(Msg::PaginateData);
+        link.send_message(Msg::GetFacets);
 
-        CatalogueComponent {
+        let mut catalogue = CatalogueComponent {
             limit,
             offset,
             page_size,
             search_term,
+            work_type: None,
+            publication_year: None,
             data,
             result_count,
+            facets,
             fetch_data,
+            fetch_facets,
+            data_generation: 0,
+            facets_generation: 0,
+            search_timeout: None,
+            router,
+            _route_listener,
             link,
-        }
+        };
+        // restore the search and page given in the URL, e.g. from a bookmark or shared link
+        catalogue.load_query(&RouteService::<()>::new().get_query());
+        catalogue
     }
 
     fn update(&mut self, msg: Self::Message) -> ShouldRender {
         match msg {
-            Msg::SetFetchState(fetch_state) => {
+            Msg::SetFetchState(generation, fetch_state) => {
+                // a slow response to an earlier search must not replace newer results
+                if generation != self.data_generation {
+                    return false;
+                }
                 self.fetch_data.apply(fetch_state);
                 self.data = match self.fetch_data.as_ref().state() {
                     FetchState::Fetched(body) => body.data.works.clone(),
@@ -86,10 +154,14 @@ impl Component for CatalogueComponent {
                 true
             }
             Msg::GetData => {
+                self.data_generation += 1;
+                let generation = self.data_generation;
+                self.link.send_future(
+                    self.fetch_data
+                        .fetch(move |state| Msg::SetFetchState(generation, state)),
+                );
                 self.link
-                    .send_future(self.fetch_data.fetch(Msg::SetFetchState));
-                self.link
-                    .send_message(Msg::SetFetchState(FetchAction::Fetching));
+                    .send_message(Msg::SetFetchState(generation, FetchAction::Fetching));
                 false
             }
             Msg::PaginateData => {
@@ -101,6 +173,8 @@ impl Component for CatalogueComponent {
                         filter: Some(filter),
                         // Catalogue is public so results should never be filtered by logged-in user
                         publishers: None,
+                        work_type: self.work_type.clone(),
+                        publication_year: self.publication_year,
                     },
                     ..Default::default()
                 };
@@ -109,34 +183,113 @@ impl Component for CatalogueComponent {
                 self.link.send_message(Msg::GetData);
                 false
             }
+            Msg::SetFacetsFetchState(generation, fetch_state) => {
+                if generation != self.facets_generation {
+                    return false;
+                }
+                self.fetch_facets.apply(fetch_state);
+                self.facets = match self.fetch_facets.as_ref().state() {
+                    FetchState::Fetched(body) => body.data.work_facets.clone(),
+                    _ => Default::default(),
+                };
+                true
+            }
+            Msg::GetFacets => {
+                let body = WorkFacetsRequestBody {
+                    variables: FacetsVariables {
+                        filter: Some(self.search_term.clone()),
+                        publishers: None,
+                        work_type: self.work_type.clone(),
+                        publication_year: self.publication_year,
+                    },
+                    ..Default::default()
+                };
+                let request = WorkFacetsRequest { body };
+                self.fetch_facets = Fetch::new(request);
+                self.facets_generation += 1;
+                let generation = self.facets_generation;
+                self.link.send_future(
+                    self.fetch_facets
+                        .fetch(move |state| Msg::SetFacetsFetchState(generation, state)),
+                );
+                self.link
+                    .send_message(Msg::SetFacetsFetchState(generation, FetchAction::Fetching));
+                false
+            }
+            Msg::ToggleWorkTypeFacet(work_type) => {
+                if self.work_type.as_ref() == Some(&work_type) {
+                    self.work_type = None;
+                } else {
+                    self.work_type = Some(work_type);
+                }
+                self.link.send_message(Msg::TriggerSearch);
+                false
+            }
+            Msg::SetYearFilter(year) => {
+                self.publication_year = year;
+                self.link.send_message(Msg::TriggerSearch);
+                false
+            }
             Msg::Search(_) => {
                 // needed because of macro, but unused here
                 false
             }
             Msg::ChangeSearchTerm(term) => {
                 self.search_term = term;
+                // replacing the task cancels the search scheduled by the previous keystroke
+                self.search_timeout = Some(TimeoutService::spawn(
+                    SEARCH_DEBOUNCE,
+                    self.link.callback(|_| Msg::TriggerSearch),
+                ));
                 false
             }
             Msg::TriggerSearch => {
+                // a submitted search makes the one scheduled while typing redundant
+                self.search_timeout = None;
                 self.limit = self.page_size;
                 self.offset = 0;
-                self.link.send_message(Msg::PaginateData);
+                self.link.send_message(Msg::UpdateUrl);
                 false
             }
             Msg::NextPage => {
                 if self.limit < self.result_count && !self.is_next_disabled() {
                     self.offset += self.page_size;
-                    self.link.send_message(Msg::PaginateData);
+                    self.link.send_message(Msg::UpdateUrl);
                 }
                 false
             }
             Msg::PreviousPage => {
                 if self.offset > 0 && !self.is_previous_disabled() {
                     self.offset -= self.page_size;
-                    self.link.send_message(Msg::PaginateData);
+                    self.link.send_message(Msg::UpdateUrl);
                 }
                 false
             }
+            Msg::UpdateUrl => {
+                // every change goes through the browser history; data is fetched once the
+                // router reports the new route back
+                let route = Route::from(format!("/{}", self.query_string()));
+                self.router.send(RouteRequest::ChangeRoute(route));
+                false
+            }
+            Msg::RouteChanged(route) => {
+                let (path, query) = match route.route.find('?') {
+                    Some(index) => route.route.split_at(index),
+                    None => (route.route.as_str(), ""),
+                };
+                // the catalogue is only shown on the home page, which is about to be left
+                if path != "/" {
+                    return false;
+                }
+                let filters = self.filters();
+                self.load_query(query);
+                self.link.send_message(Msg::PaginateData);
+                // facet counts do not depend on the page
+                if self.filters() != filters {
+                    self.link.send_message(Msg::GetFacets);
+                }
+                true
+            }
         }
     }
 
@@ -159,14 +312,17 @@ impl Component for CatalogueComponent {
                     <div class="level-right" />
                 </nav>
                 <nav class="pagination is-centered" role="navigation" aria-label="pagination">
-                    <a class="pagination-previous"
+                    // buttons rather than links so that they can be reached and used by keyboard
+                    <button class="pagination-previous"
+                        type="button"
                         onclick=self.link.callback(|_| Msg::PreviousPage)
                         disabled=self.is_previous_disabled()
-                    >{ crate::string::PREVIOUS_PAGE_BUTTON }</a>
-                    <a class="pagination-next"
+                    >{ t!("previous_page_button") }</button>
+                    <button class="pagination-next"
+                        type="button"
                         onclick=self.link.callback(|_| Msg::NextPage)
                         disabled=self.is_next_disabled()
-                    >{ crate::string::NEXT_PAGE_BUTTON }</a>
+                    >{ t!("next_page_button") }</button>
                     <div class="pagination-list">
                         <form
                             style="width: 80%"
@@ -197,19 +353,155 @@ impl Component for CatalogueComponent {
                         </form>
                     </div>
                 </nav>
-                {
-                    match self.fetch_data.as_ref().state() {
-                        FetchState::NotFetching(_) => {
-                            html! {<Reloader onclick=self.link.callback(|_| Msg::GetData)/>}
+                <div class="columns">
+                    <div class="column is-one-quarter">
+                        { self.render_facets() }
+                    </div>
+                    <div class="column">
+                        {
+                            match self.fetch_data.as_ref().state() {
+                                FetchState::NotFetching(_) => {
+                                    html! {<Reloader onclick=self.link.callback(|_| Msg::GetData)/>}
+                                }
+                                FetchState::Fetching(_) => html! {<Loader/>},
+                                FetchState::Fetched(_body) if self.result_count == 0 => html! {
+                                    <div class="notification is-info is-light">
+                                        { t!("empty_results") }
+                                    </div>
+                                },
+                                FetchState::Fetched(_body) => html! {
+                                    { for self.data.iter().map(|w| w.as_catalogue_box()) }
+                                },
+                                FetchState::Failed(_, err) => html! {
+                                    <FetchError
+                                        error=err.to_string()
+                                        onretry=self.link.callback(|_| Msg::GetData)
+                                    />
+                                },
+                            }
                         }
-                        FetchState::Fetching(_) => html! {<Loader/>},
-                        FetchState::Fetched(_body) => html! {
-                            { for self.data.iter().map(|w| w.as_catalogue_box()) }
-                        },
-                        FetchState::Failed(_, err) => html! {&err},
+                    </div>
+                </div>
+            </div>
+        }
+    }
+}
+
+impl CatalogueComponent {
+    /// The search, filters and page as a query string, e.g. `?q=foo&page=3`. Defaults are left
+    /// out, so the first page of an unfiltered catalogue has an empty query string.
+    fn query_string(&self) -> String {
+        let mut query = form_urlencoded::Serializer::new(String::new());
+        if !self.search_term.is_empty() {
+            query.append_pair(SEARCH_PARAM, &self.search_term);
+        }
+        if let Some(work_type) = &self.work_type {
+            query.append_pair(WORK_TYPE_PARAM, &work_type.to_string());
+        }
+        if let Some(year) = self.publication_year {
+            query.append_pair(YEAR_PARAM, &year.to_string());
+        }
+        let page = self.offset / self.page_size + 1;
+        if page > 1 {
+            query.append_pair(PAGE_PARAM, &page.to_string());
+        }
+        match query.finish() {
+            query if query.is_empty() => query,
+            query => format!("?{}", query),
+        }
+    }
+
+    fn filters(&self) -> (String, Option<WorkType>, Option<i32>) {
+        (
+            self.search_term.clone(),
+            self.work_type.clone(),
+            self.publication_year,
+        )
+    }
+
+    /// Set the search, filters and page from a query string. Parameters that are missing or
+    /// cannot be read fall back to their defaults.
+    fn load_query(&mut self, query: &str) {
+        self.search_term = Default::default();
+        self.work_type = None;
+        self.publication_year = None;
+        self.offset = 0;
+        self.limit = self.page_size;
+        for (key, value) in form_urlencoded::parse(query.trim_start_matches('?').as_bytes()) {
+            match key.as_ref() {
+                SEARCH_PARAM => self.search_term = value.into_owned(),
+                WORK_TYPE_PARAM => self.work_type = WorkType::from_str(&value).ok(),
+                YEAR_PARAM => self.publication_year = value.parse().ok(),
+                PAGE_PARAM => {
+                    if let Ok(page) = value.parse::<i32>() {
+                        self.offset = (page.max(1) - 1) * self.page_size;
                     }
                 }
-            </div>
+                _ => {}
+            }
+        }
+    }
+
+    fn render_facets(&self) -> Html {
+        html! {
+            <>
+                <nav class="panel">
+                    <p class="panel-heading">{ "Work Type" }</p>
+                    { for self.facets.work_types.iter().map(|f| self.render_work_type_facet(f)) }
+                </nav>
+                <nav class="panel">
+                    <p class="panel-heading">{ "Publication Year" }</p>
+                    {
+                        if self.publication_year.is_some() {
+                            html! {
+                                <a
+                                    class="panel-block"
+                                    onclick=self.link.callback(|_| Msg::SetYearFilter(None))
+                                >
+                                    { "All years" }
+                                </a>
+                            }
+                        } else {
+                            html! {}
+                        }
+                    }
+                    { for self.facets.publication_years.iter().map(|f| self.render_year_facet(f)) }
+                </nav>
+            </>
+        }
+    }
+
+    fn render_work_type_facet(&self, facet: &WorkTypeFacet) -> Html {
+        let work_type = facet.work_type.clone();
+        let class = match self.work_type.as_ref() == Some(&facet.work_type) {
+            true => "panel-block is-active",
+            false => "panel-block",
+        };
+        html! {
+            <a
+                class=class
+                onclick=self.link.callback(move |_| Msg::ToggleWorkTypeFacet(work_type.clone()))
+            >
+                <span class="is-flex-grow-1">{ &facet.work_type }</span>
+                <span class="tag is-rounded">{ facet.count }</span>
+            </a>
+        }
+    }
+
+    fn render_year_facet(&self, facet: &PublicationYearFacet) -> Html {
+        let year = facet.year;
+        let class = match self.publication_year == Some(year) {
+            true => "panel-block is-active",
+            false => "panel-block",
+        };
+        html! {
+            <a
+                class=class
+                onclick=self.link.callback(move |_| Msg::SetYearFilter(Some(year)))
+            >
+                <span class="is-flex-grow-1">{ year }</span>
+                <span class="tag is-rounded">{ facet.count }</span>
+            </a>
         }
     }
 }
@@ -13,9 +13,11 @@ use crate::agent::notification_bus::NotificationBus;
 use crate::agent::notification_bus::NotificationDispatcher;
 use crate::agent::notification_bus::NotificationStatus;
 use crate::agent::notification_bus::Request;
+use crate::component::more_results_notice;
 use crate::component::utils::FormBooleanSelect;
 use crate::component::utils::FormContributionTypeSelect;
 use crate::component::utils::FormTextInput;
+use crate::component::SEARCH_RESULT_LIMIT;
 use crate::models::contribution::contribution_types_query::FetchActionContributionTypes;
 use crate::models::contribution::contribution_types_query::FetchContributionTypes;
 use crate::models::contribution::create_contribution_mutation::CreateContributionRequest;
@@ -36,11 +38,6 @@ use crate::models::contributor::contributors_query::FetchActionContributors;
 use crate::models::contributor::contributors_query::FetchContributors;
 use crate::models::contributor::contributors_query::Variables;
 use crate::models::contributor::Contributor;
-use crate::string::CANCEL_BUTTON;
-use crate::string::EMPTY_CONTRIBUTIONS;
-use crate::string::NO;
-use crate::string::REMOVE_BUTTON;
-use crate::string::YES;
 
 pub struct ContributionsFormComponent {
     props: Props,
@@ -187,10 +184,8 @@ impl Component for ContributionsFormComponent {
                         }
                         None => {
                             self.link.send_message(Msg::ToggleAddFormDisplay(false));
-                            self.notification_bus.send(Request::NotificationBusMsg((
-                                "Failed to save".to_string(),
-                                NotificationStatus::Danger,
-                            )));
+                            self.notification_bus
+                                .send(Request::NotificationBusMsg(body.failure_notification()));
                             false
                         }
                     },
@@ -249,10 +244,8 @@ impl Component for ContributionsFormComponent {
                             true
                         }
                         None => {
-                            self.notification_bus.send(Request::NotificationBusMsg((
-                                "Failed to save".to_string(),
-                                NotificationStatus::Danger,
-                            )));
+                            self.notification_bus
+                                .send(Request::NotificationBusMsg(body.failure_notification()));
                             false
                         }
                     },
@@ -301,7 +294,7 @@ impl Component for ContributionsFormComponent {
                 let body = ContributorsRequestBody {
                     variables: Variables {
                         filter: Some(value),
-                        limit: Some(9999),
+                        limit: Some(SEARCH_RESULT_LIMIT),
                         ..Default::default()
                     },
                     ..Default::default()
@@ -392,6 +385,7 @@ impl Component for ContributionsFormComponent {
                                         )
                                     })
                                 }
+                                { self.more_results() }
                             </div>
                         </div>
                     </div>
@@ -480,7 +474,7 @@ impl Component for ContributionsFormComponent {
                                 class="button"
                                 onclick=&close_modal
                             >
-                                { CANCEL_BUTTON }
+                                { t!("cancel_button") }
                             </button>
                         </footer>
                     </div>
@@ -491,7 +485,7 @@ impl Component for ContributionsFormComponent {
                     } else {
                         html! {
                             <div class="notification is-warning is-light">
-                                { EMPTY_CONTRIBUTIONS }
+                                { t!("empty_contributions") }
                             </div>
                         }
                     }
@@ -516,6 +510,15 @@ impl ContributionsFormComponent {
         }
     }
 
+    fn more_results(&self) -> Html {
+        match self.fetch_contributors.as_ref().state() {
+            FetchState::Fetched(body) => {
+                more_results_notice(body.data.contributors.len(), body.data.contributor_count)
+            }
+            _ => html! {},
+        }
+    }
+
     fn render_contribution(&self, c: &Contribution) -> Html {
         // there's probably a better way to do this. We basically need to copy 3 instances
         // of contributor_id and take ownership of them so they can be passed on to
@@ -557,8 +560,8 @@ impl ContributionsFormComponent {
                         <div class="control is-expanded">
                             {
                                 match c.main_contribution {
-                                    true => { YES },
-                                    false => { NO }
+                                    true => { t!("yes") },
+                                    false => { t!("no") }
                                 }
                             }
                         </div>
@@ -571,7 +574,7 @@ impl ContributionsFormComponent {
                                 class="button is-danger"
                                 onclick=self.link.callback(move |_| Msg::DeleteContribution(contributor_id.clone(), contribution_type.clone()))
                             >
-                                { REMOVE_BUTTON }
+                                { t!("remove_button") }
                             </a>
                         </div>
                     </div>
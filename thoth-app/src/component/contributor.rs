@@ -1,3 +1,5 @@
+use thoth_api::identifier::validate_optional;
+use thoth_api::identifier::Orcid;
 use yew::html;
 use yew::prelude::*;
 use yew::ComponentLink;
@@ -41,13 +43,13 @@ use crate::models::contributor::update_contributor_mutation::Variables as Update
 use crate::models::contributor::Contributor;
 use crate::route::AdminRoute;
 use crate::route::AppRoute;
-use crate::string::SAVE_BUTTON;
 
 pub struct ContributorComponent {
     contributor: Contributor,
     fetch_contributor: FetchContributor,
     push_contributor: PushUpdateContributor,
     delete_contributor: PushDeleteContributor,
+    orcid_error: Option<String>,
     link: ComponentLink<Self>,
     router: RouteAgentDispatcher<()>,
     notification_bus: NotificationDispatcher,
@@ -67,6 +69,7 @@ pub enum Msg {
     ChangeLastName(String),
     ChangeFullName(String),
     ChangeOrcid(String),
+    ValidateOrcid,
     ChangeWebsite(String),
     ChangeRoute(AppRoute),
 }
@@ -108,6 +111,7 @@ impl Component for ContributorComponent {
             fetch_contributor,
             push_contributor,
             delete_contributor,
+            orcid_error: None,
             link,
             router,
             notification_bus,
@@ -166,10 +170,8 @@ impl Component for ContributorComponent {
                             true
                         }
                         None => {
-                            self.notification_bus.send(Request::NotificationBusMsg((
-                                "Failed to save".to_string(),
-                                NotificationStatus::Danger,
-                            )));
+                            self.notification_bus
+                                .send(Request::NotificationBusMsg(body.failure_notification()));
                             false
                         }
                     },
@@ -183,6 +185,10 @@ impl Component for ContributorComponent {
                 }
             }
             Msg::UpdateContributor => {
+                if let Err(e) = validate_optional::<Orcid>(&self.contributor.orcid) {
+                    self.orcid_error = Some(e.to_string());
+                    return true;
+                }
                 let body = UpdateContributorRequestBody {
                     variables: UpdateVariables {
                         contributor_id: self.contributor.contributor_id.clone(),
@@ -219,10 +225,8 @@ impl Component for ContributorComponent {
                             true
                         }
                         None => {
-                            self.notification_bus.send(Request::NotificationBusMsg((
-                                "Failed to save".to_string(),
-                                NotificationStatus::Danger,
-                            )));
+                            self.notification_bus
+                                .send(Request::NotificationBusMsg(body.failure_notification()));
                             false
                         }
                     },
@@ -267,6 +271,11 @@ impl Component for ContributorComponent {
                 .contributor
                 .full_name
                 .neq_assign(full_name.trim().to_owned()),
+            Msg::ValidateOrcid => self.orcid_error.neq_assign(
+                validate_optional::<Orcid>(&self.contributor.orcid)
+                    .err()
+                    .map(|e| e.to_string()),
+            ),
             Msg::ChangeOrcid(value) => {
                 let orcid = match value.trim().is_empty() {
                     true => None,
@@ -366,6 +375,8 @@ impl Component for ContributorComponent {
                                 label = "ORCID (Full URL)"
                                 value=&self.contributor.orcid
                                 oninput=self.link.callback(|e: InputData| Msg::ChangeOrcid(e.value))
+                                onblur=self.link.callback(|_| Msg::ValidateOrcid)
+                                error=&self.orcid_error
                             />
                             <FormUrlInput
                                 label = "Website"
@@ -376,7 +387,7 @@ impl Component for ContributorComponent {
                             <div class="field">
                                 <div class="control">
                                     <button class="button is-success" type="submit">
-                                        { SAVE_BUTTON }
+                                        { t!("save_button") }
                                     </button>
                                 </div>
                             </div>
@@ -15,7 +15,7 @@ pagination_component! {
     FetchContributors,
     ContributorsRequestBody,
     Variables,
-    SEARCH_CONTRIBUTORS,
-    PAGINATION_COUNT_CONTRIBUTORS,
+    "search_contributors",
+    "pagination_count_contributors",
     vec!["ID".to_string(), "FullName".to_string(), "ORCID".to_string()]
 }
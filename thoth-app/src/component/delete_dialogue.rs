@@ -1,5 +1,3 @@
-use crate::string::CANCEL_BUTTON;
-use crate::string::DELETE_BUTTON;
 use yew::html;
 use yew::prelude::*;
 
@@ -53,7 +51,7 @@ impl Component for ConfirmDeleteComponent {
         html! {
             <>
                 <button class="button is-danger" onclick=open_modal>
-                    { DELETE_BUTTON }
+                    { t!("delete_button") }
                 </button>
                 <div class=self.confirm_delete_status()>
                     <div class="modal-background" onclick=&close_modal></div>
@@ -78,13 +76,13 @@ impl Component for ConfirmDeleteComponent {
                                 class="button is-success"
                                 onclick=&self.props.onclick
                             >
-                                { DELETE_BUTTON }
+                                { t!("delete_button") }
                             </button>
                             <button
                                 class="button"
                                 onclick=&close_modal
                             >
-                                { CANCEL_BUTTON }
+                                { t!("cancel_button") }
                             </button>
                         </footer>
                     </div>
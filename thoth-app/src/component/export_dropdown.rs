@@ -0,0 +1,170 @@
+use std::collections::HashMap;
+use yew::html;
+use yew::prelude::*;
+use yew::ComponentLink;
+use yewtil::fetch::Fetch;
+use yewtil::fetch::FetchAction;
+use yewtil::fetch::FetchState;
+use yewtil::future::LinkFuture;
+use yewtil::NeqAssign;
+
+use crate::models::export::formats_query::FetchActionFormats;
+use crate::models::export::formats_query::FetchFormats;
+use crate::models::export::validation_query::FetchActionValidation;
+use crate::models::export::validation_query::FetchValidation;
+use crate::models::export::validation_query::ValidationRequest;
+use crate::models::export::ExportFormat;
+
+pub struct ExportDropdownComponent {
+    props: Props,
+    formats: Vec<ExportFormat>,
+    fetch_formats: FetchFormats,
+    // dry-run results of the formats that can be validated, keyed on format ID
+    validations: HashMap<String, FetchValidation>,
+    link: ComponentLink<Self>,
+}
+
+pub enum Msg {
+    SetFormatsFetchState(FetchActionFormats),
+    GetFormats,
+    SetValidationFetchState(String, FetchActionValidation),
+    ValidateFormats,
+}
+
+#[derive(Clone, Properties, PartialEq)]
+pub struct Props {
+    pub work_id: String,
+}
+
+impl Component for ExportDropdownComponent {
+    type Message = Msg;
+    type Properties = Props;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        link.send_message(Msg::GetFormats);
+
+        ExportDropdownComponent {
+            props,
+            formats: vec![],
+            fetch_formats: Default::default(),
+            validations: HashMap::new(),
+            link,
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::SetFormatsFetchState(fetch_state) => {
+                self.fetch_formats.apply(fetch_state);
+                self.formats = match self.fetch_formats.as_ref().state() {
+                    FetchState::Fetched(body) => body.clone(),
+                    _ => vec![],
+                };
+                if !self.formats.is_empty() {
+                    self.link.send_message(Msg::ValidateFormats);
+                }
+                true
+            }
+            Msg::GetFormats => {
+                self.link
+                    .send_future(self.fetch_formats.fetch(Msg::SetFormatsFetchState));
+                self.link
+                    .send_message(Msg::SetFormatsFetchState(FetchAction::Fetching));
+                false
+            }
+            Msg::SetValidationFetchState(format_id, fetch_state) => {
+                if let Some(validation) = self.validations.get_mut(&format_id) {
+                    validation.apply(fetch_state);
+                }
+                true
+            }
+            Msg::ValidateFormats => {
+                self.validations.clear();
+                for format in self.formats.iter().filter(|f| f.validates) {
+                    let request = ValidationRequest {
+                        format: format.clone(),
+                        work_id: self.props.work_id.clone(),
+                    };
+                    let validation: FetchValidation = Fetch::new(request);
+                    let format_id = format.id.clone();
+                    self.link.send_future(validation.fetch(move |fetch_state| {
+                        Msg::SetValidationFetchState(format_id.clone(), fetch_state)
+                    }));
+                    self.validations.insert(format.id.clone(), validation);
+                }
+                false
+            }
+        }
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        let updated = self.props.neq_assign(props);
+        if updated {
+            self.link.send_message(Msg::ValidateFormats);
+        }
+        updated
+    }
+
+    fn view(&self) -> Html {
+        html! {
+            <div class="dropdown is-hoverable is-right">
+                <div class="dropdown-trigger">
+                    <button class="button" aria-haspopup="true" aria-controls="export-menu">
+                        <span>{ "Export" }</span>
+                        <span class="icon is-small">
+                            <i class="fas fa-angle-down" aria-hidden="true"></i>
+                        </span>
+                    </button>
+                </div>
+                <div class="dropdown-menu" id="export-menu" role="menu">
+                    <div class="dropdown-content">
+                        {
+                            for self.formats
+                                .iter()
+                                .filter(|f| !f.per_publisher)
+                                .map(|f| self.render_format(f))
+                        }
+                    </div>
+                </div>
+            </div>
+        }
+    }
+}
+
+impl ExportDropdownComponent {
+    /// Reasons the work cannot be exported in this format, if the dry run has found any
+    fn export_errors(&self, format: &ExportFormat) -> Vec<String> {
+        match self
+            .validations
+            .get(&format.id)
+            .map(|validation| validation.as_ref().state())
+        {
+            Some(FetchState::Fetched(report)) if !report.valid => report.errors(),
+            _ => vec![],
+        }
+    }
+
+    fn render_format(&self, format: &ExportFormat) -> Html {
+        let errors = self.export_errors(format);
+        if errors.is_empty() {
+            html! {
+                <a
+                    class="dropdown-item"
+                    href=format.url(&self.props.work_id)
+                    target="_blank"
+                >
+                    { &format.name }
+                </a>
+            }
+        } else {
+            html! {
+                <span
+                    class="dropdown-item has-text-grey-light"
+                    title=errors.join("\n")
+                >
+                    { &format.name }
+                </span>
+            }
+        }
+    }
+}
@@ -41,7 +41,6 @@ use crate::models::funder::update_funder_mutation::Variables as UpdateVariables;
 use crate::models::funder::Funder;
 use crate::route::AdminRoute;
 use crate::route::AppRoute;
-use crate::string::SAVE_BUTTON;
 
 pub struct FunderComponent {
     funder: Funder,
@@ -163,10 +162,8 @@ impl Component for FunderComponent {
                             true
                         }
                         None => {
-                            self.notification_bus.send(Request::NotificationBusMsg((
-                                "Failed to save".to_string(),
-                                NotificationStatus::Danger,
-                            )));
+                            self.notification_bus
+                                .send(Request::NotificationBusMsg(body.failure_notification()));
                             false
                         }
                     },
@@ -213,10 +210,8 @@ impl Component for FunderComponent {
                             true
                         }
                         None => {
-                            self.notification_bus.send(Request::NotificationBusMsg((
-                                "Failed to save".to_string(),
-                                NotificationStatus::Danger,
-                            )));
+                            self.notification_bus
+                                .send(Request::NotificationBusMsg(body.failure_notification()));
                             false
                         }
                     },
@@ -335,7 +330,7 @@ impl Component for FunderComponent {
                             <div class="field">
                                 <div class="control">
                                     <button class="button is-success" type="submit">
-                                        { SAVE_BUTTON }
+                                        { t!("save_button") }
                                     </button>
                                 </div>
                             </div>
@@ -15,7 +15,7 @@ pagination_component! {
     FetchFunders,
     FundersRequestBody,
     Variables,
-    SEARCH_FUNDERS,
-    PAGINATION_COUNT_FUNDERS,
+    "search_funders",
+    "pagination_count_funders",
     vec!["ID".to_string(), "Funder".to_string(), "DOI".to_string()]
 }
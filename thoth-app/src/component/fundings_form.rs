@@ -11,7 +11,9 @@ use crate::agent::notification_bus::NotificationBus;
 use crate::agent::notification_bus::NotificationDispatcher;
 use crate::agent::notification_bus::NotificationStatus;
 use crate::agent::notification_bus::Request;
+use crate::component::more_results_notice;
 use crate::component::utils::FormTextInput;
+use crate::component::SEARCH_RESULT_LIMIT;
 use crate::models::funder::funders_query::FetchActionFunders;
 use crate::models::funder::funders_query::FetchFunders;
 use crate::models::funder::funders_query::FundersRequest;
@@ -29,9 +31,6 @@ use crate::models::funding::delete_funding_mutation::PushActionDeleteFunding;
 use crate::models::funding::delete_funding_mutation::PushDeleteFunding;
 use crate::models::funding::delete_funding_mutation::Variables as DeleteVariables;
 use crate::models::funding::Funding;
-use crate::string::CANCEL_BUTTON;
-use crate::string::EMPTY_FUNDINGS;
-use crate::string::REMOVE_BUTTON;
 
 pub struct FundingsFormComponent {
     props: Props,
@@ -148,10 +147,8 @@ impl Component for FundingsFormComponent {
                         }
                         None => {
                             self.link.send_message(Msg::ToggleAddFormDisplay(false));
-                            self.notification_bus.send(Request::NotificationBusMsg((
-                                "Failed to save".to_string(),
-                                NotificationStatus::Danger,
-                            )));
+                            self.notification_bus
+                                .send(Request::NotificationBusMsg(body.failure_notification()));
                             false
                         }
                     },
@@ -205,10 +202,8 @@ impl Component for FundingsFormComponent {
                             true
                         }
                         None => {
-                            self.notification_bus.send(Request::NotificationBusMsg((
-                                "Failed to save".to_string(),
-                                NotificationStatus::Danger,
-                            )));
+                            self.notification_bus
+                                .send(Request::NotificationBusMsg(body.failure_notification()));
                             false
                         }
                     },
@@ -248,7 +243,7 @@ impl Component for FundingsFormComponent {
                 let body = FundersRequestBody {
                     variables: Variables {
                         filter: Some(value),
-                        limit: Some(9999),
+                        limit: Some(SEARCH_RESULT_LIMIT),
                         ..Default::default()
                     },
                     ..Default::default()
@@ -345,6 +340,7 @@ impl Component for FundingsFormComponent {
                                         )
                                     })
                                 }
+                                { self.more_results() }
                             </div>
                         </div>
                     </div>
@@ -414,7 +410,7 @@ impl Component for FundingsFormComponent {
                                 class="button"
                                 onclick=&close_modal
                             >
-                                { CANCEL_BUTTON }
+                                { t!("cancel_button") }
                             </button>
                         </footer>
                     </div>
@@ -425,7 +421,7 @@ impl Component for FundingsFormComponent {
                     } else {
                         html! {
                             <div class="notification is-info is-light">
-                                { EMPTY_FUNDINGS }
+                                { t!("empty_fundings") }
                             </div>
                         }
                     }
@@ -450,6 +446,15 @@ impl FundingsFormComponent {
         }
     }
 
+    fn more_results(&self) -> Html {
+        match self.fetch_funders.as_ref().state() {
+            FetchState::Fetched(body) => {
+                more_results_notice(body.data.funders.len(), body.data.funder_count)
+            }
+            _ => html! {},
+        }
+    }
+
     fn render_funding(&self, f: &Funding) -> Html {
         let funding_id = f.funding_id.clone();
         html! {
@@ -501,7 +506,7 @@ impl FundingsFormComponent {
                                 class="button is-danger"
                                 onclick=self.link.callback(move |_| Msg::DeleteFunding(funding_id.clone()))
                             >
-                                { REMOVE_BUTTON }
+                                { t!("remove_button") }
                             </a>
                         </div>
                     </div>
@@ -4,7 +4,7 @@ use yew::Properties;
 use yewtil::Pure;
 use yewtil::PureComponent;
 
-use crate::THOTH_API;
+use crate::api_url;
 
 pub type HeroComponent = Pure<PureHero>;
 
@@ -21,7 +21,7 @@ impl PureComponent for PureHero {
                     </div>
 
                     <nav class="columns home-icons">
-                        <a class="home-icons-item column has-text-centered" href={format!("{}/graphiql", THOTH_API)}>
+                        <a class="home-icons-item column has-text-centered" href={format!("{}/graphiql", api_url())}>
                             <p class="title is-4">
                                 <strong>{ "Open API" }</strong>
                             </p>
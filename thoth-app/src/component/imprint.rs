@@ -44,7 +44,6 @@ use crate::models::publisher::publishers_query::Variables as PublishersVariables
 use crate::models::publisher::Publisher;
 use crate::route::AdminRoute;
 use crate::route::AppRoute;
-use crate::string::SAVE_BUTTON;
 
 pub struct ImprintComponent {
     imprint: Imprint,
@@ -202,10 +201,8 @@ impl Component for ImprintComponent {
                             true
                         }
                         None => {
-                            self.notification_bus.send(Request::NotificationBusMsg((
-                                "Failed to save".to_string(),
-                                NotificationStatus::Danger,
-                            )));
+                            self.notification_bus
+                                .send(Request::NotificationBusMsg(body.failure_notification()));
                             false
                         }
                     },
@@ -253,10 +250,8 @@ impl Component for ImprintComponent {
                             true
                         }
                         None => {
-                            self.notification_bus.send(Request::NotificationBusMsg((
-                                "Failed to save".to_string(),
-                                NotificationStatus::Danger,
-                            )));
+                            self.notification_bus
+                                .send(Request::NotificationBusMsg(body.failure_notification()));
                             false
                         }
                     },
@@ -383,7 +378,7 @@ impl Component for ImprintComponent {
                             <div class="field">
                                 <div class="control">
                                     <button class="button is-success" type="submit">
-                                        { SAVE_BUTTON }
+                                        { t!("save_button") }
                                     </button>
                                 </div>
                             </div>
@@ -15,7 +15,7 @@ pagination_component! {
     FetchImprints,
     ImprintsRequestBody,
     Variables,
-    SEARCH_IMPRINTS,
-    PAGINATION_COUNT_IMPRINTS,
+    "search_imprints",
+    "pagination_count_imprints",
     vec!["ID".to_string(), "Imprint".to_string(), "Publisher".to_string(), "ImprintURL".to_string()]
 }
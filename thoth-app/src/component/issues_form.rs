@@ -12,7 +12,9 @@ use crate::agent::notification_bus::NotificationBus;
 use crate::agent::notification_bus::NotificationDispatcher;
 use crate::agent::notification_bus::NotificationStatus;
 use crate::agent::notification_bus::Request;
+use crate::component::more_results_notice;
 use crate::component::utils::FormNumberInput;
+use crate::component::SEARCH_RESULT_LIMIT;
 use crate::models::issue::create_issue_mutation::CreateIssueRequest;
 use crate::models::issue::create_issue_mutation::CreateIssueRequestBody;
 use crate::models::issue::create_issue_mutation::PushActionCreateIssue;
@@ -30,9 +32,6 @@ use crate::models::series::serieses_query::SeriesesRequest;
 use crate::models::series::serieses_query::SeriesesRequestBody;
 use crate::models::series::serieses_query::Variables;
 use crate::models::series::Series;
-use crate::string::CANCEL_BUTTON;
-use crate::string::EMPTY_ISSUES;
-use crate::string::REMOVE_BUTTON;
 
 pub struct IssuesFormComponent {
     props: Props,
@@ -156,10 +155,8 @@ impl Component for IssuesFormComponent {
                         }
                         None => {
                             self.link.send_message(Msg::ToggleAddFormDisplay(false));
-                            self.notification_bus.send(Request::NotificationBusMsg((
-                                "Failed to save".to_string(),
-                                NotificationStatus::Danger,
-                            )));
+                            self.notification_bus
+                                .send(Request::NotificationBusMsg(body.failure_notification()));
                             false
                         }
                     },
@@ -209,10 +206,8 @@ impl Component for IssuesFormComponent {
                             true
                         }
                         None => {
-                            self.notification_bus.send(Request::NotificationBusMsg((
-                                "Failed to save".to_string(),
-                                NotificationStatus::Danger,
-                            )));
+                            self.notification_bus
+                                .send(Request::NotificationBusMsg(body.failure_notification()));
                             false
                         }
                     },
@@ -255,7 +250,7 @@ impl Component for IssuesFormComponent {
                 let body = SeriesesRequestBody {
                     variables: Variables {
                         filter: Some(value),
-                        limit: Some(9999),
+                        limit: Some(SEARCH_RESULT_LIMIT),
                         publishers: self.props.current_user.resource_access.restricted_to(),
                         ..Default::default()
                     },
@@ -339,6 +334,7 @@ impl Component for IssuesFormComponent {
                                         }
                                     })
                                 }
+                                { self.more_results() }
                             </div>
                         </div>
                     </div>
@@ -387,7 +383,7 @@ impl Component for IssuesFormComponent {
                                 class="button"
                                 onclick=&close_modal
                             >
-                                { CANCEL_BUTTON }
+                                { t!("cancel_button") }
                             </button>
                         </footer>
                     </div>
@@ -398,7 +394,7 @@ impl Component for IssuesFormComponent {
                     } else {
                         html! {
                             <div class="notification is-info is-light">
-                                { EMPTY_ISSUES }
+                                { t!("empty_issues") }
                             </div>
                         }
                     }
@@ -423,6 +419,15 @@ impl IssuesFormComponent {
         }
     }
 
+    fn more_results(&self) -> Html {
+        match self.fetch_serieses.as_ref().state() {
+            FetchState::Fetched(body) => {
+                more_results_notice(body.data.serieses.len(), body.data.series_count)
+            }
+            _ => html! {},
+        }
+    }
+
     fn render_issue(&self, i: &Issue) -> Html {
         // there's probably a better way to do this. We basically need to copy 3 instances
         // of contributor_id and take ownership of them so they can be passed on to
@@ -476,7 +481,7 @@ impl IssuesFormComponent {
                                 class="button is-danger"
                                 onclick=self.link.callback(move |_| Msg::DeleteIssue(series_id.clone()))
                             >
-                                { REMOVE_BUTTON }
+                                { t!("remove_button") }
                             </a>
                         </div>
                     </div>
@@ -34,11 +34,6 @@ use crate::models::language::language_relations_query::FetchLanguageRelations;
 use crate::models::language::Language;
 use crate::models::language::LanguageCodeValues;
 use crate::models::language::LanguageRelationValues;
-use crate::string::CANCEL_BUTTON;
-use crate::string::EMPTY_LANGUAGES;
-use crate::string::NO;
-use crate::string::REMOVE_BUTTON;
-use crate::string::YES;
 
 pub struct LanguagesFormComponent {
     props: Props,
@@ -176,10 +171,8 @@ impl Component for LanguagesFormComponent {
                         }
                         None => {
                             self.link.send_message(Msg::ToggleAddFormDisplay(false));
-                            self.notification_bus.send(Request::NotificationBusMsg((
-                                "Failed to save".to_string(),
-                                NotificationStatus::Danger,
-                            )));
+                            self.notification_bus
+                                .send(Request::NotificationBusMsg(body.failure_notification()));
                             false
                         }
                     },
@@ -230,10 +223,8 @@ impl Component for LanguagesFormComponent {
                             true
                         }
                         None => {
-                            self.notification_bus.send(Request::NotificationBusMsg((
-                                "Failed to save".to_string(),
-                                NotificationStatus::Danger,
-                            )));
+                            self.notification_bus
+                                .send(Request::NotificationBusMsg(body.failure_notification()));
                             false
                         }
                     },
@@ -369,7 +360,7 @@ impl Component for LanguagesFormComponent {
                                 class="button"
                                 onclick=&close_modal
                             >
-                                { CANCEL_BUTTON }
+                                { t!("cancel_button") }
                             </button>
                         </footer>
                     </div>
@@ -380,7 +371,7 @@ impl Component for LanguagesFormComponent {
                     } else {
                         html! {
                             <div class="notification is-warning is-light">
-                                { EMPTY_LANGUAGES }
+                                { t!("empty_languages") }
                             </div>
                         }
                     }
@@ -428,8 +419,8 @@ impl LanguagesFormComponent {
                         <div class="control is-expanded">
                             {
                                 match &l.main_language {
-                                    true => { YES },
-                                    false => { NO }
+                                    true => { t!("yes") },
+                                    false => { t!("no") }
                                 }
                             }
                         </div>
@@ -442,7 +433,7 @@ impl LanguagesFormComponent {
                                 class="button is-danger"
                                 onclick=self.link.callback(move |_| Msg::DeleteLanguage(language_id.clone()))
                             >
-                                { REMOVE_BUTTON }
+                                { t!("remove_button") }
                             </a>
                         </div>
                     </div>
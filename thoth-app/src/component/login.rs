@@ -17,11 +17,6 @@ use crate::route::AdminRoute;
 use crate::route::AppRoute;
 use crate::service::account::AccountError;
 use crate::service::account::AccountService;
-use crate::string::AUTHENTICATION_ERROR;
-use crate::string::INPUT_EMAIL;
-use crate::string::INPUT_PASSWORD;
-use crate::string::RESPONSE_ERROR;
-use crate::string::TEXT_LOGIN;
 
 pub struct LoginComponent {
     request: LoginCredentials,
@@ -108,13 +103,13 @@ impl Component for LoginComponent {
                 match err {
                     AccountError::AuthenticationError => {
                         self.notification_bus.send(Request::NotificationBusMsg((
-                            AUTHENTICATION_ERROR.into(),
+                            t!("authentication_error").into(),
                             NotificationStatus::Warning,
                         )));
                     }
                     AccountError::ResponseError => {
                         self.notification_bus.send(Request::NotificationBusMsg((
-                            RESPONSE_ERROR.into(),
+                            t!("response_error").into(),
                             NotificationStatus::Danger,
                         )));
                     }
@@ -139,7 +134,7 @@ impl Component for LoginComponent {
                                     type="email"
                                     value=&self.request.email
                                     oninput=self.link.callback(|e: InputData| Msg::ChangeEmail(e.value))
-                                    placeholder=INPUT_EMAIL
+                                    placeholder=t!("input_email")
                                 />
                                 <span class="icon is-small is-left">
                                     <i class="fas fa-envelope"></i>
@@ -153,7 +148,7 @@ impl Component for LoginComponent {
                                     type="password"
                                     value=&self.request.password
                                     oninput=self.link.callback(|e: InputData| Msg::ChangePassword(e.value))
-                                    placeholder=INPUT_PASSWORD
+                                    placeholder=t!("input_password")
                                 />
                                 <span class="icon is-small is-left">
                                     <i class="fas fa-lock"></i>
@@ -166,7 +161,7 @@ impl Component for LoginComponent {
                                     class="button is-success"
                                     onclick=self.link.callback(|_| Msg::Request)
                                 >
-                                    { TEXT_LOGIN }
+                                    { t!("text_login") }
                                 </button>
                             </p>
                         </div>
@@ -70,6 +70,14 @@ impl Component for MenuComponent {
                             {"Works"}
                         </  RouterAnchor<AppRoute>>
                     </li>
+                    <li>
+                        <RouterAnchor<AppRoute>
+                            classes={self.is_active(AdminRoute::ImportWorks)}
+                            route=AppRoute::Admin(AdminRoute::ImportWorks)
+                        >
+                            {"Import Works"}
+                        </  RouterAnchor<AppRoute>>
+                    </li>
                     <li>
                         <RouterAnchor<AppRoute>
                             classes={self.is_active(AdminRoute::Publications)}
@@ -1,12 +1,9 @@
 #[macro_export]
 macro_rules! pagination_helpers {
-    ($component:ident, $pagination_text:ident, $search_text:ident) => {
-        use crate::string::$pagination_text;
-        use crate::string::$search_text;
-
+    ($component:ident, $pagination_text:expr, $search_text:expr) => {
         impl $component {
             fn search_text(&self) -> String {
-                format!("{}", $search_text)
+                t!($search_text).to_string()
             }
 
             fn display_count(&self) -> String {
@@ -18,7 +15,7 @@ macro_rules! pagination_helpers {
                     true => self.result_count,
                     false => self.limit + self.offset,
                 };
-                format!("{} {}-{} of {}", $pagination_text, offset_display, limit_display, self.result_count)
+                format!("{} {}-{} of {}", t!($pagination_text), offset_display, limit_display, self.result_count)
             }
 
             fn is_previous_disabled(&self) -> bool {
@@ -36,11 +33,11 @@ macro_rules! pagination_helpers {
                         <a class="pagination-previous"
                             onclick=self.link.callback(|_| Msg::PreviousPage)
                             disabled=self.is_previous_disabled()
-                        >{ crate::string::PREVIOUS_PAGE_BUTTON }</a>
+                        >{ t!("previous_page_button") }</a>
                         <a class="pagination-next"
                             onclick=self.link.callback(|_| Msg::NextPage)
                             disabled=self.is_next_disabled()
-                        >{ crate::string::NEXT_PAGE_BUTTON }</a>
+                        >{ t!("next_page_button") }</a>
                         <div class="pagination-list">
                             <div class="field" style="width: 80%">
                                 <p class="control is-expanded has-icons-left">
@@ -76,8 +73,8 @@ macro_rules! pagination_component {
         $fetch_data:ty,
         $request_body:ident,
         $request_variables:ident,
-        $search_text:ident,
-        $pagination_text:ident,
+        $search_text:expr,
+        $pagination_text:expr,
         $table_headers:expr
     ) => {
         use thoth_api::account::model::AccountDetails;
@@ -97,6 +94,7 @@ macro_rules! pagination_component {
         use yewtil::fetch::FetchState;
         use yewtil::future::LinkFuture;
 
+        use crate::component::utils::FetchError;
         use crate::component::utils::Loader;
         use crate::component::utils::Reloader;
         use crate::route::AppRoute;
@@ -187,13 +185,17 @@ macro_rules! pagination_component {
                     }
                     Msg::PaginateData => {
                         let filter = self.search_term.clone();
+                        // some entities accept further, optional filters which are left unset here
+                        #[allow(clippy::needless_update)]
+                        let variables = $request_variables {
+                            limit: Some(self.limit),
+                            offset: Some(self.offset),
+                            filter: Some(filter),
+                            publishers: self.props.current_user.resource_access.restricted_to(),
+                            ..Default::default()
+                        };
                         let body = $request_body {
-                            variables: $request_variables {
-                                limit: Some(self.limit),
-                                offset: Some(self.offset),
-                                filter: Some(filter),
-                                publishers: self.props.current_user.resource_access.restricted_to(),
-                            },
+                            variables,
                             ..Default::default()
                         };
                         let request = $request { body };
@@ -270,6 +272,12 @@ macro_rules! pagination_component {
                                     html! {<Reloader onclick=self.link.callback(|_| Msg::GetData)/>}
                                 },
                                 FetchState::Fetching(_) => html! {<Loader/>},
+                                // a successful fetch with nothing in it is not a failure
+                                FetchState::Fetched(_body) if self.result_count == 0 => html! {
+                                    <div class="notification is-info is-light">
+                                        { t!("empty_results") }
+                                    </div>
+                                },
                                 FetchState::Fetched(_body) => html! {
                                     <table class="table is-fullwidth is-hoverable">
                                         <thead>
@@ -296,7 +304,12 @@ macro_rules! pagination_component {
                                         </tbody>
                                     </table>
                                 },
-                                FetchState::Failed(_, err) => html! {&err},
+                                FetchState::Failed(_, err) => html! {
+                                    <FetchError
+                                        error=err.to_string()
+                                        onretry=self.link.callback(|_| Msg::GetData)
+                                    />
+                                },
                             }
                         }
                     </>
@@ -306,6 +319,24 @@ macro_rules! pagination_component {
     };
 }
 
+/// Number of matches requested by the search dropdowns in the work forms.
+/// The API caps every list at 1000 items, so a search never asks for more
+/// than it can display and shows a notice when further matches exist.
+pub const SEARCH_RESULT_LIMIT: i32 = 50;
+
+/// Dropdown entry telling the user that more matches exist than are listed.
+pub fn more_results_notice(shown: usize, total: i32) -> yew::Html {
+    if total as usize > shown {
+        yew::html! {
+            <div class="dropdown-item has-text-grey-light">
+                { t!("more_results") }
+            </div>
+        }
+    } else {
+        yew::html! {}
+    }
+}
+
 pub mod admin;
 pub mod catalogue;
 pub mod contributions_form;
@@ -313,6 +344,7 @@ pub mod contributor;
 pub mod contributors;
 pub mod dashboard;
 pub mod delete_dialogue;
+pub mod export_dropdown;
 pub mod funder;
 pub mod funders;
 pub mod fundings_form;
@@ -331,6 +363,7 @@ pub mod new_publisher;
 pub mod new_series;
 pub mod new_work;
 pub mod notification;
+pub mod onix_preview;
 pub mod prices_form;
 pub mod publication;
 pub mod publications;
@@ -343,4 +376,6 @@ pub mod serieses;
 pub mod subjects_form;
 pub mod utils;
 pub mod work;
+pub mod work_history;
+pub mod work_import;
 pub mod works;
@@ -4,9 +4,13 @@ use yew::prelude::*;
 use yew::virtual_dom::VNode;
 use yew_router::prelude::*;
 
+use crate::api_url;
+use crate::i18n::locale;
+use crate::i18n::set_locale;
+use crate::i18n::Locale;
+use crate::i18n::LOCALES;
 use crate::route::AdminRoute;
 use crate::route::AppRoute;
-use crate::THOTH_API;
 
 pub struct NavbarComponent {
     props: Props,
@@ -15,6 +19,7 @@ pub struct NavbarComponent {
 
 pub enum Msg {
     Logout,
+    SetLocale(Locale),
 }
 
 #[derive(Properties, Clone)]
@@ -42,6 +47,12 @@ impl Component for NavbarComponent {
                 self.props.callback.emit(());
                 true
             }
+            Msg::SetLocale(new_locale) => {
+                set_locale(new_locale);
+                // reload so that every component is rendered again in the new locale
+                yew::utils::window().location().reload().ok();
+                false
+            }
         }
     }
 
@@ -86,7 +97,7 @@ impl Component for NavbarComponent {
                                     { "Timeline" }
                                 </a>
                                 <hr class="navbar-divider" />
-                                <a class="navbar-item" href={format!("{}/graphiql", THOTH_API)} title="GraphiQL">
+                                <a class="navbar-item" href={format!("{}/graphiql", api_url())} title="GraphiQL">
                                     { "GraphiQL" }
                                 </a>
                             </div>
@@ -102,6 +113,26 @@ impl Component for NavbarComponent {
                 </div>
 
                 <div class="navbar-end">
+                    <div class="navbar-item has-dropdown is-hoverable">
+                        <a class="navbar-link">
+                            { locale().name() }
+                        </a>
+                        <div class="navbar-dropdown is-right">
+                            {
+                                for LOCALES.iter().map(|l| {
+                                    let new_locale = *l;
+                                    html! {
+                                        <a
+                                            class="navbar-item"
+                                            onclick=self.link.callback(move |_| Msg::SetLocale(new_locale))
+                                        >
+                                            { l.name() }
+                                        </a>
+                                    }
+                                })
+                            }
+                        </div>
+                    </div>
                     <div class="navbar-item">
                         <div class="buttons">
                             <a class="button is-danger" href="https://github.com/thoth-pub/thoth/blob/master/CHANGELOG.md">
@@ -1,3 +1,5 @@
+use thoth_api::identifier::validate_optional;
+use thoth_api::identifier::Orcid;
 use yew::html;
 use yew::prelude::*;
 use yew::ComponentLink;
@@ -24,11 +26,11 @@ use crate::models::contributor::create_contributor_mutation::Variables;
 use crate::models::contributor::Contributor;
 use crate::route::AdminRoute;
 use crate::route::AppRoute;
-use crate::string::SAVE_BUTTON;
 
 pub struct NewContributorComponent {
     contributor: Contributor,
     push_contributor: PushCreateContributor,
+    orcid_error: Option<String>,
     link: ComponentLink<Self>,
     router: RouteAgentDispatcher<()>,
     notification_bus: NotificationDispatcher,
@@ -41,6 +43,7 @@ pub enum Msg {
     ChangeLastName(String),
     ChangeFullName(String),
     ChangeOrcid(String),
+    ValidateOrcid,
     ChangeWebsite(String),
     ChangeRoute(AppRoute),
 }
@@ -58,6 +61,7 @@ impl Component for NewContributorComponent {
         NewContributorComponent {
             contributor,
             push_contributor,
+            orcid_error: None,
             link,
             router,
             notification_bus,
@@ -83,10 +87,8 @@ impl Component for NewContributorComponent {
                             true
                         }
                         None => {
-                            self.notification_bus.send(Request::NotificationBusMsg((
-                                "Failed to save".to_string(),
-                                NotificationStatus::Danger,
-                            )));
+                            self.notification_bus
+                                .send(Request::NotificationBusMsg(body.failure_notification()));
                             false
                         }
                     },
@@ -100,6 +102,10 @@ impl Component for NewContributorComponent {
                 }
             }
             Msg::CreateContributor => {
+                if let Err(e) = validate_optional::<Orcid>(&self.contributor.orcid) {
+                    self.orcid_error = Some(e.to_string());
+                    return true;
+                }
                 let body = CreateContributorRequestBody {
                     variables: Variables {
                         first_name: self.contributor.first_name.clone(),
@@ -133,6 +139,11 @@ impl Component for NewContributorComponent {
                 .contributor
                 .full_name
                 .neq_assign(full_name.trim().to_owned()),
+            Msg::ValidateOrcid => self.orcid_error.neq_assign(
+                validate_optional::<Orcid>(&self.contributor.orcid)
+                    .err()
+                    .map(|e| e.to_string()),
+            ),
             Msg::ChangeOrcid(value) => {
                 let orcid = match value.trim().is_empty() {
                     true => None,
@@ -197,6 +208,8 @@ impl Component for NewContributorComponent {
                         label = "ORCID (Full URL)"
                         value=&self.contributor.orcid
                         oninput=self.link.callback(|e: InputData| Msg::ChangeOrcid(e.value))
+                        onblur=self.link.callback(|_| Msg::ValidateOrcid)
+                        error=&self.orcid_error
                     />
                     <FormUrlInput
                         label = "Website"
@@ -207,7 +220,7 @@ impl Component for NewContributorComponent {
                     <div class="field">
                         <div class="control">
                             <button class="button is-success" type="submit">
-                                { SAVE_BUTTON }
+                                { t!("save_button") }
                             </button>
                         </div>
                     </div>
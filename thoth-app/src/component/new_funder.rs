@@ -1,3 +1,4 @@
+use thoth_api::funder::model::FunderCandidate;
 use yew::html;
 use yew::prelude::*;
 use yew::ComponentLink;
@@ -21,14 +22,17 @@ use crate::models::funder::create_funder_mutation::CreateFunderRequestBody;
 use crate::models::funder::create_funder_mutation::PushActionCreateFunder;
 use crate::models::funder::create_funder_mutation::PushCreateFunder;
 use crate::models::funder::create_funder_mutation::Variables;
+use crate::models::funder::funder_registry_query::FetchActionFunderRegistry;
+use crate::models::funder::funder_registry_query::FetchFunderRegistry;
+use crate::models::funder::funder_registry_query::FunderRegistryRequest;
 use crate::models::funder::Funder;
 use crate::route::AdminRoute;
 use crate::route::AppRoute;
-use crate::string::SAVE_BUTTON;
 
 pub struct NewFunderComponent {
     funder: Funder,
     push_funder: PushCreateFunder,
+    fetch_registry: FetchFunderRegistry,
     link: ComponentLink<Self>,
     router: RouteAgentDispatcher<()>,
     notification_bus: NotificationDispatcher,
@@ -37,6 +41,9 @@ pub struct NewFunderComponent {
 pub enum Msg {
     SetFunderPushState(PushActionCreateFunder),
     CreateFunder,
+    SetRegistryFetchState(FetchActionFunderRegistry),
+    SearchRegistry,
+    SelectCandidate(FunderCandidate),
     ChangeFunderName(String),
     ChangeFunderDoi(String),
     ChangeRoute(AppRoute),
@@ -48,6 +55,7 @@ impl Component for NewFunderComponent {
 
     fn create(_props: Self::Properties, link: ComponentLink<Self>) -> Self {
         let push_funder = Default::default();
+        let fetch_registry = Default::default();
         let notification_bus = NotificationBus::dispatcher();
         let funder: Funder = Default::default();
         let router = RouteAgentDispatcher::new();
@@ -55,6 +63,7 @@ impl Component for NewFunderComponent {
         NewFunderComponent {
             funder,
             push_funder,
+            fetch_registry,
             link,
             router,
             notification_bus,
@@ -80,10 +89,8 @@ impl Component for NewFunderComponent {
                             true
                         }
                         None => {
-                            self.notification_bus.send(Request::NotificationBusMsg((
-                                "Failed to save".to_string(),
-                                NotificationStatus::Danger,
-                            )));
+                            self.notification_bus
+                                .send(Request::NotificationBusMsg(body.failure_notification()));
                             false
                         }
                     },
@@ -112,6 +119,33 @@ impl Component for NewFunderComponent {
                     .send_message(Msg::SetFunderPushState(FetchAction::Fetching));
                 false
             }
+            Msg::SetRegistryFetchState(fetch_state) => {
+                self.fetch_registry.apply(fetch_state);
+                if let FetchState::Failed(_, err) = self.fetch_registry.as_ref().state() {
+                    self.notification_bus.send(Request::NotificationBusMsg((
+                        err.to_string(),
+                        NotificationStatus::Danger,
+                    )));
+                }
+                true
+            }
+            Msg::SearchRegistry => {
+                let request = FunderRegistryRequest {
+                    name: self.funder.funder_name.clone(),
+                };
+                self.fetch_registry = Fetch::new(request);
+                self.link
+                    .send_future(self.fetch_registry.fetch(Msg::SetRegistryFetchState));
+                self.link
+                    .send_message(Msg::SetRegistryFetchState(FetchAction::Fetching));
+                false
+            }
+            Msg::SelectCandidate(candidate) => {
+                self.funder.funder_name = candidate.funder_name;
+                self.funder.funder_doi = Some(candidate.funder_doi);
+                self.fetch_registry = Default::default();
+                true
+            }
             Msg::ChangeFunderName(funder_name) => self
                 .funder
                 .funder_name
@@ -158,6 +192,19 @@ impl Component for NewFunderComponent {
                         oninput=self.link.callback(|e: InputData| Msg::ChangeFunderName(e.value))
                         required=true
                     />
+                    <div class="field">
+                        <div class="control">
+                            <button
+                                class=self.registry_button_class()
+                                type="button"
+                                disabled=self.funder.funder_name.is_empty()
+                                onclick=self.link.callback(|_| Msg::SearchRegistry)
+                            >
+                                { t!("search_funder_registry") }
+                            </button>
+                        </div>
+                    </div>
+                    { self.render_candidates() }
                     <FormUrlInput
                         label = "Funder DOI"
                         value=&self.funder.funder_doi
@@ -167,7 +214,7 @@ impl Component for NewFunderComponent {
                     <div class="field">
                         <div class="control">
                             <button class="button is-success" type="submit">
-                                { SAVE_BUTTON }
+                                { t!("save_button") }
                             </button>
                         </div>
                     </div>
@@ -176,3 +223,38 @@ impl Component for NewFunderComponent {
         }
     }
 }
+
+impl NewFunderComponent {
+    fn registry_button_class(&self) -> &str {
+        match self.fetch_registry.as_ref().state() {
+            FetchState::Fetching(_) => "button is-info is-loading",
+            _ => "button is-info",
+        }
+    }
+
+    fn render_candidates(&self) -> Html {
+        match self.fetch_registry.as_ref().state() {
+            FetchState::Fetched(candidates) if candidates.is_empty() => html! {
+                <div class="notification is-warning is-light">{ t!("empty_funder_registry") }</div>
+            },
+            FetchState::Fetched(candidates) => html! {
+                <nav class="panel">
+                    {
+                        for candidates.iter().map(|c| {
+                            let candidate = c.clone();
+                            html! {
+                                <a
+                                    class="panel-block"
+                                    onclick=self.link.callback(move |_| Msg::SelectCandidate(candidate.clone()))
+                                >
+                                    { format!("{} - {}", c.funder_name, c.funder_doi) }
+                                </a>
+                            }
+                        })
+                    }
+                </nav>
+            },
+            _ => html! {},
+        }
+    }
+}
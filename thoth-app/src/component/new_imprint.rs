@@ -32,7 +32,6 @@ use crate::models::publisher::publishers_query::Variables as PublishersVariables
 use crate::models::publisher::Publisher;
 use crate::route::AdminRoute;
 use crate::route::AppRoute;
-use crate::string::SAVE_BUTTON;
 
 pub struct NewImprintComponent {
     imprint: Imprint,
@@ -140,10 +139,8 @@ impl Component for NewImprintComponent {
                             true
                         }
                         None => {
-                            self.notification_bus.send(Request::NotificationBusMsg((
-                                "Failed to save".to_string(),
-                                NotificationStatus::Danger,
-                            )));
+                            self.notification_bus
+                                .send(Request::NotificationBusMsg(body.failure_notification()));
                             false
                         }
                     },
@@ -248,7 +245,7 @@ impl Component for NewImprintComponent {
                     <div class="field">
                         <div class="control">
                             <button class="button is-success" type="submit">
-                                { SAVE_BUTTON }
+                                { t!("save_button") }
                             </button>
                         </div>
                     </div>
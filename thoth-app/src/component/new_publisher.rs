@@ -24,7 +24,6 @@ use crate::models::publisher::create_publisher_mutation::Variables;
 use crate::models::publisher::Publisher;
 use crate::route::AdminRoute;
 use crate::route::AppRoute;
-use crate::string::SAVE_BUTTON;
 
 pub struct NewPublisherComponent {
     publisher: Publisher,
@@ -81,10 +80,8 @@ impl Component for NewPublisherComponent {
                             true
                         }
                         None => {
-                            self.notification_bus.send(Request::NotificationBusMsg((
-                                "Failed to save".to_string(),
-                                NotificationStatus::Danger,
-                            )));
+                            self.notification_bus
+                                .send(Request::NotificationBusMsg(body.failure_notification()));
                             false
                         }
                     },
@@ -183,7 +180,7 @@ impl Component for NewPublisherComponent {
                     <div class="field">
                         <div class="control">
                             <button class="button is-success" type="submit">
-                                { SAVE_BUTTON }
+                                { t!("save_button") }
                             </button>
                         </div>
                     </div>
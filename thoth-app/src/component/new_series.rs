@@ -38,7 +38,6 @@ use crate::models::series::Series;
 use crate::models::series::SeriesTypeValues;
 use crate::route::AdminRoute;
 use crate::route::AppRoute;
-use crate::string::SAVE_BUTTON;
 
 pub struct NewSeriesComponent {
     series: Series,
@@ -170,10 +169,8 @@ impl Component for NewSeriesComponent {
                             true
                         }
                         None => {
-                            self.notification_bus.send(Request::NotificationBusMsg((
-                                "Failed to save".to_string(),
-                                NotificationStatus::Danger,
-                            )));
+                            self.notification_bus
+                                .send(Request::NotificationBusMsg(body.failure_notification()));
                             false
                         }
                     },
@@ -315,7 +312,7 @@ impl Component for NewSeriesComponent {
                     <div class="field">
                         <div class="control">
                             <button class="button is-success" type="submit">
-                                { SAVE_BUTTON }
+                                { t!("save_button") }
                             </button>
                         </div>
                     </div>
@@ -1,5 +1,7 @@
 use std::str::FromStr;
 use thoth_api::account::model::AccountDetails;
+use thoth_api::identifier::validate_optional;
+use thoth_api::identifier::Doi;
 use thoth_api::work::model::WorkStatus;
 use thoth_api::work::model::WorkType;
 use yew::html;
@@ -14,6 +16,7 @@ use yewtil::fetch::FetchState;
 use yewtil::future::LinkFuture;
 use yewtil::NeqAssign;
 
+use crate::agent::notification_bus::saved_notification;
 use crate::agent::notification_bus::NotificationBus;
 use crate::agent::notification_bus::NotificationDispatcher;
 use crate::agent::notification_bus::NotificationStatus;
@@ -46,7 +49,6 @@ use crate::models::work::WorkStatusValues;
 use crate::models::work::WorkTypeValues;
 use crate::route::AdminRoute;
 use crate::route::AppRoute;
-use crate::string::SAVE_BUTTON;
 
 pub struct NewWorkComponent {
     work: Work,
@@ -56,6 +58,7 @@ pub struct NewWorkComponent {
     fetch_imprints: FetchImprints,
     fetch_work_types: FetchWorkTypes,
     fetch_work_statuses: FetchWorkStatuses,
+    doi_error: Option<String>,
     link: ComponentLink<Self>,
     router: RouteAgentDispatcher<()>,
     notification_bus: NotificationDispatcher,
@@ -86,6 +89,7 @@ pub enum Msg {
     ChangeImprint(String),
     ChangeEdition(String),
     ChangeDoi(String),
+    ValidateDoi,
     ChangeDate(String),
     ChangePlace(String),
     ChangeWidth(String),
@@ -141,6 +145,7 @@ impl Component for NewWorkComponent {
             fetch_imprints,
             fetch_work_types,
             fetch_work_statuses,
+            doi_error: None,
             link,
             router,
             notification_bus,
@@ -152,30 +157,43 @@ impl Component for NewWorkComponent {
         match msg {
             Msg::SetImprintsFetchState(fetch_state) => {
                 self.fetch_imprints.apply(fetch_state);
-                self.data.imprints = match self.fetch_imprints.as_ref().state() {
-                    FetchState::NotFetching(_) => vec![],
-                    FetchState::Fetching(_) => vec![],
-                    FetchState::Fetched(body) => body.data.imprints.clone(),
-                    FetchState::Failed(_, _err) => vec![],
-                };
-                true
+                match self.fetch_imprints.as_ref().state() {
+                    FetchState::Fetched(body) if !body.data.imprints.is_empty() => {
+                        // Keep requesting pages until one comes back empty
+                        self.data
+                            .imprints
+                            .extend(body.data.imprints.iter().cloned());
+                        self.fetch_imprints_page();
+                        true
+                    }
+                    FetchState::Fetched(_) => {
+                        // Preselect the default imprint when all imprints on offer are the same publisher's
+                        if self.imprint_id.is_empty() {
+                            if let Some(first) = self.data.imprints.first() {
+                                let publisher_id = &first.publisher.publisher_id;
+                                if self
+                                    .data
+                                    .imprints
+                                    .iter()
+                                    .all(|i| &i.publisher.publisher_id == publisher_id)
+                                {
+                                    if let Some(default_imprint_id) =
+                                        &first.publisher.default_imprint_id
+                                    {
+                                        self.imprint_id = default_imprint_id.clone();
+                                    }
+                                }
+                            }
+                        }
+                        true
+                    }
+                    _ => false,
+                }
             }
             Msg::GetImprints => {
-                let body = ImprintsRequestBody {
-                    variables: ImprintsVariables {
-                        publishers: self.props.current_user.resource_access.restricted_to(),
-                        ..Default::default()
-                    },
-                    ..Default::default()
-                };
-                let request = ImprintsRequest { body };
-                self.fetch_imprints = Fetch::new(request);
-
-                self.link
-                    .send_future(self.fetch_imprints.fetch(Msg::SetImprintsFetchState));
-                self.link
-                    .send_message(Msg::SetImprintsFetchState(FetchAction::Fetching));
-                false
+                self.data.imprints.clear();
+                self.fetch_imprints_page();
+                true
             }
             Msg::SetWorkTypesFetchState(fetch_state) => {
                 self.fetch_work_types.apply(fetch_state);
@@ -220,20 +238,17 @@ impl Component for NewWorkComponent {
                     FetchState::Fetching(_) => false,
                     FetchState::Fetched(body) => match &body.data.create_work {
                         Some(w) => {
-                            self.notification_bus.send(Request::NotificationBusMsg((
-                                format!("Saved {}", w.title),
-                                NotificationStatus::Success,
-                            )));
+                            self.notification_bus.send(Request::NotificationBusMsg(
+                                saved_notification(&w.title, &w.warnings),
+                            ));
                             self.link.send_message(Msg::ChangeRoute(AppRoute::Admin(
                                 AdminRoute::Work(w.work_id.clone()),
                             )));
                             true
                         }
                         None => {
-                            self.notification_bus.send(Request::NotificationBusMsg((
-                                "Failed to save".to_string(),
-                                NotificationStatus::Danger,
-                            )));
+                            self.notification_bus
+                                .send(Request::NotificationBusMsg(body.failure_notification()));
                             false
                         }
                     },
@@ -247,6 +262,10 @@ impl Component for NewWorkComponent {
                 }
             }
             Msg::CreateWork => {
+                if let Err(e) = validate_optional::<Doi>(&self.work.doi) {
+                    self.doi_error = Some(e.to_string());
+                    return true;
+                }
                 let body = CreateWorkRequestBody {
                     variables: Variables {
                         work_type: self.work.work_type.clone(),
@@ -324,6 +343,11 @@ impl Component for NewWorkComponent {
                 let edition: i32 = edition.parse().unwrap_or(1);
                 self.work.edition.neq_assign(edition)
             }
+            Msg::ValidateDoi => self.doi_error.neq_assign(
+                validate_optional::<Doi>(&self.work.doi)
+                    .err()
+                    .map(|e| e.to_string()),
+            ),
             Msg::ChangeDoi(value) => {
                 let doi = match value.trim().is_empty() {
                     true => None,
@@ -615,6 +639,8 @@ impl Component for NewWorkComponent {
                                 label = "DOI"
                                 value=&self.work.doi
                                 oninput=self.link.callback(|e: InputData| Msg::ChangeDoi(e.value))
+                                onblur=self.link.callback(|_| Msg::ValidateDoi)
+                                error=&self.doi_error
                             />
                             <FormTextInput
                                 label = "LCCN"
@@ -721,7 +747,7 @@ impl Component for NewWorkComponent {
                     <div class="field">
                         <div class="control">
                             <button class="button is-success" type="submit">
-                                { SAVE_BUTTON }
+                                { t!("save_button") }
                             </button>
                         </div>
                     </div>
@@ -730,3 +756,23 @@ impl Component for NewWorkComponent {
         }
     }
 }
+
+impl NewWorkComponent {
+    fn fetch_imprints_page(&mut self) {
+        let body = ImprintsRequestBody {
+            variables: ImprintsVariables {
+                offset: Some(self.data.imprints.len() as i32),
+                publishers: self.props.current_user.resource_access.restricted_to(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let request = ImprintsRequest { body };
+        self.fetch_imprints = Fetch::new(request);
+
+        self.link
+            .send_future(self.fetch_imprints.fetch(Msg::SetImprintsFetchState));
+        self.link
+            .send_message(Msg::SetImprintsFetchState(FetchAction::Fetching));
+    }
+}
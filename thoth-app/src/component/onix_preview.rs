@@ -0,0 +1,218 @@
+use anyhow::Error;
+use yew::format::Json;
+use yew::format::Nothing;
+use yew::format::Text;
+use yew::html;
+use yew::prelude::*;
+use yew::services::fetch::FetchService;
+use yew::services::fetch::FetchTask;
+use yew::services::fetch::Request;
+use yew::services::fetch::Response;
+use yew::ComponentLink;
+
+use crate::component::utils::Loader;
+use crate::models::export::ValidationIssue;
+use crate::models::export::ValidationReport;
+
+pub struct OnixPreviewComponent {
+    props: Props,
+    onix: Option<String>,
+    report: Option<ValidationReport>,
+    failed: bool,
+    // requests in flight; dropping a task cancels its request
+    onix_task: Option<FetchTask>,
+    report_task: Option<FetchTask>,
+    link: ComponentLink<Self>,
+}
+
+pub enum Msg {
+    Refresh,
+    SetOnix(Option<String>),
+    SetReport(Option<ValidationReport>),
+}
+
+#[derive(Clone, Properties)]
+pub struct Props {
+    pub work_id: String,
+}
+
+impl Component for OnixPreviewComponent {
+    type Message = Msg;
+    type Properties = Props;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        // the preview is only generated on request, as the export reads the whole work
+        OnixPreviewComponent {
+            props,
+            onix: None,
+            report: None,
+            failed: false,
+            onix_task: None,
+            report_task: None,
+            link,
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::Refresh => {
+                self.failed = false;
+                let url = format!("{}/onix/{}", crate::api_url(), self.props.work_id);
+                let request = Request::get(&url).body(Nothing).unwrap();
+                let callback = self.link.callback(|response: Response<Text>| {
+                    let (meta, body) = response.into_parts();
+                    match body {
+                        Ok(onix) if meta.status.is_success() => Msg::SetOnix(Some(onix)),
+                        _ => Msg::SetOnix(None),
+                    }
+                });
+                self.onix_task = FetchService::fetch(request, callback).ok();
+
+                let url = format!("{}?validate=true", url);
+                let request = Request::get(&url).body(Nothing).unwrap();
+                let callback = self.link.callback(
+                    |response: Response<Json<Result<ValidationReport, Error>>>| {
+                        let (meta, Json(body)) = response.into_parts();
+                        match body {
+                            Ok(report) if meta.status.is_success() => Msg::SetReport(Some(report)),
+                            _ => Msg::SetReport(None),
+                        }
+                    },
+                );
+                self.report_task = FetchService::fetch(request, callback).ok();
+                self.failed = self.onix_task.is_none() || self.report_task.is_none();
+                true
+            }
+            Msg::SetOnix(onix) => {
+                self.onix_task = None;
+                self.failed |= onix.is_none();
+                self.onix = onix;
+                true
+            }
+            Msg::SetReport(report) => {
+                self.report_task = None;
+                self.failed |= report.is_none();
+                self.report = report;
+                true
+            }
+        }
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        // a preview of another work would be misleading
+        if self.props.work_id != props.work_id {
+            self.onix = None;
+            self.report = None;
+            self.failed = false;
+        }
+        self.props = props;
+        true
+    }
+
+    fn view(&self) -> Html {
+        html! {
+            <nav class="panel">
+                <p class="panel-heading">
+                    { "ONIX preview" }
+                </p>
+                <div class="panel-block">
+                    <button
+                        class="button is-link is-outlined"
+                        onclick=self.link.callback(|_| Msg::Refresh)
+                        disabled=self.is_loading()
+                    >
+                        { t!("onix_preview_button") }
+                    </button>
+                </div>
+                { self.render_preview() }
+            </nav>
+        }
+    }
+}
+
+impl OnixPreviewComponent {
+    fn is_loading(&self) -> bool {
+        self.onix_task.is_some() || self.report_task.is_some()
+    }
+
+    fn render_preview(&self) -> Html {
+        if self.is_loading() {
+            return html! {
+                <div class="panel-block">
+                    <Loader/>
+                </div>
+            };
+        }
+        if self.onix.is_none() && self.report.is_none() && !self.failed {
+            return html! {
+                <div class="notification is-info is-light">
+                    { t!("onix_preview_info") }
+                </div>
+            };
+        }
+        // the validation report is still worth showing when the ONIX could not be generated
+        html! {
+            <>
+                {
+                    if self.failed {
+                        html! {
+                            <div class="notification is-danger is-light">
+                                { t!("onix_preview_error") }
+                            </div>
+                        }
+                    } else {
+                        html! {}
+                    }
+                }
+                {
+                    match &self.report {
+                        Some(report) => self.render_issues(&report.issues),
+                        None => html! {},
+                    }
+                }
+                {
+                    match &self.onix {
+                        Some(onix) => html! {
+                            <div class="panel-block">
+                                <pre style="width: 100%; max-height: 40em; overflow: auto;">
+                                    { onix }
+                                </pre>
+                            </div>
+                        },
+                        None => html! {},
+                    }
+                }
+            </>
+        }
+    }
+
+    fn render_issues(&self, issues: &[ValidationIssue]) -> Html {
+        if issues.is_empty() {
+            html! {
+                <div class="notification is-success is-light">
+                    { t!("empty_onix_issues") }
+                </div>
+            }
+        } else {
+            html! {
+                { for issues.iter().map(|i| self.render_issue(i)) }
+            }
+        }
+    }
+
+    fn render_issue(&self, issue: &ValidationIssue) -> Html {
+        let tag_class = match issue.severity.as_str() {
+            "error" => "tag is-danger",
+            _ => "tag is-warning",
+        };
+        html! {
+            <div class="panel-block">
+                <span class=tag_class>{ &issue.severity }</span>
+                <span class="ml-2">
+                    <strong>{ &issue.field }</strong>
+                    { format!(": {}", issue.message) }
+                </span>
+            </div>
+        }
+    }
+}
@@ -1,5 +1,6 @@
 use std::str::FromStr;
 use thoth_api::price::model::CurrencyCode;
+use thoth_api::price::model::PriceType;
 use yew::html;
 use yew::prelude::*;
 use yew::ComponentLink;
@@ -15,6 +16,8 @@ use crate::agent::notification_bus::NotificationStatus;
 use crate::agent::notification_bus::Request;
 use crate::component::utils::FormCurrencyCodeSelect;
 use crate::component::utils::FormFloatInput;
+use crate::component::utils::FormPriceTypeSelect;
+use crate::component::utils::FormTextInput;
 use crate::models::price::create_price_mutation::CreatePriceRequest;
 use crate::models::price::create_price_mutation::CreatePriceRequestBody;
 use crate::models::price::create_price_mutation::PushActionCreatePrice;
@@ -27,11 +30,11 @@ use crate::models::price::delete_price_mutation::DeletePriceRequestBody;
 use crate::models::price::delete_price_mutation::PushActionDeletePrice;
 use crate::models::price::delete_price_mutation::PushDeletePrice;
 use crate::models::price::delete_price_mutation::Variables as DeleteVariables;
+use crate::models::price::price_types_query::FetchActionPriceTypes;
+use crate::models::price::price_types_query::FetchPriceTypes;
 use crate::models::price::CurrencyCodeValues;
 use crate::models::price::Price;
-use crate::string::CANCEL_BUTTON;
-use crate::string::EMPTY_PRICES;
-use crate::string::REMOVE_BUTTON;
+use crate::models::price::PriceTypeValues;
 
 pub struct PricesFormComponent {
     props: Props,
@@ -39,6 +42,7 @@ pub struct PricesFormComponent {
     new_price: Price,
     show_add_form: bool,
     fetch_currency_codes: FetchCurrencyCodes,
+    fetch_price_types: FetchPriceTypes,
     push_price: PushCreatePrice,
     delete_price: PushDeletePrice,
     link: ComponentLink<Self>,
@@ -48,18 +52,23 @@ pub struct PricesFormComponent {
 #[derive(Default)]
 struct PricesFormData {
     currency_codes: Vec<CurrencyCodeValues>,
+    price_types: Vec<PriceTypeValues>,
 }
 
 pub enum Msg {
     ToggleAddFormDisplay(bool),
     SetCurrencyCodesFetchState(FetchActionCurrencyCodes),
     GetCurrencyCodes,
+    SetPriceTypesFetchState(FetchActionPriceTypes),
+    GetPriceTypes,
     SetPricePushState(PushActionCreatePrice),
     CreatePrice,
     SetPriceDeleteState(PushActionDeletePrice),
     DeletePrice(String),
     ChangeCurrencyCode(CurrencyCode),
     ChangeUnitPrice(String),
+    ChangePriceType(PriceType),
+    ChangeTerritory(String),
     DoNothing,
 }
 
@@ -79,11 +88,13 @@ impl Component for PricesFormComponent {
         let show_add_form = false;
         let new_price: Price = Default::default();
         let fetch_currency_codes = Default::default();
+        let fetch_price_types = Default::default();
         let push_price = Default::default();
         let delete_price = Default::default();
         let notification_bus = NotificationBus::dispatcher();
 
         link.send_message(Msg::GetCurrencyCodes);
+        link.send_message(Msg::GetPriceTypes);
 
         PricesFormComponent {
             props,
@@ -91,6 +102,7 @@ impl Component for PricesFormComponent {
             new_price,
             show_add_form,
             fetch_currency_codes,
+            fetch_price_types,
             push_price,
             delete_price,
             link,
@@ -123,6 +135,23 @@ impl Component for PricesFormComponent {
                     .send_message(Msg::SetCurrencyCodesFetchState(FetchAction::Fetching));
                 false
             }
+            Msg::SetPriceTypesFetchState(fetch_state) => {
+                self.fetch_price_types.apply(fetch_state);
+                self.data.price_types = match self.fetch_price_types.as_ref().state() {
+                    FetchState::NotFetching(_) => vec![],
+                    FetchState::Fetching(_) => vec![],
+                    FetchState::Fetched(body) => body.data.price_types.enum_values.clone(),
+                    FetchState::Failed(_, _err) => vec![],
+                };
+                true
+            }
+            Msg::GetPriceTypes => {
+                self.link
+                    .send_future(self.fetch_price_types.fetch(Msg::SetPriceTypesFetchState));
+                self.link
+                    .send_message(Msg::SetPriceTypesFetchState(FetchAction::Fetching));
+                false
+            }
             Msg::SetPricePushState(fetch_state) => {
                 self.push_price.apply(fetch_state);
                 match self.push_price.as_ref().state() {
@@ -141,10 +170,8 @@ impl Component for PricesFormComponent {
                         }
                         None => {
                             self.link.send_message(Msg::ToggleAddFormDisplay(false));
-                            self.notification_bus.send(Request::NotificationBusMsg((
-                                "Failed to save".to_string(),
-                                NotificationStatus::Danger,
-                            )));
+                            self.notification_bus
+                                .send(Request::NotificationBusMsg(body.failure_notification()));
                             false
                         }
                     },
@@ -164,6 +191,8 @@ impl Component for PricesFormComponent {
                         publication_id: self.props.publication_id.clone(),
                         currency_code: self.new_price.currency_code.clone(),
                         unit_price: self.new_price.unit_price,
+                        price_type: self.new_price.price_type.clone(),
+                        territory: self.new_price.territory.clone(),
                     },
                     ..Default::default()
                 };
@@ -194,10 +223,8 @@ impl Component for PricesFormComponent {
                             true
                         }
                         None => {
-                            self.notification_bus.send(Request::NotificationBusMsg((
-                                "Failed to save".to_string(),
-                                NotificationStatus::Danger,
-                            )));
+                            self.notification_bus
+                                .send(Request::NotificationBusMsg(body.failure_notification()));
                             false
                         }
                     },
@@ -228,6 +255,14 @@ impl Component for PricesFormComponent {
                 let unit_price: f64 = val.parse().unwrap_or(0.00);
                 self.new_price.unit_price.neq_assign(unit_price)
             }
+            Msg::ChangePriceType(price_type) => self.new_price.price_type.neq_assign(price_type),
+            Msg::ChangeTerritory(value) => {
+                let territory = match value.trim().is_empty() {
+                    true => "WORLD".to_string(),
+                    false => value.trim().to_uppercase(),
+                };
+                self.new_price.territory.neq_assign(territory)
+            }
             Msg::DoNothing => false, // callbacks need to return a message
         }
     }
@@ -298,6 +333,27 @@ impl Component for PricesFormComponent {
                                     required = true
                                     step="0.01"
                                 />
+                                <FormPriceTypeSelect
+                                    label = "Price Type"
+                                    value=&self.new_price.price_type
+                                    data=&self.data.price_types
+                                    onchange=self.link.callback(|event| match event {
+                                        ChangeData::Select(elem) => {
+                                            let value = elem.value();
+                                            Msg::ChangePriceType(
+                                                PriceType::from_str(&value).unwrap()
+                                            )
+                                        }
+                                        _ => unreachable!(),
+                                    })
+                                    required = true
+                                />
+                                <FormTextInput
+                                    label = "Territory (WORLD or space-separated country codes)"
+                                    value=&self.new_price.territory
+                                    oninput=self.link.callback(|e: InputData| Msg::ChangeTerritory(e.value))
+                                    required = true
+                                />
                             </form>
                         </section>
                         <footer class="modal-card-foot">
@@ -314,7 +370,7 @@ impl Component for PricesFormComponent {
                                 class="button"
                                 onclick=&close_modal
                             >
-                                { CANCEL_BUTTON }
+                                { t!("cancel_button") }
                             </button>
                         </footer>
                     </div>
@@ -325,7 +381,7 @@ impl Component for PricesFormComponent {
                     } else {
                         html! {
                             <div class="notification is-warning is-light">
-                                { EMPTY_PRICES }
+                                { t!("empty_prices") }
                             </div>
                         }
                     }
@@ -361,7 +417,21 @@ impl PricesFormComponent {
                     <div class="field" style="width: 8em;">
                         <label class="label">{ "Price" }</label>
                         <div class="control is-expanded">
-                            {&p.unit_price}
+                            {p.currency_code.format_amount(p.unit_price)}
+                        </div>
+                    </div>
+
+                    <div class="field" style="width: 14em;">
+                        <label class="label">{ "Price Type" }</label>
+                        <div class="control is-expanded">
+                            {&p.price_type}
+                        </div>
+                    </div>
+
+                    <div class="field" style="width: 8em;">
+                        <label class="label">{ "Territory" }</label>
+                        <div class="control is-expanded">
+                            {&p.territory}
                         </div>
                     </div>
 
@@ -372,7 +442,7 @@ impl PricesFormComponent {
                                 class="button is-danger"
                                 onclick=self.link.callback(move |_| Msg::DeletePrice(price_id.clone()))
                             >
-                                { REMOVE_BUTTON }
+                                { t!("remove_button") }
                             </a>
                         </div>
                     </div>
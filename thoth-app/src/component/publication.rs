@@ -31,7 +31,6 @@ use crate::models::publication::publication_query::Variables;
 use crate::models::publication::Publication;
 use crate::route::AdminRoute;
 use crate::route::AppRoute;
-use crate::string::DELETE_BUTTON;
 
 pub struct PublicationComponent {
     publication: Publication,
@@ -153,10 +152,8 @@ impl Component for PublicationComponent {
                             true
                         }
                         None => {
-                            self.notification_bus.send(Request::NotificationBusMsg((
-                                "Failed to save".to_string(),
-                                NotificationStatus::Danger,
-                            )));
+                            self.notification_bus
+                                .send(Request::NotificationBusMsg(body.failure_notification()));
                             false
                         }
                     },
@@ -216,7 +213,7 @@ impl Component for PublicationComponent {
                             <div class="level-right">
                                 <p class="level-item">
                                     <button class="button is-danger" onclick=self.link.callback(|_| Msg::DeletePublication)>
-                                        { DELETE_BUTTON }
+                                        { t!("delete_button") }
                                     </button>
                                 </p>
                             </div>
@@ -15,7 +15,7 @@ pagination_component! {
     FetchPublications,
     PublicationsRequestBody,
     Variables,
-    SEARCH_PUBLICATIONS,
-    PAGINATION_COUNT_PUBLICATIONS,
+    "search_publications",
+    "pagination_count_publications",
     vec!["ID".to_string(), "Work Title".to_string(), "Work DOI".to_string(), "Publisher".to_string(), "Type".to_string(), "ISBN".to_string(), "URL".to_string()]
 }
@@ -1,4 +1,6 @@
 use std::str::FromStr;
+use thoth_api::identifier::validate_optional;
+use thoth_api::identifier::Isbn;
 use thoth_api::publication::model::PublicationType;
 use yew::html;
 use yew::prelude::*;
@@ -30,9 +32,6 @@ use crate::models::publication::publication_types_query::FetchActionPublicationT
 use crate::models::publication::publication_types_query::FetchPublicationTypes;
 use crate::models::publication::Publication;
 use crate::models::publication::PublicationTypeValues;
-use crate::string::CANCEL_BUTTON;
-use crate::string::EMPTY_PUBLICATIONS;
-use crate::string::REMOVE_BUTTON;
 
 pub struct PublicationsFormComponent {
     props: Props,
@@ -42,6 +41,7 @@ pub struct PublicationsFormComponent {
     fetch_publication_types: FetchPublicationTypes,
     push_publication: PushCreatePublication,
     delete_publication: PushDeletePublication,
+    isbn_error: Option<String>,
     link: ComponentLink<Self>,
     notification_bus: NotificationDispatcher,
 }
@@ -61,6 +61,7 @@ pub enum Msg {
     DeletePublication(String),
     ChangePublicationType(PublicationType),
     ChangeIsbn(String),
+    ValidateIsbn,
     ChangeUrl(String),
     DoNothing,
 }
@@ -94,6 +95,7 @@ impl Component for PublicationsFormComponent {
             fetch_publication_types: Default::default(),
             push_publication,
             delete_publication,
+            isbn_error: None,
             link,
             notification_bus,
         }
@@ -103,6 +105,7 @@ impl Component for PublicationsFormComponent {
         match msg {
             Msg::ToggleAddFormDisplay(value) => {
                 self.show_add_form = value;
+                self.isbn_error = None;
                 true
             }
             Msg::SetPublicationTypesFetchState(fetch_state) => {
@@ -142,10 +145,8 @@ impl Component for PublicationsFormComponent {
                         }
                         None => {
                             self.link.send_message(Msg::ToggleAddFormDisplay(false));
-                            self.notification_bus.send(Request::NotificationBusMsg((
-                                "Failed to save".to_string(),
-                                NotificationStatus::Danger,
-                            )));
+                            self.notification_bus
+                                .send(Request::NotificationBusMsg(body.failure_notification()));
                             false
                         }
                     },
@@ -160,6 +161,10 @@ impl Component for PublicationsFormComponent {
                 }
             }
             Msg::CreatePublication => {
+                if let Err(e) = validate_optional::<Isbn>(&self.new_publication.isbn) {
+                    self.isbn_error = Some(e.to_string());
+                    return true;
+                }
                 let body = CreatePublicationRequestBody {
                     variables: Variables {
                         work_id: self.props.work_id.clone(),
@@ -196,10 +201,8 @@ impl Component for PublicationsFormComponent {
                             true
                         }
                         None => {
-                            self.notification_bus.send(Request::NotificationBusMsg((
-                                "Failed to save".to_string(),
-                                NotificationStatus::Danger,
-                            )));
+                            self.notification_bus
+                                .send(Request::NotificationBusMsg(body.failure_notification()));
                             false
                         }
                     },
@@ -230,6 +233,11 @@ impl Component for PublicationsFormComponent {
             Msg::ChangePublicationType(val) => {
                 self.new_publication.publication_type.neq_assign(val)
             }
+            Msg::ValidateIsbn => self.isbn_error.neq_assign(
+                validate_optional::<Isbn>(&self.new_publication.isbn)
+                    .err()
+                    .map(|e| e.to_string()),
+            ),
             Msg::ChangeIsbn(value) => {
                 let isbn = match value.trim().is_empty() {
                     true => None,
@@ -311,6 +319,8 @@ impl Component for PublicationsFormComponent {
                                     label = "ISBN"
                                     value=&self.new_publication.isbn.clone().unwrap_or("".to_string())
                                     oninput=self.link.callback(|e: InputData| Msg::ChangeIsbn(e.value))
+                                    onblur=self.link.callback(|_| Msg::ValidateIsbn)
+                                    error=&self.isbn_error
                                 />
                                 <FormUrlInput
                                     label = "URL"
@@ -333,7 +343,7 @@ impl Component for PublicationsFormComponent {
                                 class="button"
                                 onclick=&close_modal
                             >
-                                { CANCEL_BUTTON }
+                                { t!("cancel_button") }
                             </button>
                         </footer>
                     </div>
@@ -344,7 +354,7 @@ impl Component for PublicationsFormComponent {
                     } else {
                         html! {
                             <div class="notification is-warning is-light">
-                                { EMPTY_PUBLICATIONS }
+                                { t!("empty_publications") }
                             </div>
                         }
                     }
@@ -401,7 +411,7 @@ impl PublicationsFormComponent {
                                 class="button is-danger"
                                 onclick=self.link.callback(move |_| Msg::DeletePublication(publication_id.clone()))
                             >
-                                { REMOVE_BUTTON }
+                                { t!("remove_button") }
                             </a>
                         </div>
                     </div>
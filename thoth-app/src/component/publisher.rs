@@ -16,9 +16,16 @@ use crate::agent::notification_bus::NotificationDispatcher;
 use crate::agent::notification_bus::NotificationStatus;
 use crate::agent::notification_bus::Request;
 use crate::component::delete_dialogue::ConfirmDeleteComponent;
+use crate::component::utils::FormImprintSelect;
 use crate::component::utils::FormTextInput;
 use crate::component::utils::FormUrlInput;
 use crate::component::utils::Loader;
+use crate::models::imprint::imprints_query::FetchActionImprints;
+use crate::models::imprint::imprints_query::FetchImprints;
+use crate::models::imprint::imprints_query::ImprintsRequest;
+use crate::models::imprint::imprints_query::ImprintsRequestBody;
+use crate::models::imprint::imprints_query::Variables as ImprintsVariables;
+use crate::models::imprint::Imprint;
 use crate::models::publisher::delete_publisher_mutation::DeletePublisherRequest;
 use crate::models::publisher::delete_publisher_mutation::DeletePublisherRequestBody;
 use crate::models::publisher::delete_publisher_mutation::PushActionDeletePublisher;
@@ -37,11 +44,12 @@ use crate::models::publisher::update_publisher_mutation::Variables as UpdateVari
 use crate::models::publisher::Publisher;
 use crate::route::AdminRoute;
 use crate::route::AppRoute;
-use crate::string::SAVE_BUTTON;
 
 pub struct PublisherComponent {
     publisher: Publisher,
     fetch_publisher: FetchPublisher,
+    imprints: Vec<Imprint>,
+    fetch_imprints: FetchImprints,
     push_publisher: PushUpdatePublisher,
     delete_publisher: PushDeletePublisher,
     link: ComponentLink<Self>,
@@ -53,6 +61,8 @@ pub struct PublisherComponent {
 pub enum Msg {
     SetPublisherFetchState(FetchActionPublisher),
     GetPublisher,
+    SetImprintsFetchState(FetchActionImprints),
+    GetImprints,
     SetPublisherPushState(PushActionUpdatePublisher),
     UpdatePublisher,
     SetPublisherDeleteState(PushActionDeletePublisher),
@@ -60,6 +70,7 @@ pub enum Msg {
     ChangePublisherName(String),
     ChangePublisherShortname(String),
     ChangePublisherUrl(String),
+    ChangeDefaultImprint(String),
     ChangeRoute(AppRoute),
 }
 
@@ -75,6 +86,7 @@ impl Component for PublisherComponent {
 
     fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
         let fetch_publisher: FetchPublisher = Default::default();
+        let fetch_imprints: FetchImprints = Default::default();
         let push_publisher = Default::default();
         let delete_publisher = Default::default();
         let notification_bus = NotificationBus::dispatcher();
@@ -82,10 +94,13 @@ impl Component for PublisherComponent {
         let router = RouteAgentDispatcher::new();
 
         link.send_message(Msg::GetPublisher);
+        link.send_message(Msg::GetImprints);
 
         PublisherComponent {
             publisher,
             fetch_publisher,
+            imprints: vec![],
+            fetch_imprints,
             push_publisher,
             delete_publisher,
             link,
@@ -138,6 +153,33 @@ impl Component for PublisherComponent {
                     .send_message(Msg::SetPublisherFetchState(FetchAction::Fetching));
                 false
             }
+            Msg::SetImprintsFetchState(fetch_state) => {
+                self.fetch_imprints.apply(fetch_state);
+                self.imprints = match self.fetch_imprints.as_ref().state() {
+                    FetchState::NotFetching(_) => vec![],
+                    FetchState::Fetching(_) => vec![],
+                    FetchState::Fetched(body) => body.data.imprints.clone(),
+                    FetchState::Failed(_, _err) => vec![],
+                };
+                true
+            }
+            Msg::GetImprints => {
+                let body = ImprintsRequestBody {
+                    variables: ImprintsVariables {
+                        publishers: Some(vec![self.props.publisher_id.clone()]),
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                };
+                let request = ImprintsRequest { body };
+                self.fetch_imprints = Fetch::new(request);
+
+                self.link
+                    .send_future(self.fetch_imprints.fetch(Msg::SetImprintsFetchState));
+                self.link
+                    .send_message(Msg::SetImprintsFetchState(FetchAction::Fetching));
+                false
+            }
             Msg::SetPublisherPushState(fetch_state) => {
                 self.push_publisher.apply(fetch_state);
                 match self.push_publisher.as_ref().state() {
@@ -152,10 +194,8 @@ impl Component for PublisherComponent {
                             true
                         }
                         None => {
-                            self.notification_bus.send(Request::NotificationBusMsg((
-                                "Failed to save".to_string(),
-                                NotificationStatus::Danger,
-                            )));
+                            self.notification_bus
+                                .send(Request::NotificationBusMsg(body.failure_notification()));
                             false
                         }
                     },
@@ -175,6 +215,7 @@ impl Component for PublisherComponent {
                         publisher_name: self.publisher.publisher_name.clone(),
                         publisher_shortname: self.publisher.publisher_shortname.clone(),
                         publisher_url: self.publisher.publisher_url.clone(),
+                        default_imprint_id: self.publisher.default_imprint_id.clone(),
                     },
                     ..Default::default()
                 };
@@ -203,10 +244,8 @@ impl Component for PublisherComponent {
                             true
                         }
                         None => {
-                            self.notification_bus.send(Request::NotificationBusMsg((
-                                "Failed to save".to_string(),
-                                NotificationStatus::Danger,
-                            )));
+                            self.notification_bus
+                                .send(Request::NotificationBusMsg(body.failure_notification()));
                             false
                         }
                     },
@@ -254,6 +293,15 @@ impl Component for PublisherComponent {
                 };
                 self.publisher.publisher_url.neq_assign(publisher_url)
             }
+            Msg::ChangeDefaultImprint(value) => {
+                let default_imprint_id = match value.is_empty() {
+                    true => None,
+                    false => Some(value),
+                };
+                self.publisher
+                    .default_imprint_id
+                    .neq_assign(default_imprint_id)
+            }
             Msg::ChangeRoute(r) => {
                 let route = Route::from(r);
                 self.router.send(RouteRequest::ChangeRoute(route));
@@ -311,11 +359,22 @@ impl Component for PublisherComponent {
                                 value=&self.publisher.publisher_url
                                 oninput=self.link.callback(|e: InputData| Msg::ChangePublisherUrl(e.value))
                             />
+                            <FormImprintSelect
+                                label = "Default Imprint"
+                                value=&self.publisher.default_imprint_id
+                                data=&self.imprints
+                                onchange=self.link.callback(|event| match event {
+                                    ChangeData::Select(elem) => {
+                                        Msg::ChangeDefaultImprint(elem.value())
+                                    }
+                                    _ => unreachable!(),
+                                })
+                            />
 
                             <div class="field">
                                 <div class="control">
                                     <button class="button is-success" type="submit">
-                                        { SAVE_BUTTON }
+                                        { t!("save_button") }
                                     </button>
                                 </div>
                             </div>
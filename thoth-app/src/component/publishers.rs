@@ -15,7 +15,7 @@ pagination_component! {
     FetchPublishers,
     PublishersRequestBody,
     Variables,
-    SEARCH_PUBLISHERS,
-    PAGINATION_COUNT_PUBLISHERS,
+    "search_publishers",
+    "pagination_count_publishers",
     vec!["ID".to_string(), "Name".to_string(), "ShortName".to_string(), "URL".to_string()]
 }
@@ -7,6 +7,9 @@ use yew::Callback;
 use yew_router::prelude::*;
 use yew_router::route::Route;
 use yew_router::switch::Permissive;
+use yewtil::fetch::FetchAction;
+use yewtil::fetch::FetchState;
+use yewtil::future::LinkFuture;
 
 use crate::agent::session_timer;
 use crate::agent::session_timer::SessionTimerAgent;
@@ -17,11 +20,17 @@ use crate::component::hero::HeroComponent;
 use crate::component::login::LoginComponent;
 use crate::component::navbar::NavbarComponent;
 use crate::component::notification::NotificationComponent;
+use crate::models::config::config_query::FetchActionConfig;
+use crate::models::config::config_query::FetchConfig;
 use crate::route::AppRoute;
 use crate::service::account::AccountError;
 use crate::service::account::AccountService;
+use crate::set_api_url;
 
 pub struct RootComponent {
+    // nothing that talks to the API is rendered until the runtime config has been looked for
+    config_loaded: bool,
+    fetch_config: FetchConfig,
     current_route: Option<AppRoute>,
     account_service: AccountService,
     current_user: Option<AccountDetails>,
@@ -35,6 +44,8 @@ pub struct RootComponent {
 }
 
 pub enum Msg {
+    GetConfig,
+    SetConfigFetchState(FetchActionConfig),
     FetchCurrentUser,
     CurrentUserResponse(Result<AccountDetails, AccountError>),
     RenewToken,
@@ -55,6 +66,8 @@ impl Component for RootComponent {
         let route = route_service.get_route();
 
         RootComponent {
+            config_loaded: false,
+            fetch_config: Default::default(),
             current_route: AppRoute::switch(route),
             account_service: AccountService::new(),
             current_user: Default::default(),
@@ -69,13 +82,37 @@ impl Component for RootComponent {
     }
 
     fn rendered(&mut self, first_render: bool) {
-        if first_render && self.account_service.is_loggedin() {
-            self.link.send_message(Msg::FetchCurrentUser);
+        if first_render {
+            self.link.send_message(Msg::GetConfig);
         }
     }
 
     fn update(&mut self, msg: Self::Message) -> ShouldRender {
         match msg {
+            Msg::GetConfig => {
+                self.link
+                    .send_future(self.fetch_config.fetch(Msg::SetConfigFetchState));
+                self.link
+                    .send_message(Msg::SetConfigFetchState(FetchAction::Fetching));
+                return false;
+            }
+            Msg::SetConfigFetchState(fetch_state) => {
+                self.fetch_config.apply(fetch_state);
+                match self.fetch_config.as_ref().state() {
+                    FetchState::NotFetching(_) | FetchState::Fetching(_) => return false,
+                    FetchState::Fetched(config) => {
+                        if let Some(url) = &config.thoth_api {
+                            set_api_url(url.trim_end_matches('/').to_string());
+                        }
+                    }
+                    // a missing or unreadable config leaves the API the app was built with
+                    FetchState::Failed(_, _) => {}
+                }
+                self.config_loaded = true;
+                if self.account_service.is_loggedin() {
+                    self.link.send_message(Msg::FetchCurrentUser);
+                }
+            }
             Msg::FetchCurrentUser => {
                 let task = self
                     .account_service
@@ -83,6 +120,12 @@ impl Component for RootComponent {
                 self.current_user_task = Some(task);
             }
             Msg::RenewToken => {
+                // only renew the token when it is about to expire
+                if let Some(account_details) = &self.current_user {
+                    if !self.account_service.token_expires_soon(account_details) {
+                        return false;
+                    }
+                }
                 let task = self
                     .account_service
                     .renew_token(self.renew_token_response.clone());
@@ -126,6 +169,9 @@ impl Component for RootComponent {
     }
 
     fn view(&self) -> VNode {
+        if !self.config_loaded {
+            return html! {};
+        }
         let callback_login = self.link.callback(Msg::Login);
         let callback_logout = self.link.callback(|_| Msg::Logout);
 
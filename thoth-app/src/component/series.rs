@@ -50,7 +50,6 @@ use crate::models::series::Series;
 use crate::models::series::SeriesTypeValues;
 use crate::route::AdminRoute;
 use crate::route::AppRoute;
-use crate::string::SAVE_BUTTON;
 
 pub struct SeriesComponent {
     series: Series,
@@ -236,10 +235,8 @@ impl Component for SeriesComponent {
                             true
                         }
                         None => {
-                            self.notification_bus.send(Request::NotificationBusMsg((
-                                "Failed to save".to_string(),
-                                NotificationStatus::Danger,
-                            )));
+                            self.notification_bus
+                                .send(Request::NotificationBusMsg(body.failure_notification()));
                             false
                         }
                     },
@@ -290,10 +287,8 @@ impl Component for SeriesComponent {
                             true
                         }
                         None => {
-                            self.notification_bus.send(Request::NotificationBusMsg((
-                                "Failed to save".to_string(),
-                                NotificationStatus::Danger,
-                            )));
+                            self.notification_bus
+                                .send(Request::NotificationBusMsg(body.failure_notification()));
                             false
                         }
                     },
@@ -440,7 +435,7 @@ impl Component for SeriesComponent {
                             <div class="field">
                                 <div class="control">
                                     <button class="button is-success" type="submit">
-                                        { SAVE_BUTTON }
+                                        { t!("save_button") }
                                     </button>
                                 </div>
                             </div>
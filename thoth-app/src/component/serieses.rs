@@ -15,7 +15,7 @@ pagination_component! {
     FetchSerieses,
     SeriesesRequestBody,
     Variables,
-    SEARCH_SERIESES,
-    PAGINATION_COUNT_SERIESES,
+    "search_serieses",
+    "pagination_count_serieses",
     vec!["ID".to_string(), "Series".to_string(), "SeriesType".to_string(), "ISSNPrint".to_string(), "ISSNDigital".to_string()]
 }
@@ -28,11 +28,13 @@ use crate::models::subject::delete_subject_mutation::PushDeleteSubject;
 use crate::models::subject::delete_subject_mutation::Variables as DeleteVariables;
 use crate::models::subject::subject_types_query::FetchActionSubjectTypes;
 use crate::models::subject::subject_types_query::FetchSubjectTypes;
+use crate::models::subject::suggest_keywords_query::FetchActionSuggestKeywords;
+use crate::models::subject::suggest_keywords_query::FetchSuggestKeywords;
+use crate::models::subject::suggest_keywords_query::SuggestKeywordsRequest;
+use crate::models::subject::suggest_keywords_query::SuggestKeywordsRequestBody;
+use crate::models::subject::suggest_keywords_query::Variables as SuggestKeywordsVariables;
 use crate::models::subject::Subject;
 use crate::models::subject::SubjectTypeValues;
-use crate::string::CANCEL_BUTTON;
-use crate::string::EMPTY_SUBJECTS;
-use crate::string::REMOVE_BUTTON;
 
 pub struct SubjectsFormComponent {
     props: Props,
@@ -40,6 +42,7 @@ pub struct SubjectsFormComponent {
     new_subject: Subject,
     show_add_form: bool,
     fetch_subject_types: FetchSubjectTypes,
+    fetch_keyword_suggestions: FetchSuggestKeywords,
     push_subject: PushCreateSubject,
     delete_subject: PushDeleteSubject,
     link: ComponentLink<Self>,
@@ -49,12 +52,16 @@ pub struct SubjectsFormComponent {
 #[derive(Default)]
 struct SubjectsFormData {
     subject_types: Vec<SubjectTypeValues>,
+    keyword_suggestions: Vec<String>,
 }
 
 pub enum Msg {
     ToggleAddFormDisplay(bool),
     SetSubjectTypesFetchState(FetchActionSubjectTypes),
     GetSubjectTypes,
+    SetKeywordSuggestionsFetchState(FetchActionSuggestKeywords),
+    GetKeywordSuggestions,
+    AcceptKeyword(String),
     SetSubjectPushState(PushActionCreateSubject),
     CreateSubject,
     SetSubjectDeleteState(PushActionDeleteSubject),
@@ -85,6 +92,7 @@ impl Component for SubjectsFormComponent {
         let notification_bus = NotificationBus::dispatcher();
 
         link.send_message(Msg::GetSubjectTypes);
+        link.send_message(Msg::GetKeywordSuggestions);
 
         SubjectsFormComponent {
             props,
@@ -92,6 +100,7 @@ impl Component for SubjectsFormComponent {
             new_subject,
             show_add_form,
             fetch_subject_types: Default::default(),
+            fetch_keyword_suggestions: Default::default(),
             push_subject,
             delete_subject,
             link,
@@ -124,6 +133,56 @@ impl Component for SubjectsFormComponent {
                     .send_message(Msg::SetSubjectTypesFetchState(FetchAction::Fetching));
                 false
             }
+            Msg::SetKeywordSuggestionsFetchState(fetch_state) => {
+                self.fetch_keyword_suggestions.apply(fetch_state);
+                self.data.keyword_suggestions =
+                    match self.fetch_keyword_suggestions.as_ref().state() {
+                        FetchState::NotFetching(_) => vec![],
+                        FetchState::Fetching(_) => vec![],
+                        FetchState::Fetched(body) => body.data.suggest_keywords.clone(),
+                        FetchState::Failed(_, _err) => vec![],
+                    };
+                true
+            }
+            Msg::GetKeywordSuggestions => {
+                let body = SuggestKeywordsRequestBody {
+                    variables: SuggestKeywordsVariables {
+                        work_id: self.props.work_id.clone(),
+                    },
+                    ..Default::default()
+                };
+                let request = SuggestKeywordsRequest { body };
+                self.fetch_keyword_suggestions = Fetch::new(request);
+                self.link.send_future(
+                    self.fetch_keyword_suggestions
+                        .fetch(Msg::SetKeywordSuggestionsFetchState),
+                );
+                self.link
+                    .send_message(Msg::SetKeywordSuggestionsFetchState(FetchAction::Fetching));
+                false
+            }
+            Msg::AcceptKeyword(keyword) => {
+                // add the suggestion after any keywords the work already has
+                let subject_ordinal = self
+                    .props
+                    .subjects
+                    .clone()
+                    .unwrap_or_default()
+                    .iter()
+                    .filter(|s| s.subject_type == SubjectType::Keyword)
+                    .map(|s| s.subject_ordinal)
+                    .max()
+                    .unwrap_or(0)
+                    + 1;
+                self.new_subject = Subject {
+                    subject_type: SubjectType::Keyword,
+                    subject_code: keyword,
+                    subject_ordinal,
+                    ..Default::default()
+                };
+                self.link.send_message(Msg::CreateSubject);
+                false
+            }
             Msg::SetSubjectPushState(fetch_state) => {
                 self.push_subject.apply(fetch_state);
                 match self.push_subject.as_ref().state() {
@@ -142,10 +201,8 @@ impl Component for SubjectsFormComponent {
                         }
                         None => {
                             self.link.send_message(Msg::ToggleAddFormDisplay(false));
-                            self.notification_bus.send(Request::NotificationBusMsg((
-                                "Failed to save".to_string(),
-                                NotificationStatus::Danger,
-                            )));
+                            self.notification_bus
+                                .send(Request::NotificationBusMsg(body.failure_notification()));
                             false
                         }
                     },
@@ -196,10 +253,8 @@ impl Component for SubjectsFormComponent {
                             true
                         }
                         None => {
-                            self.notification_bus.send(Request::NotificationBusMsg((
-                                "Failed to save".to_string(),
-                                NotificationStatus::Danger,
-                            )));
+                            self.notification_bus
+                                .send(Request::NotificationBusMsg(body.failure_notification()));
                             false
                         }
                     },
@@ -236,7 +291,12 @@ impl Component for SubjectsFormComponent {
     }
 
     fn change(&mut self, props: Self::Properties) -> ShouldRender {
-        self.props.neq_assign(props)
+        let work_changed = self.props.work_id != props.work_id;
+        let updated = self.props.neq_assign(props);
+        if work_changed {
+            self.link.send_message(Msg::GetKeywordSuggestions);
+        }
+        updated
     }
 
     fn view(&self) -> Html {
@@ -320,18 +380,19 @@ impl Component for SubjectsFormComponent {
                                 class="button"
                                 onclick=&close_modal
                             >
-                                { CANCEL_BUTTON }
+                                { t!("cancel_button") }
                             </button>
                         </footer>
                     </div>
                 </div>
+                { self.render_keyword_suggestions() }
                 {
                     if subjects.len() > 0 {
                         html!{{for subjects.iter().map(|p| self.render_subject(p))}}
                     } else {
                         html! {
                             <div class="notification is-warning is-light">
-                                { EMPTY_SUBJECTS }
+                                { t!("empty_subjects") }
                             </div>
                         }
                     }
@@ -349,6 +410,50 @@ impl SubjectsFormComponent {
         }
     }
 
+    fn render_keyword_suggestions(&self) -> Html {
+        let existing: Vec<String> = self
+            .props
+            .subjects
+            .clone()
+            .unwrap_or_default()
+            .iter()
+            .filter(|s| s.subject_type == SubjectType::Keyword)
+            .map(|s| s.subject_code.to_lowercase())
+            .collect();
+        let suggestions: Vec<String> = self
+            .data
+            .keyword_suggestions
+            .iter()
+            .filter(|k| !existing.contains(&k.to_lowercase()))
+            .cloned()
+            .collect();
+        if suggestions.is_empty() {
+            return html! {};
+        }
+        html! {
+            <div class="panel-block">
+                <div class="field">
+                    <label class="label">{ t!("suggested_keywords") }</label>
+                    <div class="tags">
+                        {
+                            for suggestions.into_iter().map(|keyword| {
+                                let label = keyword.clone();
+                                html! {
+                                    <a
+                                        class="tag is-info is-light"
+                                        onclick=self.link.callback(move |_| Msg::AcceptKeyword(keyword.clone()))
+                                    >
+                                        { label }
+                                    </a>
+                                }
+                            })
+                        }
+                    </div>
+                </div>
+            </div>
+        }
+    }
+
     fn render_subject(&self, s: &Subject) -> Html {
         // there's probably a better way to do this. We basically need to copy 3 instances
         // of contributor_id and take ownership of them so they can be passed on to
@@ -388,7 +493,7 @@ impl SubjectsFormComponent {
                                 class="button is-danger"
                                 onclick=self.link.callback(move |_| Msg::DeleteSubject(subject_id.clone()))
                             >
-                                { REMOVE_BUTTON }
+                                { t!("remove_button") }
                             </a>
                         </div>
                     </div>
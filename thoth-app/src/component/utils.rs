@@ -2,6 +2,7 @@ use thoth_api::contribution::model::ContributionType;
 use thoth_api::language::model::LanguageCode;
 use thoth_api::language::model::LanguageRelation;
 use thoth_api::price::model::CurrencyCode;
+use thoth_api::price::model::PriceType;
 use thoth_api::publication::model::PublicationType;
 use thoth_api::series::model::SeriesType;
 use thoth_api::subject::model::SubjectType;
@@ -23,15 +24,13 @@ use crate::models::imprint::Imprint;
 use crate::models::language::LanguageCodeValues;
 use crate::models::language::LanguageRelationValues;
 use crate::models::price::CurrencyCodeValues;
+use crate::models::price::PriceTypeValues;
 use crate::models::publication::PublicationTypeValues;
 use crate::models::publisher::Publisher;
 use crate::models::series::SeriesTypeValues;
 use crate::models::subject::SubjectTypeValues;
 use crate::models::work::WorkStatusValues;
 use crate::models::work::WorkTypeValues;
-use crate::string::NO;
-use crate::string::RELOAD_BUTTON;
-use crate::string::YES;
 
 pub type FormInput = Pure<PureInput>;
 pub type FormTextarea = Pure<PureTextarea>;
@@ -49,11 +48,13 @@ pub type FormSubjectTypeSelect = Pure<PureSubjectTypeSelect>;
 pub type FormLanguageCodeSelect = Pure<PureLanguageCodeSelect>;
 pub type FormLanguageRelationSelect = Pure<PureLanguageRelationSelect>;
 pub type FormCurrencyCodeSelect = Pure<PureCurrencyCodeSelect>;
+pub type FormPriceTypeSelect = Pure<PurePriceTypeSelect>;
 pub type FormBooleanSelect = Pure<PureBooleanSelect>;
 pub type FormImprintSelect = Pure<PureImprintSelect>;
 pub type FormPublisherSelect = Pure<PurePublisherSelect>;
 pub type Loader = Pure<PureLoader>;
 pub type Reloader = Pure<PureReloader>;
+pub type FetchError = Pure<PureFetchError>;
 
 #[derive(Clone, PartialEq, Properties)]
 pub struct PureInput {
@@ -66,6 +67,8 @@ pub struct PureInput {
     pub onblur: Callback<FocusEvent>,
     #[prop_or(false)]
     pub required: bool,
+    #[prop_or_default]
+    pub error: Option<String>,
 }
 
 #[derive(Clone, PartialEq, Properties)]
@@ -88,6 +91,8 @@ pub struct PureTextInput {
     pub onblur: Callback<FocusEvent>,
     #[prop_or(false)]
     pub required: bool,
+    #[prop_or_default]
+    pub error: Option<String>,
 }
 
 #[derive(Clone, PartialEq, Properties)]
@@ -100,6 +105,8 @@ pub struct PureUrlInput {
     pub onblur: Callback<FocusEvent>,
     #[prop_or(false)]
     pub required: bool,
+    #[prop_or_default]
+    pub error: Option<String>,
 }
 
 #[derive(Clone, PartialEq, Properties)]
@@ -230,6 +237,16 @@ pub struct PureCurrencyCodeSelect {
     pub required: bool,
 }
 
+#[derive(Clone, PartialEq, Properties)]
+pub struct PurePriceTypeSelect {
+    pub label: String,
+    pub data: Vec<PriceTypeValues>,
+    pub value: PriceType,
+    pub onchange: Callback<ChangeData>,
+    #[prop_or(false)]
+    pub required: bool,
+}
+
 #[derive(Clone, PartialEq, Properties)]
 pub struct PureBooleanSelect {
     pub label: String,
@@ -269,14 +286,24 @@ pub struct PureReloader {
     pub onclick: Callback<MouseEvent>,
 }
 
+#[derive(Clone, PartialEq, Properties)]
+pub struct PureFetchError {
+    pub error: String,
+    pub onretry: Callback<MouseEvent>,
+}
+
 impl PureComponent for PureInput {
     fn render(&self) -> VNode {
+        let input_class = match self.error {
+            Some(_) => "input is-danger",
+            None => "input",
+        };
         html! {
             <div class="field">
                 <label class="label">{ &self.label }</label>
                 <div class="control is-expanded">
                     <input
-                        class="input"
+                        class=input_class
                         type={ &self.input_type }
                         placeholder={ &self.label }
                         value={ &self.value }
@@ -285,6 +312,14 @@ impl PureComponent for PureInput {
                         required={ self.required }
                     />
                 </div>
+                {
+                    match &self.error {
+                        Some(error) => html! {
+                            <p class="help is-danger">{ error }</p>
+                        },
+                        None => html! {},
+                    }
+                }
             </div>
         }
     }
@@ -320,6 +355,7 @@ impl PureComponent for PureTextInput {
                 oninput=&self.oninput
                 onblur=&self.onblur
                 required=self.required
+                error=&self.error
             />
         }
     }
@@ -335,6 +371,7 @@ impl PureComponent for PureUrlInput {
                 oninput=&self.oninput
                 onblur=&self.onblur
                 required=self.required
+                error=&self.error
             />
         }
     }
@@ -567,6 +604,26 @@ impl PureComponent for PureCurrencyCodeSelect {
     }
 }
 
+impl PureComponent for PurePriceTypeSelect {
+    fn render(&self) -> VNode {
+        html! {
+            <div class="field">
+                <label class="label">{ &self.label }</label>
+                <div class="control is-expanded">
+                    <div class="select">
+                    <select
+                        required=self.required
+                        onchange=&self.onchange
+                    >
+                        { for self.data.iter().map(|p| self.render_pricetype(p)) }
+                    </select>
+                    </div>
+                </div>
+            </div>
+        }
+    }
+}
+
 impl PureComponent for PureBooleanSelect {
     fn render(&self) -> VNode {
         html! {
@@ -580,10 +637,10 @@ impl PureComponent for PureBooleanSelect {
                         onblur=&self.onblur
                     >
                         <option value=true selected=self.value>
-                            { YES }
+                            { t!("yes") }
                         </option>
                         <option value=false selected=!self.value>
-                            { NO }
+                            { t!("no") }
                         </option>
                     </select>
                     </div>
@@ -773,6 +830,22 @@ impl PureCurrencyCodeSelect {
     }
 }
 
+impl PurePriceTypeSelect {
+    fn render_pricetype(&self, p: &PriceTypeValues) -> VNode {
+        if p.name == self.value {
+            html! {
+                <option value={&p.name} selected=true>
+                    {&p.name}
+                </option>
+            }
+        } else {
+            html! {
+                <option value={&p.name}>{&p.name}</option>
+            }
+        }
+    }
+}
+
 impl PureImprintSelect {
     fn render_imprint(&self, i: &Imprint) -> VNode {
         let value = &self.value.clone().unwrap_or_else(|| "".to_string());
@@ -832,9 +905,23 @@ impl PureComponent for PureReloader {
                     <span class="icon">
                         <i class="fas fa-sync"></i>
                     </span>
-                    <span>{ RELOAD_BUTTON }</span>
+                    <span>{ t!("reload_button") }</span>
                 </button>
             </div>
         }
     }
 }
+
+impl PureComponent for PureFetchError {
+    fn render(&self) -> VNode {
+        html! {
+            <>
+                <div class="notification is-danger is-light">
+                    <p>{ t!("fetch_error") }</p>
+                    <p><small>{ &self.error }</small></p>
+                </div>
+                <Reloader onclick=self.onretry.clone()/>
+            </>
+        }
+    }
+}
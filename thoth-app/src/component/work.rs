@@ -1,9 +1,14 @@
 use std::str::FromStr;
 use thoth_api::account::model::AccountDetails;
+use thoth_api::identifier::validate_optional;
+use thoth_api::identifier::Doi;
+use thoth_api::identifier::ShortDoi;
+use thoth_api::work::model::Work as WorkSnapshot;
 use thoth_api::work::model::WorkStatus;
 use thoth_api::work::model::WorkType;
 use yew::html;
 use yew::prelude::*;
+use yew::services::DialogService;
 use yew::ComponentLink;
 use yew_router::agent::RouteAgentDispatcher;
 use yew_router::agent::RouteRequest;
@@ -14,15 +19,18 @@ use yewtil::fetch::FetchState;
 use yewtil::future::LinkFuture;
 use yewtil::NeqAssign;
 
+use crate::agent::notification_bus::saved_notification;
 use crate::agent::notification_bus::NotificationBus;
 use crate::agent::notification_bus::NotificationDispatcher;
 use crate::agent::notification_bus::NotificationStatus;
 use crate::agent::notification_bus::Request;
 use crate::component::contributions_form::ContributionsFormComponent;
 use crate::component::delete_dialogue::ConfirmDeleteComponent;
+use crate::component::export_dropdown::ExportDropdownComponent;
 use crate::component::fundings_form::FundingsFormComponent;
 use crate::component::issues_form::IssuesFormComponent;
 use crate::component::languages_form::LanguagesFormComponent;
+use crate::component::onix_preview::OnixPreviewComponent;
 use crate::component::publications_form::PublicationsFormComponent;
 use crate::component::subjects_form::SubjectsFormComponent;
 use crate::component::utils::FormDateInput;
@@ -34,8 +42,14 @@ use crate::component::utils::FormUrlInput;
 use crate::component::utils::FormWorkStatusSelect;
 use crate::component::utils::FormWorkTypeSelect;
 use crate::component::utils::Loader;
+use crate::component::work_history::WorkHistoryComponent;
 use crate::models::contribution::Contribution;
 use crate::models::funding::Funding;
+use crate::models::imprint::imprints_query::FetchActionImprints;
+use crate::models::imprint::imprints_query::FetchImprints;
+use crate::models::imprint::imprints_query::ImprintsRequest;
+use crate::models::imprint::imprints_query::ImprintsRequestBody;
+use crate::models::imprint::imprints_query::Variables as ImprintsVariables;
 use crate::models::imprint::Imprint;
 use crate::models::issue::Issue;
 use crate::models::language::Language;
@@ -61,14 +75,18 @@ use crate::models::work::WorkStatusValues;
 use crate::models::work::WorkTypeValues;
 use crate::route::AdminRoute;
 use crate::route::AppRoute;
-use crate::string::SAVE_BUTTON;
 
 pub struct WorkComponent {
     work: Work,
     data: WorkFormData,
     fetch_work: FetchWork,
+    fetch_imprints: FetchImprints,
     push_work: PushUpdateWork,
     delete_work: PushDeleteWork,
+    doi_error: Option<String>,
+    short_doi_error: Option<String>,
+    // number of successful saves, so the history panel knows to reload
+    save_count: usize,
     link: ComponentLink<Self>,
     router: RouteAgentDispatcher<()>,
     notification_bus: NotificationDispatcher,
@@ -85,6 +103,8 @@ struct WorkFormData {
 pub enum Msg {
     SetWorkFetchState(FetchActionWork),
     GetWork,
+    SetImprintsFetchState(FetchActionImprints),
+    GetImprints,
     SetWorkPushState(PushActionUpdateWork),
     UpdateWork,
     SetWorkDeleteState(PushActionDeleteWork),
@@ -97,6 +117,9 @@ pub enum Msg {
     ChangeImprint(String),
     ChangeEdition(String),
     ChangeDoi(String),
+    ValidateDoi,
+    ChangeShortDoi(String),
+    ValidateShortDoi,
     ChangeDate(String),
     ChangePlace(String),
     ChangeWidth(String),
@@ -124,6 +147,7 @@ pub enum Msg {
     UpdateLanguages(Option<Vec<Language>>),
     UpdateSubjects(Option<Vec<Subject>>),
     UpdateIssues(Option<Vec<Issue>>),
+    RestoreVersion(String),
     ChangeRoute(AppRoute),
 }
 
@@ -139,6 +163,7 @@ impl Component for WorkComponent {
 
     fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
         let fetch_work: FetchWork = Default::default();
+        let fetch_imprints: FetchImprints = Default::default();
         let push_work = Default::default();
         let delete_work = Default::default();
         let notification_bus = NotificationBus::dispatcher();
@@ -147,13 +172,18 @@ impl Component for WorkComponent {
         let router = RouteAgentDispatcher::new();
 
         link.send_message(Msg::GetWork);
+        link.send_message(Msg::GetImprints);
 
         WorkComponent {
             work,
             data,
             fetch_work,
+            fetch_imprints,
             push_work,
             delete_work,
+            doi_error: None,
+            short_doi_error: None,
+            save_count: 0,
             link,
             router,
             notification_bus,
@@ -173,7 +203,6 @@ impl Component for WorkComponent {
                             Some(w) => w.to_owned(),
                             None => Default::default(),
                         };
-                        self.data.imprints = body.data.imprints.to_owned();
                         self.data.work_types = body.data.work_types.enum_values.to_owned();
                         self.data.work_statuses = body.data.work_statuses.enum_values.to_owned();
 
@@ -198,7 +227,6 @@ impl Component for WorkComponent {
                 let body = WorkRequestBody {
                     variables: Variables {
                         work_id: Some(self.props.work_id.clone()),
-                        publishers: self.props.current_user.resource_access.restricted_to(),
                     },
                     ..Default::default()
                 };
@@ -211,6 +239,25 @@ impl Component for WorkComponent {
                     .send_message(Msg::SetWorkFetchState(FetchAction::Fetching));
                 false
             }
+            Msg::SetImprintsFetchState(fetch_state) => {
+                self.fetch_imprints.apply(fetch_state);
+                match self.fetch_imprints.as_ref().state() {
+                    FetchState::Fetched(body) if !body.data.imprints.is_empty() => {
+                        // Keep requesting pages until one comes back empty
+                        self.data
+                            .imprints
+                            .extend(body.data.imprints.iter().cloned());
+                        self.fetch_imprints_page();
+                        true
+                    }
+                    _ => false,
+                }
+            }
+            Msg::GetImprints => {
+                self.data.imprints.clear();
+                self.fetch_imprints_page();
+                true
+            }
             Msg::SetWorkPushState(fetch_state) => {
                 self.push_work.apply(fetch_state);
                 match self.push_work.as_ref().state() {
@@ -218,17 +265,22 @@ impl Component for WorkComponent {
                     FetchState::Fetching(_) => false,
                     FetchState::Fetched(body) => match &body.data.update_work {
                         Some(w) => {
-                            self.notification_bus.send(Request::NotificationBusMsg((
-                                format!("Saved {}", w.title),
-                                NotificationStatus::Success,
-                            )));
+                            self.notification_bus.send(Request::NotificationBusMsg(
+                                saved_notification(&w.title, &w.warnings),
+                            ));
+                            self.save_count += 1;
+                            self.work.version = Some(w.version.clone());
                             true
                         }
                         None => {
-                            self.notification_bus.send(Request::NotificationBusMsg((
-                                "Failed to save".to_string(),
-                                NotificationStatus::Danger,
-                            )));
+                            if body.has_error_type("STALE_DATA") {
+                                if DialogService::confirm(t!("stale_data_prompt")) {
+                                    self.link.send_message(Msg::GetWork);
+                                }
+                            } else {
+                                self.notification_bus
+                                    .send(Request::NotificationBusMsg(body.failure_notification()));
+                            }
                             false
                         }
                     },
@@ -242,6 +294,14 @@ impl Component for WorkComponent {
                 }
             }
             Msg::UpdateWork => {
+                if let Err(e) = validate_optional::<Doi>(&self.work.doi) {
+                    self.doi_error = Some(e.to_string());
+                    return true;
+                }
+                if let Err(e) = validate_optional::<ShortDoi>(&self.work.short_doi) {
+                    self.short_doi_error = Some(e.to_string());
+                    return true;
+                }
                 let body = UpdateWorkRequestBody {
                     variables: UpdateVariables {
                         work_id: self.work.work_id.clone(),
@@ -254,6 +314,7 @@ impl Component for WorkComponent {
                         edition: self.work.edition,
                         imprint_id: self.work.imprint.imprint_id.clone(),
                         doi: self.work.doi.clone(),
+                        short_doi: self.work.short_doi.clone(),
                         publication_date: self.work.publication_date.clone(),
                         place: self.work.place.clone(),
                         width: self.work.width,
@@ -275,6 +336,7 @@ impl Component for WorkComponent {
                         toc: self.work.toc.clone(),
                         cover_url: self.work.cover_url.clone(),
                         cover_caption: self.work.cover_caption.clone(),
+                        expected_version: self.work.version.clone(),
                     },
                     ..Default::default()
                 };
@@ -302,10 +364,8 @@ impl Component for WorkComponent {
                             true
                         }
                         None => {
-                            self.notification_bus.send(Request::NotificationBusMsg((
-                                "Failed to save".to_string(),
-                                NotificationStatus::Danger,
-                            )));
+                            self.notification_bus
+                                .send(Request::NotificationBusMsg(body.failure_notification()));
                             false
                         }
                     },
@@ -381,6 +441,11 @@ impl Component for WorkComponent {
                 let edition: i32 = edition.parse().unwrap_or(1);
                 self.work.edition.neq_assign(edition)
             }
+            Msg::ValidateDoi => self.doi_error.neq_assign(
+                validate_optional::<Doi>(&self.work.doi)
+                    .err()
+                    .map(|e| e.to_string()),
+            ),
             Msg::ChangeDoi(value) => {
                 let doi = match value.trim().is_empty() {
                     true => None,
@@ -388,6 +453,18 @@ impl Component for WorkComponent {
                 };
                 self.work.doi.neq_assign(doi)
             }
+            Msg::ValidateShortDoi => self.short_doi_error.neq_assign(
+                validate_optional::<ShortDoi>(&self.work.short_doi)
+                    .err()
+                    .map(|e| e.to_string()),
+            ),
+            Msg::ChangeShortDoi(value) => {
+                let short_doi = match value.trim().is_empty() {
+                    true => None,
+                    false => Some(value.trim().to_owned()),
+                };
+                self.work.short_doi.neq_assign(short_doi)
+            }
             Msg::ChangeDate(date) => self.work.publication_date.neq_assign(Some(date)),
             Msg::ChangePlace(value) => {
                 let place = match value.trim().is_empty() {
@@ -543,6 +620,58 @@ impl Component for WorkComponent {
             Msg::UpdateLanguages(languages) => self.work.languages.neq_assign(languages),
             Msg::UpdateSubjects(subjects) => self.work.subjects.neq_assign(subjects),
             Msg::UpdateIssues(issues) => self.work.issues.neq_assign(issues),
+            Msg::RestoreVersion(snapshot) => {
+                match serde_json::from_str::<WorkSnapshot>(&snapshot) {
+                    Ok(version) => {
+                        self.work.work_type = version.work_type;
+                        self.work.work_status = version.work_status;
+                        self.work.full_title = version.full_title;
+                        self.work.title = version.title;
+                        self.work.subtitle = version.subtitle;
+                        self.work.reference = version.reference;
+                        self.work.edition = version.edition;
+                        self.work.doi = version.doi;
+                        self.work.short_doi = version.short_doi;
+                        self.work.publication_date =
+                            version.publication_date.map(|d| d.to_string());
+                        self.work.place = version.place;
+                        self.work.width = version.width;
+                        self.work.height = version.height;
+                        self.work.page_count = version.page_count;
+                        self.work.page_breakdown = version.page_breakdown;
+                        self.work.image_count = version.image_count;
+                        self.work.table_count = version.table_count;
+                        self.work.audio_count = version.audio_count;
+                        self.work.video_count = version.video_count;
+                        self.work.license = version.license;
+                        self.work.copyright_holder = version.copyright_holder;
+                        self.work.landing_page = version.landing_page;
+                        self.work.lccn = version.lccn;
+                        self.work.oclc = version.oclc;
+                        self.work.short_abstract = version.short_abstract;
+                        self.work.long_abstract = version.long_abstract;
+                        self.work.general_note = version.general_note;
+                        self.work.toc = version.toc;
+                        self.work.cover_url = version.cover_url;
+                        self.work.cover_caption = version.cover_caption;
+                        // keep the current imprint if the old one is no longer available
+                        self.link
+                            .send_message(Msg::ChangeImprint(version.imprint_id.to_string()));
+                        self.doi_error = None;
+                        self.short_doi_error = None;
+                        // saved through the usual update, so the restored record is validated again
+                        self.link.send_message(Msg::UpdateWork);
+                        true
+                    }
+                    Err(_) => {
+                        self.notification_bus.send(Request::NotificationBusMsg((
+                            "Could not read this version of the work".to_string(),
+                            NotificationStatus::Danger,
+                        )));
+                        false
+                    }
+                }
+            }
             Msg::ChangeRoute(r) => {
                 let route = Route::from(r);
                 self.router.send(RouteRequest::ChangeRoute(route));
@@ -556,8 +685,9 @@ impl Component for WorkComponent {
             self.props.current_user.resource_access != props.current_user.resource_access;
         self.props = props;
         if updated_permissions {
-            // Required in order to retrieve updated list of imprints for dropdown
             self.link.send_message(Msg::GetWork);
+            // Required in order to retrieve updated list of imprints for dropdown
+            self.link.send_message(Msg::GetImprints);
         }
         false
     }
@@ -580,6 +710,11 @@ impl Component for WorkComponent {
                                 </p>
                             </div>
                             <div class="level-right">
+                                <div class="level-item">
+                                    <ExportDropdownComponent
+                                        work_id=&self.work.work_id
+                                    />
+                                </div>
                                 <p class="level-item">
                                     <ConfirmDeleteComponent
                                         onclick=self.link.callback(|_| Msg::DeleteWork)
@@ -694,6 +829,15 @@ impl Component for WorkComponent {
                                         label = "DOI"
                                         value=&self.work.doi
                                         oninput=self.link.callback(|e: InputData| Msg::ChangeDoi(e.value))
+                                        onblur=self.link.callback(|_| Msg::ValidateDoi)
+                                        error=&self.doi_error
+                                    />
+                                    <FormUrlInput
+                                        label = "Short DOI"
+                                        value=&self.work.short_doi
+                                        oninput=self.link.callback(|e: InputData| Msg::ChangeShortDoi(e.value))
+                                        onblur=self.link.callback(|_| Msg::ValidateShortDoi)
+                                        error=&self.short_doi_error
                                     />
                                     <FormTextInput
                                         label = "LCCN"
@@ -800,7 +944,7 @@ impl Component for WorkComponent {
                             <div class="field">
                                 <div class="control">
                                     <button class="button is-success" type="submit">
-                                        { SAVE_BUTTON }
+                                        { t!("save_button") }
                                     </button>
                                 </div>
                             </div>
@@ -845,6 +989,14 @@ impl Component for WorkComponent {
                             work_id=&self.work.work_id
                             update_fundings=self.link.callback(|f: Option<Vec<Funding>>| Msg::UpdateFundings(f))
                         />
+                        <OnixPreviewComponent
+                            work_id=&self.work.work_id
+                        />
+                        <WorkHistoryComponent
+                            work_id=&self.work.work_id
+                            save_count=self.save_count
+                            restore_version=self.link.callback(|s: String| Msg::RestoreVersion(s))
+                        />
                     </>
                 }
             }
@@ -852,3 +1004,23 @@ impl Component for WorkComponent {
         }
     }
 }
+
+impl WorkComponent {
+    fn fetch_imprints_page(&mut self) {
+        let body = ImprintsRequestBody {
+            variables: ImprintsVariables {
+                offset: Some(self.data.imprints.len() as i32),
+                publishers: self.props.current_user.resource_access.restricted_to(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let request = ImprintsRequest { body };
+        self.fetch_imprints = Fetch::new(request);
+
+        self.link
+            .send_future(self.fetch_imprints.fetch(Msg::SetImprintsFetchState));
+        self.link
+            .send_message(Msg::SetImprintsFetchState(FetchAction::Fetching));
+    }
+}
@@ -0,0 +1,173 @@
+use wasm_bindgen::JsValue;
+use yew::html;
+use yew::prelude::*;
+use yew::ComponentLink;
+use yewtil::fetch::Fetch;
+use yewtil::fetch::FetchAction;
+use yewtil::fetch::FetchState;
+use yewtil::future::LinkFuture;
+
+use crate::models::work::work_history_query::FetchActionWorkHistory;
+use crate::models::work::work_history_query::FetchWorkHistory;
+use crate::models::work::work_history_query::FieldChange;
+use crate::models::work::work_history_query::Variables;
+use crate::models::work::work_history_query::WorkHistoryRequest;
+use crate::models::work::work_history_query::WorkHistoryRequestBody;
+use crate::models::work::work_history_query::WorkVersion;
+
+pub struct WorkHistoryComponent {
+    props: Props,
+    versions: Vec<WorkVersion>,
+    fetch_history: FetchWorkHistory,
+    link: ComponentLink<Self>,
+}
+
+pub enum Msg {
+    SetHistoryFetchState(FetchActionWorkHistory),
+    GetHistory,
+    RestoreVersion(String),
+}
+
+#[derive(Clone, Properties)]
+pub struct Props {
+    pub work_id: String,
+    // number of times the work has been saved on this page, to reload the history after each
+    pub save_count: usize,
+    pub restore_version: Callback<String>,
+}
+
+impl Component for WorkHistoryComponent {
+    type Message = Msg;
+    type Properties = Props;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        link.send_message(Msg::GetHistory);
+
+        WorkHistoryComponent {
+            props,
+            versions: vec![],
+            fetch_history: Default::default(),
+            link,
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::SetHistoryFetchState(fetch_state) => {
+                self.fetch_history.apply(fetch_state);
+                self.versions = match self.fetch_history.as_ref().state() {
+                    FetchState::Fetched(body) => body.data.work_history.clone(),
+                    _ => vec![],
+                };
+                true
+            }
+            Msg::GetHistory => {
+                let body = WorkHistoryRequestBody {
+                    variables: Variables {
+                        work_id: self.props.work_id.clone(),
+                    },
+                    ..Default::default()
+                };
+                let request = WorkHistoryRequest { body };
+                self.fetch_history = Fetch::new(request);
+
+                self.link
+                    .send_future(self.fetch_history.fetch(Msg::SetHistoryFetchState));
+                self.link
+                    .send_message(Msg::SetHistoryFetchState(FetchAction::Fetching));
+                false
+            }
+            Msg::RestoreVersion(snapshot) => {
+                self.props.restore_version.emit(snapshot);
+                false
+            }
+        }
+    }
+
+    fn change(&mut self, props: Self::Properties) -> ShouldRender {
+        let updated_work =
+            self.props.work_id != props.work_id || self.props.save_count != props.save_count;
+        self.props = props;
+        if updated_work {
+            self.link.send_message(Msg::GetHistory);
+        }
+        false
+    }
+
+    fn view(&self) -> Html {
+        html! {
+            <nav class="panel">
+                <p class="panel-heading">
+                    { "History" }
+                </p>
+                {
+                    if self.versions.is_empty() {
+                        html! {
+                            <div class="notification is-info is-light">
+                                { t!("empty_history") }
+                            </div>
+                        }
+                    } else {
+                        html! {
+                            { for self.versions.iter().map(|v| self.render_version(v)) }
+                        }
+                    }
+                }
+            </nav>
+        }
+    }
+}
+
+impl WorkHistoryComponent {
+    fn render_version(&self, version: &WorkVersion) -> Html {
+        let snapshot = version.snapshot.clone();
+        let timestamp: String = js_sys::Date::new(&JsValue::from_f64(version.timestamp * 1000.0))
+            .to_utc_string()
+            .into();
+        html! {
+            <div class="panel-block is-block">
+                <nav class="level">
+                    <div class="level-left">
+                        <p class="level-item">
+                            { format!("Replaced by {} on {}", version.editor, timestamp) }
+                        </p>
+                    </div>
+                    <div class="level-right">
+                        <button
+                            class="button is-small is-warning level-item"
+                            onclick=self.link.callback(move |_| Msg::RestoreVersion(snapshot.clone()))
+                        >
+                            { t!("restore_button") }
+                        </button>
+                    </div>
+                </nav>
+                <table class="table is-narrow is-fullwidth">
+                    <thead>
+                        <tr>
+                            <th>{ "Field" }</th>
+                            <th>{ "This version" }</th>
+                            <th>{ "Replaced with" }</th>
+                        </tr>
+                    </thead>
+                    <tbody>
+                        { for version.changes.iter().map(|c| self.render_change(c)) }
+                    </tbody>
+                </table>
+            </div>
+        }
+    }
+
+    fn render_change(&self, change: &FieldChange) -> Html {
+        html! {
+            <tr>
+                <td>{ &change.field }</td>
+                <td class="has-background-danger-light">
+                    { change.previous.clone().unwrap_or_default() }
+                </td>
+                <td class="has-background-success-light">
+                    { change.replacement.clone().unwrap_or_default() }
+                </td>
+            </tr>
+        }
+    }
+}
@@ -0,0 +1,399 @@
+use thoth_api::account::model::AccountDetails;
+use yew::html;
+use yew::prelude::*;
+use yew::services::reader::FileData;
+use yew::services::reader::ReaderService;
+use yew::services::reader::ReaderTask;
+use yew::ComponentLink;
+use yew_router::prelude::RouterAnchor;
+use yewtil::fetch::Fetch;
+use yewtil::fetch::FetchAction;
+use yewtil::fetch::FetchState;
+use yewtil::future::LinkFuture;
+
+use crate::agent::notification_bus::NotificationBus;
+use crate::agent::notification_bus::NotificationDispatcher;
+use crate::agent::notification_bus::NotificationStatus;
+use crate::agent::notification_bus::Request;
+use crate::component::utils::FormImprintSelect;
+use crate::models::imprint::imprints_query::FetchActionImprints;
+use crate::models::imprint::imprints_query::FetchImprints;
+use crate::models::imprint::imprints_query::ImprintsRequest;
+use crate::models::imprint::imprints_query::ImprintsRequestBody;
+use crate::models::imprint::imprints_query::Variables as ImprintsVariables;
+use crate::models::imprint::Imprint;
+use crate::models::work::create_works_mutation::CreateWorksRequest;
+use crate::models::work::create_works_mutation::CreateWorksRequestBody;
+use crate::models::work::create_works_mutation::PushActionCreateWorks;
+use crate::models::work::create_works_mutation::PushCreateWorks;
+use crate::models::work::create_works_mutation::Variables;
+use crate::models::work::create_works_mutation::WorkImportResult;
+use crate::models::work::work_import::import_rows;
+use crate::models::work::work_import::ImportRow;
+use crate::route::AdminRoute;
+use crate::route::AppRoute;
+
+const IMPORT_COLUMNS: &str = "title, subtitle, work_type, work_status, reference, edition, doi, \
+    publication_date, place, page_count, license, copyright_holder, landing_page, \
+    short_abstract, long_abstract, cover_url, and an ISBN and URL for each publication \
+    (paperback_isbn, paperback_url, hardback_isbn, pdf_isbn, pdf_url, epub_isbn, ...)";
+
+pub struct WorkImportComponent {
+    imprint_id: String,
+    csv_text: Option<String>,
+    rows: Vec<ImportRow>,
+    parse_error: Option<String>,
+    // row numbers of the works sent in the last import, in the order they were sent
+    submitted_rows: Vec<usize>,
+    results: Vec<WorkImportResult>,
+    reader_task: Option<ReaderTask>,
+    push_works: PushCreateWorks,
+    data: ImportFormData,
+    fetch_imprints: FetchImprints,
+    link: ComponentLink<Self>,
+    notification_bus: NotificationDispatcher,
+    props: Props,
+}
+
+#[derive(Default)]
+struct ImportFormData {
+    imprints: Vec<Imprint>,
+}
+
+pub enum Msg {
+    SetImprintsFetchState(FetchActionImprints),
+    GetImprints,
+    ChangeImprint(String),
+    ReadFile(ChangeData),
+    SetFileContent(FileData),
+    SetWorksPushState(PushActionCreateWorks),
+    CreateWorks,
+}
+
+#[derive(Clone, Properties)]
+pub struct Props {
+    pub current_user: AccountDetails,
+}
+
+impl Component for WorkImportComponent {
+    type Message = Msg;
+    type Properties = Props;
+
+    fn create(props: Self::Properties, link: ComponentLink<Self>) -> Self {
+        let notification_bus = NotificationBus::dispatcher();
+
+        link.send_message(Msg::GetImprints);
+
+        WorkImportComponent {
+            imprint_id: Default::default(),
+            csv_text: None,
+            rows: vec![],
+            parse_error: None,
+            submitted_rows: vec![],
+            results: vec![],
+            reader_task: None,
+            push_works: Default::default(),
+            data: Default::default(),
+            fetch_imprints: Default::default(),
+            link,
+            notification_bus,
+            props,
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> ShouldRender {
+        match msg {
+            Msg::SetImprintsFetchState(fetch_state) => {
+                self.fetch_imprints.apply(fetch_state);
+                self.data.imprints = match self.fetch_imprints.as_ref().state() {
+                    FetchState::NotFetching(_) => vec![],
+                    FetchState::Fetching(_) => vec![],
+                    FetchState::Fetched(body) => body.data.imprints.clone(),
+                    FetchState::Failed(_, _err) => vec![],
+                };
+                true
+            }
+            Msg::GetImprints => {
+                let body = ImprintsRequestBody {
+                    variables: ImprintsVariables {
+                        publishers: self.props.current_user.resource_access.restricted_to(),
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                };
+                let request = ImprintsRequest { body };
+                self.fetch_imprints = Fetch::new(request);
+                self.link
+                    .send_future(self.fetch_imprints.fetch(Msg::SetImprintsFetchState));
+                self.link
+                    .send_message(Msg::SetImprintsFetchState(FetchAction::Fetching));
+                false
+            }
+            Msg::ChangeImprint(imprint_id) => {
+                self.imprint_id = imprint_id;
+                self.map_rows();
+                true
+            }
+            Msg::ReadFile(data) => {
+                if let ChangeData::Files(files) = data {
+                    if let Some(file) = files.get(0) {
+                        let callback = self.link.callback(Msg::SetFileContent);
+                        match ReaderService::read_file(file, callback) {
+                            Ok(task) => self.reader_task = Some(task),
+                            Err(e) => self.notification_bus.send(Request::NotificationBusMsg((
+                                e.to_string(),
+                                NotificationStatus::Danger,
+                            ))),
+                        }
+                    }
+                }
+                false
+            }
+            Msg::SetFileContent(file) => {
+                self.reader_task = None;
+                match String::from_utf8(file.content) {
+                    Ok(text) => self.csv_text = Some(text),
+                    Err(_) => {
+                        self.csv_text = None;
+                        self.notification_bus.send(Request::NotificationBusMsg((
+                            format!("{} is not a UTF-8 encoded CSV file", file.name),
+                            NotificationStatus::Danger,
+                        )));
+                    }
+                }
+                self.map_rows();
+                true
+            }
+            Msg::SetWorksPushState(fetch_state) => {
+                self.push_works.apply(fetch_state);
+                match self.push_works.as_ref().state() {
+                    FetchState::NotFetching(_) => false,
+                    FetchState::Fetching(_) => false,
+                    FetchState::Fetched(body) => {
+                        self.results = body.data.create_works.clone();
+                        let created = self.results.iter().filter(|r| r.work_id.is_some()).count();
+                        let rejected = self.results.len() - created;
+                        let status = match rejected {
+                            0 => NotificationStatus::Success,
+                            _ => NotificationStatus::Warning,
+                        };
+                        self.notification_bus.send(Request::NotificationBusMsg((
+                            format!("Imported {} works, {} rejected", created, rejected),
+                            status,
+                        )));
+                        true
+                    }
+                    FetchState::Failed(_, err) => {
+                        self.notification_bus.send(Request::NotificationBusMsg((
+                            err.to_string(),
+                            NotificationStatus::Danger,
+                        )));
+                        false
+                    }
+                }
+            }
+            Msg::CreateWorks => {
+                let valid_rows: Vec<&ImportRow> =
+                    self.rows.iter().filter(|row| row.is_valid()).collect();
+                self.submitted_rows = valid_rows.iter().map(|row| row.row_number).collect();
+                self.results = vec![];
+                let body = CreateWorksRequestBody {
+                    variables: Variables {
+                        data: valid_rows.iter().map(|row| row.work.clone()).collect(),
+                    },
+                    ..Default::default()
+                };
+                let request = CreateWorksRequest { body };
+                self.push_works = Fetch::new(request);
+                self.link
+                    .send_future(self.push_works.fetch(Msg::SetWorksPushState));
+                self.link
+                    .send_message(Msg::SetWorksPushState(FetchAction::Fetching));
+                true
+            }
+        }
+    }
+
+    fn change(&mut self, _props: Self::Properties) -> ShouldRender {
+        false
+    }
+
+    fn view(&self) -> Html {
+        let valid_count = self.rows.iter().filter(|row| row.is_valid()).count();
+        let can_import = valid_count > 0 && !self.imprint_id.is_empty();
+        html! {
+            <>
+                <nav class="level">
+                    <div class="level-left">
+                        <p class="subtitle is-5">
+                            { "Import works" }
+                        </p>
+                    </div>
+                    <div class="level-right" />
+                </nav>
+                <article class="message is-info">
+                    <div class="message-body">
+                        { "Upload a CSV file whose first row names its columns. Recognised columns: " }
+                        { IMPORT_COLUMNS }
+                    </div>
+                </article>
+                <FormImprintSelect
+                    label = "Imprint"
+                    value=&self.imprint_id
+                    data=&self.data.imprints
+                    onchange=self.link.callback(|event| match event {
+                        ChangeData::Select(elem) => Msg::ChangeImprint(elem.value()),
+                        _ => unreachable!(),
+                    })
+                    required = true
+                />
+                <div class="field">
+                    <div class="file">
+                        <label class="file-label">
+                            <input
+                                class="file-input"
+                                type="file"
+                                accept=".csv,text/csv"
+                                onchange=self.link.callback(Msg::ReadFile)
+                            />
+                            <span class="file-cta">
+                                <span class="file-icon">
+                                    <i class="fas fa-upload"></i>
+                                </span>
+                                <span class="file-label">{ "Choose a CSV file…" }</span>
+                            </span>
+                        </label>
+                    </div>
+                </div>
+                {
+                    match &self.parse_error {
+                        Some(error) => html! {
+                            <div class="notification is-danger is-light">{ error }</div>
+                        },
+                        None => html! {},
+                    }
+                }
+                {
+                    if self.rows.is_empty() {
+                        html! {}
+                    } else {
+                        html! {
+                            <>
+                                <table class="table is-fullwidth is-hoverable">
+                                    <thead>
+                                        <tr>
+                                            <th>{ "Row" }</th>
+                                            <th>{ "Title" }</th>
+                                            <th>{ "Type" }</th>
+                                            <th>{ "Publications" }</th>
+                                            <th>{ "Status" }</th>
+                                        </tr>
+                                    </thead>
+                                    <tbody>
+                                        { for self.rows.iter().map(|row| self.render_row(row)) }
+                                    </tbody>
+                                </table>
+                                <div class="field">
+                                    <div class="control">
+                                        <button
+                                            class="button is-success"
+                                            disabled=!can_import
+                                            onclick=self.link.callback(|_| Msg::CreateWorks)
+                                        >
+                                            { format!("Import {} of {} works", valid_count, self.rows.len()) }
+                                        </button>
+                                    </div>
+                                </div>
+                            </>
+                        }
+                    }
+                }
+            </>
+        }
+    }
+}
+
+impl WorkImportComponent {
+    fn map_rows(&mut self) {
+        self.submitted_rows = vec![];
+        self.results = vec![];
+        match self
+            .csv_text
+            .as_ref()
+            .map(|text| import_rows(text, &self.imprint_id))
+        {
+            Some(Ok(rows)) => {
+                self.rows = rows;
+                self.parse_error = None;
+            }
+            Some(Err(e)) => {
+                self.rows = vec![];
+                self.parse_error = Some(e);
+            }
+            None => {
+                self.rows = vec![];
+                self.parse_error = None;
+            }
+        }
+    }
+
+    fn result(&self, row: &ImportRow) -> Option<&WorkImportResult> {
+        let position = self
+            .submitted_rows
+            .iter()
+            .position(|row_number| *row_number == row.row_number)?;
+        self.results.iter().find(|r| r.row as usize == position)
+    }
+
+    fn render_row(&self, row: &ImportRow) -> Html {
+        let result = self.result(row);
+        let rejected = !row.is_valid() || result.map_or(false, |r| r.work_id.is_none());
+        let class = match rejected {
+            true => "has-background-danger-light",
+            false => "",
+        };
+        let publications: Vec<String> = row
+            .work
+            .publications
+            .iter()
+            .map(|p| p.publication_type.to_string())
+            .collect();
+        html! {
+            <tr class=class>
+                <td>{ row.row_number }</td>
+                <td>{ &row.work.work.full_title }</td>
+                <td>{ &row.work.work.work_type }</td>
+                <td>{ publications.join(", ") }</td>
+                <td>
+                {
+                    match result {
+                        Some(WorkImportResult { work_id: Some(work_id), .. }) => html! {
+                            <RouterAnchor<AppRoute>
+                                route=AppRoute::Admin(AdminRoute::Work(work_id.clone()))
+                            >
+                                { "Created" }
+                            </RouterAnchor<AppRoute>>
+                        },
+                        Some(WorkImportResult { error_type, error_message, .. }) => html! {
+                            <span class="has-text-danger">
+                                {
+                                    format!(
+                                        "Rejected ({}): {}",
+                                        error_type.clone().unwrap_or_default(),
+                                        error_message.clone().unwrap_or_default()
+                                    )
+                                }
+                            </span>
+                        },
+                        None => html! {
+                            <ul class="has-text-danger">
+                                { for row.errors.iter().map(|e| html! {<li>{ e }</li>}) }
+                            </ul>
+                        },
+                    }
+                }
+                </td>
+            </tr>
+        }
+    }
+}
@@ -15,7 +15,7 @@ pagination_component! {
     FetchWorks,
     WorksRequestBody,
     Variables,
-    SEARCH_WORKS,
-    PAGINATION_COUNT_WORKS,
+    "search_works",
+    "pagination_count_works",
     vec!["ID".to_string(), "Title".to_string(), "Type".to_string(), "Contributors".to_string(), "DOI".to_string(), "Publisher".to_string()]
 }
@@ -0,0 +1,105 @@
+use std::cell::Cell;
+use yew::format::Text;
+use yew::services::storage::Area;
+use yew::services::storage::StorageService;
+
+use crate::string::ENGLISH;
+use crate::string::SPANISH;
+use crate::LOCALE_KEY;
+
+/// Look up a string of the app by its key in the active locale, e.g. `t!("save_button")`
+#[macro_export]
+macro_rules! t {
+    ($key:expr) => {
+        $crate::i18n::translate($key)
+    };
+}
+
+/// A language the app can be shown in
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Locale {
+    English,
+    Spanish,
+}
+
+/// Locales offered in the language switch, in the order they are listed
+pub const LOCALES: [Locale; 2] = [Locale::English, Locale::Spanish];
+
+impl Locale {
+    /// Language tag the choice of locale is remembered by
+    pub fn code(&self) -> &'static str {
+        match self {
+            Locale::English => "en",
+            Locale::Spanish => "es",
+        }
+    }
+
+    /// Name of the language, in that language
+    pub fn name(&self) -> &'static str {
+        match self {
+            Locale::English => "English",
+            Locale::Spanish => "Español",
+        }
+    }
+
+    pub fn from_code(code: &str) -> Option<Self> {
+        LOCALES.iter().copied().find(|locale| locale.code() == code)
+    }
+
+    fn strings(&self) -> &'static [(&'static str, &'static str)] {
+        match self {
+            Locale::English => ENGLISH,
+            Locale::Spanish => SPANISH,
+        }
+    }
+}
+
+impl Default for Locale {
+    fn default() -> Locale {
+        Locale::English
+    }
+}
+
+thread_local! {
+    static LOCALE: Cell<Locale> = Cell::new(stored_locale());
+}
+
+// Falls back to English rather than failing if local storage is unavailable, as the strings
+// needed to report the failure depend on the locale
+fn stored_locale() -> Locale {
+    StorageService::new(Area::Local)
+        .ok()
+        .and_then(|storage| {
+            let code: Text = storage.restore(LOCALE_KEY);
+            code.ok()
+        })
+        .and_then(|code| Locale::from_code(&code))
+        .unwrap_or_default()
+}
+
+/// The locale the app is being shown in
+pub fn locale() -> Locale {
+    LOCALE.with(|locale| locale.get())
+}
+
+/// Show the app in another locale from now on, remembering the choice for later visits.
+/// Components that have already rendered keep their strings until they render again.
+pub fn set_locale(locale: Locale) {
+    let mut storage = StorageService::new(Area::Local).expect(t!("storage_error"));
+    storage.store(LOCALE_KEY, Ok(locale.code().to_string()));
+    LOCALE.with(|active| active.set(locale));
+}
+
+/// The string with the given key in the active locale, or in English if the locale lacks it.
+/// Unknown keys are returned as they are, so that they stand out on the page.
+pub fn translate(key: &'static str) -> &'static str {
+    let find = |strings: &'static [(&'static str, &'static str)]| {
+        strings
+            .iter()
+            .find(|(name, _)| *name == key)
+            .map(|(_, content)| *content)
+    };
+    find(locale().strings())
+        .or_else(|| find(ENGLISH))
+        .unwrap_or(key)
+}
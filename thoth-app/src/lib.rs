@@ -1,8 +1,12 @@
 #![recursion_limit = "2048"]
 
+use std::cell::RefCell;
 use std::env;
 use wasm_bindgen::prelude::*;
 
+// declared first so that `t!` can be used in every other module
+#[macro_use]
+mod i18n;
 mod agent;
 #[macro_use]
 mod component;
@@ -13,8 +17,24 @@ mod string;
 
 use crate::component::root::RootComponent;
 
-pub const THOTH_API: &str = env!("THOTH_API");
+/// API endpoint the app was built against, used unless `/config.json` names another
+const DEFAULT_THOTH_API: &str = env!("THOTH_API");
 const SESSION_KEY: &str = "thoth.token";
+const LOCALE_KEY: &str = "thoth.locale";
+
+thread_local! {
+    static THOTH_API: RefCell<String> = RefCell::new(DEFAULT_THOTH_API.to_string());
+}
+
+/// Base URL of the Thoth API the app talks to
+pub fn api_url() -> String {
+    THOTH_API.with(|api| api.borrow().clone())
+}
+
+/// Point the app at another API. Only meant to be called at startup, before any request is made.
+pub fn set_api_url(url: String) {
+    THOTH_API.with(|api| *api.borrow_mut() = url);
+}
 
 #[wasm_bindgen]
 pub fn run_app() -> Result<(), JsValue> {
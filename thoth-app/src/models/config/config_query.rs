@@ -0,0 +1,36 @@
+use yewtil::fetch::Fetch;
+use yewtil::fetch::FetchAction;
+use yewtil::fetch::FetchRequest;
+use yewtil::fetch::Json;
+use yewtil::fetch::MethodBody;
+
+use super::RuntimeConfig;
+
+pub type FetchConfig = Fetch<ConfigRequest, RuntimeConfig>;
+pub type FetchActionConfig = FetchAction<RuntimeConfig>;
+
+#[derive(Default, Debug, Clone)]
+pub struct ConfigRequest {}
+
+impl FetchRequest for ConfigRequest {
+    type RequestBody = ();
+    type ResponseBody = RuntimeConfig;
+    type Format = Json;
+
+    // Served by whatever serves the app, not by the API, hence the relative URL
+    fn url(&self) -> String {
+        "/config.json".to_string()
+    }
+
+    fn method(&self) -> MethodBody<Self::RequestBody> {
+        MethodBody::Get
+    }
+
+    fn headers(&self) -> Vec<(String, String)> {
+        vec![]
+    }
+
+    fn use_cors(&self) -> bool {
+        false
+    }
+}
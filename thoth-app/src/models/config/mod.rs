@@ -0,0 +1,11 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+/// Settings read at startup from `/config.json`, so that one build of the app can be deployed
+/// against different APIs. Anything left out keeps the value the app was built with.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct RuntimeConfig {
+    pub thoth_api: Option<String>,
+}
+
+pub mod config_query;
@@ -13,7 +13,7 @@ pub const CONTRIBUTORS_QUERY: &str = "
             orcid
             website
         }
-        contributorCount(filter: $filter)
+        contributorCount: contributorTotal(filter: $filter)
     }
 ";
 
@@ -42,5 +42,6 @@ pub struct Variables {
 #[serde(rename_all = "camelCase")]
 pub struct ContributorsResponseData {
     pub contributors: Vec<Contributor>,
+    #[serde(deserialize_with = "crate::models::count_from_digits")]
     pub contributor_count: i32,
 }
@@ -0,0 +1,36 @@
+use yewtil::fetch::Fetch;
+use yewtil::fetch::FetchAction;
+use yewtil::fetch::FetchRequest;
+use yewtil::fetch::Json;
+use yewtil::fetch::MethodBody;
+
+use super::ExportFormat;
+use crate::api_url;
+
+pub type FetchFormats = Fetch<FormatsRequest, Vec<ExportFormat>>;
+pub type FetchActionFormats = FetchAction<Vec<ExportFormat>>;
+
+#[derive(Default, Debug, Clone)]
+pub struct FormatsRequest {}
+
+impl FetchRequest for FormatsRequest {
+    type RequestBody = ();
+    type ResponseBody = Vec<ExportFormat>;
+    type Format = Json;
+
+    fn url(&self) -> String {
+        format!("{}/formats", api_url())
+    }
+
+    fn method(&self) -> MethodBody<Self::RequestBody> {
+        MethodBody::Get
+    }
+
+    fn headers(&self) -> Vec<(String, String)> {
+        vec![]
+    }
+
+    fn use_cors(&self) -> bool {
+        true
+    }
+}
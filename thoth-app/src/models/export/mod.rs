@@ -0,0 +1,47 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct ExportFormat {
+    pub id: String,
+    pub name: String,
+    pub path: String,
+    pub content_type: String,
+    pub validates: bool,
+    pub per_publisher: bool,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct ValidationIssue {
+    pub field: String,
+    pub severity: String,
+    pub message: String,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct ValidationReport {
+    pub work_id: String,
+    pub format_id: String,
+    pub valid: bool,
+    pub issues: Vec<ValidationIssue>,
+}
+
+impl ValidationReport {
+    /// Messages of the issues that prevent the work from being exported
+    pub fn errors(&self) -> Vec<String> {
+        self.issues
+            .iter()
+            .filter(|i| i.severity == "error")
+            .map(|i| format!("{}: {}", i.field, i.message))
+            .collect()
+    }
+}
+
+impl ExportFormat {
+    pub fn url(&self, work_id: &str) -> String {
+        format!("{}/{}/{}", crate::api_url(), self.path, work_id)
+    }
+}
+
+pub mod formats_query;
+pub mod validation_query;
@@ -0,0 +1,40 @@
+use yewtil::fetch::Fetch;
+use yewtil::fetch::FetchAction;
+use yewtil::fetch::FetchRequest;
+use yewtil::fetch::Json;
+use yewtil::fetch::MethodBody;
+
+use super::ExportFormat;
+use super::ValidationReport;
+
+pub type FetchValidation = Fetch<ValidationRequest, ValidationReport>;
+pub type FetchActionValidation = FetchAction<ValidationReport>;
+
+/// Dry run of an export, reporting whether the work can be exported in the given format
+#[derive(Default, Debug, Clone)]
+pub struct ValidationRequest {
+    pub format: ExportFormat,
+    pub work_id: String,
+}
+
+impl FetchRequest for ValidationRequest {
+    type RequestBody = ();
+    type ResponseBody = ValidationReport;
+    type Format = Json;
+
+    fn url(&self) -> String {
+        format!("{}?validate=true", self.format.url(&self.work_id))
+    }
+
+    fn method(&self) -> MethodBody<Self::RequestBody> {
+        MethodBody::Get
+    }
+
+    fn headers(&self) -> Vec<(String, String)> {
+        vec![]
+    }
+
+    fn use_cors(&self) -> bool {
+        true
+    }
+}
@@ -0,0 +1,49 @@
+use thoth_api::funder::model::FunderCandidate;
+use yewtil::fetch::Fetch;
+use yewtil::fetch::FetchAction;
+use yewtil::fetch::FetchRequest;
+use yewtil::fetch::Json;
+use yewtil::fetch::MethodBody;
+
+use crate::api_url;
+
+pub type FetchFunderRegistry = Fetch<FunderRegistryRequest, Vec<FunderCandidate>>;
+pub type FetchActionFunderRegistry = FetchAction<Vec<FunderCandidate>>;
+
+#[derive(Default, Debug, Clone)]
+pub struct FunderRegistryRequest {
+    pub name: String,
+}
+
+impl FetchRequest for FunderRegistryRequest {
+    type RequestBody = ();
+    type ResponseBody = Vec<FunderCandidate>;
+    type Format = Json;
+
+    fn url(&self) -> String {
+        format!(
+            "{}/funder/registry?name={}",
+            api_url(),
+            String::from(js_sys::encode_uri_component(&self.name))
+        )
+    }
+
+    fn method(&self) -> MethodBody<Self::RequestBody> {
+        MethodBody::Get
+    }
+
+    fn headers(&self) -> Vec<(String, String)> {
+        use crate::service::account::AccountService;
+
+        let account_service = AccountService::new();
+        if let Some(token) = account_service.get_token() {
+            vec![("Authorization".into(), format!("Bearer {}", token))]
+        } else {
+            vec![]
+        }
+    }
+
+    fn use_cors(&self) -> bool {
+        true
+    }
+}
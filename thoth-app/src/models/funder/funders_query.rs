@@ -10,7 +10,7 @@ pub const FUNDERS_QUERY: &str = "
             funderName
             funderDoi
         }
-        funderCount(filter: $filter)
+        funderCount: funderTotal(filter: $filter)
     }
 ";
 
@@ -39,5 +39,6 @@ pub struct Variables {
 #[serde(rename_all = "camelCase")]
 pub struct FundersResponseData {
     pub funders: Vec<Funder>,
+    #[serde(deserialize_with = "crate::models::count_from_digits")]
     pub funder_count: i32,
 }
@@ -61,5 +61,6 @@ pub mod create_funder_mutation;
 pub mod delete_funder_mutation;
 pub mod funder_activity_query;
 pub mod funder_query;
+pub mod funder_registry_query;
 pub mod funders_query;
 pub mod update_funder_mutation;
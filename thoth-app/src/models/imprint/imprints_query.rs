@@ -14,9 +14,10 @@ const IMPRINTS_QUERY: &str = "
                 publisherName
                 publisherShortname
                 publisherUrl
+                defaultImprintId
             }
         }
-        imprintCount(filter: $filter, publishers: $publishers)
+        imprintCount: imprintTotal(filter: $filter, publishers: $publishers)
     }
 ";
 
@@ -44,5 +45,6 @@ pub struct Variables {
 #[serde(rename_all = "camelCase")]
 pub struct ImprintsResponseData {
     pub imprints: Vec<Imprint>,
+    #[serde(deserialize_with = "crate::models::count_from_digits")]
     pub imprint_count: i32,
 }
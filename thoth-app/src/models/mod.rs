@@ -16,7 +16,7 @@ macro_rules! graphql_query_builder {
         use yewtil::fetch::Json;
         use yewtil::fetch::MethodBody;
 
-        use crate::THOTH_API;
+        use crate::api_url;
 
         pub type $fetch = Fetch<$request, $response_body>;
         pub type $fetch_action = FetchAction<$response_body>;
@@ -34,7 +34,27 @@ macro_rules! graphql_query_builder {
 
         #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
         pub struct $response_body {
+            // A failed non-nullable field nulls the whole of `data`
+            #[serde(default, deserialize_with = "crate::models::null_as_default")]
             pub data: $response_data,
+            #[serde(default)]
+            pub errors: Vec<crate::models::GraphqlError>,
+        }
+
+        impl $response_body {
+            /// Notification explaining why the request did not return the expected data
+            pub fn failure_notification(
+                &self,
+            ) -> (String, crate::agent::notification_bus::NotificationStatus) {
+                crate::models::failure_notification(&self.errors)
+            }
+
+            /// Whether the API reported an error of the given type, e.g. `STALE_DATA`
+            pub fn has_error_type(&self, error_type: &str) -> bool {
+                self.errors
+                    .iter()
+                    .any(|e| e.extensions.error_type.as_deref() == Some(error_type))
+            }
         }
 
         impl FetchRequest for $request {
@@ -43,7 +63,7 @@ macro_rules! graphql_query_builder {
             type Format = Json;
 
             fn url(&self) -> String {
-                format!("{}/graphql", THOTH_API)
+                format!("{}/graphql", api_url())
             }
 
             fn method(&self) -> MethodBody<Self::RequestBody> {
@@ -81,14 +101,76 @@ macro_rules! graphql_query_builder {
             fn default() -> $response_body {
                 $response_body {
                     data: Default::default(),
+                    errors: vec![],
                 }
             }
         }
     };
 }
 
+use serde::Deserialize;
+use serde::Deserializer;
+use serde::Serialize;
+
+use crate::agent::notification_bus::NotificationStatus;
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct GraphqlErrorExtensions {
+    #[serde(rename = "type")]
+    pub error_type: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct GraphqlError {
+    pub message: String,
+    #[serde(default)]
+    pub extensions: GraphqlErrorExtensions,
+}
+
+pub fn null_as_default<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de> + Default,
+{
+    Ok(Option::deserialize(deserializer)?.unwrap_or_default())
+}
+
+/// Read a `Count`, which the API gives as a string of decimal digits. Counts are paginated as
+/// `Int`s here, so one too large for an `i32` is capped rather than rejected.
+pub fn count_from_digits<'de, D>(deserializer: D) -> Result<i32, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let count: i64 = String::deserialize(deserializer)?
+        .parse()
+        .map_err(serde::de::Error::custom)?;
+    Ok(count.min(i64::from(i32::MAX)) as i32)
+}
+
+/// Describe a failed request using the error type reported by the API
+pub fn failure_notification(errors: &[GraphqlError]) -> (String, NotificationStatus) {
+    match errors.first() {
+        Some(error) => match error.extensions.error_type.as_deref() {
+            Some("NO_ACCESS") => (
+                t!("permission_error").to_string(),
+                NotificationStatus::Warning,
+            ),
+            Some("INTERNAL_ERROR") | None => {
+                (t!("save_error").to_string(), NotificationStatus::Danger)
+            }
+            Some(_) => (
+                format!("{}: {}", t!("save_error"), error.message),
+                NotificationStatus::Danger,
+            ),
+        },
+        None => (t!("save_error").to_string(), NotificationStatus::Danger),
+    }
+}
+
+pub mod config;
 pub mod contribution;
 pub mod contributor;
+pub mod export;
 pub mod funder;
 pub mod funding;
 pub mod imprint;
@@ -1,6 +1,7 @@
 use serde::Deserialize;
 use serde::Serialize;
 use thoth_api::price::model::CurrencyCode;
+use thoth_api::price::model::PriceType;
 
 use super::Price;
 
@@ -8,17 +9,23 @@ const CREATE_PRICE_MUTATION: &str = "
     mutation CreatePrice(
         $publicationId: Uuid!,
         $currencyCode: CurrencyCode!,
-        $unitPrice: Float!
+        $unitPrice: Float!,
+        $priceType: PriceType!,
+        $territory: String!
     ) {
         createPrice(data: {
             publicationId: $publicationId
             currencyCode: $currencyCode
             unitPrice: $unitPrice
+            priceType: $priceType
+            territory: $territory
         }){
             priceId
             publicationId
             currencyCode
             unitPrice
+            priceType
+            territory
         }
     }
 ";
@@ -40,6 +47,8 @@ pub struct Variables {
     pub publication_id: String,
     pub currency_code: CurrencyCode,
     pub unit_price: f64,
+    pub price_type: PriceType,
+    pub territory: String,
 }
 
 #[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
@@ -14,6 +14,8 @@ const DELETE_PRICE_MUTATION: &str = "
             publicationId
             currencyCode
             unitPrice
+            priceType
+            territory
         }
     }
 ";
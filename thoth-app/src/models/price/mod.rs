@@ -1,6 +1,7 @@
 use serde::Deserialize;
 use serde::Serialize;
 use thoth_api::price::model::CurrencyCode;
+use thoth_api::price::model::PriceType;
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
@@ -9,6 +10,8 @@ pub struct Price {
     pub publication_id: String,
     pub currency_code: CurrencyCode,
     pub unit_price: f64,
+    pub price_type: PriceType,
+    pub territory: String,
 }
 
 #[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
@@ -23,6 +26,18 @@ pub struct CurrencyCodeValues {
     pub name: CurrencyCode,
 }
 
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct PriceTypeDefinition {
+    pub enum_values: Vec<PriceTypeValues>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct PriceTypeValues {
+    pub name: PriceType,
+}
+
 impl Default for Price {
     fn default() -> Price {
         Price {
@@ -30,6 +45,8 @@ impl Default for Price {
             publication_id: "".to_string(),
             currency_code: CurrencyCode::Gbp,
             unit_price: 0.00,
+            price_type: Default::default(),
+            territory: "WORLD".to_string(),
         }
     }
 }
@@ -37,3 +54,4 @@ impl Default for Price {
 pub mod create_price_mutation;
 pub mod currency_codes_query;
 pub mod delete_price_mutation;
+pub mod price_types_query;
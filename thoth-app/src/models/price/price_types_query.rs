@@ -0,0 +1,33 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::PriceTypeDefinition;
+
+const PRICE_TYPES_QUERY: &str = "
+    {
+        price_types: __type(name: \"PriceType\") {
+            enumValues {
+                name
+            }
+        }
+    }
+";
+
+graphql_query_builder! {
+    PriceTypesRequest,
+    PriceTypesRequestBody,
+    Variables,
+    PRICE_TYPES_QUERY,
+    PriceTypesResponseBody,
+    PriceTypesResponseData,
+    FetchPriceTypes,
+    FetchActionPriceTypes
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct Variables {}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct PriceTypesResponseData {
+    pub price_types: PriceTypeDefinition,
+}
@@ -16,6 +16,8 @@ pub const PUBLICATION_QUERY: &str = "
                 publicationId
                 currencyCode
                 unitPrice
+                priceType
+                territory
             }
             work {
                 imprint {
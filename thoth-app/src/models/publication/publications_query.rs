@@ -33,7 +33,7 @@ pub const PUBLICATIONS_QUERY: &str = "
                 }
             }
         }
-        publicationCount(filter: $filter, publishers: $publishers)
+        publicationCount: publicationTotal(filter: $filter, publishers: $publishers)
     }
 ";
 
@@ -72,5 +72,6 @@ pub struct DetailedPublication {
 #[serde(rename_all = "camelCase")]
 pub struct PublicationsResponseData {
     pub publications: Vec<DetailedPublication>,
+    #[serde(deserialize_with = "crate::models::count_from_digits")]
     pub publication_count: i32,
 }
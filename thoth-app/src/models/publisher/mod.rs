@@ -15,6 +15,7 @@ pub struct Publisher {
     pub publisher_name: String,
     pub publisher_shortname: Option<String>,
     pub publisher_url: Option<String>,
+    pub default_imprint_id: Option<String>,
 }
 
 impl Publisher {
@@ -53,6 +54,7 @@ impl Default for Publisher {
             publisher_name: "".to_string(),
             publisher_shortname: None,
             publisher_url: None,
+            default_imprint_id: None,
         }
     }
 }
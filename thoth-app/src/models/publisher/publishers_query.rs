@@ -11,7 +11,7 @@ const PUBLISHERS_QUERY: &str = "
             publisherShortname
             publisherUrl
         }
-        publisherCount(filter: $filter, publishers: $publishers)
+        publisherCount: publisherTotal(filter: $filter, publishers: $publishers)
     }
 ";
 
@@ -39,5 +39,6 @@ pub struct Variables {
 #[serde(rename_all = "camelCase")]
 pub struct PublishersResponseData {
     pub publishers: Vec<Publisher>,
+    #[serde(deserialize_with = "crate::models::count_from_digits")]
     pub publisher_count: i32,
 }
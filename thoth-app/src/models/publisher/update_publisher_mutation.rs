@@ -9,12 +9,14 @@ const UPDATE_PUBLISHER_MUTATION: &str = "
         $publisherName: String!,
         $publisherShortname: String
         $publisherUrl: String
+        $defaultImprintId: Uuid
     ) {
         updatePublisher(data: {
             publisherId: $publisherId
             publisherName: $publisherName
             publisherShortname: $publisherShortname
             publisherUrl: $publisherUrl
+            defaultImprintId: $defaultImprintId
         }){
             publisherId
             publisherName
@@ -40,6 +42,7 @@ pub struct Variables {
     pub publisher_name: String,
     pub publisher_shortname: Option<String>,
     pub publisher_url: Option<String>,
+    pub default_imprint_id: Option<String>,
 }
 
 #[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
@@ -23,7 +23,7 @@ pub const SERIESES_QUERY: &str = "
                 }
             }
         }
-        seriesCount(filter: $filter, publishers: $publishers)
+        seriesCount: seriesTotal(filter: $filter, publishers: $publishers)
     }
 ";
 
@@ -51,5 +51,6 @@ pub struct Variables {
 #[serde(rename_all = "camelCase")]
 pub struct SeriesesResponseData {
     pub serieses: Vec<Series>,
+    #[serde(deserialize_with = "crate::models::count_from_digits")]
     pub series_count: i32,
 }
@@ -3,12 +3,12 @@ use serde::Serialize;
 
 const STATS_QUERY: &str = "
     query StatsQuery($publishers: [Uuid!]) {
-        workCount(publishers: $publishers)
-        publisherCount(publishers: $publishers)
-        imprintCount(publishers: $publishers)
-        seriesCount(publishers: $publishers)
-        contributorCount
-        publicationCount(publishers: $publishers)
+        workCount: workTotal(publishers: $publishers)
+        publisherCount: publisherTotal(publishers: $publishers)
+        imprintCount: imprintTotal(publishers: $publishers)
+        seriesCount: seriesTotal(publishers: $publishers)
+        contributorCount: contributorTotal
+        publicationCount: publicationTotal(publishers: $publishers)
     }
 ";
 
@@ -31,10 +31,16 @@ pub struct Variables {
 #[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct StatsResponseData {
+    #[serde(deserialize_with = "crate::models::count_from_digits")]
     pub work_count: i32,
+    #[serde(deserialize_with = "crate::models::count_from_digits")]
     pub publisher_count: i32,
+    #[serde(deserialize_with = "crate::models::count_from_digits")]
     pub imprint_count: i32,
+    #[serde(deserialize_with = "crate::models::count_from_digits")]
     pub series_count: i32,
+    #[serde(deserialize_with = "crate::models::count_from_digits")]
     pub contributor_count: i32,
+    #[serde(deserialize_with = "crate::models::count_from_digits")]
     pub publication_count: i32,
 }
@@ -39,3 +39,4 @@ impl Default for Subject {
 pub mod create_subject_mutation;
 pub mod delete_subject_mutation;
 pub mod subject_types_query;
+pub mod suggest_keywords_query;
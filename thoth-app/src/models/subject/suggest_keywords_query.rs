@@ -0,0 +1,31 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+pub const SUGGEST_KEYWORDS_QUERY: &str = "
+    query SuggestKeywordsQuery($workId: Uuid!) {
+        suggestKeywords(workId: $workId)
+    }
+";
+
+graphql_query_builder! {
+    SuggestKeywordsRequest,
+    SuggestKeywordsRequestBody,
+    Variables,
+    SUGGEST_KEYWORDS_QUERY,
+    SuggestKeywordsResponseBody,
+    SuggestKeywordsResponseData,
+    FetchSuggestKeywords,
+    FetchActionSuggestKeywords
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Variables {
+    pub work_id: String,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct SuggestKeywordsResponseData {
+    pub suggest_keywords: Vec<String>,
+}
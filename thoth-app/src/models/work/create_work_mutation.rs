@@ -70,6 +70,7 @@ const CREATE_WORK_MUTATION: &str = "
         }){
             workId
             title
+            warnings
         }
     }
 ";
@@ -125,6 +126,7 @@ pub struct Variables {
 pub struct SlimWork {
     pub work_id: String,
     pub title: String,
+    pub warnings: Vec<String>,
 }
 
 #[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
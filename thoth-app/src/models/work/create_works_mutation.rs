@@ -0,0 +1,63 @@
+use serde::Deserialize;
+use serde::Serialize;
+use thoth_api::publication::model::PublicationType;
+
+use super::create_work_mutation::Variables as NewWork;
+
+const CREATE_WORKS_MUTATION: &str = "
+    mutation CreateWorks($data: [NewWorkImport!]!) {
+        createWorks(data: $data) {
+            row
+            workId
+            errorType
+            errorMessage
+        }
+    }
+";
+
+graphql_query_builder! {
+    CreateWorksRequest,
+    CreateWorksRequestBody,
+    Variables,
+    CREATE_WORKS_MUTATION,
+    CreateWorksResponseBody,
+    CreateWorksResponseData,
+    PushCreateWorks,
+    PushActionCreateWorks
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Variables {
+    pub data: Vec<WorkImport>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkImport {
+    pub work: NewWork,
+    pub publications: Vec<NewWorkPublication>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct NewWorkPublication {
+    pub publication_type: PublicationType,
+    pub isbn: Option<String>,
+    pub publication_url: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkImportResult {
+    pub row: i32,
+    pub work_id: Option<String>,
+    pub error_type: Option<String>,
+    pub error_message: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateWorksResponseData {
+    pub create_works: Vec<WorkImportResult>,
+}
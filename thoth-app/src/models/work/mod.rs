@@ -2,6 +2,8 @@ use serde::Deserialize;
 use serde::Serialize;
 use std::str::FromStr;
 use std::string::ParseError;
+use thoth_api::publication::model::PublicationType;
+use thoth_api::work::citation::CitationStyle;
 use thoth_api::work::model::WorkStatus;
 use thoth_api::work::model::WorkType;
 use yew::html;
@@ -16,9 +18,18 @@ use super::issue::Issue;
 use super::language::Language;
 use super::publication::Publication;
 use super::subject::Subject;
+use crate::api_url;
 use crate::route::AdminRoute;
 use crate::route::AppRoute;
-use crate::THOTH_API;
+use crate::service::clipboard::copy_to_clipboard;
+
+/// Citation styles offered for copying, in the order they are listed
+const CITATION_STYLES: [CitationStyle; 4] = [
+    CitationStyle::Apa,
+    CitationStyle::Chicago,
+    CitationStyle::Harvard,
+    CitationStyle::Mla,
+];
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
@@ -32,6 +43,7 @@ pub struct Work {
     pub reference: Option<String>,
     pub edition: i32,
     pub doi: Option<String>,
+    pub short_doi: Option<String>,
     pub publication_date: Option<String>,
     pub place: Option<String>,
     pub width: Option<i32>,
@@ -59,7 +71,22 @@ pub struct Work {
     pub fundings: Option<Vec<Funding>>,
     pub subjects: Option<Vec<Subject>>,
     pub issues: Option<Vec<Issue>>,
+    pub isbns: Option<Vec<WorkIsbn>>,
+    // citations are formatted by the API, each requested under an alias of its style
+    pub citation_apa: Option<String>,
+    pub citation_chicago: Option<String>,
+    pub citation_harvard: Option<String>,
+    pub citation_mla: Option<String>,
     pub imprint: Imprint,
+    // sent back when saving, so the API can reject the save if the work has changed since
+    pub version: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkIsbn {
+    pub isbn: String,
+    pub publication_type: PublicationType,
 }
 
 #[derive(Clone, Debug, Serialize, PartialEq)]
@@ -124,7 +151,7 @@ impl Work {
     }
 
     pub fn onix_endpoint(&self) -> String {
-        format!("{}/onix/{}", THOTH_API, &self.work_id)
+        format!("{}/onix/{}", api_url(), &self.work_id)
     }
 
     pub fn cover_alt_text(&self) -> String {
@@ -240,7 +267,12 @@ impl Work {
     }
 
     pub fn as_catalogue_box(&self) -> Html {
-        let doi = self.doi.clone().unwrap_or_else(|| "".to_string());
+        // the shortDOI resolves to the same work and is easier to read
+        let doi = self
+            .short_doi
+            .clone()
+            .or_else(|| self.doi.clone())
+            .unwrap_or_else(|| "".to_string());
         let cover_url = self
             .cover_url
             .clone()
@@ -338,6 +370,29 @@ impl Work {
                                         </div>
                                     </div>
                                 </div>
+
+                                <div class="level-item dropdown is-hoverable">
+                                    <div class="dropdown-trigger">
+                                        <button
+                                            class="button is-small"
+                                            aria-haspopup="true"
+                                            aria-controls="cite-menu"
+                                        >
+                                            <span class="icon is-small">
+                                                <i class="fas fa-quote-right" aria-hidden="true"></i>
+                                            </span>
+                                            <span>{"Cite"}</span>
+                                            <span class="icon is-small">
+                                                <i class="fas fa-angle-down" aria-hidden="true"></i>
+                                            </span>
+                                        </button>
+                                    </div>
+                                    <div class="dropdown-menu" id="cite-menu" role="menu">
+                                        <div class="dropdown-content">
+                                            { for self.copy_items().into_iter().map(|(label, text)| copy_item(label, text)) }
+                                        </div>
+                                    </div>
+                                </div>
                             </div>
                         </nav>
                     </div>
@@ -347,6 +402,49 @@ impl Work {
     }
 }
 
+impl Work {
+    /// What a reader may want to copy from the work's catalogue entry, with a label for each
+    fn copy_items(&self) -> Vec<(String, String)> {
+        let mut items = Vec::new();
+        if let Some(doi) = &self.doi {
+            items.push(("Copy DOI".to_string(), doi.clone()));
+        }
+        for isbn in self.isbns.as_deref().unwrap_or_default() {
+            items.push((
+                format!("Copy ISBN ({})", isbn.publication_type),
+                isbn.isbn.clone(),
+            ));
+        }
+        for style in &CITATION_STYLES {
+            if let Some(citation) = self.citation(*style) {
+                items.push((format!("Copy {} citation", style), citation.clone()));
+            }
+        }
+        items
+    }
+
+    /// The citation in the given style, if it was requested along with the work
+    pub fn citation(&self, style: CitationStyle) -> Option<&String> {
+        match style {
+            CitationStyle::Apa => self.citation_apa.as_ref(),
+            CitationStyle::Chicago => self.citation_chicago.as_ref(),
+            CitationStyle::Harvard => self.citation_harvard.as_ref(),
+            CitationStyle::Mla => self.citation_mla.as_ref(),
+        }
+    }
+}
+
+fn copy_item(label: String, text: String) -> Html {
+    html! {
+        <a
+            class="dropdown-item"
+            onclick=Callback::from(move |_| copy_to_clipboard(&text))
+        >
+            { label }
+        </a>
+    }
+}
+
 impl FromStr for License {
     type Err = ParseError;
 
@@ -432,6 +530,7 @@ impl Default for Work {
             reference: None,
             edition: 1,
             doi: None,
+            short_doi: None,
             publication_date: None,
             place: None,
             width: None,
@@ -459,14 +558,24 @@ impl Default for Work {
             fundings: None,
             subjects: None,
             issues: None,
+            isbns: None,
+            citation_apa: None,
+            citation_chicago: None,
+            citation_harvard: None,
+            citation_mla: None,
             imprint: Default::default(),
+            version: None,
         }
     }
 }
 
 pub mod create_work_mutation;
+pub mod create_works_mutation;
 pub mod delete_work_mutation;
 pub mod update_work_mutation;
+pub mod work_facets_query;
+pub mod work_history_query;
+pub mod work_import;
 pub mod work_query;
 pub mod work_statuses_query;
 pub mod work_types_query;
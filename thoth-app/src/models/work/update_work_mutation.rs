@@ -15,6 +15,7 @@ const UPDATE_WORK_MUTATION: &str = "
         $edition: Int!,
         $imprintId: Uuid!,
         $doi: String,
+        $shortDoi: String,
         $publicationDate: NaiveDate,
         $place: String,
         $width: Int,
@@ -35,7 +36,8 @@ const UPDATE_WORK_MUTATION: &str = "
         $generalNote: String,
         $toc: String,
         $coverUrl: String,
-        $coverCaption: String
+        $coverCaption: String,
+        $expectedVersion: String
     ) {
         updateWork(data: {
             workId: $workId
@@ -48,6 +50,7 @@ const UPDATE_WORK_MUTATION: &str = "
             edition: $edition
             imprintId: $imprintId
             doi: $doi
+            shortDoi: $shortDoi
             publicationDate: $publicationDate
             place: $place
             width: $width
@@ -69,9 +72,11 @@ const UPDATE_WORK_MUTATION: &str = "
             toc: $toc
             coverUrl: $coverUrl
             coverCaption: $coverCaption
-        }){
+        }, expectedVersion: $expectedVersion){
             workId
             title
+            warnings
+            version
         }
     }
 ";
@@ -99,6 +104,7 @@ pub struct Variables {
     pub reference: Option<String>,
     pub edition: i32,
     pub doi: Option<String>,
+    pub short_doi: Option<String>,
     pub publication_date: Option<String>,
     pub place: Option<String>,
     pub width: Option<i32>,
@@ -121,6 +127,7 @@ pub struct Variables {
     pub cover_url: Option<String>,
     pub cover_caption: Option<String>,
     pub imprint_id: String,
+    pub expected_version: Option<String>,
 }
 
 #[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
@@ -128,6 +135,8 @@ pub struct Variables {
 pub struct SlimWork {
     pub work_id: String,
     pub title: String,
+    pub version: String,
+    pub warnings: Vec<String>,
 }
 
 #[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
@@ -0,0 +1,75 @@
+use serde::Deserialize;
+use serde::Serialize;
+use thoth_api::work::model::WorkType;
+
+pub const WORK_FACETS_QUERY: &str = "
+    query WorkFacetsQuery(
+        $filter: String,
+        $publishers: [Uuid!],
+        $workType: WorkType,
+        $publicationYear: Int,
+    ) {
+        workFacets(
+            filter: $filter,
+            publishers: $publishers,
+            workType: $workType,
+            publicationYear: $publicationYear,
+        ) {
+            workTypes {
+                workType
+                count
+            }
+            publicationYears {
+                year
+                count
+            }
+        }
+    }
+";
+
+graphql_query_builder! {
+    WorkFacetsRequest,
+    WorkFacetsRequestBody,
+    Variables,
+    WORK_FACETS_QUERY,
+    WorkFacetsResponseBody,
+    WorkFacetsResponseData,
+    FetchWorkFacets,
+    FetchActionWorkFacets
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Variables {
+    pub filter: Option<String>,
+    pub publishers: Option<Vec<String>>,
+    pub work_type: Option<WorkType>,
+    pub publication_year: Option<i32>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkTypeFacet {
+    pub work_type: WorkType,
+    pub count: i32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct PublicationYearFacet {
+    pub year: i32,
+    pub count: i32,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkFacets {
+    pub work_types: Vec<WorkTypeFacet>,
+    pub publication_years: Vec<PublicationYearFacet>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkFacetsResponseData {
+    pub work_facets: WorkFacets,
+}
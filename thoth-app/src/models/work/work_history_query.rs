@@ -0,0 +1,60 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+pub const WORK_HISTORY_QUERY: &str = "
+    query WorkHistoryQuery($workId: Uuid!) {
+        workHistory(workId: $workId) {
+            workHistoryId
+            timestamp
+            editor
+            snapshot
+            changes {
+                field
+                previous
+                replacement
+            }
+        }
+    }
+";
+
+graphql_query_builder! {
+    WorkHistoryRequest,
+    WorkHistoryRequestBody,
+    Variables,
+    WORK_HISTORY_QUERY,
+    WorkHistoryResponseBody,
+    WorkHistoryResponseData,
+    FetchWorkHistory,
+    FetchActionWorkHistory
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Variables {
+    pub work_id: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct FieldChange {
+    pub field: String,
+    pub previous: Option<String>,
+    pub replacement: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkVersion {
+    pub work_history_id: String,
+    // seconds since the Unix epoch
+    pub timestamp: f64,
+    pub editor: String,
+    pub snapshot: String,
+    pub changes: Vec<FieldChange>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkHistoryResponseData {
+    pub work_history: Vec<WorkVersion>,
+}
@@ -0,0 +1,238 @@
+use std::collections::HashMap;
+use std::str::FromStr;
+use thoth_api::identifier::Isbn;
+use thoth_api::publication::model::PublicationType;
+use thoth_api::work::model::WorkStatus;
+use thoth_api::work::model::WorkType;
+
+use super::create_work_mutation::Variables as NewWork;
+use super::create_works_mutation::NewWorkPublication;
+use super::create_works_mutation::WorkImport;
+
+/// Publication types that can be given as `{type}_isbn` and `{type}_url` columns
+const PUBLICATION_TYPES: [PublicationType; 7] = [
+    PublicationType::Paperback,
+    PublicationType::Hardback,
+    PublicationType::PDF,
+    PublicationType::HTML,
+    PublicationType::XML,
+    PublicationType::Epub,
+    PublicationType::Mobi,
+];
+
+/// A spreadsheet row mapped to a work, along with any problems that prevent importing it
+#[derive(Debug, Clone, PartialEq)]
+pub struct ImportRow {
+    /// Row number as shown by spreadsheet software, the header being row 1
+    pub row_number: usize,
+    pub work: WorkImport,
+    pub errors: Vec<String>,
+}
+
+impl ImportRow {
+    pub fn is_valid(&self) -> bool {
+        self.errors.is_empty()
+    }
+}
+
+/// Split CSV text into records of fields, following the quoting rules of RFC 4180
+pub fn parse_csv(text: &str) -> Vec<Vec<String>> {
+    let mut records = vec![];
+    let mut record = vec![];
+    let mut field = String::new();
+    let mut in_quotes = false;
+    let mut chars = text.trim_start_matches('\u{feff}').chars().peekable();
+    while let Some(c) = chars.next() {
+        if in_quotes {
+            match c {
+                '"' if chars.peek() == Some(&'"') => {
+                    field.push('"');
+                    chars.next();
+                }
+                '"' => in_quotes = false,
+                _ => field.push(c),
+            }
+        } else {
+            match c {
+                '"' => in_quotes = true,
+                ',' => record.push(std::mem::take(&mut field)),
+                '\r' => {}
+                '\n' => {
+                    record.push(std::mem::take(&mut field));
+                    records.push(std::mem::take(&mut record));
+                }
+                _ => field.push(c),
+            }
+        }
+    }
+    if !field.is_empty() || !record.is_empty() {
+        record.push(field);
+        records.push(record);
+    }
+    records
+}
+
+/// Map the rows of a CSV file, whose first row names the columns, to works in the given imprint.
+/// Fails if the file has no `title` column.
+pub fn import_rows(text: &str, imprint_id: &str) -> Result<Vec<ImportRow>, String> {
+    let mut records = parse_csv(text).into_iter();
+    let columns: HashMap<String, usize> = records
+        .next()
+        .unwrap_or_default()
+        .iter()
+        .enumerate()
+        .map(|(i, heading)| (column_key(heading), i))
+        .collect();
+    if !columns.contains_key("title") {
+        return Err("The first row of the file must name its columns, including \"title\"".into());
+    }
+    Ok(records
+        .enumerate()
+        .filter(|(_, record)| record.iter().any(|value| !value.trim().is_empty()))
+        .map(|(i, record)| import_row(i + 2, &columns, &record, imprint_id))
+        .collect())
+}
+
+fn import_row(
+    row_number: usize,
+    columns: &HashMap<String, usize>,
+    record: &[String],
+    imprint_id: &str,
+) -> ImportRow {
+    let get = |key: &str| -> Option<String> {
+        columns
+            .get(key)
+            .and_then(|i| record.get(*i))
+            .map(|value| value.trim().to_string())
+            .filter(|value| !value.is_empty())
+    };
+    let mut errors = vec![];
+    let title = get("title").unwrap_or_default();
+    if title.is_empty() {
+        errors.push("Title is required".to_string());
+    }
+    let subtitle = get("subtitle");
+    let full_title = match &subtitle {
+        Some(subtitle) => format!("{}: {}", title, subtitle),
+        None => title.clone(),
+    };
+    let work_type = match get("work_type") {
+        Some(value) => parse_label::<WorkType>(&value).unwrap_or_else(|| {
+            errors.push(format!("{} is not a valid Work Type", value));
+            Default::default()
+        }),
+        None => Default::default(),
+    };
+    let work_status = match get("work_status") {
+        Some(value) => parse_label::<WorkStatus>(&value).unwrap_or_else(|| {
+            errors.push(format!("{} is not a valid Work Status", value));
+            Default::default()
+        }),
+        None => Default::default(),
+    };
+    let edition = match get("edition") {
+        Some(value) => value
+            .parse::<i32>()
+            .ok()
+            .filter(|e| *e > 0)
+            .unwrap_or_else(|| {
+                errors.push(format!("{} is not a valid edition number", value));
+                1
+            }),
+        None => 1,
+    };
+    let copyright_holder = get("copyright_holder").unwrap_or_default();
+    if copyright_holder.is_empty() {
+        errors.push("Copyright holder is required".to_string());
+    }
+    let publication_date = get("publication_date");
+    if let Some(date) = &publication_date {
+        if !is_iso_date(date) {
+            errors.push(format!("{} is not a date in the form YYYY-MM-DD", date));
+        }
+    }
+    let page_count = get("page_count").and_then(|value| match value.parse::<i32>() {
+        Ok(count) => Some(count),
+        Err(_) => {
+            errors.push(format!("{} is not a valid page count", value));
+            None
+        }
+    });
+    let mut publications = vec![];
+    for publication_type in PUBLICATION_TYPES.iter() {
+        let prefix = publication_type.to_string().to_lowercase();
+        let isbn = get(&format!("{}_isbn", prefix));
+        let publication_url = get(&format!("{}_url", prefix));
+        if let Some(isbn) = &isbn {
+            if let Err(e) = isbn.parse::<Isbn>() {
+                errors.push(e.to_string());
+            }
+        }
+        if isbn.is_some() || publication_url.is_some() {
+            publications.push(NewWorkPublication {
+                publication_type: publication_type.clone(),
+                isbn,
+                publication_url,
+            });
+        }
+    }
+    let work = NewWork {
+        work_type,
+        work_status,
+        full_title,
+        title,
+        subtitle,
+        reference: get("reference"),
+        edition,
+        doi: get("doi"),
+        publication_date,
+        place: get("place"),
+        page_count,
+        license: get("license"),
+        copyright_holder,
+        landing_page: get("landing_page"),
+        short_abstract: get("short_abstract"),
+        long_abstract: get("long_abstract"),
+        cover_url: get("cover_url"),
+        imprint_id: imprint_id.to_string(),
+        ..Default::default()
+    };
+    ImportRow {
+        row_number,
+        work: WorkImport { work, publications },
+        errors,
+    }
+}
+
+/// Normalise a column heading, so that e.g. "PDF ISBN" and "pdf_isbn" name the same column
+fn column_key(heading: &str) -> String {
+    heading
+        .trim()
+        .to_lowercase()
+        .replace(|c: char| c == ' ' || c == '-', "_")
+}
+
+/// Parse an enum from its display label, tolerating labels typed in lower case
+fn parse_label<T: FromStr>(value: &str) -> Option<T> {
+    T::from_str(value).ok().or_else(|| {
+        let title_case: Vec<String> = value
+            .split_whitespace()
+            .map(|word| {
+                let mut chars = word.chars();
+                match chars.next() {
+                    Some(first) => first.to_uppercase().chain(chars).collect(),
+                    None => String::new(),
+                }
+            })
+            .collect();
+        T::from_str(&title_case.join(" ")).ok()
+    })
+}
+
+fn is_iso_date(value: &str) -> bool {
+    value.len() == 10
+        && value.char_indices().all(|(i, c)| match i {
+            4 | 7 => c == '-',
+            _ => c.is_ascii_digit(),
+        })
+}
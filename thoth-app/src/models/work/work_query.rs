@@ -1,13 +1,12 @@
 use serde::Deserialize;
 use serde::Serialize;
 
-use super::super::imprint::Imprint;
 use super::Work;
 use super::WorkStatusDefinition;
 use super::WorkTypeDefinition;
 
 pub const WORK_QUERY: &str = "
-    query WorkQuery($workId: Uuid!, $publishers: [Uuid!]) {
+    query WorkQuery($workId: Uuid!) {
         work(workId: $workId) {
             workId
             workType
@@ -18,6 +17,7 @@ pub const WORK_QUERY: &str = "
             reference
             edition
             doi
+            shortDoi
             publicationDate
             place
             width
@@ -38,6 +38,7 @@ pub const WORK_QUERY: &str = "
             toc
             coverUrl
             coverCaption
+            version
             contributions {
                 workId
                 contributorId
@@ -64,6 +65,8 @@ pub const WORK_QUERY: &str = "
                     publicationId
                     currencyCode
                     unitPrice
+                    priceType
+                    territory
                 }
                 work {
                     imprint {
@@ -135,16 +138,6 @@ pub const WORK_QUERY: &str = "
                 }
             }
         }
-        imprints(limit: 9999, publishers: $publishers) {
-            imprintId
-            imprintName
-            publisher {
-                publisherId
-                publisherName
-                publisherShortname
-                publisherUrl
-            }
-        }
         work_types: __type(name: \"WorkType\") {
             enumValues {
                 name
@@ -173,13 +166,11 @@ graphql_query_builder! {
 #[serde(rename_all = "camelCase")]
 pub struct Variables {
     pub work_id: Option<String>,
-    pub publishers: Option<Vec<String>>,
 }
 
 #[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
 pub struct WorkResponseData {
     pub work: Option<Work>,
-    pub imprints: Vec<Imprint>,
     pub work_types: WorkTypeDefinition,
     pub work_statuses: WorkStatusDefinition,
 }
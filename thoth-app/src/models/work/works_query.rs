@@ -1,11 +1,26 @@
 use serde::Deserialize;
 use serde::Serialize;
+use thoth_api::work::model::WorkType;
 
 use super::Work;
 
 pub const WORKS_QUERY: &str = "
-    query WorksQuery($limit: Int, $offset: Int, $filter: String, $publishers: [Uuid!]) {
-        works(limit: $limit, offset: $offset, filter: $filter, publishers: $publishers) {
+    query WorksQuery(
+        $limit: Int,
+        $offset: Int,
+        $filter: String,
+        $publishers: [Uuid!],
+        $workType: WorkType,
+        $publicationYear: Int,
+    ) {
+        works(
+            limit: $limit,
+            offset: $offset,
+            filter: $filter,
+            publishers: $publishers,
+            workType: $workType,
+            publicationYear: $publicationYear,
+        ) {
             workId
             workType
             workStatus
@@ -14,15 +29,25 @@ pub const WORKS_QUERY: &str = "
             edition
             copyrightHolder
             doi
+            shortDoi
             coverUrl
             license
             place
             publicationDate
+            isbns {
+                isbn
+                publicationType
+            }
+            citationApa: citation(style: APA)
+            citationChicago: citation(style: CHICAGO)
+            citationHarvard: citation(style: HARVARD)
+            citationMla: citation(style: MLA)
             contributions {
                 workId
                 contributorId
                 contributionType
                 mainContribution
+                firstName
                 lastName
                 fullName
                 contributor {
@@ -42,7 +67,12 @@ pub const WORKS_QUERY: &str = "
                 }
             }
         }
-        workCount(filter: $filter, publishers: $publishers)
+        workCount: workTotal(
+            filter: $filter,
+            publishers: $publishers,
+            workType: $workType,
+            publicationYear: $publicationYear,
+        )
     }
 ";
 
@@ -64,11 +94,14 @@ pub struct Variables {
     pub offset: Option<i32>,
     pub filter: Option<String>,
     pub publishers: Option<Vec<String>>,
+    pub work_type: Option<WorkType>,
+    pub publication_year: Option<i32>,
 }
 
 #[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct WorksResponseData {
     pub works: Vec<Work>,
+    #[serde(deserialize_with = "crate::models::count_from_digits")]
     pub work_count: i32,
 }
@@ -23,6 +23,8 @@ pub enum AdminRoute {
     Work(String),
     #[to = "/work"]
     NewWork,
+    #[to = "/import"]
+    ImportWorks,
     #[to = "/publishers"]
     Publishers,
     #[to = "/publisher/{id}"]
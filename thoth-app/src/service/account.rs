@@ -14,7 +14,6 @@ use yew::services::fetch::Response;
 use yew::services::storage::Area;
 use yew::services::storage::StorageService;
 
-use crate::string::STORAGE_ERROR;
 use crate::SESSION_KEY;
 
 #[derive(Debug, Error)]
@@ -27,6 +26,8 @@ pub enum AccountError {
 
 const HTTP_UNAUTHORIZED: u16 = 401;
 const HTTP_FORBIDDEN: u16 = 403;
+// Renew the token when it is due to expire within this number of seconds
+const TOKEN_RENEWAL_WINDOW: i64 = 10 * 60;
 
 pub struct AccountService {}
 
@@ -36,7 +37,7 @@ impl AccountService {
     }
 
     pub fn get_token(&self) -> Option<String> {
-        let storage_service = StorageService::new(Area::Local).expect(STORAGE_ERROR);
+        let storage_service = StorageService::new(Area::Local).expect(t!("storage_error"));
         if let Ok(token) = storage_service.restore(SESSION_KEY) {
             Some(token)
         } else {
@@ -49,7 +50,7 @@ impl AccountService {
     }
 
     fn update_storage(&self, token: Option<String>) {
-        let mut storage_service = StorageService::new(Area::Local).expect(STORAGE_ERROR);
+        let mut storage_service = StorageService::new(Area::Local).expect(t!("storage_error"));
         if let Some(t) = token {
             storage_service.store(SESSION_KEY, Ok(t));
         } else {
@@ -65,6 +66,16 @@ impl AccountService {
         self.update_storage(None)
     }
 
+    pub fn token_expires_soon(&self, account_details: &AccountDetails) -> bool {
+        match account_details.token_expiry {
+            Some(expiry) => {
+                let now = (js_sys::Date::now() / 1000.0) as i64;
+                expiry - now <= TOKEN_RENEWAL_WINDOW
+            }
+            None => true,
+        }
+    }
+
     pub fn login(
         &mut self,
         login_credentials: LoginCredentials,
@@ -123,7 +134,7 @@ impl AccountService {
             }
         };
 
-        let url = format!("{}{}", crate::THOTH_API, url);
+        let url = format!("{}{}", crate::api_url(), url);
         let mut builder = Request::builder()
             .method(method)
             .uri(url.as_str())
@@ -0,0 +1,18 @@
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen]
+extern "C" {
+    type Clipboard;
+
+    #[wasm_bindgen(js_namespace = navigator, js_name = clipboard)]
+    static CLIPBOARD: Clipboard;
+
+    #[wasm_bindgen(method, js_name = writeText)]
+    fn write_text(this: &Clipboard, text: &str) -> js_sys::Promise;
+}
+
+/// Put text on the user's clipboard. The browser copies it in the background, and may refuse
+/// to outside of a click or key press.
+pub fn copy_to_clipboard(text: &str) {
+    CLIPBOARD.write_text(text);
+}
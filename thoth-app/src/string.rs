@@ -1,45 +1,121 @@
+/// Tables of the strings shown in the app, one per locale, keyed by name. The English table is
+/// complete and is the fallback for keys missing from other locales; look strings up with `t!`.
 macro_rules! strings {
-    ($($name:ident => $content:expr,)*) => (
-        $(pub const $name: &str = $content;)*
+    ($table:ident { $($key:expr => $content:expr,)* }) => (
+        pub const $table: &[(&str, &str)] = &[$(($key, $content),)*];
     )
 }
 
 strings! {
-    YES => "Yes",
-    NO => "No",
-    INPUT_EMAIL => "Email",
-    INPUT_PASSWORD => "Password",
-    TEXT_LOGIN => "Login",
-    SAVE_BUTTON => "Save",
-    DELETE_BUTTON => "Delete",
-    CANCEL_BUTTON => "Cancel",
-    REMOVE_BUTTON => "Remove",
-    RELOAD_BUTTON => "Reload",
-    NEXT_PAGE_BUTTON => "Next page",
-    PREVIOUS_PAGE_BUTTON => "Previous",
-    PAGINATION_COUNT_FUNDERS => "Displaying funders",
-    PAGINATION_COUNT_WORKS => "Displaying works",
-    PAGINATION_COUNT_SERIESES => "Displaying series",
-    PAGINATION_COUNT_PUBLISHERS => "Displaying publishers",
-    PAGINATION_COUNT_IMPRINTS => "Displaying imprints",
-    PAGINATION_COUNT_CONTRIBUTORS => "Displaying contributors",
-    PAGINATION_COUNT_PUBLICATIONS => "Displaying publications",
-    AUTHENTICATION_ERROR => "Authentication failed",
-    RESPONSE_ERROR => "Failed to obtain a valid response from the server.",
-    EMPTY_CONTRIBUTIONS => "This work does not have any contributions. Search contributors above to add its contributions.",
-    EMPTY_ISSUES => "This work is not part of a series. Search above to add a new series issue.",
-    EMPTY_LANGUAGES => "This work does not have any languages. Search above to add a new language.",
-    EMPTY_PUBLICATIONS => "This work does not have any publications. Click above to add associated publications",
-    EMPTY_SUBJECTS => "This work does not have any subjects. Click above to add associated subjects",
-    EMPTY_FUNDINGS => "This work does not have any funding. Click above to add associated funding",
-    EMPTY_PRICES => "This publication does not have any pricing information. Click above to add prices.",
-    SEARCH_FUNDERS => "Search by name or DOI",
-    SEARCH_WORKS => "Search by title, DOI, internal reference, abstract or landing page",
-    SEARCH_SERIESES => "Search by series name, ISSN or URL",
-    SEARCH_PUBLISHERS => "Search by publisher name or short name",
-    SEARCH_IMPRINTS => "Search by imprint name or URL",
-    SEARCH_CONTRIBUTORS => "Search by name or ORCID",
-    SEARCH_PUBLICATIONS => "Search by ISBN or URL",
-    STORAGE_ERROR => "local storage is disabled",
-    PERMISSIONS_ERROR => "This account is not permitted to access any data",
+    ENGLISH {
+        "yes" => "Yes",
+        "no" => "No",
+        "input_email" => "Email",
+        "input_password" => "Password",
+        "text_login" => "Login",
+        "save_button" => "Save",
+        "delete_button" => "Delete",
+        "cancel_button" => "Cancel",
+        "remove_button" => "Remove",
+        "reload_button" => "Reload",
+        "next_page_button" => "Next page",
+        "previous_page_button" => "Previous",
+        "pagination_count_funders" => "Displaying funders",
+        "pagination_count_works" => "Displaying works",
+        "pagination_count_serieses" => "Displaying series",
+        "pagination_count_publishers" => "Displaying publishers",
+        "pagination_count_imprints" => "Displaying imprints",
+        "pagination_count_contributors" => "Displaying contributors",
+        "pagination_count_publications" => "Displaying publications",
+        "authentication_error" => "Authentication failed",
+        "response_error" => "Failed to obtain a valid response from the server.",
+        "save_error" => "Failed to save",
+        "stale_data_prompt" => "This work has been changed by someone else since you opened it. Reload it to see their changes? Your unsaved edits will be lost.",
+        "permission_error" => "You don't have permission to edit this publisher",
+        "empty_contributions" => "This work does not have any contributions. Search contributors above to add its contributions.",
+        "empty_issues" => "This work is not part of a series. Search above to add a new series issue.",
+        "empty_languages" => "This work does not have any languages. Search above to add a new language.",
+        "empty_publications" => "This work does not have any publications. Click above to add associated publications",
+        "empty_subjects" => "This work does not have any subjects. Click above to add associated subjects",
+        "suggested_keywords" => "Suggested keywords (click to add)",
+        "empty_history" => "This work has not been edited since it was created.",
+        "restore_button" => "Restore this version",
+        "onix_preview_button" => "Preview ONIX",
+        "onix_preview_info" => "Generate the ONIX this work would be exported as, with any problems found in it. Save your changes first to include them.",
+        "onix_preview_error" => "Failed to generate an ONIX preview of this work.",
+        "empty_onix_issues" => "No problems found in this work's ONIX.",
+        "empty_fundings" => "This work does not have any funding. Click above to add associated funding",
+        "empty_prices" => "This publication does not have any pricing information. Click above to add prices.",
+        "search_funders" => "Search by name or DOI",
+        "search_funder_registry" => "Search Funder Registry",
+        "empty_funder_registry" => "No matching funders found in the Crossref Funder Registry",
+        "search_works" => "Search by title, DOI, internal reference, abstract or landing page",
+        "search_serieses" => "Search by series name, ISSN or URL",
+        "search_publishers" => "Search by publisher name or short name",
+        "search_imprints" => "Search by imprint name or URL",
+        "search_contributors" => "Search by name or ORCID",
+        "search_publications" => "Search by ISBN or URL",
+        "storage_error" => "local storage is disabled",
+        "permissions_error" => "This account is not permitted to access any data",
+        "fetch_error" => "Something went wrong while loading the results.",
+        "empty_results" => "No results found.",
+        "more_results" => "More matches not shown: keep typing to narrow the search.",
+    }
+}
+
+strings! {
+    SPANISH {
+        "yes" => "Sí",
+        "no" => "No",
+        "input_email" => "Correo electrónico",
+        "input_password" => "Contraseña",
+        "text_login" => "Iniciar sesión",
+        "save_button" => "Guardar",
+        "delete_button" => "Eliminar",
+        "cancel_button" => "Cancelar",
+        "remove_button" => "Quitar",
+        "reload_button" => "Recargar",
+        "next_page_button" => "Página siguiente",
+        "previous_page_button" => "Anterior",
+        "pagination_count_funders" => "Mostrando financiadores",
+        "pagination_count_works" => "Mostrando obras",
+        "pagination_count_serieses" => "Mostrando colecciones",
+        "pagination_count_publishers" => "Mostrando editoriales",
+        "pagination_count_imprints" => "Mostrando sellos",
+        "pagination_count_contributors" => "Mostrando colaboradores",
+        "pagination_count_publications" => "Mostrando publicaciones",
+        "authentication_error" => "Error de autenticación",
+        "response_error" => "No se ha obtenido una respuesta válida del servidor.",
+        "save_error" => "No se ha podido guardar",
+        "stale_data_prompt" => "Otra persona ha modificado esta obra desde que la abrió. ¿Recargarla para ver sus cambios? Se perderán las modificaciones que no haya guardado.",
+        "permission_error" => "No tiene permiso para editar esta editorial",
+        "empty_contributions" => "Esta obra no tiene colaboraciones. Busque colaboradores arriba para añadir sus colaboraciones.",
+        "empty_issues" => "Esta obra no forma parte de ninguna colección. Busque arriba para añadir un número de colección.",
+        "empty_languages" => "Esta obra no tiene idiomas. Busque arriba para añadir un idioma.",
+        "empty_publications" => "Esta obra no tiene publicaciones. Haga clic arriba para añadir publicaciones asociadas",
+        "empty_subjects" => "Esta obra no tiene materias. Haga clic arriba para añadir materias asociadas",
+        "suggested_keywords" => "Palabras clave sugeridas (haga clic para añadirlas)",
+        "empty_history" => "Esta obra no se ha editado desde que se creó.",
+        "restore_button" => "Restaurar esta versión",
+        "onix_preview_button" => "Previsualizar ONIX",
+        "onix_preview_info" => "Genere el ONIX con el que se exportaría esta obra, con los problemas que se encuentren en él. Guarde antes sus cambios para incluirlos.",
+        "onix_preview_error" => "No se ha podido generar una previsualización ONIX de esta obra.",
+        "empty_onix_issues" => "No se han encontrado problemas en el ONIX de esta obra.",
+        "empty_fundings" => "Esta obra no tiene financiación. Haga clic arriba para añadir financiación asociada",
+        "empty_prices" => "Esta publicación no tiene información de precios. Haga clic arriba para añadir precios.",
+        "search_funders" => "Buscar por nombre o DOI",
+        "search_funder_registry" => "Buscar en el Funder Registry",
+        "empty_funder_registry" => "No se han encontrado financiadores en el Crossref Funder Registry",
+        "search_works" => "Buscar por título, DOI, referencia interna, resumen o página de presentación",
+        "search_serieses" => "Buscar por nombre de colección, ISSN o URL",
+        "search_publishers" => "Buscar por nombre o nombre corto de la editorial",
+        "search_imprints" => "Buscar por nombre de sello o URL",
+        "search_contributors" => "Buscar por nombre u ORCID",
+        "search_publications" => "Buscar por ISBN o URL",
+        "storage_error" => "el almacenamiento local está desactivado",
+        "permissions_error" => "Esta cuenta no tiene permiso para acceder a ningún dato",
+        "fetch_error" => "Se ha producido un error al cargar los resultados.",
+        "empty_results" => "No se han encontrado resultados.",
+        "more_results" => "Hay más coincidencias: siga escribiendo para acotar la búsqueda.",
+    }
 }
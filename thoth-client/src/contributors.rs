@@ -0,0 +1,44 @@
+use graphql_client::{GraphQLQuery, Response};
+use thoth_api::errors::ThothError;
+use uuid::Uuid;
+
+use crate::PAGE_LIMIT;
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "assets/schema.json",
+    query_path = "assets/contributors_query.graphql",
+    response_derives = "Debug"
+)]
+pub struct ContributorsQuery;
+
+/// Get every work of a publisher with the contributors credited on it
+pub async fn get_publisher_contributions(
+    publisher_id: Uuid,
+    work_statuses: Option<Vec<contributors_query::WorkStatus>>,
+    thoth_url: String,
+) -> Result<Vec<contributors_query::ContributorsQueryWorks>, ThothError> {
+    let client = reqwest::Client::new();
+    let mut works = Vec::new();
+    // the same query is sent for every page, only moving on the offset
+    let mut request_body = ContributorsQuery::build_query(contributors_query::Variables {
+        limit: PAGE_LIMIT,
+        offset: 0,
+        publishers: Some(vec![publisher_id]),
+        work_statuses,
+    });
+    loop {
+        request_body.variables.offset = works.len() as i64;
+        let res = client.post(&thoth_url).json(&request_body).send().await?;
+        let response_body: Response<contributors_query::ResponseData> = res.json().await?;
+        match response_body.data {
+            Some(data) => {
+                if data.works.is_empty() {
+                    return Ok(works);
+                }
+                works.extend(data.works);
+            }
+            _ => return Err(ThothError::InternalError("Query failed".to_string())),
+        }
+    }
+}
@@ -0,0 +1,53 @@
+use chrono::naive::NaiveDate;
+use graphql_client::{GraphQLQuery, Response};
+use thoth_api::errors::ThothError;
+use uuid::Uuid;
+
+use crate::work::NaiveDateTime;
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "assets/schema.json",
+    query_path = "assets/feed_query.graphql",
+    response_derives = "Debug"
+)]
+pub struct FeedQuery;
+
+/// Get the most recently published active works, optionally of a single publisher.
+/// Works without a publication date have not been published yet and are left out.
+pub async fn get_feed_works(
+    count: usize,
+    publisher_id: Option<Uuid>,
+    thoth_url: String,
+) -> Result<Vec<feed_query::FeedQueryWorks>, ThothError> {
+    let client = reqwest::Client::new();
+    let page_limit = count.max(1) as i64;
+    let mut offset = 0;
+    let mut works = Vec::new();
+    loop {
+        let request_body = FeedQuery::build_query(feed_query::Variables {
+            limit: page_limit,
+            offset,
+            publishers: publisher_id.map(|id| vec![id]),
+        });
+        let res = client.post(&thoth_url).json(&request_body).send().await?;
+        let response_body: Response<feed_query::ResponseData> = res.json().await?;
+        match response_body.data {
+            Some(data) => {
+                let page_size = data.works.len() as i64;
+                offset += page_size;
+                // Undated works sort before dated ones, so keep paging until enough are found
+                works.extend(
+                    data.works
+                        .into_iter()
+                        .filter(|work| work.publication_date.is_some()),
+                );
+                if works.len() >= count || page_size < page_limit {
+                    works.truncate(count);
+                    return Ok(works);
+                }
+            }
+            _ => return Err(ThothError::InternalError("Query failed".to_string())),
+        }
+    }
+}
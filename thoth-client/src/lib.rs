@@ -1 +1,30 @@
+use serde::Deserialize;
+use serde::Deserializer;
+
+pub mod contributors;
+pub mod counter;
+pub mod feed;
+pub mod oai;
+pub mod sitemap;
 pub mod work;
+
+/// Number of results requested per page when walking through a whole list. Lists are read until
+/// an empty page, as a server with a lower maximum limit returns shorter pages.
+pub const PAGE_LIMIT: i64 = 1000;
+
+/// A number of records, which the API serialises as a string of decimal digits so that it is
+/// not limited to 32 bits
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Count(pub i64);
+
+impl<'de> Deserialize<'de> for Count {
+    fn deserialize<D>(deserializer: D) -> Result<Count, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map(Count)
+            .map_err(serde::de::Error::custom)
+    }
+}
@@ -0,0 +1,63 @@
+use graphql_client::{GraphQLQuery, Response};
+use thoth_api::errors::ThothError;
+use uuid::Uuid;
+
+use crate::work::NaiveDateTime;
+use crate::Count;
+use crate::PAGE_LIMIT;
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "assets/schema.json",
+    query_path = "assets/oai_works_query.graphql",
+    response_derives = "Debug"
+)]
+pub struct OaiWorksQuery;
+
+/// Get a page of work identifiers, oldest update first, with the total number of matching works
+pub async fn get_work_headers(
+    variables: oai_works_query::Variables,
+    thoth_url: String,
+) -> Result<oai_works_query::ResponseData, ThothError> {
+    let request_body = OaiWorksQuery::build_query(variables);
+    let client = reqwest::Client::new();
+    let res = client.post(&thoth_url).json(&request_body).send().await?;
+    let response_body: Response<oai_works_query::ResponseData> = res.json().await?;
+    match response_body.data {
+        Some(data) => Ok(data),
+        _ => Err(ThothError::InternalError("Query failed".to_string())),
+    }
+}
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "assets/schema.json",
+    query_path = "assets/oai_sets_query.graphql",
+    response_derives = "Debug"
+)]
+pub struct OaiSetsQuery;
+
+/// Get every publisher, to be listed as a set
+pub async fn get_sets(
+    thoth_url: String,
+) -> Result<Vec<oai_sets_query::OaiSetsQueryPublishers>, ThothError> {
+    let client = reqwest::Client::new();
+    let mut publishers = Vec::new();
+    loop {
+        let request_body = OaiSetsQuery::build_query(oai_sets_query::Variables {
+            limit: PAGE_LIMIT,
+            offset: publishers.len() as i64,
+        });
+        let res = client.post(&thoth_url).json(&request_body).send().await?;
+        let response_body: Response<oai_sets_query::ResponseData> = res.json().await?;
+        match response_body.data {
+            Some(data) => {
+                if data.publishers.is_empty() {
+                    return Ok(publishers);
+                }
+                publishers.extend(data.publishers);
+            }
+            _ => return Err(ThothError::InternalError("Query failed".to_string())),
+        }
+    }
+}
@@ -0,0 +1,29 @@
+use graphql_client::{GraphQLQuery, Response};
+use thoth_api::errors::ThothError;
+
+use crate::work::NaiveDateTime;
+use crate::Count;
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "assets/schema.json",
+    query_path = "assets/sitemap_query.graphql",
+    response_derives = "Debug"
+)]
+pub struct SitemapQuery;
+
+/// Get a page of active works, with the total number of active works
+pub async fn get_sitemap_page(
+    limit: i64,
+    offset: i64,
+    thoth_url: String,
+) -> Result<sitemap_query::ResponseData, ThothError> {
+    let request_body = SitemapQuery::build_query(sitemap_query::Variables { limit, offset });
+    let client = reqwest::Client::new();
+    let res = client.post(&thoth_url).json(&request_body).send().await?;
+    let response_body: Response<sitemap_query::ResponseData> = res.json().await?;
+    match response_body.data {
+        Some(data) => Ok(data),
+        _ => Err(ThothError::InternalError("Query failed".to_string())),
+    }
+}
@@ -1,10 +1,19 @@
+use std::cmp::Ordering;
 use std::fmt;
 
 use chrono::naive::NaiveDate;
+use futures::stream;
+use futures::Stream;
 use graphql_client::{GraphQLQuery, Response};
 use thoth_api::errors::ThothError;
 use uuid::Uuid;
 
+use crate::Count;
+use crate::PAGE_LIMIT;
+
+/// The API serialises `NaiveDateTime` as a float of seconds since the Unix epoch
+pub type NaiveDateTime = f64;
+
 #[derive(GraphQLQuery)]
 #[graphql(
     schema_path = "assets/schema.json",
@@ -35,35 +44,225 @@ pub async fn get_work(
     }
 }
 
+impl work_query::WorkQueryWork {
+    /// Main contributions in a stable order: earliest added first, ties broken by full name
+    pub fn main_contributions(&self) -> Vec<&work_query::WorkQueryWorkContributions> {
+        let mut contributions: Vec<&work_query::WorkQueryWorkContributions> = self
+            .contributions
+            .iter()
+            .filter(|c| c.main_contribution)
+            .collect();
+        contributions.sort_by(|a, b| {
+            a.created_at
+                .partial_cmp(&b.created_at)
+                .unwrap_or(Ordering::Equal)
+                .then_with(|| a.full_name.cmp(&b.full_name))
+        });
+        contributions
+    }
+
+    /// The contribution to credit when only one can be given, e.g. in a citation key
+    pub fn primary_contribution(&self) -> Option<&work_query::WorkQueryWorkContributions> {
+        self.main_contributions().into_iter().next()
+    }
+
+    /// Where the work's full text can be read: the URL of its PDF publication. Thoth records no
+    /// other location for a work, so works without a PDF URL have no canonical location.
+    pub fn canonical_location(&self) -> Option<&str> {
+        self.publications
+            .iter()
+            .filter(|p| p.publication_type == work_query::PublicationType::PDF)
+            .find_map(|p| p.publication_url.as_deref())
+    }
+}
+
+impl work_query::WorkQueryWorkTitles {
+    /// Whether this is the English distinctive title, which repeats the work's own title
+    pub fn is_default(&self) -> bool {
+        self.language_code == work_query::LanguageCode::ENG
+            && self.title_type == work_query::TitleType::DISTINCTIVE
+    }
+
+    pub fn full_title(&self) -> String {
+        match &self.subtitle {
+            Some(subtitle) => format!("{}: {}", self.title, subtitle),
+            None => self.title.clone(),
+        }
+    }
+}
+
 impl fmt::Display for work_query::LanguageCode {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{:?}", self)
     }
 }
 
+impl fmt::Display for work_query::CurrencyCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
 #[derive(GraphQLQuery)]
 #[graphql(
     schema_path = "assets/schema.json",
-    query_path = "assets/works_query.graphql",
-    response_derives = "Debug"
+    query_path = "assets/work_ids_query.graphql",
+    response_derives = "Debug, Clone"
 )]
-pub struct WorksQuery;
+pub struct WorkIdsQuery;
 
-pub async fn get_works(thoth_url: String) -> Result<Vec<works_query::WorksQueryWorks>, ThothError> {
-    let request_body = WorksQuery::build_query(works_query::Variables);
+/// Get the IDs of every work, or of every work of the given publishers, optionally only those
+/// with one of the given statuses
+pub async fn get_work_ids(
+    publishers: Option<Vec<Uuid>>,
+    work_statuses: Option<Vec<work_ids_query::WorkStatus>>,
+    thoth_url: String,
+) -> Result<Vec<Uuid>, ThothError> {
     let client = reqwest::Client::new();
-    let res = client.post(&thoth_url).json(&request_body).send().await?;
-    let response_body: Response<works_query::ResponseData> = res.json().await?;
-    match response_body.data {
-        Some(data) => {
-            if let Some(errors) = response_body.errors {
-                println!("there are errors:");
-                for error in &errors {
-                    println!("{:?}", error);
+    let mut work_ids = Vec::new();
+    // the same query is sent for every page, only moving on the offset
+    let mut request_body = WorkIdsQuery::build_query(work_ids_query::Variables {
+        limit: PAGE_LIMIT,
+        offset: 0,
+        publishers,
+        work_statuses,
+    });
+    loop {
+        request_body.variables.offset = work_ids.len() as i64;
+        let res = client.post(&thoth_url).json(&request_body).send().await?;
+        let response_body: Response<work_ids_query::ResponseData> = res.json().await?;
+        match response_body.data {
+            Some(data) => {
+                if data.works.is_empty() {
+                    return Ok(work_ids);
                 }
+                work_ids.extend(data.works.into_iter().map(|w| w.work_id));
             }
-            Ok(data.works)
+            _ => return Err(ThothError::InternalError("Query failed".to_string())),
         }
-        _ => Err(ThothError::InternalError("Query failed".to_string())),
     }
 }
+
+/// Options for `stream_works`
+#[derive(Debug, Clone)]
+pub struct StreamParameters {
+    /// Number of works in each page
+    pub page_size: i64,
+    /// Number of works to skip before the first page, e.g. to resume an interrupted run
+    pub offset: i64,
+    /// Statuses of the works to include, or `None` for works of any status
+    pub work_statuses: Option<Vec<work_ids_query::WorkStatus>>,
+}
+
+impl Default for StreamParameters {
+    fn default() -> StreamParameters {
+        StreamParameters {
+            page_size: 100,
+            offset: 0,
+            work_statuses: None,
+        }
+    }
+}
+
+/// A page of works yielded by `stream_works`
+#[derive(Debug)]
+pub struct WorksPage {
+    /// Number of works that came before this page
+    pub offset: i64,
+    /// Number of works in the whole list, for reporting progress
+    pub total: i64,
+    pub works: Vec<work_query::WorkQueryWork>,
+}
+
+/// Get every work, or every work of the given publishers, a page at a time, in order of ID.
+/// Only works with one of `parameters.work_statuses` are included, if given.
+/// Each page is yielded as soon as it has been fetched and is not kept afterwards. If a page
+/// cannot be fetched the error is yielded in its place and the stream ends.
+pub fn stream_works(
+    publishers: Option<Vec<Uuid>>,
+    parameters: StreamParameters,
+    thoth_url: String,
+) -> impl Stream<Item = Result<WorksPage, ThothError>> {
+    let page_size = parameters.page_size.max(1);
+    let work_statuses = parameters.work_statuses;
+    stream::unfold(Some(parameters.offset), move |next_offset| {
+        let publishers = publishers.clone();
+        let work_statuses = work_statuses.clone();
+        let thoth_url = thoth_url.clone();
+        async move {
+            let offset = next_offset?;
+            match get_works_page(publishers, work_statuses, page_size, offset, thoth_url).await {
+                Ok(page) => {
+                    let page_len = page.works.len() as i64;
+                    if page_len == 0 {
+                        return None;
+                    }
+                    // a short page does not mean the end, as the server may return fewer works
+                    // than asked for if its maximum limit is lower than the page size
+                    let next_offset = if offset + page_len >= page.total {
+                        None
+                    } else {
+                        Some(offset + page_len)
+                    };
+                    Some((Ok(page), next_offset))
+                }
+                Err(e) => Some((Err(e), None)),
+            }
+        }
+    })
+}
+
+/// A page of work IDs returned by `get_work_ids_page`
+#[derive(Debug)]
+pub struct WorkIdsPage {
+    /// Number of works in the whole list
+    pub total: i64,
+    pub work_ids: Vec<Uuid>,
+}
+
+/// Get one page of the IDs of every work, or of every work of the given publishers, in order
+/// of ID, optionally only those with one of the given statuses
+pub async fn get_work_ids_page(
+    publishers: Option<Vec<Uuid>>,
+    work_statuses: Option<Vec<work_ids_query::WorkStatus>>,
+    limit: i64,
+    offset: i64,
+    thoth_url: String,
+) -> Result<WorkIdsPage, ThothError> {
+    let request_body = WorkIdsQuery::build_query(work_ids_query::Variables {
+        limit,
+        offset,
+        publishers,
+        work_statuses,
+    });
+    let client = reqwest::Client::new();
+    let res = client.post(&thoth_url).json(&request_body).send().await?;
+    let response_body: Response<work_ids_query::ResponseData> = res.json().await?;
+    let data = response_body
+        .data
+        .ok_or_else(|| ThothError::InternalError("Query failed".to_string()))?;
+    Ok(WorkIdsPage {
+        total: data.work_total.0,
+        work_ids: data.works.into_iter().map(|w| w.work_id).collect(),
+    })
+}
+
+async fn get_works_page(
+    publishers: Option<Vec<Uuid>>,
+    work_statuses: Option<Vec<work_ids_query::WorkStatus>>,
+    limit: i64,
+    offset: i64,
+    thoth_url: String,
+) -> Result<WorksPage, ThothError> {
+    let page =
+        get_work_ids_page(publishers, work_statuses, limit, offset, thoth_url.clone()).await?;
+    let mut works = Vec::with_capacity(page.work_ids.len());
+    for work_id in page.work_ids {
+        works.push(get_work(work_id, thoth_url.clone()).await?);
+    }
+    Ok(WorksPage {
+        offset,
+        total: page.total,
+        works,
+    })
+}